@@ -174,7 +174,13 @@ fn make_task(id: LeafId, title: &str, parent: Option<LeafId>, kind: Kind) -> Tas
         milestone: None,
         memories: Vec::new(),
         due: None,
+        remind_days_before: None,
         parent,
+        order: None,
+        iteration: None,
+        estimate_minutes: None,
+        completed_at_utc: None,
+        pinned: false,
         kind,
         status: Status::Open,
         priority_level: None,
@@ -5,7 +5,7 @@ use clap::Parser;
 use crate::cmd::Commands;
 
 /// Simple, file-backed task manager CLI.
-/// Storage defaults to ./tasks.json or a path passed via --db.
+/// Storage is the nearest `.pm/` workspace, or a path passed via --db.
 #[derive(Parser)]
 #[command(
     name = "pm",
@@ -13,10 +13,45 @@ use crate::cmd::Commands;
     about = "Local-first project management CLI with a hierarchical TUI, agent-ready CLAUDE.md context, artifact tracking, three-tier memory, and an MCP server."
 )]
 pub struct Cli {
-    /// Path to the JSON database file.
+    /// Path to the `.pm/` workspace. Defaults to the nearest `.pm/` at or
+    /// above the current directory, then `~/.pm/`.
     #[arg(long, global = true)]
     pub db: Option<PathBuf>,
 
+    /// Use this project's file as the database, found by name among the
+    /// `<name>_tasks.json` projects in the workspace; a unique prefix will
+    /// do. Give it before the command (`pm --project web list`): after it,
+    /// `--project` is the command's own filter. `--db` wins over it.
+    #[arg(long, value_name = "NAME")]
+    pub project: Option<String>,
+
+    /// Skip the automatic backup taken before destructive bulk commands:
+    /// `import`, `delete` of more than one task, `replace`, `dedupe` and
+    /// `restore`.
+    #[arg(long, global = true)]
+    pub no_backup: bool,
+
+    /// Print only errors and warnings, dropping confirmations such as
+    /// "Added task TSK7". Output a command exists to produce, like
+    /// `pm list`, is still printed.
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Print extra detail on stderr: the workspace used and how it was
+    /// found, and the task each id or name argument resolved to.
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
+
+    /// Show due dates as "in 3d" or "2d late" in tables and `pm view`,
+    /// whatever `output.dates` in `config.json` says.
+    #[arg(long, global = true, conflicts_with = "iso_dates")]
+    pub relative_dates: bool,
+
+    /// Show due dates as YYYY-MM-DD in tables and `pm view`. JSON, CSV and
+    /// `--format plain` always do.
+    #[arg(long, global = true)]
+    pub iso_dates: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -4,7 +4,7 @@
 //! subcommands available in the CLI, from basic CRUD operations to complex
 //! hierarchical queries and the TUI interface.
 
-use clap::Subcommand;
+use clap::{Args, Subcommand};
 use clap_complete::{generate, Shell};
 
 use crate::db::*;
@@ -14,15 +14,20 @@ use crate::memory::store::MemoryContext;
 use crate::memory::{
     lookup_by_name, promote_memory, write_memory, MemoryFile, MemoryHit, MemoryType, Scope,
 };
+use crate::output::{date_style, quiet, Progress};
+use crate::say;
 use crate::store::front_matter::MemoryRef;
 use crate::store::id::{IdInput, LeafId};
 use crate::store::migrate::kind_to_prefix;
-use crate::task::{Task, TaskTemplate};
+use crate::store::{taskwarrior, Config, DateStyle, TaskBundle};
+use crate::task::{fill_placeholders, Task, TaskTemplate, TemplateValues};
+use crate::tui::app::AppExit;
+use crate::tui::enums::ViewHandoff;
 use crate::tui::menu::MenuApp;
 use crate::tui::run::{run_activity_view, run_tui, run_tui_with_edit};
 use crate::tui::workflow::WorkflowExit;
 use crate::tui::workflow_run::run_workflow_tui;
-use chrono::{Local, NaiveDate, TimeZone, Utc};
+use chrono::{Datelike, Local, NaiveDate, TimeZone, Utc};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -30,92 +35,44 @@ use std::path::{Path, PathBuf};
 #[derive(Subcommand)]
 pub enum Commands {
     /// Launch the interactive UI interface.
-    Ui,
+    Ui {
+        /// Hide the header banner to save rows on short terminals or for
+        /// screen readers. Also settable as `ui.compact` in `.pm/config.json`.
+        #[arg(long)]
+        compact: bool,
+    },
 
     /// Launch the workflow kanban board interface.
-    Wf,
-
-    /// Add a new task.
-    Add {
-        /// Short title for the task.
-        title: String,
-        /// Use a template for default values.
-        #[arg(long)]
-        template: Option<String>,
-        /// Optional longer description.
-        #[arg(long)]
-        desc: Option<String>,
-        /// Comma-separated tags. May be repeated.
-        #[arg(long = "tag")]
-        tags: Vec<String>,
-        /// Due date: YYYY-MM-DD, "today", "tomorrow", or "in Nd".
-        #[arg(long)]
-        due: Option<String>,
-        /// Parent task ID or name.
-        #[arg(long)]
-        parent: Option<String>,
-        /// Item kind: product | epic | task | subtask | milestone.
-        #[arg(long, value_enum, default_value_t = Kind::Task)]
-        kind: Kind,
-        /// Priority level: must-have | nice-to-have | cut-first.
-        #[arg(long, value_enum)]
-        priority_level: Option<Priority>,
-        /// Urgency: urgent-important | urgent-not-important | not-urgent-important | not-urgent-not-important.
-        #[arg(long, value_enum)]
-        urgency: Option<Urgency>,
-        /// Process stage: ideation | design | prototyping | implementation | testing | refinement | release.
-        #[arg(long, value_enum)]
-        process_stage: Option<ProcessStage>,
-        /// Issue link (URL).
-        #[arg(long)]
-        issue_link: Option<String>,
-        /// PR link (URL).
+    Wf {
+        /// Hide the header banner (see `pm ui --compact`).
         #[arg(long)]
-        pr_link: Option<String>,
-        /// Summary (one-line description).
-        #[arg(long)]
-        summary: Option<String>,
-        /// User story.
-        #[arg(long)]
-        user_story: Option<String>,
-        /// Requirements specification.
-        #[arg(long)]
-        requirements: Option<String>,
-        /// Artifacts (file paths, comma-separated).
-        #[arg(long)]
-        artifacts: Vec<String>,
-        /// Status: open | in-progress | done.
-        #[arg(long, value_enum, default_value_t = Status::Open)]
-        status: Status,
+        compact: bool,
+        /// Board columns for this session, overriding any project's
+        /// `stage_columns`: `TITLE=STAGE,...` per column, separated by `;`.
+        /// `unassigned` stands for no stage; every stage must appear once.
+        #[arg(long, value_name = "SPEC")]
+        stage_columns: Option<String>,
     },
 
+    /// Add a new task.
+    Add(AddOptions),
+
     /// List tasks with optional filters.
-    List {
-        /// Include completed tasks.
-        #[arg(long)]
-        all: bool,
-        /// Filter by status.
-        #[arg(long, value_enum)]
-        status: Option<Status>,
-        /// Filter by kind.
-        #[arg(long, value_enum)]
-        kind: Option<Kind>,
-        /// Filter by project.
+    List(ListOptions),
+
+    /// List open tasks with no parent or project, ready to be filed.
+    Inbox {
+        /// Walk each inbox task, prompting for an epic (or other parent) to file
+        /// it under.
         #[arg(long)]
-        project: Option<String>,
+        triage: bool,
         /// Filter by tag. May be repeated. Accepts comma-separated.
         #[arg(long = "tag")]
         tags: Vec<String>,
-        /// Due filter: today | this-week | overdue | none.
-        #[arg(long, value_enum)]
-        due: Option<DueFilter>,
-        /// Render as a tree across parent-child relationships.
-        #[arg(long)]
-        tree: bool,
-        /// Sort key.
+        /// Sort key: due | priority | id | created | updated (the last two newest first).
         #[arg(long, value_enum, default_value_t = SortKey::Due)]
         sort: SortKey,
-        /// Limit number of rows printed.
+        /// Limit number of tasks listed or triaged.
         #[arg(long)]
         limit: Option<usize>,
     },
@@ -130,6 +87,11 @@ pub enum Commands {
         /// Show ancestor chain.
         #[arg(long)]
         parents: bool,
+        /// List the artifact paths as absolute paths, flagging any that no
+        /// longer exist. File names are taken from the ticket's `artifacts/`
+        /// directory.
+        #[arg(long)]
+        artifacts: bool,
     },
 
     /// Update fields on a task.
@@ -142,6 +104,15 @@ pub enum Commands {
         desc: Option<String>,
         #[arg(long)]
         due: Option<String>,
+        /// Flag the task as upcoming this many days before it is due.
+        #[arg(long, value_name = "DAYS", conflicts_with = "clear_remind")]
+        remind: Option<u32>,
+        /// Plan the task into this sprint or iteration.
+        #[arg(long, value_name = "NAME", conflicts_with = "clear_iteration")]
+        iteration: Option<String>,
+        /// Estimated effort: minutes, or hours and minutes like 1h30m.
+        #[arg(long, value_name = "DURATION", conflicts_with = "clear_estimate")]
+        estimate: Option<String>,
         /// Parent task ID or name.
         #[arg(long)]
         parent: Option<String>,
@@ -149,6 +120,10 @@ pub enum Commands {
         kind: Option<Kind>,
         #[arg(long, value_enum)]
         status: Option<Status>,
+        /// Process stage: ideation | design | prototyping | implementation | testing | refinement | release.
+        /// Overrides the `workflow.stage_on_status` move for a `--status` change.
+        #[arg(long, value_enum)]
+        process_stage: Option<ProcessStage>,
         /// Add tags. May be repeated and comma-separated.
         #[arg(long = "add-tag")]
         add_tags: Vec<String>,
@@ -158,11 +133,52 @@ pub enum Commands {
         /// Clear due date.
         #[arg(long)]
         clear_due: bool,
+        /// Clear the reminder lead time.
+        #[arg(long)]
+        clear_remind: bool,
+        /// Take the task out of its iteration.
+        #[arg(long)]
+        clear_iteration: bool,
+        /// Clear the estimate.
+        #[arg(long)]
+        clear_estimate: bool,
         /// Clear parent.
         #[arg(long)]
         clear_parent: bool,
     },
 
+    /// Replace text across tasks, e.g. `pm replace login auth` to rename a
+    /// concept everywhere. Snapshots the workspace first unless `--no-backup`.
+    /// A title is never replaced by nothing; that task is reported instead.
+    Replace {
+        /// Text to find; a regular expression with `--regex`.
+        from: String,
+        /// Replacement. With `--regex`, `$1` and `${name}` insert groups.
+        to: String,
+        /// Field to rewrite: title | description | tags. May be repeated.
+        /// Defaults to all three.
+        #[arg(long = "field", value_enum)]
+        fields: Vec<ReplaceField>,
+        /// Treat `from` as a regular expression.
+        #[arg(long)]
+        regex: bool,
+        /// Show each change without saving anything.
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Find tasks that look like duplicates: same parent, same kind and
+    /// the same title. Lists each group, then asks which task of it to
+    /// keep; the others' children move under that one and the others are
+    /// deleted. Snapshots the workspace before the first merge unless
+    /// `--no-backup`.
+    Dedupe {
+        /// Also group near-identical titles: case, punctuation and about
+        /// one typo per five characters are ignored.
+        #[arg(long)]
+        fuzzy: bool,
+    },
+
     /// Mark a task done.
     Complete {
         /// Task ID or name to complete (mutually exclusive with bulk options)
@@ -179,6 +195,13 @@ pub enum Commands {
         /// Complete all tasks with this status
         #[arg(long, value_enum)]
         status: Option<Status>,
+        /// Record the completion at this time instead of now: YYYY-MM-DD,
+        /// "yesterday", "YYYY-MM-DD HH:MM" (local) or RFC 3339.
+        #[arg(long, value_name = "DATE|DATETIME")]
+        at: Option<String>,
+        /// Allow `--at` to lie in the future.
+        #[arg(long, requires = "at")]
+        force: bool,
     },
 
     /// Reopen a task (status open).
@@ -187,7 +210,13 @@ pub enum Commands {
         id: String,
     },
 
-    /// Delete a task by ID or name.
+    /// Delete a task by ID or name. Deleting more than one task (a bulk
+    /// option or `--cascade`) takes a snapshot first, unless `--no-backup`.
+    ///
+    /// A `--tag`, `--project` or `--status` sweep takes each matching task
+    /// with its whole subtree, so nothing is left behind without its parent.
+    /// It lists every task it will remove first, and asks before removing
+    /// more than ten unless `--yes` is given.
     Delete {
         /// Task ID or name to delete (mutually exclusive with bulk options)
         id: Option<String>,
@@ -203,13 +232,108 @@ pub enum Commands {
         /// Delete all tasks with this status
         #[arg(long, value_enum)]
         status: Option<Status>,
+        /// Skip the confirmation a large bulk delete asks for.
+        #[arg(long, short = 'y')]
+        yes: bool,
     },
 
-    /// List distinct projects.
+    /// Count this database's tasks by the Project ticket above them. With
+    /// the global `-v`, also list each Project ticket with the add defaults
+    /// configured for it in `.pm/config.json`, and the project files in the
+    /// workspace (the ones `pm menu` and `pm --project` pick from) with
+    /// their task counts and paths.
     Projects,
 
     /// List distinct tags and counts.
-    Tags,
+    Tags {
+        /// Group dotted tags (`area.backend`) under their prefixes, with the
+        /// number of tasks at or below each node.
+        #[arg(long)]
+        tree: bool,
+    },
+
+    /// Count tasks grouped by one field, largest group first. Takes the
+    /// `pm list` filters; closed tasks are left out unless `--all` or
+    /// `--status` says otherwise.
+    Count {
+        /// Field to group on: status | kind | project | process-stage | tag |
+        /// assignee. With `tag` a task counts once per tag.
+        #[arg(long, value_enum)]
+        by: CountBy,
+        /// Include completed tasks.
+        #[arg(long)]
+        all: bool,
+        /// Filter by status. Completed tasks match without `--all`.
+        #[arg(long, value_enum)]
+        status: Option<Status>,
+        /// Filter by kind.
+        #[arg(long, value_enum)]
+        kind: Option<Kind>,
+        /// Filter by project.
+        #[arg(long)]
+        project: Option<String>,
+        /// Filter by tag. May be repeated. Accepts comma-separated.
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+        /// Treat `--tag` as a dotted prefix, as in `pm list`.
+        #[arg(long)]
+        expand_tags: bool,
+        /// Due filter: today | this-week | overdue | upcoming | none.
+        #[arg(long, value_enum)]
+        due: Option<DueFilter>,
+        /// Only tasks planned into this sprint or iteration.
+        #[arg(long, value_name = "NAME")]
+        iteration: Option<String>,
+        /// Print a JSON array of `{"value", "count"}` objects.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Summarise a sprint or iteration: committed vs completed tasks and the
+    /// work still open. Without a name, list every iteration.
+    Iteration {
+        /// Iteration name, e.g. 2024-S3.
+        name: Option<String>,
+    },
+
+    /// List milestones by date, each with how many tasks due before it are
+    /// done: `5/8 done before 'Beta Launch'`.
+    Milestones,
+
+    /// Remaining estimated work per day, for charting a burndown. Each day
+    /// is the estimate still open at its end; only estimated tasks count.
+    Burndown {
+        /// Only tasks planned into this sprint or iteration.
+        #[arg(long, value_name = "NAME")]
+        iteration: Option<String>,
+        /// Output format: csv | json.
+        #[arg(long, value_enum, default_value_t = ChartFormat::Csv)]
+        format: ChartFormat,
+        /// Write to this file instead of stdout.
+        #[arg(long, short)]
+        output: Option<String>,
+    },
+
+    /// Throughput reports. `--velocity` charts the tasks completed in each
+    /// ISO week, empty weeks included, and the weekly average.
+    Stats {
+        /// Tasks completed per ISO week, by completion time.
+        #[arg(long)]
+        velocity: bool,
+        /// Number of weeks to report, ending with the current one (1-520).
+        #[arg(
+            long,
+            default_value_t = 8,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=520)
+        )]
+        weeks: usize,
+        /// Only tasks under this project.
+        #[arg(long)]
+        project: Option<String>,
+        /// Only tasks planned into this sprint or iteration.
+        #[arg(long, value_name = "NAME")]
+        iteration: Option<String>,
+    },
 
     /// Generate shell completion scripts.
     Completions {
@@ -218,17 +342,33 @@ pub enum Commands {
         shell: Shell,
     },
 
+    /// Print every TUI keybinding and CLI subcommand as markdown, for man
+    /// pages and docs.
+    #[command(hide = true)]
+    HelpDump,
+
     /// Manage task templates.
     Template {
         #[command(subcommand)]
         action: TemplateAction,
     },
 
-    /// Export tasks to CSV format.
+    /// Export tasks to CSV, a JSON task bundle or Taskwarrior JSON.
     Export {
-        /// Output file path (default: tasks.csv)
+        /// Output file path (default: tasks.csv, tasks.json or
+        /// taskwarrior.json)
         #[arg(long, short)]
         output: Option<String>,
+        /// Export only this ticket (the status, project and tag filters do
+        /// not apply).
+        #[arg(long, conflicts_with_all = ["all_projects", "project", "tag"])]
+        id: Option<String>,
+        /// With `--id`, also export every descendant.
+        #[arg(long, requires = "id")]
+        recurse: bool,
+        /// Output format: csv | json | taskwarrior.
+        #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+        format: ExportFormat,
         /// Include completed tasks
         #[arg(long)]
         all: bool,
@@ -241,15 +381,39 @@ pub enum Commands {
         /// Filter by tag
         #[arg(long)]
         tag: Option<String>,
+        /// Export only the task templates, as a JSON array that `pm import
+        /// --templates` reads. Requires `--format json`.
+        #[arg(long, conflicts_with_all = ["id", "all", "all_projects", "project", "tag"])]
+        templates_only: bool,
     },
 
-    /// Import tasks from CSV format.
+    /// Import tasks from CSV, a JSON task bundle (`pm export --format json`)
+    /// or a Taskwarrior export (`task export`).
     Import {
-        /// Input CSV file path
+        /// Input file path; a JSON array is read as a Taskwarrior export,
+        /// other `.json` files as task bundles
         input: String,
-        /// Skip creating backup before import
+        /// Update tasks whose ID column matches an existing task instead of
+        /// skipping them; rows that match nothing are created as usual.
+        /// CSV only.
         #[arg(long)]
-        no_backup: bool,
+        update: bool,
+        /// Read the file as task templates (`pm export --templates-only`)
+        /// and merge them by name. Templates whose name is already in use
+        /// are skipped.
+        #[arg(long, conflicts_with = "update")]
+        templates: bool,
+        /// With `--templates`, replace existing templates of the same name
+        /// instead of skipping them.
+        #[arg(long, requires = "templates")]
+        overwrite: bool,
+    },
+
+    /// Check a CSV or JSON import file against the current workspace and
+    /// report problems line by line, without importing anything.
+    ValidateImport {
+        /// File to check; `.json` files are read as task bundles
+        input: String,
     },
 
     /// Create timestamped backup of current project or all projects.
@@ -259,6 +423,41 @@ pub enum Commands {
         all: bool,
     },
 
+    /// Save a named checkpoint of the workspace to `.pm/snapshots/<name>.json`.
+    Snapshot {
+        /// Snapshot name (letters, digits, '.', '-', '_').
+        #[arg(required_unless_present = "list")]
+        name: Option<String>,
+        /// List existing snapshots instead of creating one.
+        #[arg(long)]
+        list: bool,
+        /// Overwrite an existing snapshot with the same name.
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Replace the workspace with a named snapshot. The current state is
+    /// saved as an automatic snapshot first, unless `--no-backup`.
+    Restore {
+        /// Snapshot name to restore.
+        name: String,
+    },
+
+    /// Show how a task changed across the timestamped files in `backup/`
+    /// and the named snapshots, oldest first.
+    History {
+        /// Task id (deleted tasks are accepted too).
+        id: String,
+    },
+
+    /// Compare a backup or snapshot with the current workspace: tasks
+    /// added, removed, and changed field by field.
+    Diff {
+        /// Snapshot name or backup file name, or a unique prefix of one
+        /// such as a backup's date (`2025-01-02` or `2025-01-02 09:00`).
+        reference: String,
+    },
+
     /// Open project main menu (interactive mode).
     Menu,
 
@@ -383,6 +582,30 @@ pub enum Commands {
         milestone_id: String,
     },
 
+    /// Pin a ticket into the working set: listed first under any sort and
+    /// marked with a star.
+    Pin {
+        /// Ticket id.
+        id: String,
+    },
+
+    /// Take a ticket out of the working set.
+    Unpin {
+        /// Ticket id.
+        id: String,
+    },
+
+    /// Focus on one subtree: `pm list`, `pm ui` and `pm wf` show only the
+    /// ticket and its descendants, and `pm add` files new tickets under it.
+    /// Without arguments, show the current focus.
+    Focus {
+        /// Ticket id or title to focus on.
+        id: Option<String>,
+        /// Leave focus and show the whole workspace again.
+        #[arg(long, conflicts_with = "id")]
+        clear: bool,
+    },
+
     // ----- v2 views and maintenance -----
     /// Rebuild state.json from the on-disk tree. Pass `--migrate` to import a
     /// legacy `tasks.json` archive into the workspace via the bridge.
@@ -392,6 +615,17 @@ pub enum Commands {
         migrate: bool,
     },
 
+    /// Turn the legacy `tasks.json` workspace (the menu's "Default
+    /// (Legacy)") into a named project, keeping every task and template.
+    /// The original is kept under `.legacy-backup/`. Running it again
+    /// finishes an interrupted migration, and does nothing once there is
+    /// nothing left to migrate.
+    MigrateLegacy {
+        /// Name of the project to create.
+        #[arg(long, default_value = "default")]
+        name: String,
+    },
+
     /// Search CLAUDE.md content across the workspace.
     Search {
         /// Substring or regex pattern.
@@ -464,6 +698,218 @@ pub enum Commands {
     /// Run the stdio MCP server. Exposes the 14-tool surface over JSON-RPC
     /// 2.0; see docs/mcp.md for the catalogue. Runs until stdin closes.
     Mcp,
+
+    /// Serve tasks as JSON over HTTP for editor plugins and dashboards:
+    /// GET /tasks, /tasks/{id} and /projects; POST /tasks and
+    /// /tasks/{id}/complete. Needs a build with `--features server`.
+    Serve {
+        /// Port to listen on; 0 picks a free one.
+        #[arg(long, default_value_t = 7878)]
+        port: u16,
+        /// Address to bind. Anything other than localhost exposes the
+        /// workspace, unauthenticated, to the network.
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+    },
+}
+
+/// Arguments to `pm add`, passed whole to [`cmd_add`].
+#[derive(Args)]
+pub struct AddOptions {
+    /// Short title for the task.
+    #[arg(required_unless_present = "stdin")]
+    pub title: Option<String>,
+    /// Read titles from stdin, one task per non-empty line, ignoring the
+    /// positional title. The other flags apply to every task; inline
+    /// `#tag` and `!must-have` words add tags and set priority per line.
+    #[arg(long)]
+    pub stdin: bool,
+    /// Print only the new id on stdout, one per line with `--stdin`, for
+    /// `TASK=$(pm add "x" --print-id)`. Confirmations move to stderr.
+    #[arg(long)]
+    pub print_id: bool,
+    /// Use a template for default values.
+    #[arg(long)]
+    pub template: Option<String>,
+    /// Optional longer description.
+    #[arg(long)]
+    pub desc: Option<String>,
+    /// Comma-separated tags. May be repeated. With `--template` they are
+    /// added to the template's tags.
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+    /// With `--template`, use the `--tag` tags instead of the template's
+    /// rather than adding to them.
+    #[arg(long, requires = "template")]
+    pub replace_tags: bool,
+    /// Due date: YYYY-MM-DD, "today", "tomorrow", or "in Nd".
+    #[arg(long)]
+    pub due: Option<String>,
+    /// Flag the task as upcoming this many days before it is due.
+    #[arg(long, value_name = "DAYS")]
+    pub remind: Option<u32>,
+    /// Sprint or iteration to plan the task into, e.g. 2024-S3.
+    #[arg(long, value_name = "NAME")]
+    pub iteration: Option<String>,
+    /// Estimated effort: minutes, or hours and minutes like 1h30m.
+    #[arg(long, value_name = "DURATION")]
+    pub estimate: Option<String>,
+    /// Parent task ID or name.
+    #[arg(long)]
+    pub parent: Option<String>,
+    /// Create the parent, one level above the new item, if `--parent`
+    /// doesn't match an existing task.
+    #[arg(long, requires = "parent")]
+    pub create_parent: bool,
+    /// Insert directly after this sibling, under the same parent.
+    #[arg(long, value_name = "ID", conflicts_with_all = ["before", "parent"])]
+    pub after: Option<String>,
+    /// Insert directly before this sibling, under the same parent.
+    #[arg(long, value_name = "ID", conflicts_with = "parent")]
+    pub before: Option<String>,
+    /// Item kind: product | epic | task | subtask | milestone. Defaults to
+    /// the `--after`/`--before` task's kind, then the template's kind,
+    /// then the project default, then task.
+    #[arg(long, value_enum)]
+    pub kind: Option<Kind>,
+    /// Priority level: must-have | nice-to-have | cut-first.
+    #[arg(long, value_enum)]
+    pub priority_level: Option<Priority>,
+    /// Urgency: urgent-important | urgent-not-important | not-urgent-important | not-urgent-not-important.
+    #[arg(long, value_enum)]
+    pub urgency: Option<Urgency>,
+    /// Process stage: ideation | design | prototyping | implementation | testing | refinement | release.
+    #[arg(long, value_enum)]
+    pub process_stage: Option<ProcessStage>,
+    /// Issue link (URL).
+    #[arg(long)]
+    pub issue_link: Option<String>,
+    /// PR link (URL).
+    #[arg(long)]
+    pub pr_link: Option<String>,
+    /// Summary (one-line description).
+    #[arg(long)]
+    pub summary: Option<String>,
+    /// User story.
+    #[arg(long)]
+    pub user_story: Option<String>,
+    /// Requirements specification.
+    #[arg(long)]
+    pub requirements: Option<String>,
+    /// Artifacts (file paths, comma-separated).
+    #[arg(long)]
+    pub artifacts: Vec<String>,
+    /// Status: open | in-progress | done | blocked | cancelled.
+    #[arg(long, value_enum, default_value_t = Status::Open)]
+    pub status: Status,
+}
+
+/// Arguments to `pm list`, passed whole to [`cmd_list`].
+#[derive(Args)]
+pub struct ListOptions {
+    /// Include completed tasks.
+    #[arg(long)]
+    pub all: bool,
+    /// Filter by status. Completed tasks match without `--all`.
+    #[arg(long, value_enum)]
+    pub status: Option<Status>,
+    /// Only completed tasks; overrides `--all`.
+    #[arg(long, visible_alias = "completed", conflicts_with = "status")]
+    pub done_only: bool,
+    /// Filter by kind.
+    #[arg(long, value_enum)]
+    pub kind: Option<Kind>,
+    /// Filter by project.
+    #[arg(long)]
+    pub project: Option<String>,
+    /// Filter by tag. May be repeated. Accepts comma-separated.
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+    /// Treat `--tag` as a dotted prefix: `--tag area` also matches
+    /// `area.backend`. Exact matching is the default.
+    #[arg(long)]
+    pub expand_tags: bool,
+    /// Due filter: today | this-week | overdue | upcoming | none.
+    /// `upcoming` is a task inside its `--remind` window, not yet due.
+    #[arg(long, value_enum)]
+    pub due: Option<DueFilter>,
+    /// Only tasks overdue by at least this many days.
+    #[arg(long, value_name = "MIN_DAYS")]
+    pub overdue_by: Option<u32>,
+    /// Only tasks due within the next this-many days (today included).
+    /// Combined with `--overdue-by`, a task matching either is kept.
+    #[arg(long, value_name = "MAX_DAYS")]
+    pub due_in: Option<u32>,
+    /// Only tasks planned into this sprint or iteration.
+    #[arg(long, value_name = "NAME")]
+    pub iteration: Option<String>,
+    /// Only tasks whose parent has this status, e.g. `done` for
+    /// leftover children. Tasks without a parent are left out.
+    #[arg(long, value_enum, value_name = "STATUS")]
+    pub parent_status: Option<Status>,
+    /// With `--parent-status`, also keep tasks that have no parent.
+    #[arg(long, requires = "parent_status")]
+    pub include_rootless: bool,
+    /// Only pinned tasks (`pm pin`).
+    #[arg(long)]
+    pub pinned: bool,
+    /// Only tasks with no value for this field: due, description,
+    /// summary, user-story, requirements, tags, parent, priority,
+    /// urgency, stage, estimate, iteration, milestone, deps, issue-link,
+    /// pr-link, artifacts or assignee. Blank text counts as missing.
+    /// May be repeated or comma-separated; every field must be missing.
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "FIELD")]
+    pub missing: Vec<PresenceField>,
+    /// Only tasks with a value for this field, e.g. `--has issue-link`.
+    /// Takes the same fields as `--missing`; all must be present.
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "FIELD")]
+    pub has: Vec<PresenceField>,
+    /// Only the ancestors of this task (ID or name), root first, closed ones
+    /// included without `--all`. Overrides `--sort`.
+    #[arg(long, value_name = "ID", group = "relation")]
+    pub ancestors_of: Option<String>,
+    /// Only the direct children of this task (ID or name).
+    #[arg(long, value_name = "ID", group = "relation")]
+    pub children_of: Option<String>,
+    /// Only the tasks anywhere below this task (ID or name).
+    #[arg(long, value_name = "ID", group = "relation")]
+    pub descendants_of: Option<String>,
+    /// Render as a tree across parent-child relationships.
+    #[arg(long)]
+    pub tree: bool,
+    /// Order rows as a tree walk (each parent followed by its subtree)
+    /// but print them unindented. Overrides `--sort`.
+    #[arg(long, conflicts_with = "tree")]
+    pub flat_tree: bool,
+    /// Add a column with each task's ancestor titles ("Epic > Task").
+    #[arg(long)]
+    pub breadcrumb: bool,
+    /// Columns to print, in order: id, kind, status, priority, urgency,
+    /// stage, due, project, parents, assignee, created, updated, tags,
+    /// title. Defaults to id,kind,status,priority,due,project,title.
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "FIELDS")]
+    pub fields: Vec<ListColumn>,
+    /// Output style: table, or plain for tab-separated, untruncated
+    /// fields in `--fields` order.
+    #[arg(long, value_enum, default_value_t = ListFormat::Table)]
+    pub format: ListFormat,
+    /// Omit the header row.
+    #[arg(long)]
+    pub no_header: bool,
+    /// Sort keys, comma-separated and applied in order: due | priority |
+    /// id | created | updated (the last two newest first). A leading `-`
+    /// reverses a key, e.g. `priority,-due,id`.
+    #[arg(long, default_value = "due", allow_hyphen_values = true)]
+    pub sort: SortSpec,
+    /// Limit number of rows printed.
+    #[arg(long)]
+    pub limit: Option<usize>,
+    /// Skip this many rows, after filtering and sorting, before
+    /// `--limit` applies: `--offset 20 --limit 20` is the second page of
+    /// twenty. With either flag, a footer gives the rows shown and the
+    /// total, on stderr with `--format plain`; `--no-header` drops it.
+    #[arg(long, default_value_t = 0)]
+    pub offset: usize,
 }
 
 #[derive(Subcommand)]
@@ -588,12 +1034,20 @@ pub enum TemplateAction {
     Create {
         /// Template name
         name: String,
-        /// Title template (can include {title} placeholder)
+        /// Title template, e.g. "[BUG] {title}". May use {title}, {date}
+        /// and {project}; without {title} it is ignored.
         #[arg(long)]
         title_template: Option<String>,
-        /// Description template
+        /// Description template; may use {title}, {date} and {project}
         #[arg(long)]
         description: Option<String>,
+        /// User story seed shown in the fullscreen user-story editor
+        #[arg(long)]
+        user_story: Option<String>,
+        /// Requirements seed, e.g. an acceptance-criteria checklist
+        /// ("- [ ] ..." lines). Use `\n` for line breaks.
+        #[arg(long)]
+        requirements: Option<String>,
         /// Default tags (comma-separated)
         #[arg(long)]
         tags: Option<String>,
@@ -616,37 +1070,129 @@ pub enum TemplateAction {
 }
 
 /// Launch the terminal user interface.
-pub fn cmd_ui(db_path: &Path) {
-    if let Err(e) = run_tui(db_path) {
-        eprintln!("UI error: {e}");
-        std::process::exit(1);
-    }
+pub fn cmd_ui(db_path: &Path, compact: bool) {
+    run_views(db_path, compact, None, TuiView::List(None));
 }
 
 /// Add a new task to the database.
-pub fn cmd_add(
-    db: &mut Database,
-    db_path: &Path,
-    title: String,
-    template: Option<String>,
-    desc: Option<String>,
-    tags: Vec<String>,
-    due: Option<String>,
-    parent: Option<String>,
-    kind: Kind,
-    priority_level: Option<Priority>,
-    urgency: Option<Urgency>,
-    process_stage: Option<ProcessStage>,
-    issue_link: Option<String>,
-    pr_link: Option<String>,
-    summary: Option<String>,
-    user_story: Option<String>,
-    requirements: Option<String>,
-    artifacts: Vec<String>,
-    status: Status,
-) {
-    // Apply template defaults if specified
+pub fn cmd_add(db: &mut Database, db_path: &Path, opts: AddOptions) {
+    let AddOptions {
+        title,
+        stdin,
+        print_id,
+        template,
+        desc,
+        tags,
+        replace_tags,
+        due,
+        remind,
+        iteration,
+        estimate,
+        parent,
+        create_parent,
+        after,
+        before,
+        kind,
+        priority_level,
+        urgency,
+        process_stage,
+        issue_link,
+        pr_link,
+        summary,
+        user_story,
+        requirements,
+        artifacts,
+        status,
+    } = opts;
+    let lines: Vec<AddLine> = if stdin {
+        let mut lines = Vec::new();
+        for line in std::io::stdin().lines() {
+            match line {
+                Ok(line) => lines.extend(parse_add_line(&line)),
+                Err(e) => {
+                    eprintln!("Failed to read stdin: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        if lines.is_empty() {
+            eprintln!("No task titles on stdin.");
+            std::process::exit(1);
+        }
+        lines
+    } else {
+        vec![AddLine {
+            title: title.unwrap_or_default(),
+            tags: Vec::new(),
+            priority: None,
+        }]
+    };
+
+    // A task placed beside another shares its parent and, unless `--kind`
+    // says otherwise, its kind.
+    let anchor = match after.map(|r| (r, false)).or(before.map(|r| (r, true))) {
+        Some((reference, before)) => match resolve_task_identifier(&reference, db) {
+            Ok(rid) => Some((rid, before)),
+            Err(e) => {
+                eprintln!("Error resolving reference task: {}", e);
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+    let anchor_task = anchor.and_then(|(rid, _)| db.get(rid));
+    let kind = kind.or(anchor_task.map(|t| t.kind));
+    // Under `pm focus`, a ticket placed nowhere in particular goes under the
+    // focus root, as the kind one level below it unless `--kind`, a
+    // template or the project defaults say otherwise.
+    let focus = if parent.is_none() && anchor.is_none() {
+        active_focus(db, db_path)
+    } else {
+        None
+    };
+    let focus_kind = focus
+        .and_then(|root| db.get(root))
+        .and_then(|root| child_kind_for(root.kind));
+
+    // Resolve the parent first: it decides which project's defaults apply.
+    // A parent still to be created has no project, so no defaults either.
+    let mut missing_parent: Option<String> = None;
+    let parent_id = match parent {
+        Some(parent_str) => match resolve_task_identifier(&parent_str, db) {
+            Ok(pid) => Some(pid),
+            // Only a name that matches nothing is created. An ambiguous
+            // name or a typed id that doesn't exist is reported as usual.
+            Err(e) if create_parent && e.starts_with("No task found with name") => {
+                missing_parent = Some(parent_str);
+                None
+            }
+            Err(e) => {
+                eprintln!("Error resolving parent: {}", e);
+                std::process::exit(1);
+            }
+        },
+        None => anchor_task.and_then(|t| t.parent).or(focus),
+    };
+    let config = Config::load(db_path);
+    let defaults = parent_id
+        .and_then(|pid| project_defaults_for(db, &config, pid))
+        .cloned()
+        .unwrap_or_default();
+    // A default kind that cannot live under the parent is skipped rather
+    // than turned into a hierarchy error.
+    let default_kind = defaults.kind.filter(|&k| {
+        parent_id
+            .and_then(|pid| db.get(pid))
+            .is_none_or(|p| validate_hierarchy(p.kind, k))
+    });
+
+    // Precedence for every field: explicit flag > template > project
+    // defaults > built-in default. The template itself is kept to fill its
+    // placeholders per title; `templated_desc` says the description came
+    // from it rather than from `--desc`.
     let (
+        applied_template,
+        templated_desc,
         task_kind,
         final_tags,
         final_priority,
@@ -654,6 +1200,8 @@ pub fn cmd_add(
         final_process_stage,
         final_status,
         final_desc,
+        final_user_story,
+        final_requirements,
     ) = if let Some(template_name) = template {
         let template = db
             .state
@@ -664,16 +1212,26 @@ pub fn cmd_add(
 
         match template {
             Some(tmpl) => {
-                let template_tags = if tags.is_empty() {
-                    tmpl.tags
+                // `--tag` adds to the template's tags (or the project
+                // defaults, when the template has none) unless
+                // `--replace-tags` asks for them alone.
+                let base = if tmpl.tags.is_empty() {
+                    &defaults.tags
                 } else {
+                    &tmpl.tags
+                };
+                let template_tags = if replace_tags && !tags.is_empty() {
                     split_and_normalise_tags(&tags)
+                } else {
+                    split_and_normalise_tags(&[base.as_slice(), tags.as_slice()].concat())
                 };
                 (
-                    if kind == Kind::Task { tmpl.kind } else { kind },
+                    Some(tmpl.clone()),
+                    desc.is_none() && tmpl.description_template.is_some(),
+                    kind.unwrap_or(tmpl.kind),
                     template_tags,
-                    priority_level.or(tmpl.priority_level),
-                    urgency.or(tmpl.urgency),
+                    priority_level.or(tmpl.priority_level).or(defaults.priority),
+                    urgency.or(tmpl.urgency).or(defaults.urgency),
                     process_stage.or(tmpl.process_stage),
                     if status == Status::Open {
                         tmpl.status
@@ -681,6 +1239,8 @@ pub fn cmd_add(
                         status
                     },
                     desc.or(tmpl.description_template.clone()),
+                    user_story.or(tmpl.user_story_template.clone()),
+                    requirements.or(tmpl.requirements_template.clone()),
                 )
             }
             None => {
@@ -690,122 +1250,301 @@ pub fn cmd_add(
         }
     } else {
         (
-            kind,
-            split_and_normalise_tags(&tags),
-            priority_level,
-            urgency,
+            None,
+            false,
+            kind.or(default_kind).or(focus_kind).unwrap_or(Kind::Task),
+            split_and_normalise_tags(if tags.is_empty() {
+                &defaults.tags
+            } else {
+                &tags
+            }),
+            priority_level.or(defaults.priority),
+            urgency.or(defaults.urgency),
             process_stage,
             status,
             desc,
+            user_story,
+            requirements,
         )
     };
 
+    for w in tag_spelling_warnings(&db.tasks, &split_and_normalise_tags(&tags)) {
+        eprintln!("{w}");
+    }
     let now_utc = Utc::now().timestamp();
-    let id = db.allocate_id(kind_to_prefix(task_kind));
 
-    // Resolve and validate parent
-    let parent_id = if let Some(parent_str) = parent {
-        match resolve_task_identifier(&parent_str, db) {
-            Ok(pid) => {
-                if pid == id {
-                    eprintln!("Parent cannot equal child.");
-                    std::process::exit(1);
-                }
+    let created_parent = missing_parent.map(|parent_title| {
+        let Some(parent_kind) = parent_kind_for(task_kind) else {
+            eprintln!(
+                "Cannot create a parent for a {}: it has no parent kind",
+                format_kind(task_kind)
+            );
+            std::process::exit(1);
+        };
+        let id = db.allocate_id(kind_to_prefix(parent_kind));
+        db.tasks.push(Task {
+            id,
+            title: parent_title.clone(),
+            summary: None,
+            description: None,
+            user_story: None,
+            requirements: None,
+            tags: Vec::new(),
+            deps: Vec::new(),
+            milestone: None,
+            memories: Vec::new(),
+            due: None,
+            remind_days_before: None,
+            parent: None,
+            order: None,
+            iteration: None,
+            estimate_minutes: None,
+            completed_at_utc: None,
+            pinned: false,
+            kind: parent_kind,
+            status: Status::Open,
+            priority_level: None,
+            urgency: None,
+            process_stage: None,
+            issue_link: None,
+            pr_link: None,
+            artifacts: Vec::new(),
+            created_at_utc: now_utc,
+            updated_at_utc: now_utc,
+        });
+        (id, parent_title)
+    });
+    let parent_id = parent_id.or(created_parent.as_ref().map(|(id, _)| *id));
 
-                // Check hierarchy rules
-                if let Some(parent_task) = db.get(pid) {
-                    if !validate_hierarchy(parent_task.kind, task_kind) {
-                        eprintln!("Invalid hierarchy: {} cannot be child of {}. Valid hierarchy: Project > Product > Epic > Task > Subtask",
-                            format_kind(task_kind), format_kind(parent_task.kind));
-                        std::process::exit(1);
-                    }
-                }
-                Some(pid)
-            }
-            Err(e) => {
-                eprintln!("Error resolving parent: {}", e);
-                std::process::exit(1);
-            }
+    // Check hierarchy rules against the resolved kind.
+    if let Some(parent_task) = parent_id.and_then(|pid| db.get(pid)) {
+        if !validate_hierarchy(parent_task.kind, task_kind) {
+            eprintln!("Invalid hierarchy: {} cannot be child of {}. Valid hierarchy: Project > Product > Epic > Task > Subtask",
+                format_kind(task_kind), format_kind(parent_task.kind));
+            std::process::exit(1);
         }
-    } else {
-        None
-    };
+    }
 
     let due = due.as_deref().and_then(parse_due_input);
-    let artifacts_list = artifacts
+    let estimate = estimate.as_deref().map(estimate_arg);
+    let artifacts_list: Vec<String> = artifacts
         .iter()
         .flat_map(|s| s.split(','))
         .map(|s| s.trim().to_string())
         .filter(|s| !s.is_empty())
         .collect();
 
-    let task = Task {
-        id,
-        title,
-        summary,
-        description: final_desc,
-        user_story,
-        requirements,
-        tags: final_tags,
-        deps: Vec::new(),
-        milestone: None,
-        memories: Vec::new(),
-        due,
-        parent: parent_id,
-        kind: task_kind,
-        status: final_status,
-        priority_level: final_priority,
-        urgency: final_urgency,
-        process_stage: final_process_stage,
-        issue_link,
-        pr_link,
-        artifacts: artifacts_list,
-        created_at_utc: now_utc,
-        updated_at_utc: now_utc,
-    };
-    let title_for_msg = task.title.clone();
-    db.tasks.push(task);
+    let mut positions = anchor
+        .and_then(|(rid, before)| {
+            let reference = db.get(rid)?;
+            Some(positions_beside(&db.tasks, reference, before, lines.len()))
+        })
+        .unwrap_or_default()
+        .into_iter();
+    let today = Local::now().date_naive();
+    let project = parent_id
+        .and_then(|pid| project_ancestor(db, pid))
+        .and_then(|pid| db.get(pid))
+        .map(|p| p.title.clone())
+        .unwrap_or_default();
+    let mut added: Vec<(LeafId, String)> = Vec::new();
+    for mut line in lines {
+        let mut description = final_desc.clone();
+        if let Some(tmpl) = &applied_template {
+            let values = TemplateValues {
+                title: &line.title,
+                date: today,
+                project: &project,
+            };
+            if templated_desc {
+                description = description.map(|d| fill_placeholders(&d, &values));
+            }
+            line.title = tmpl.fill_title(&values);
+        }
+        if let Some(w) = title_length_warning(&line.title, config.titles.max_length) {
+            eprintln!("{w}");
+        }
+        for w in tag_spelling_warnings(&db.tasks, &line.tags) {
+            eprintln!("{w}");
+        }
+        let id = db.allocate_id(kind_to_prefix(task_kind));
+        let mut tags = final_tags.clone();
+        tags.extend(line.tags);
+        tags.sort();
+        tags.dedup();
+        let task = Task {
+            id,
+            title: line.title,
+            summary: summary.clone(),
+            description,
+            user_story: final_user_story.clone(),
+            requirements: final_requirements.clone(),
+            tags,
+            deps: Vec::new(),
+            milestone: None,
+            memories: Vec::new(),
+            due,
+            remind_days_before: remind,
+            parent: parent_id,
+            order: positions.next(),
+            iteration: iteration.clone(),
+            estimate_minutes: estimate,
+            completed_at_utc: None,
+            pinned: false,
+            kind: task_kind,
+            status: final_status,
+            priority_level: line.priority.or(final_priority),
+            urgency: final_urgency,
+            process_stage: final_process_stage,
+            issue_link: issue_link.clone(),
+            pr_link: pr_link.clone(),
+            artifacts: artifacts_list.clone(),
+            created_at_utc: now_utc,
+            updated_at_utc: now_utc,
+        };
+        added.push((id, task.title.clone()));
+        db.tasks.push(task);
+    }
     if let Err(e) = db.save(db_path) {
         eprintln!("Failed to save DB: {e}");
         std::process::exit(1);
     }
-    commit_or_warn(
-        db_path,
-        &commit_subject_for(id, "add", Some(&title_for_msg)),
-    );
-    emit_or_warn(db_path, "add", Some(id), Some(&title_for_msg));
-    println!("Added task {}", id);
+    // With --print-id stdout carries the ids alone, so confirmations go to
+    // stderr instead.
+    let confirm = |message: String| {
+        if !print_id {
+            say!("{message}");
+        } else if !quiet() {
+            eprintln!("{message}");
+        }
+    };
+    if let Some((id, title)) = &created_parent {
+        emit_or_warn(db_path, "add", Some(*id), Some(title));
+        confirm(format!("Created parent {}: {}", id, title));
+    }
+    if print_id {
+        for (id, _) in &added {
+            println!("{id}");
+        }
+    }
+    match added.as_slice() {
+        [(id, title)] => {
+            commit_or_warn(db_path, &commit_subject_for(*id, "add", Some(title)));
+            emit_or_warn(db_path, "add", Some(*id), Some(title));
+            confirm(format!("Added task {}", id));
+        }
+        many => {
+            let range = format!("{}..{}", many[0].0, many[many.len() - 1].0);
+            let count = format!("{} tasks", many.len());
+            commit_or_warn(
+                db_path,
+                &crate::store::git::subject(&range, "add", Some(&count)),
+            );
+            for (id, title) in many {
+                emit_or_warn(db_path, "add", Some(*id), Some(title));
+            }
+            confirm(format!("Added {count} ({range})"));
+        }
+    }
 }
 
 /// List tasks with optional filtering and sorting.
-pub fn cmd_list(
-    db: &Database,
-    all: bool,
-    status: Option<Status>,
-    kind: Option<Kind>,
-    project: Option<String>,
-    tags: Vec<String>,
-    due: Option<DueFilter>,
-    tree: bool,
-    sort: SortKey,
-    limit: Option<usize>,
-) {
+pub fn cmd_list(db: &Database, db_path: &Path, opts: ListOptions) {
+    let ListOptions {
+        all,
+        status,
+        done_only,
+        kind,
+        project,
+        tags,
+        expand_tags,
+        due,
+        overdue_by,
+        due_in,
+        iteration,
+        parent_status,
+        include_rootless,
+        pinned: pinned_only,
+        missing,
+        has,
+        ancestors_of,
+        children_of,
+        descendants_of,
+        tree,
+        flat_tree,
+        breadcrumb,
+        fields,
+        format,
+        no_header,
+        sort,
+        limit,
+        offset,
+    } = opts;
+    let related = ancestors_of
+        .map(|id| (Relation::Ancestors, id))
+        .or(children_of.map(|id| (Relation::Children, id)))
+        .or(descendants_of.map(|id| (Relation::Descendants, id)));
     let tags = split_and_normalise_tags(&tags);
     let today = Local::now().date_naive();
-    let (week_start, week_end) = start_end_of_this_week(today);
+    let status = if done_only {
+        Some(Status::Done)
+    } else {
+        status
+    };
+
+    // The anchor of a hierarchy query is resolved before anything else is
+    // filtered; the other filters then narrow its relatives.
+    let relatives: Option<(Relation, Vec<LeafId>)> =
+        related.map(
+            |(relation, anchor)| match resolve_task_identifier(&anchor, db) {
+                Ok(id) => (relation, related_ids(db, id, relation)),
+                Err(e) => {
+                    eprintln!("Error resolving {anchor}: {e}");
+                    std::process::exit(1);
+                }
+            },
+        );
+
+    let whole_chain = matches!(relatives, Some((Relation::Ancestors, _)));
+    let focus = active_focus(db, db_path);
+    let in_focus = focus.map(|root| db.subtree_ids(root));
+
+    let columns = list_columns(&fields, breadcrumb);
+    let wants_assignee = |f: &PresenceField| *f == PresenceField::Assignee;
+    let assignees: HashMap<LeafId, String> = if columns.contains(&ListColumn::Assignee)
+        || missing.iter().any(wants_assignee)
+        || has.iter().any(wants_assignee)
+    {
+        let now = Utc::now();
+        crate::store::locks::list(db_path)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|lock| !lock.is_stale(now))
+            .map(|lock| (lock.id, lock.agent))
+            .collect()
+    } else {
+        HashMap::new()
+    };
 
     let mut filtered: Vec<&Task> = db
         .tasks
         .iter()
         .filter(|t| {
-            if !all && t.status == Status::Done {
+            if in_focus.as_ref().is_some_and(|ids| !ids.contains(&t.id)) {
                 return false;
             }
-            if let Some(s) = status {
-                if t.status != s {
+            if let Some((_, ids)) = &relatives {
+                if !ids.contains(&t.id) {
                     return false;
                 }
             }
+            // Completed tasks are hidden by default, but an explicit status
+            // filter decides on its own. An ancestor chain is shown whole.
+            match status {
+                Some(s) if t.status != s => return false,
+                None if !all && !whole_chain && t.status.is_closed() => return false,
+                _ => {}
+            }
             if let Some(k) = kind {
                 if t.kind != k {
                     return false;
@@ -816,121 +1555,239 @@ pub fn cmd_list(
                     return false;
                 }
             }
-            if !tags.is_empty() {
-                let tagset: BTreeSet<_> = t.tags.iter().cloned().collect();
-                for tg in &tags {
-                    if !tagset.contains(tg) {
-                        return false;
-                    }
-                }
+            if !has_all_tags(&t.tags, &tags, expand_tags) {
+                return false;
             }
             if let Some(df) = due {
-                match df {
-                    DueFilter::Today => {
-                        if t.due != Some(today) {
-                            return false;
-                        }
-                    }
-                    DueFilter::ThisWeek => {
-                        if let Some(d) = t.due {
-                            if d < week_start || d > week_end {
-                                return false;
-                            }
-                        } else {
-                            return false;
-                        }
-                    }
-                    DueFilter::Overdue => {
-                        if let Some(d) = t.due {
-                            if d >= today {
-                                return false;
-                            }
-                        } else {
-                            return false;
-                        }
-                    }
-                    DueFilter::None => {
-                        if t.due.is_some() {
-                            return false;
-                        }
-                    }
+                if !due_filter_matches(t.due, t.remind_days_before, df, today) {
+                    return false;
+                }
+            }
+            // The two thresholds cover opposite sides of today, so when both
+            // are given they widen each other rather than intersecting.
+            if overdue_by.is_some() || due_in.is_some() {
+                let late = overdue_by.is_some_and(|n| overdue_by_at_least(t.due, today, n));
+                let soon = due_in.is_some_and(|n| due_within(t.due, today, n));
+                if !late && !soon {
+                    return false;
+                }
+            }
+            if iteration.is_some() && t.iteration != iteration {
+                return false;
+            }
+            if let Some(ps) = parent_status {
+                if !parent_status_matches(db, t, ps, include_rootless) {
+                    return false;
                 }
             }
+            if pinned_only && !t.pinned {
+                return false;
+            }
+            if missing.iter().any(|&f| field_present(t, f, &assignees))
+                || has.iter().any(|&f| !field_present(t, f, &assignees))
+            {
+                return false;
+            }
             true
         })
         .collect();
 
-    match sort {
-        SortKey::Due => filtered.sort_by_key(|t| (t.due.unwrap_or(NaiveDate::MAX), t.id)),
-        SortKey::Priority => {
-            filtered.sort_by(|a, b| {
-                // Sort by priority_level first (MustHave=0, NiceToHave=1, CutFirst=2, None=3)
-                let a_priority = match a.priority_level {
-                    Some(Priority::MustHave) => 0,
-                    Some(Priority::NiceToHave) => 1,
-                    Some(Priority::CutFirst) => 2,
-                    None => 3,
-                };
-                let b_priority = match b.priority_level {
-                    Some(Priority::MustHave) => 0,
-                    Some(Priority::NiceToHave) => 1,
-                    Some(Priority::CutFirst) => 2,
-                    None => 3,
-                };
-
-                // Then by urgency (UrgentImportant=0, UrgentNotImportant=1, NotUrgentImportant=2, NotUrgentNotImportant=3, None=4)
-                let a_urgency = match a.urgency {
-                    Some(Urgency::UrgentImportant) => 0,
-                    Some(Urgency::UrgentNotImportant) => 1,
-                    Some(Urgency::NotUrgentImportant) => 2,
-                    Some(Urgency::NotUrgentNotImportant) => 3,
-                    None => 4,
-                };
-                let b_urgency = match b.urgency {
-                    Some(Urgency::UrgentImportant) => 0,
-                    Some(Urgency::UrgentNotImportant) => 1,
-                    Some(Urgency::NotUrgentImportant) => 2,
-                    Some(Urgency::NotUrgentNotImportant) => 3,
-                    None => 4,
-                };
-
-                // Finally by ID for stable sort
-                a_priority
-                    .cmp(&b_priority)
-                    .then(a_urgency.cmp(&b_urgency))
-                    .then(a.id.cmp(&b.id))
-            });
-        }
-        SortKey::Id => filtered.sort_by_key(|t| t.id),
+    if flat_tree {
+        let order = tree_order(&db.tasks);
+        filtered.sort_by_key(|t| order.get(&t.id).copied().unwrap_or(usize::MAX));
+    } else if let Some((Relation::Ancestors, chain)) = &relatives {
+        filtered.sort_by_key(|t| chain.iter().position(|&id| id == t.id));
+    } else {
+        sort_tasks_by(&mut filtered, &sort.0);
     }
 
+    // Page after sorting, so the same offset always skips the same rows.
+    let total = filtered.len();
+    let paged = limit.is_some() || offset > 0;
+    filtered.drain(..offset.min(total));
     if let Some(n) = limit {
         filtered.truncate(n);
     }
 
+    // Plain output is for pipes, so the focus header and the paging footer
+    // go to stderr there.
+    if let (Some(root), false) = (focus, no_header) {
+        let header = format!("Focus: {}", focus_line(db, root));
+        match format {
+            ListFormat::Table => println!("{header}"),
+            ListFormat::Plain => eprintln!("{header}"),
+        }
+    }
     if tree {
         // Compute depths for indentation using ancestry in the full DB.
         let mut depth_map: HashMap<LeafId, usize> = HashMap::new();
+        let mut cycles: BTreeSet<LeafId> = BTreeSet::new();
         for t in &db.tasks {
-            let mut depth = 0usize;
-            let mut cur = t.parent;
-            while let Some(pid) = cur {
-                depth += 1;
-                cur = db.get(pid).and_then(|p| p.parent);
-                if depth > 64 {
-                    break; // cycle guard
+            let depth = match db.depth_of(t.id) {
+                Ok(d) => d,
+                Err(cycle) => {
+                    cycles.insert(cycle.id);
+                    0
                 }
-            }
+            };
             depth_map.insert(t.id, depth);
         }
-        print_table(db, &filtered, Some(&depth_map));
+        for id in cycles {
+            eprintln!("warning: {}", CycleDetected { id });
+        }
+        print_table(
+            db,
+            &filtered,
+            Some(&depth_map),
+            &columns,
+            &assignees,
+            format,
+            !no_header,
+        );
+    } else {
+        print_table(
+            db, &filtered, None, &columns, &assignees, format, !no_header,
+        );
+    }
+    if paged && !no_header {
+        let footer = page_footer(offset, filtered.len(), total);
+        match format {
+            ListFormat::Table => println!("{footer}"),
+            ListFormat::Plain => eprintln!("{footer}"),
+        }
+    }
+}
+
+/// The `pm list --offset/--limit` footer: which rows of the full result
+/// were printed, counting from 1.
+fn page_footer(offset: usize, shown: usize, total: usize) -> String {
+    if shown == 0 {
+        format!("No rows past {offset} of {total}")
     } else {
-        print_table(db, &filtered, None);
+        format!("Rows {}-{} of {total}", offset + 1, offset + shown)
+    }
+}
+
+/// List unfiled tasks, or with `triage` prompt for a home for each in turn.
+pub fn cmd_inbox(
+    db: &mut Database,
+    db_path: &Path,
+    triage: bool,
+    tags: Vec<String>,
+    sort: SortKey,
+    limit: Option<usize>,
+) {
+    let tags = split_and_normalise_tags(&tags);
+    let mut inbox: Vec<&Task> = db
+        .tasks
+        .iter()
+        .filter(|t| is_inbox(t) && !t.status.is_closed())
+        .filter(|t| has_all_tags(&t.tags, &tags, false))
+        .collect();
+    sort_tasks(&mut inbox, sort);
+    if let Some(n) = limit {
+        inbox.truncate(n);
+    }
+
+    if inbox.is_empty() {
+        println!("Inbox is empty.");
+        return;
+    }
+    if !triage {
+        let columns = list_columns(&[], false);
+        print_table(
+            db,
+            &inbox,
+            None,
+            &columns,
+            &HashMap::new(),
+            ListFormat::Table,
+            true,
+        );
+        return;
+    }
+    let ids: Vec<LeafId> = inbox.iter().map(|t| t.id).collect();
+    triage_inbox(db, db_path, &ids);
+}
+
+/// Print `label` and read one trimmed line from stdin; `None` at end of input.
+fn prompt_line(label: &str) -> Option<String> {
+    use std::io::{self, Write};
+    print!("{label}");
+    io::stdout().flush().ok();
+    let mut line = String::new();
+    match io::stdin().read_line(&mut line) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line.trim().to_string()),
+    }
+}
+
+/// Interactive loop behind `pm inbox --triage`. Each task is moved as soon
+/// as it is filed, so quitting part-way keeps the work done so far. A
+/// ticket's kind is fixed by its id prefix, so only parents that accept the
+/// task's kind are taken.
+fn triage_inbox(db: &mut Database, db_path: &Path, ids: &[LeafId]) {
+    let mut filed = 0;
+    'tasks: for (n, &id) in ids.iter().enumerate() {
+        let Some(task) = db.get(id) else { continue };
+        let kind = task.kind;
+        println!("\n[{}/{}] {id}  {}", n + 1, ids.len(), task.title);
+
+        let parent = loop {
+            let Some(input) =
+                prompt_line("Parent (ID or name; Enter leaves it in the inbox, q quits): ")
+            else {
+                break 'tasks;
+            };
+            match input.as_str() {
+                "" => continue 'tasks,
+                "q" => break 'tasks,
+                _ => {}
+            }
+            let pid = match resolve_task_identifier(&input, db) {
+                Ok(pid) => pid,
+                Err(e) => {
+                    eprintln!("Error resolving parent: {e}");
+                    continue;
+                }
+            };
+            if let Err(e) = check_reparent(db, id, pid) {
+                eprintln!("{e}");
+                continue;
+            }
+            let parent_kind = db.get(pid).expect("checked by check_reparent").kind;
+            if validate_hierarchy(parent_kind, kind) {
+                break pid;
+            }
+            eprintln!(
+                "Invalid hierarchy: {} cannot be child of {}.",
+                format_kind(kind),
+                format_kind(parent_kind)
+            );
+        };
+
+        if let Err(e) = relocate_ticket(db, db_path, id, Some(parent)) {
+            eprintln!("Failed to save DB: {e}");
+            std::process::exit(1);
+        }
+        let detail = format!("-> {parent}");
+        commit_or_warn(db_path, &commit_subject_for(id, "move", Some(&detail)));
+        emit_or_warn(db_path, "move", Some(id), Some(&detail));
+        println!("Filed {id} under {parent}");
+        filed += 1;
     }
+    println!("\nFiled {filed}, left {} in the inbox.", ids.len() - filed);
 }
 
 /// View detailed information about a specific task.
-pub fn cmd_view(db: &Database, id: String, children: bool, parents: bool) {
+pub fn cmd_view(
+    db: &Database,
+    db_path: &Path,
+    id: String,
+    children: bool,
+    parents: bool,
+    artifacts: bool,
+) {
     let task_id = match resolve_task_identifier(&id, db) {
         Ok(id) => id,
         Err(e) => {
@@ -950,14 +1807,26 @@ pub fn cmd_view(db: &Database, id: String, children: bool, parents: bool) {
     println!("Kind:         {}", format_kind(task.kind));
     println!("Status:       {}", format_status(task.status));
     println!("Priority:     {}", format_priority(task.priority_level));
-    println!("Project:      {}", project_for_view);
     println!(
-        "Due:          {}",
-        match task.due {
-            Some(d) => format!("{d} ({})", format_due_relative(Some(d), today)),
-            None => "-".into(),
-        }
+        "Stage:        {}",
+        format_stage_progress(task.process_stage)
     );
+    println!("Project:      {}", project_for_view);
+    let due = match date_style() {
+        DateStyle::Relative => format_due_detail(task.due, task.remind_days_before, today),
+        DateStyle::Iso => format_due_iso(task.due, task.remind_days_before),
+    };
+    println!("Due:          {due}");
+    println!("Iteration:    {}", task.iteration.as_deref().unwrap_or("-"));
+    let own_estimate = task.estimate_minutes.map_or("-".into(), format_estimate);
+    match estimate_rollup(db, task.id) {
+        (total, open) if total != task.estimate_minutes.unwrap_or(0) => println!(
+            "Estimate:     {own_estimate} (subtree {}, {} open)",
+            format_estimate(total),
+            format_estimate(open)
+        ),
+        _ => println!("Estimate:     {own_estimate}"),
+    }
     println!(
         "Parent:       {}",
         task.parent
@@ -986,14 +1855,20 @@ pub fn cmd_view(db: &Database, id: String, children: bool, parents: bool) {
             .unwrap()
             .to_rfc3339()
     );
-    println!(
-        "Description:\n{}\n",
-        task.description.unwrap_or_else(|| "-".into())
-    );
+    match &task.description {
+        Some(text) => println!("Description ({}):\n{}\n", format_text_stats(text), text),
+        None => println!("Description:\n-\n"),
+    }
+    if let Some(text) = &task.requirements {
+        println!("Requirements ({}):\n{}\n", format_text_stats(text), text);
+    }
 
     let child_map = build_children_map(&db.tasks);
 
     if parents {
+        if let Err(cycle) = db.ancestor_ids(task_id) {
+            eprintln!("warning: {cycle}");
+        }
         let chain = collect_ancestors(task_id, db);
         if chain.is_empty() {
             println!("Ancestors: -");
@@ -1042,6 +1917,32 @@ pub fn cmd_view(db: &Database, id: String, children: bool, parents: bool) {
             println!("  -");
         }
     }
+
+    if artifacts {
+        println!("Artifacts:");
+        if task.artifacts.is_empty() {
+            println!("  -");
+        }
+        let dir = db
+            .artifacts_dir(db_path, task.id)
+            .unwrap_or_else(|| db_path.join("artifacts"));
+        for artifact in &task.artifacts {
+            let path = resolve_artifact_path(&dir, artifact);
+            if path.exists() {
+                println!("  {}", path.display());
+            } else {
+                println!("  {} (missing)", path.display());
+            }
+        }
+    }
+}
+
+/// Parse an `--estimate` value, exiting with an error if it is not one.
+fn estimate_arg(raw: &str) -> u32 {
+    parse_estimate(raw).unwrap_or_else(|| {
+        eprintln!("Unrecognised estimate '{raw}'. Use minutes (90) or hours and minutes (1h30m).");
+        std::process::exit(1);
+    })
 }
 
 /// Update an existing task's fields.
@@ -1052,12 +1953,19 @@ pub fn cmd_update(
     title: Option<String>,
     desc: Option<String>,
     due: Option<String>,
+    remind: Option<u32>,
+    iteration: Option<String>,
+    estimate: Option<String>,
     parent: Option<String>,
     kind: Option<Kind>,
     status: Option<Status>,
+    process_stage: Option<ProcessStage>,
     add_tags: Vec<String>,
     rm_tags: Vec<String>,
     clear_due: bool,
+    clear_remind: bool,
+    clear_iteration: bool,
+    clear_estimate: bool,
     clear_parent: bool,
 ) {
     let task_id = match resolve_task_identifier(&id, db) {
@@ -1083,37 +1991,29 @@ pub fn cmd_update(
 
     // Validate parent exists and won't cause cycles before getting mutable borrow
     if let Some(pid) = parent_id {
-        if pid == task_id {
-            eprintln!("Parent cannot equal child.");
-            std::process::exit(1);
-        }
-        if db.get(pid).is_none() {
-            eprintln!("Parent ID {pid} does not exist.");
+        if let Err(e) = check_reparent(db, task_id, pid) {
+            eprintln!("{e}");
             std::process::exit(1);
         }
-        // Detect cycle.
-        let mut cur = Some(pid);
-        let mut hops = 0;
-        while let Some(p) = cur {
-            if p == task_id {
-                eprintln!("Setting parent would create a cycle.");
-                std::process::exit(1);
-            }
-            cur = db.get(p).and_then(|x| x.parent);
-            hops += 1;
-            if hops > 64 {
-                break;
-            }
-        }
     }
 
+    if let Some(s) = status {
+        check_status_flow(db, db_path, &[task_id], s);
+    }
+    let estimate = estimate.as_deref().map(estimate_arg);
+    let workflow = Config::load(db_path).workflow;
+
     // Store values needed for hierarchy validation
-    let (final_parent, final_kind) = {
+    let (final_parent, final_kind, advanced) = {
         let Some(t) = db.get_mut(task_id) else {
             eprintln!("Task {} not found.", task_id);
             std::process::exit(1);
         };
         if let Some(s) = title {
+            let max_length = Config::load(db_path).titles.max_length;
+            if let Some(w) = title_length_warning(&s, max_length) {
+                eprintln!("{w}");
+            }
             t.title = s;
         }
         if let Some(d) = desc {
@@ -1122,6 +2022,24 @@ pub fn cmd_update(
         if clear_due {
             t.due = None;
         }
+        if clear_remind {
+            t.remind_days_before = None;
+        }
+        if remind.is_some() {
+            t.remind_days_before = remind;
+        }
+        if clear_iteration {
+            t.iteration = None;
+        }
+        if iteration.is_some() {
+            t.iteration = iteration;
+        }
+        if clear_estimate {
+            t.estimate_minutes = None;
+        }
+        if estimate.is_some() {
+            t.estimate_minutes = estimate;
+        }
         if let Some(ds) = due {
             t.due = parse_due_input(&ds);
             if t.due.is_none() {
@@ -1140,11 +2058,19 @@ pub fn cmd_update(
         if let Some(k) = kind {
             t.kind = k;
         }
+        let mut advanced = None;
         if let Some(s) = status {
+            let from = t.status;
             t.status = s;
+            if process_stage.is_none() {
+                advanced = advance_stage(t, from, &workflow);
+            }
+        }
+        if process_stage.is_some() {
+            t.process_stage = process_stage;
         }
 
-        (t.parent, t.kind)
+        (t.parent, t.kind, advanced)
     };
 
     // Validate hierarchy after kind/parent updates
@@ -1158,12 +2084,16 @@ pub fn cmd_update(
         }
     }
 
+    let mut add = split_and_normalise_tags(&add_tags);
+    for w in tag_spelling_warnings(&db.tasks, &add) {
+        eprintln!("{w}");
+    }
+
     // Get mutable borrow again for tag updates
     let Some(t) = db.get_mut(task_id) else {
         eprintln!("Task {} not found.", task_id);
         std::process::exit(1);
     };
-    let mut add = split_and_normalise_tags(&add_tags);
     let rm = split_and_normalise_tags(&rm_tags)
         .into_iter()
         .collect::<HashSet<_>>();
@@ -1186,10 +2116,243 @@ pub fn cmd_update(
     }
     commit_or_warn(db_path, &commit_subject_for(task_id, "update", None));
     emit_or_warn(db_path, "update", Some(task_id), None);
-    println!("Updated task {}", task_id);
+    say!("Updated task {}", task_id);
+    say_stage_advanced(advanced);
+}
+
+/// Note a stage moved by `workflow.stage_on_status`.
+fn say_stage_advanced(stage: Option<ProcessStage>) {
+    if let Some(stage) = stage {
+        say!("Stage moved to {}.", format_process_stage(Some(stage)));
+    }
+}
+
+/// `pm replace`: rewrite matches of `from` in the chosen fields of every
+/// task, printing each change. With `dry_run` nothing is saved.
+#[allow(clippy::too_many_arguments)]
+pub fn cmd_replace(
+    db: &mut Database,
+    db_path: &Path,
+    from: &str,
+    to: &str,
+    mut fields: Vec<ReplaceField>,
+    regex: bool,
+    dry_run: bool,
+    no_backup: bool,
+) {
+    let source = if regex {
+        from.to_string()
+    } else {
+        regex::escape(from)
+    };
+    let pattern = match regex::Regex::new(&source) {
+        Ok(pattern) => pattern,
+        Err(e) => {
+            eprintln!("Invalid pattern: {e}");
+            std::process::exit(1);
+        }
+    };
+    if fields.is_empty() {
+        fields = vec![
+            ReplaceField::Title,
+            ReplaceField::Description,
+            ReplaceField::Tags,
+        ];
+    }
+    fields.sort();
+    fields.dedup();
+
+    // Work on a copy so a dry run, or a declined backup, changes nothing.
+    let mut tasks = db.tasks.clone();
+    let mut changed: Vec<LeafId> = Vec::new();
+    let mut replaced = 0;
+    let max_length = Config::load(db_path).titles.max_length;
+    for task in tasks.iter_mut() {
+        let (edits, n) = replace_in_task(task, &pattern, to, !regex, &fields);
+        // replace_in_task keeps a title it would blank; the match is still there.
+        if fields.contains(&ReplaceField::Title)
+            && pattern.is_match(&task.title)
+            && !edits.iter().any(|e| e.field == ReplaceField::Title)
+        {
+            eprintln!(
+                "Warning: {}: the replacement would leave an empty title; title left unchanged.",
+                task.id
+            );
+        }
+        if edits.is_empty() {
+            continue;
+        }
+        replaced += n;
+        changed.push(task.id);
+        for edit in edits {
+            let field = match edit.field {
+                ReplaceField::Title => "title",
+                ReplaceField::Description => "description",
+                ReplaceField::Tags => "tags",
+            };
+            println!(
+                "{} {}: {:?} -> {:?}",
+                task.id, field, edit.before, edit.after
+            );
+            if edit.field == ReplaceField::Title {
+                if let Some(w) = title_length_warning(&edit.after, max_length) {
+                    eprintln!("{}: {w}", task.id);
+                }
+            }
+        }
+    }
+    if changed.is_empty() {
+        println!("No matches.");
+        return;
+    }
+    let summary = format!("{} replacement(s) in {} task(s)", replaced, changed.len());
+    if dry_run {
+        println!("Dry run: {summary} would be saved.");
+        return;
+    }
+    if !no_backup && !backup_before(db, db_path, "replace") {
+        println!("Replace cancelled.");
+        return;
+    }
+
+    let now = Utc::now().timestamp();
+    for task in tasks.iter_mut().filter(|t| changed.contains(&t.id)) {
+        task.updated_at_utc = now;
+    }
+    db.tasks = tasks;
+    if let Err(e) = db.save(db_path) {
+        eprintln!("Failed to save DB: {e}");
+        std::process::exit(1);
+    }
+    commit_or_warn(
+        db_path,
+        &format!(
+            "pm: replace {from:?} with {to:?} ({} tickets)",
+            changed.len()
+        ),
+    );
+    for &id in &changed {
+        emit_or_warn(db_path, "update", Some(id), Some("replace"));
+    }
+    say!("Made {summary}.");
+}
+
+/// `pm dedupe`: list groups of likely duplicate tasks, then offer to merge
+/// each one into the task the user picks. Every merge is saved as it is
+/// made, so quitting part-way keeps the ones already done.
+pub fn cmd_dedupe(db: &mut Database, db_path: &Path, fuzzy: bool, no_backup: bool) {
+    let groups = duplicate_groups(&db.tasks, fuzzy);
+    if groups.is_empty() {
+        println!("No duplicates found.");
+        return;
+    }
+    for (n, group) in groups.iter().enumerate() {
+        let parent = db
+            .get(group[0])
+            .and_then(|t| t.parent)
+            .map_or("top level".to_string(), |p| format!("under {p}"));
+        println!("Group {} ({parent}):", n + 1);
+        for &id in group {
+            if let Some(task) = db.get(id) {
+                println!(
+                    "  {:<8} {:<12} {}",
+                    id.to_string(),
+                    format_status(task.status),
+                    task.title
+                );
+            }
+        }
+    }
+
+    let mut backed_up = no_backup;
+    let mut merged = 0;
+    'groups: for (n, group) in groups.iter().enumerate() {
+        let keep = loop {
+            let Some(input) = prompt_line(&format!(
+                "\nGroup {}: keep which? (ID merges the rest into it; Enter skips, q quits): ",
+                n + 1
+            )) else {
+                break 'groups;
+            };
+            match input.as_str() {
+                "" => continue 'groups,
+                "q" => break 'groups,
+                _ => {}
+            }
+            match resolve_task_identifier(&input, db) {
+                Ok(id) if group.contains(&id) => break id,
+                Ok(id) => eprintln!("{id} is not in group {}.", n + 1),
+                Err(e) => eprintln!("Error resolving task: {e}"),
+            }
+        };
+        if !backed_up {
+            if !backup_before(db, db_path, "dedupe") {
+                println!("Dedupe cancelled.");
+                return;
+            }
+            backed_up = true;
+        }
+
+        let removed: Vec<LeafId> = group.iter().copied().filter(|&id| id != keep).collect();
+        // Children move the way `pm move` moves them, so their directories
+        // follow and their old addresses keep resolving.
+        let mut moved: Vec<LeafId> = db
+            .tasks
+            .iter()
+            .filter(|t| t.parent.is_some_and(|p| removed.contains(&p)))
+            .map(|t| t.id)
+            .collect();
+        moved.sort();
+        for &child in &moved {
+            if let Err(e) = relocate_ticket(db, db_path, child, Some(keep)) {
+                eprintln!("Failed to save DB: {e}");
+                std::process::exit(1);
+            }
+        }
+        let vacated: Vec<PathBuf> = removed
+            .iter()
+            .filter_map(|id| db.state.items.get(id))
+            .map(|entry| db_path.join(&entry.path))
+            .collect();
+        db.merge_into(keep, &removed);
+        if let Err(e) = db.save(db_path) {
+            eprintln!("Failed to save DB: {e}");
+            std::process::exit(1);
+        }
+        for dir in vacated.iter().filter(|dir| dir.exists()) {
+            if let Err(e) = fs::remove_dir_all(dir) {
+                eprintln!("dedupe: warning - could not remove {}: {e}", dir.display());
+            }
+        }
+        let list = removed
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let detail = format!("merged {list}");
+        commit_or_warn(db_path, &commit_subject_for(keep, "dedupe", Some(&detail)));
+        for &id in &removed {
+            emit_or_warn(
+                db_path,
+                "delete",
+                Some(id),
+                Some(&format!("merged into {keep}")),
+            );
+        }
+        for &id in &moved {
+            emit_or_warn(db_path, "move", Some(id), Some(&format!("-> {keep}")));
+        }
+        println!(
+            "Merged {list} into {keep} ({} child(ren) moved).",
+            moved.len()
+        );
+        merged += 1;
+    }
+    println!("\nMerged {merged} of {} group(s).", groups.len());
 }
 
 /// Mark a task as completed, optionally completing all descendants.
+#[allow(clippy::too_many_arguments)]
 pub fn cmd_complete(
     db: &mut Database,
     db_path: &Path,
@@ -1198,7 +2361,32 @@ pub fn cmd_complete(
     tag: Option<String>,
     project: Option<String>,
     status_filter: Option<Status>,
+    at: Option<String>,
+    force: bool,
 ) {
+    let now = Utc::now().timestamp();
+    let completed_at = match at.as_deref() {
+        None => now,
+        Some(raw) => match parse_completion_time(raw) {
+            Some(ts) if ts > now && !force => {
+                eprintln!("Error: --at {raw} is in the future; pass --force to record it anyway");
+                std::process::exit(1);
+            }
+            Some(ts) => {
+                if ts > now {
+                    eprintln!("warning: recording a completion in the future ({raw})");
+                }
+                ts
+            }
+            None => {
+                eprintln!(
+                    "Error: could not parse --at '{raw}' (try YYYY-MM-DD or YYYY-MM-DD HH:MM)"
+                );
+                std::process::exit(1);
+            }
+        },
+    };
+
     // Validate that exactly one option is provided
     let option_count = [
         id.is_some(),
@@ -1267,11 +2455,33 @@ pub fn cmd_complete(
             }
         }
     }
-    let completed = to_mark.clone();
+    // Definition-of-done gate: a parent may only close together with its
+    // open descendants.
+    if Config::load(db_path)
+        .complete
+        .block_parent_completion_with_open_children
+    {
+        let targets: Vec<LeafId> = to_mark.iter().copied().collect();
+        if let Some((blocked, open)) = completion_blocker(db, &targets) {
+            eprintln!(
+                "Error: {}; complete them first or use --recurse",
+                format_completion_blocker(blocked, &open)
+            );
+            std::process::exit(1);
+        }
+    }
+    let mut targets: Vec<LeafId> = to_mark.iter().copied().collect();
+    targets.sort();
+    check_status_flow(db, db_path, &targets, Status::Done);
+    let completed = to_mark.clone();
+    let workflow = Config::load(db_path).workflow;
     for tid in to_mark {
         if let Some(t) = db.get_mut(tid) {
+            let from = t.status;
             t.status = Status::Done;
-            t.updated_at_utc = Utc::now().timestamp();
+            advance_stage(t, from, &workflow);
+            t.updated_at_utc = completed_at;
+            t.completed_at_utc = Some(completed_at);
         }
     }
     if let Err(e) = db.save(db_path) {
@@ -1289,7 +2499,47 @@ pub fn cmd_complete(
     for tid in &completed {
         emit_or_warn(db_path, "complete", Some(*tid), None);
     }
-    println!("Marked done.");
+    say!("Marked done.");
+    say_parent_progress(db, &completed);
+}
+
+/// Report what a completion did for the parents above it: one line for a
+/// single parent, a list when a bulk completion touched several.
+fn say_parent_progress(db: &Database, completed: &HashSet<LeafId>) {
+    let progress = parent_progress(db, completed);
+    let line = |p: &ParentProgress| {
+        let title = db.get(p.parent).map_or("", |t| t.title.as_str());
+        let all = if p.done == p.children {
+            " - all children done"
+        } else {
+            ""
+        };
+        format!("'{title}' now {}/{} done{all}", p.done, p.children)
+    };
+    match progress.as_slice() {
+        [] => {}
+        [only] => say!("Parent {}", line(only)),
+        many => {
+            say!("Progress on {} parents:", many.len());
+            for p in many {
+                say!("  {} {}", p.parent, line(p));
+            }
+        }
+    }
+}
+
+/// Exit with an error when `enforce_status_flow` is on and moving any of
+/// `ids` to `to` would skip a step of the status flow.
+fn check_status_flow(db: &Database, pm_dir: &Path, ids: &[LeafId], to: Status) {
+    if !Config::load(pm_dir).workflow.enforce_status_flow {
+        return;
+    }
+    for &id in ids {
+        if let Some(e) = db.get(id).and_then(|t| status_flow_error(id, t.status, to)) {
+            eprintln!("Error: {e} (enforce_status_flow is on)");
+            std::process::exit(1);
+        }
+    }
 }
 
 /// Reopen a completed task by setting its status to Open.
@@ -1306,7 +2556,9 @@ pub fn cmd_reopen(db: &mut Database, db_path: &Path, id: String) {
         eprintln!("Task {} not found.", task_id);
         std::process::exit(1);
     };
+    let from = t.status;
     t.status = Status::Open;
+    advance_stage(t, from, &Config::load(db_path).workflow);
     t.updated_at_utc = Utc::now().timestamp();
     if let Err(e) = db.save(db_path) {
         eprintln!("Failed to save DB: {e}");
@@ -1314,10 +2566,30 @@ pub fn cmd_reopen(db: &mut Database, db_path: &Path, id: String) {
     }
     commit_or_warn(db_path, &commit_subject_for(task_id, "reopen", None));
     emit_or_warn(db_path, "reopen", Some(task_id), None);
-    println!("Reopened {}", task_id);
+    say!("Reopened {}", task_id);
+}
+
+/// Bulk deletes removing more tasks than this ask first, or need `--yes`.
+const BULK_DELETE_CONFIRM_OVER: usize = 10;
+
+/// Ask on the terminal whether to delete `count` tasks. Without a terminal
+/// to ask on, refuse and point at `--yes`.
+fn confirm_bulk_delete(count: usize) -> bool {
+    use std::io::{IsTerminal, Write};
+    if !std::io::stdin().is_terminal() {
+        eprintln!(
+            "Error: refusing to delete {count} tasks without confirmation; pass --yes to go ahead"
+        );
+        std::process::exit(1);
+    }
+    eprint!("Delete {count} tasks? [y/N] ");
+    std::io::stderr().flush().ok();
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer).is_ok() && answer.trim().to_lowercase().starts_with('y')
 }
 
 /// Delete a task, optionally cascading to all descendants.
+#[allow(clippy::too_many_arguments)]
 pub fn cmd_delete(
     db: &mut Database,
     db_path: &Path,
@@ -1326,6 +2598,8 @@ pub fn cmd_delete(
     tag: Option<String>,
     project: Option<String>,
     status_filter: Option<Status>,
+    yes: bool,
+    no_backup: bool,
 ) {
     // Validate that exactly one option is provided
     let option_count = [
@@ -1395,17 +2669,50 @@ pub fn cmd_delete(
             return;
         }
 
-        // Show what will be deleted
-        println!("Will delete {} task(s):", to_delete.len());
-        for &task_id in &to_delete {
-            if let Some(task) = db.get(task_id) {
-                println!("  {} - {}", task_id, task.title);
-            }
+        // A matched task goes with its subtree, as a cascading single
+        // delete would, so no descendant is left pointing at a gone parent.
+        let matched = to_delete.clone();
+        let child_map = build_children_map(&db.tasks);
+        for &task_id in &matched {
+            collect_descendants(task_id, &child_map, &mut to_delete);
+        }
+
+        let descendants = to_delete.len() - matched.len();
+        if descendants == 0 {
+            println!("Will delete {} task(s):", to_delete.len());
+        } else {
+            println!(
+                "Will delete {} task(s): {} matched and {} descendant(s) of them:",
+                to_delete.len(),
+                matched.len(),
+                descendants
+            );
+        }
+        for task in db.tasks.iter().filter(|t| to_delete.contains(&t.id)) {
+            let note = if matched.contains(&task.id) {
+                ""
+            } else {
+                " (descendant)"
+            };
+            println!("  {} - {}{}", task.id, task.title, note);
+        }
+
+        if to_delete.len() > BULK_DELETE_CONFIRM_OVER
+            && !yes
+            && !confirm_bulk_delete(to_delete.len())
+        {
+            println!("Delete cancelled.");
+            return;
         }
     }
 
     let ids = to_delete;
     let count = ids.len();
+    // Removing a single ticket is routine; anything wider backs up first.
+    if count > 1 && !no_backup && !backup_before(db, db_path, "delete") {
+        println!("Delete cancelled.");
+        return;
+    }
     let first = ids.iter().next().copied();
     // Snapshot the ids before they are removed so the feed can credit each.
     let deleted: Vec<crate::store::LeafId> = ids.iter().copied().collect();
@@ -1422,25 +2729,115 @@ pub fn cmd_delete(
     for id in &deleted {
         emit_or_warn(db_path, "delete", Some(*id), None);
     }
-    println!("Deleted.");
+    say!("Deleted.");
 }
 
 /// List all distinct project names derived from each task's parent chain.
-/// A task without a Project ancestor is bucketed under `-`.
-pub fn cmd_projects(db: &Database) {
+/// A task without a Project ancestor is bucketed under `-`. With `verbose`,
+/// also list the Project tickets and their configured add defaults, then
+/// the project files next to `db_path`.
+pub fn cmd_projects(db: &Database, db_path: &Path, verbose: bool) {
     let mut counts: BTreeMap<String, usize> = BTreeMap::new();
     for t in &db.tasks {
         let key = project_label(db, t);
         *counts.entry(key).or_default() += 1;
     }
+    if verbose {
+        println!("Tasks in this database by Project ticket:");
+    }
     println!("{:<16} {}", "Project", "Count");
     for (p, c) in counts {
         println!("{:<16} {}", truncate(&p, 16), c);
     }
+    if !verbose {
+        return;
+    }
+
+    let config = Config::load(db_path);
+    let mut projects: Vec<&Task> = db
+        .tasks
+        .iter()
+        .filter(|t| t.kind == Kind::Project)
+        .collect();
+    projects.sort_by_key(|t| t.id);
+    println!();
+    println!("Project tickets:");
+    println!("{:<8} {:<16} Add defaults", "ID", "Title");
+    let mut matched: HashSet<&str> = HashSet::new();
+    for p in &projects {
+        let id = p.id.to_string();
+        let defaults = config.project_defaults(&id, &p.title);
+        matched.extend(config.project_key(&id, &p.title));
+        let shown = defaults
+            .filter(|d| !d.is_empty())
+            .map(format_project_defaults)
+            .unwrap_or_else(|| "-".to_string());
+        println!("{:<8} {:<16} {}", id, truncate(&p.title, 16), shown);
+    }
+    for key in config.projects.keys() {
+        if !matched.contains(key.as_str()) {
+            eprintln!("warning: config.json projects.{key} matches no project");
+        }
+    }
+
+    print_project_files(&crate::project::workspace_dir(db_path));
+}
+
+/// The project files in `workspace`, as `pm menu` lists them, with their
+/// task counts, last change and path.
+fn print_project_files(workspace: &Path) {
+    use crate::project::{discover_projects, get_legacy_project};
+
+    let mut files = discover_projects(workspace).unwrap_or_default();
+    files.extend(get_legacy_project(workspace));
+    println!();
+    if files.is_empty() {
+        println!(
+            "No project files in {} (pm menu creates them).",
+            workspace.display()
+        );
+        return;
+    }
+    println!(
+        "Project files in {} (pm menu, pm --project):",
+        workspace.display()
+    );
+    println!(
+        "{:<20} {:>6} {:>6} {:<16} File",
+        "Name", "Tasks", "Open", "Modified"
+    );
+    for project in files {
+        let summary = project.summary();
+        let modified = fs::metadata(&project.file_path)
+            .and_then(|m| m.modified())
+            .map(|t| {
+                chrono::DateTime::<Local>::from(t)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_else(|_| "-".to_string());
+        println!(
+            "{:<20} {:>6} {:>6} {:<16} {}",
+            truncate(&project.display_name, 20),
+            summary.total,
+            summary.open,
+            modified,
+            project.file_path.display()
+        );
+    }
 }
 
 /// List all distinct tags with their usage counts.
-pub fn cmd_tags(db: &Database) {
+pub fn cmd_tags(db: &Database, tree: bool) {
+    if tree {
+        println!("{:<32} Count", "Tag");
+        for (path, c) in tag_tree(&db.tasks) {
+            let depth = path.len() - 1;
+            let label = format!("{}{}", "  ".repeat(depth), path[depth]);
+            println!("{:<32} {}", truncate(&label, 32), c);
+        }
+        return;
+    }
     let mut counts: BTreeMap<String, usize> = BTreeMap::new();
     for t in &db.tasks {
         for tag in &t.tags {
@@ -1453,6 +2850,288 @@ pub fn cmd_tags(db: &Database) {
     }
 }
 
+/// Print task counts grouped on `by`, after applying the list filters.
+#[allow(clippy::too_many_arguments)]
+pub fn cmd_count(
+    db: &Database,
+    db_path: &Path,
+    by: CountBy,
+    all: bool,
+    status: Option<Status>,
+    kind: Option<Kind>,
+    project: Option<String>,
+    tags: Vec<String>,
+    expand_tags: bool,
+    due: Option<DueFilter>,
+    iteration: Option<String>,
+    json: bool,
+) {
+    let tags = split_and_normalise_tags(&tags);
+    let today = Local::now().date_naive();
+    let tasks: Vec<&Task> = db
+        .tasks
+        .iter()
+        .filter(|t| match status {
+            Some(s) => t.status == s,
+            None => all || !t.status.is_closed(),
+        })
+        .filter(|t| kind.is_none_or(|k| t.kind == k))
+        .filter(|t| project.as_ref().is_none_or(|p| project_label(db, t) == *p))
+        .filter(|t| has_all_tags(&t.tags, &tags, expand_tags))
+        .filter(|t| due.is_none_or(|df| due_filter_matches(t.due, t.remind_days_before, df, today)))
+        .filter(|t| iteration.is_none() || t.iteration == iteration)
+        .collect();
+
+    let assignees: HashMap<LeafId, String> = if by == CountBy::Assignee {
+        let now = Utc::now();
+        crate::store::locks::list(db_path)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|lock| !lock.is_stale(now))
+            .map(|lock| (lock.id, lock.agent))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+    let rows = count_by(db, &tasks, by, &assignees);
+
+    if json {
+        match serde_json::to_string_pretty(&rows) {
+            Ok(out) => println!("{out}"),
+            Err(e) => {
+                eprintln!("Failed to serialise counts: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    let heading = match by {
+        CountBy::Status => "Status",
+        CountBy::Kind => "Kind",
+        CountBy::Project => "Project",
+        CountBy::ProcessStage => "Stage",
+        CountBy::Tag => "Tag",
+        CountBy::Assignee => "Assignee",
+    };
+    println!("{:<24} Count", heading);
+    for row in rows {
+        println!("{:<24} {}", truncate(&row.value, 24), row.count);
+    }
+}
+
+/// Report on one iteration, or list all of them with their progress.
+pub fn cmd_iteration(db: &Database, name: Option<String>) {
+    let Some(name) = name else {
+        let mut counts: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+        for t in &db.tasks {
+            if let Some(it) = t.iteration.as_deref() {
+                let entry = counts.entry(it).or_default();
+                entry.1 += 1;
+                if t.status == Status::Done {
+                    entry.0 += 1;
+                }
+            }
+        }
+        if counts.is_empty() {
+            println!("No iterations yet. Plan a task with `pm update <ID> --iteration <NAME>`.");
+            return;
+        }
+        println!("{:<16} {:>5} {:>6}", "Iteration", "Done", "Total");
+        for (it, (done, total)) in counts {
+            println!("{:<16} {:>5} {:>6}", truncate(it, 16), done, total);
+        }
+        return;
+    };
+
+    let committed: Vec<&Task> = db
+        .tasks
+        .iter()
+        .filter(|t| t.iteration.as_deref() == Some(name.as_str()))
+        .collect();
+    if committed.is_empty() {
+        eprintln!("No tasks in iteration '{name}'.");
+        std::process::exit(1);
+    }
+    let completed = committed
+        .iter()
+        .filter(|t| t.status == Status::Done)
+        .count();
+    let cancelled = committed
+        .iter()
+        .filter(|t| t.status == Status::Cancelled)
+        .count();
+    let mut remaining: Vec<&Task> = committed
+        .iter()
+        .copied()
+        .filter(|t| !t.status.is_closed())
+        .collect();
+    sort_tasks(&mut remaining, SortKey::Priority);
+
+    println!("Iteration {name}");
+    println!("Committed:  {}", committed.len());
+    println!(
+        "Completed:  {} ({}%)",
+        completed,
+        completed * 100 / committed.len()
+    );
+    if cancelled > 0 {
+        println!("Cancelled:  {cancelled}");
+    }
+    println!("Remaining:  {}", remaining.len());
+    if remaining.is_empty() {
+        return;
+    }
+    println!();
+    println!("{:<8} {:<12} {:<12} Title", "ID", "Status", "Priority");
+    for t in remaining {
+        println!(
+            "{:<8} {:<12} {:<12} {}",
+            t.id.to_string(),
+            format_status(t.status),
+            format_priority(t.priority_level),
+            t.title
+        );
+    }
+}
+
+/// `pm milestones`: the milestone timeline with a readiness count for each.
+pub fn cmd_milestones(db: &Database) {
+    let timeline = milestone_timeline(db);
+    if timeline.is_empty() {
+        println!("No milestones yet. Add one with `pm add <TITLE> --kind milestone --due <DATE>`.");
+        return;
+    }
+    println!("{:<10} {:<8} {:<12} Readiness", "Due", "ID", "Status");
+    for entry in timeline {
+        let Some(m) = db.get(entry.id) else { continue };
+        let readiness = match m.due {
+            None => format!("no date set for '{}'", m.title),
+            Some(_) => format!(
+                "{}/{} done before '{}'",
+                entry.done_before, entry.due_before, m.title
+            ),
+        };
+        println!(
+            "{:<10} {:<8} {:<12} {}",
+            m.due.map_or("-".to_string(), |d| d.to_string()),
+            m.id.to_string(),
+            format_status(m.status),
+            readiness
+        );
+    }
+}
+
+/// `pm burndown`: one point per day from the first counted task's creation
+/// to today, as `date,remaining_minutes` CSV or a JSON array.
+pub fn cmd_burndown(
+    db: &Database,
+    iteration: Option<String>,
+    format: ChartFormat,
+    output: Option<String>,
+) {
+    let tasks: Vec<&Task> = db
+        .tasks
+        .iter()
+        .filter(|t| t.estimate_minutes.is_some())
+        .filter(|t| iteration.is_none() || t.iteration == iteration)
+        .collect();
+    let local_date = |ts: i64| {
+        Local
+            .timestamp_opt(ts, 0)
+            .single()
+            .map(|dt| dt.date_naive())
+    };
+    let Some(from) = tasks
+        .iter()
+        .filter_map(|t| local_date(t.created_at_utc))
+        .min()
+    else {
+        eprintln!("No estimated tasks to chart. Add one with `pm update <ID> --estimate 2h`.");
+        std::process::exit(1);
+    };
+    let points = burndown(&tasks, from, Local::now().date_naive(), &Local);
+    let rendered = match format {
+        ChartFormat::Csv => {
+            let mut csv = String::from("date,remaining_minutes\n");
+            for p in &points {
+                csv.push_str(&format!("{},{}\n", p.date, p.remaining_minutes));
+            }
+            csv
+        }
+        ChartFormat::Json => match serde_json::to_string_pretty(&points) {
+            Ok(json) => json + "\n",
+            Err(e) => {
+                eprintln!("Failed to serialise burndown: {e}");
+                std::process::exit(1);
+            }
+        },
+    };
+    match output {
+        Some(path) => {
+            if let Err(e) = std::fs::write(&path, rendered) {
+                eprintln!("Failed to write {path}: {e}");
+                std::process::exit(1);
+            }
+            say!("Wrote {} day(s) to {}", points.len(), path);
+        }
+        None => print!("{rendered}"),
+    }
+}
+
+/// Widest bar `pm stats --velocity` draws; busier weeks are scaled down.
+const VELOCITY_BAR_WIDTH: usize = 40;
+
+/// `pm stats --velocity`: completions per ISO week as an ASCII bar chart,
+/// followed by the weekly average.
+pub fn cmd_stats(
+    db: &Database,
+    velocity: bool,
+    weeks: usize,
+    project: Option<String>,
+    iteration: Option<String>,
+) {
+    if !velocity {
+        eprintln!("Pick a report: pm stats --velocity");
+        std::process::exit(1);
+    }
+    if weeks == 0 {
+        eprintln!("--weeks must be at least 1.");
+        std::process::exit(1);
+    }
+    let tasks: Vec<&Task> = db
+        .tasks
+        .iter()
+        .filter(|t| project.as_ref().is_none_or(|p| project_label(db, t) == *p))
+        .filter(|t| iteration.is_none() || t.iteration == iteration)
+        .collect();
+    let report = crate::db::velocity(&tasks, weeks, Local::now().date_naive(), &Local);
+    let most = report.iter().map(|w| w.completed).max().unwrap_or(0);
+    println!("{:<9} {:<10} {:>5}", "Week", "Starts", "Done");
+    for week in &report {
+        let bar = if most <= VELOCITY_BAR_WIDTH {
+            week.completed
+        } else {
+            (week.completed * VELOCITY_BAR_WIDTH).div_ceil(most)
+        };
+        let iso = week.week_start.iso_week();
+        let row = format!(
+            "{:<9} {:<10} {:>5} {}",
+            format!("{}-W{:02}", iso.year(), iso.week()),
+            week.week_start,
+            week.completed,
+            "#".repeat(bar)
+        );
+        println!("{}", row.trim_end());
+    }
+    let total: usize = report.iter().map(|w| w.completed).sum();
+    println!(
+        "Average: {:.1} per week over {} week{} ({total} completed)",
+        total as f64 / weeks as f64,
+        weeks,
+        if weeks == 1 { "" } else { "s" }
+    );
+}
+
 /// Generate shell completion scripts.
 pub fn cmd_completions(shell: Shell) {
     use crate::cli::Cli;
@@ -1463,6 +3142,113 @@ pub fn cmd_completions(shell: Shell) {
     generate(shell, &mut app, app_name, &mut std::io::stdout());
 }
 
+/// Print the TUI keybindings and every visible subcommand with its
+/// options as markdown. Keys come from the shared table in
+/// [`crate::tui::keyhelp`] with their default bindings.
+pub fn cmd_help_dump() {
+    use crate::cli::Cli;
+    use crate::tui::keyhelp::{Binding, SECTIONS};
+    use crate::tui::keymap::Keymap;
+    use clap::CommandFactory;
+
+    let keymap = Keymap::default();
+    println!("# pm keybindings");
+    for section in SECTIONS {
+        println!();
+        println!("## {}", section.title);
+        println!();
+        println!("| Key | Action | Config name |");
+        println!("| --- | --- | --- |");
+        for binding in section.bindings {
+            let name = match binding {
+                Binding::Action(action) => format!("`{}`", action.name()),
+                _ => String::new(),
+            };
+            let key = match binding {
+                Binding::Note(_) => String::new(),
+                Binding::Action(action) if keymap.key(*action).is_none() => "unbound".to_string(),
+                _ => format!("`{}`", binding.key_label(&keymap).replace('|', "\\|")),
+            };
+            println!(
+                "| {key} | {} | {name} |",
+                binding.description().replace('|', "\\|")
+            );
+        }
+    }
+
+    let cli = Cli::command();
+    println!();
+    println!("# pm commands");
+    println!();
+    println!("## Global options");
+    println!();
+    for arg in cli.get_arguments() {
+        print_help_dump_arg(arg);
+    }
+    for sub in cli.get_subcommands() {
+        print_help_dump_command(sub, "pm");
+    }
+}
+
+/// One subcommand section of `pm help-dump`, then its own subcommands.
+fn print_help_dump_command(command: &clap::Command, parent: &str) {
+    if command.is_hide_set() || command.get_name() == "help" {
+        return;
+    }
+    let path = format!("{parent} {}", command.get_name());
+    println!();
+    println!("## {path}");
+    if let Some(about) = command.get_long_about().or(command.get_about()) {
+        println!();
+        println!("{about}");
+    }
+    let args: Vec<&clap::Arg> = command
+        .get_arguments()
+        .filter(|arg| !arg.is_hide_set() && !matches!(arg.get_id().as_str(), "help" | "version"))
+        .collect();
+    if !args.is_empty() {
+        println!();
+        for arg in args {
+            print_help_dump_arg(arg);
+        }
+    }
+    for sub in command.get_subcommands() {
+        print_help_dump_command(sub, &path);
+    }
+}
+
+/// One option as a markdown list item: its spelling, value and help.
+fn print_help_dump_arg(arg: &clap::Arg) {
+    let value = arg
+        .get_value_names()
+        .and_then(|names| names.first())
+        .map_or_else(|| arg.get_id().as_str().to_uppercase(), |n| n.to_string());
+    let mut spelling = match (arg.get_short(), arg.get_long()) {
+        _ if arg.is_positional() => format!("<{value}>"),
+        (Some(short), Some(long)) => format!("-{short}, --{long}"),
+        (None, Some(long)) => format!("--{long}"),
+        (Some(short), None) => format!("-{short}"),
+        (None, None) => arg.get_id().to_string(),
+    };
+    if !arg.is_positional() && arg.get_action().takes_values() {
+        spelling.push_str(&format!(" <{value}>"));
+    }
+    let mut line = format!("- `{spelling}`");
+    if let Some(help) = arg.get_help() {
+        line.push_str(&format!(": {}", help.to_string().replace('\n', " ")));
+    }
+    let choices: Vec<String> = arg
+        .get_possible_values()
+        .iter()
+        .filter(|v| !v.is_hide_set())
+        .map(|v| v.get_name().to_string())
+        .collect();
+    if arg.get_action().takes_values() && !choices.is_empty() {
+        line.push_str(&format!(" [{}]", choices.join(", ")));
+    }
+    println!("{line}");
+}
+
 /// Handle template management commands.
 pub fn cmd_template(db: &mut Database, db_path: &Path, action: TemplateAction) {
     match action {
@@ -1496,6 +3282,8 @@ pub fn cmd_template(db: &mut Database, db_path: &Path, action: TemplateAction) {
                 name: template_name.clone(),
                 title_template: Some(task.title.clone()),
                 description_template: task.description.clone(),
+                user_story_template: task.user_story.clone(),
+                requirements_template: task.requirements.clone(),
                 tags: task.tags.clone(),
                 kind: task.kind,
                 priority_level: task.priority_level,
@@ -1511,9 +3299,10 @@ pub fn cmd_template(db: &mut Database, db_path: &Path, action: TemplateAction) {
                 std::process::exit(1);
             }
 
-            println!(
+            say!(
                 "Saved template '{}' from task {}",
-                template_name, task_id_resolved
+                template_name,
+                task_id_resolved
             );
         }
 
@@ -1548,13 +3337,15 @@ pub fn cmd_template(db: &mut Database, db_path: &Path, action: TemplateAction) {
                 std::process::exit(1);
             }
 
-            println!("Deleted template '{}'", template_name);
+            say!("Deleted template '{}'", template_name);
         }
 
         TemplateAction::Create {
             name,
             title_template,
             description,
+            user_story,
+            requirements,
             tags,
             kind,
             priority,
@@ -1578,6 +3369,8 @@ pub fn cmd_template(db: &mut Database, db_path: &Path, action: TemplateAction) {
                 name: name.clone(),
                 title_template,
                 description_template: description,
+                user_story_template: user_story.map(|s| s.replace("\\n", "\n")),
+                requirements_template: requirements.map(|s| s.replace("\\n", "\n")),
                 tags: template_tags,
                 kind,
                 priority_level: priority,
@@ -1593,7 +3386,7 @@ pub fn cmd_template(db: &mut Database, db_path: &Path, action: TemplateAction) {
                 std::process::exit(1);
             }
 
-            println!("Created template '{}'", name);
+            say!("Created template '{}'", name);
         }
         TemplateAction::Edit { kind } => {
             cmd_template_edit(db_path, &kind);
@@ -1644,7 +3437,7 @@ pub fn cmd_template_edit(pm_dir: &Path, kind: &str) {
 
     let editor = std::env::var("EDITOR").unwrap_or_else(|_| "nano".to_string());
     match std::process::Command::new(&editor).arg(&target).status() {
-        Ok(st) if st.success() => println!("Saved template at {}", target.display()),
+        Ok(st) if st.success() => say!("Saved template at {}", target.display()),
         Ok(st) => {
             eprintln!("template edit: $EDITOR exited with status {st}");
             std::process::exit(st.code().unwrap_or(1));
@@ -1702,37 +3495,156 @@ pub fn cmd_template_apply(db: &mut Database, pm_dir: &Path, id: &str) {
         &commit_subject_for(leaf, "template apply", Some(stem)),
     );
     emit_or_warn(pm_dir, "template-apply", Some(leaf), Some(stem));
-    println!("Applied {stem} template to {leaf}");
+    say!("Applied {stem} template to {leaf}");
 }
 
-/// Export tasks to CSV format for external analysis and time tracking.
+/// Export tasks to CSV format for external analysis and time tracking, or as
+/// a JSON [`TaskBundle`] that `pm import` can merge into another workspace.
+///
+/// `id` narrows the export to one ticket (plus its descendants with
+/// `recurse`) and bypasses the status, project and tag filters so a shared
+/// subtree is never missing interior nodes.
+#[allow(clippy::too_many_arguments)]
 pub fn cmd_export(
     db: &Database,
     output: Option<String>,
+    id: Option<String>,
+    recurse: bool,
+    format: ExportFormat,
     all: bool,
     project: Option<String>,
     tag: Option<String>,
 ) {
-    let output_path = output.unwrap_or_else(|| "tasks.csv".to_string());
+    let output_path = output.unwrap_or_else(|| match format {
+        ExportFormat::Csv => "tasks.csv".to_string(),
+        ExportFormat::Json => "tasks.json".to_string(),
+        ExportFormat::Taskwarrior => "taskwarrior.json".to_string(),
+    });
 
-    // Filter tasks
-    let tasks: Vec<&Task> = db
-        .tasks
-        .iter()
-        .filter(|task| {
-            // Include completed tasks only if --all is specified
-            if !all && task.status == Status::Done {
-                return false;
+    let subtree = id.map(|id| {
+        let root = match resolve_task_identifier(&id, db) {
+            Ok(root) => root,
+            Err(e) => {
+                eprintln!("Error resolving task: {}", e);
+                std::process::exit(1);
             }
-
-            // Project filter derives the project label from the parent chain.
-            if let Some(ref proj_filter) = project {
-                if project_label(db, task) != *proj_filter {
-                    return false;
-                }
+        };
+        match TaskBundle::from_subtree(db, root, recurse) {
+            Ok(bundle) => bundle,
+            Err(e) => {
+                eprintln!("Export failed: {e}");
+                std::process::exit(1);
             }
+        }
+    });
 
-            // Tag filter
+    if format == ExportFormat::Taskwarrior {
+        let tasks: Vec<&Task> = match &subtree {
+            Some(bundle) => bundle.tasks.iter().collect(),
+            None => export_filter(db, all, &project, &tag),
+        };
+        let items = taskwarrior::to_taskwarrior(db, &tasks);
+        let json = match serde_json::to_string_pretty(&items) {
+            Ok(json) => json,
+            Err(e) => {
+                eprintln!("Failed to serialise Taskwarrior tasks: {e}");
+                std::process::exit(1);
+            }
+        };
+        if let Err(e) = std::fs::write(&output_path, json) {
+            eprintln!("Failed to write JSON file: {}", e);
+            std::process::exit(1);
+        }
+        say!(
+            "Exported {} task(s) to {}; load them with `task import {}`",
+            items.len(),
+            output_path,
+            output_path
+        );
+        return;
+    }
+
+    if format == ExportFormat::Json {
+        let bundle = subtree.unwrap_or_else(|| {
+            TaskBundle::from_tasks(
+                export_filter(db, all, &project, &tag)
+                    .into_iter()
+                    .cloned()
+                    .collect(),
+            )
+        });
+        let json = match serde_json::to_string_pretty(&bundle) {
+            Ok(json) => json,
+            Err(e) => {
+                eprintln!("Failed to serialise bundle: {e}");
+                std::process::exit(1);
+            }
+        };
+        if let Err(e) = std::fs::write(&output_path, json) {
+            eprintln!("Failed to write JSON file: {}", e);
+            std::process::exit(1);
+        }
+        say!("Exported {} task(s) to {}", bundle.tasks.len(), output_path);
+        return;
+    }
+
+    let tasks: Vec<&Task> = match &subtree {
+        Some(bundle) => bundle.tasks.iter().collect(),
+        None => export_filter(db, all, &project, &tag),
+    };
+    write_csv_export(db, &tasks, &output_path);
+}
+
+/// `pm export --templates-only`: write the workspace's task templates as a
+/// JSON array for `pm import --templates` in another workspace.
+pub fn cmd_export_templates(db: &Database, output: Option<String>, format: ExportFormat) {
+    if format != ExportFormat::Json {
+        eprintln!("--templates-only exports JSON only; add --format json.");
+        std::process::exit(1);
+    }
+    let output_path = output.unwrap_or_else(|| "templates.json".to_string());
+    let json = match serde_json::to_string_pretty(&db.state.templates) {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("Failed to serialise templates: {e}");
+            std::process::exit(1);
+        }
+    };
+    if let Err(e) = std::fs::write(&output_path, json) {
+        eprintln!("Failed to write JSON file: {}", e);
+        std::process::exit(1);
+    }
+    say!(
+        "Exported {} template(s) to {}",
+        db.state.templates.len(),
+        output_path
+    );
+}
+
+/// The `pm export` selection: open tasks (all with `all`), optionally
+/// narrowed to one project label and one tag.
+fn export_filter<'a>(
+    db: &'a Database,
+    all: bool,
+    project: &Option<String>,
+    tag: &Option<String>,
+) -> Vec<&'a Task> {
+    db.tasks
+        .iter()
+        .filter(|task| {
+            // Include closed tasks only if --all is specified
+            if !all && task.status.is_closed() {
+                return false;
+            }
+
+            // Project filter derives the project label from the parent chain.
+            if let Some(ref proj_filter) = project {
+                if project_label(db, task) != *proj_filter {
+                    return false;
+                }
+            }
+
+            // Tag filter
             if let Some(ref tag_filter) = tag {
                 if !task.tags.iter().any(|t| t == tag_filter) {
                     return false;
@@ -1741,17 +3653,21 @@ pub fn cmd_export(
 
             true
         })
-        .collect();
+        .collect()
+}
 
+/// Write `tasks` as CSV rows to `output_path`.
+fn write_csv_export(db: &Database, tasks: &[&Task], output_path: &str) {
     // Create CSV content
     let mut csv_content = String::new();
 
     // CSV Header
-    csv_content.push_str("ID,Title,Kind,Status,Priority,Urgency,ProcessStage,Project,Tags,Due,Parent,CreatedUTC,UpdatedUTC,Description\n");
+    csv_content.push_str(CSV_HEADER);
+    csv_content.push('\n');
 
     // CSV Rows
     let task_count = tasks.len();
-    for task in &tasks {
+    for task in tasks {
         let priority = task
             .priority_level
             .map(|p| format_priority(Some(p)))
@@ -1813,9 +3729,9 @@ pub fn cmd_export(
     }
 
     // Write to file
-    match std::fs::write(&output_path, csv_content) {
+    match std::fs::write(output_path, csv_content) {
         Ok(_) => {
-            println!("Exported {} task(s) to {}", task_count, output_path);
+            say!("Exported {} task(s) to {}", task_count, output_path);
         }
         Err(e) => {
             eprintln!("Failed to write CSV file: {}", e);
@@ -1855,31 +3771,54 @@ pub fn create_backup(db_path: &Path) -> Result<String, std::io::Error> {
     Ok(backup_path.to_string_lossy().to_string())
 }
 
-/// Import tasks from CSV format with automatic backup.
-pub fn cmd_import(db: &mut Database, db_path: &Path, input: String, no_backup: bool) {
-    // Create backup unless explicitly disabled
-    if !no_backup {
-        match create_backup(db_path) {
-            Ok(backup_path) => {
-                println!("Created backup: {}", backup_path);
-            }
-            Err(e) => {
-                eprintln!("Warning: Failed to create backup: {}", e);
-                print!("Continue without backup? (y/N): ");
-                use std::io::{self, Write};
-                io::stdout().flush().unwrap();
-
-                let mut response = String::new();
-                if io::stdin().read_line(&mut response).is_err()
-                    || !response.trim().to_lowercase().starts_with('y')
-                {
-                    println!("Import cancelled.");
-                    return;
-                }
-            }
+/// Back up the workspace before the destructive `operation` runs, as a
+/// `pre-<operation>-<timestamp>` snapshot. A v2 workspace is a directory,
+/// which `create_backup` cannot copy; a legacy single-file database is copied
+/// instead. If the backup fails the user is asked whether to go on anyway;
+/// returns false when they decline. Callers skip this under `--no-backup`.
+pub fn backup_before(db: &Database, db_path: &Path, operation: &str) -> bool {
+    let backup = if db_path.is_dir() {
+        let name = format!(
+            "pre-{operation}-{}",
+            Local::now().format("%Y-%m-%d_%H-%M-%S")
+        );
+        crate::store::snapshots::write(db, db_path, &name, true)
+            .map(|path| path.display().to_string())
+            .map_err(|e| e.to_string())
+    } else {
+        create_backup(db_path).map_err(|e| e.to_string())
+    };
+    match backup {
+        Ok(backup_path) => {
+            say!("Created backup: {}", backup_path);
+            true
+        }
+        Err(e) => {
+            eprintln!("Warning: Failed to create backup: {}", e);
+            print!("Continue without backup? (y/N): ");
+            use std::io::{self, Write};
+            io::stdout().flush().unwrap();
+
+            let mut response = String::new();
+            io::stdin().read_line(&mut response).is_ok()
+                && response.trim().to_lowercase().starts_with('y')
         }
     }
+}
+
+/// Header line of the CSV written by `pm export` and read by `pm import`.
+const CSV_HEADER: &str = "ID,Title,Kind,Status,Priority,Urgency,ProcessStage,Project,Tags,Due,Parent,CreatedUTC,UpdatedUTC,Description";
 
+/// Import tasks from CSV format or a JSON task bundle, with automatic backup.
+pub fn cmd_import(
+    db: &mut Database,
+    db_path: &Path,
+    input: String,
+    no_backup: bool,
+    update: bool,
+    templates: bool,
+    overwrite: bool,
+) {
     // Read CSV file
     let csv_content = match fs::read_to_string(&input) {
         Ok(content) => content,
@@ -1889,24 +3828,65 @@ pub fn cmd_import(db: &mut Database, db_path: &Path, input: String, no_backup: b
         }
     };
 
-    let lines: Vec<&str> = csv_content.lines().collect();
-    if lines.is_empty() {
-        eprintln!("CSV file is empty");
-        std::process::exit(1);
-    }
-
-    // Parse header to validate format
-    let expected_header = "ID,Title,Kind,Status,Priority,Urgency,ProcessStage,Project,Tags,Due,Parent,CreatedUTC,UpdatedUTC,Description";
-    if lines[0] != expected_header {
+    // Reject the arguments or the file before backing up, so a failed
+    // import doesn't leave a snapshot behind.
+    let taskwarrior = !templates && csv_content.trim_start().starts_with('[');
+    let bundle = !templates
+        && !taskwarrior
+        && (input.ends_with(".json") || csv_content.trim_start().starts_with('{'));
+    if update && taskwarrior {
         eprintln!(
-            "Invalid CSV header. Expected:\n{}\nGot:\n{}",
-            expected_header, lines[0]
+            "--update applies to CSV files only; Taskwarrior tasks always import as new tasks"
         );
         std::process::exit(1);
     }
+    if update && bundle {
+        eprintln!("--update applies to CSV files only; task bundles always import as new tasks");
+        std::process::exit(1);
+    }
+    let lines: Vec<&str> = csv_content.lines().collect();
+    if !templates && !taskwarrior && !bundle {
+        if lines.is_empty() {
+            eprintln!("CSV file is empty");
+            std::process::exit(1);
+        }
+
+        // Parse header to validate format
+        let expected_header = CSV_HEADER;
+        if lines[0] != expected_header {
+            eprintln!(
+                "Invalid CSV header. Expected:\n{}\nGot:\n{}",
+                expected_header, lines[0]
+            );
+            std::process::exit(1);
+        }
+    }
+
+    if !no_backup && !backup_before(db, db_path, "import") {
+        println!("Import cancelled.");
+        return;
+    }
 
+    if templates {
+        import_templates(db, db_path, &input, &csv_content, overwrite);
+        return;
+    }
+    if taskwarrior {
+        import_taskwarrior(db, db_path, &input, &csv_content);
+        return;
+    }
+    if bundle {
+        import_bundle(db, db_path, &input, &csv_content);
+        return;
+    }
+
+    let enforce_status_flow = Config::load(db_path).workflow.enforce_status_flow;
     let mut imported_count = 0;
+    let mut updated_count = 0;
     let mut skipped_count = 0;
+    let mut moves: Vec<(LeafId, LeafId)> = Vec::new();
+    // Ids added or updated, for the commit and the activity feed.
+    let mut touched: Vec<LeafId> = Vec::new();
 
     // Process each CSV row (skip header)
     for (line_num, line) in lines.iter().skip(1).enumerate() {
@@ -1924,6 +3904,29 @@ pub fn cmd_import(db: &mut Database, db_path: &Path, input: String, no_backup: b
             continue;
         }
 
+        if update {
+            let existing = fields[0]
+                .parse::<IdInput>()
+                .map(|input| input.leaf())
+                .ok()
+                .or_else(|| LeafId::parse_short(&fields[0]))
+                .filter(|leaf| db.get(*leaf).is_some());
+            if let Some(leaf) = existing {
+                match update_from_csv_row(db, leaf, &fields, line_num, enforce_status_flow) {
+                    (changed, _) if changed.is_empty() => {}
+                    (changed, new_parent) => {
+                        if let Some(pid) = new_parent {
+                            moves.push((leaf, pid));
+                        }
+                        say!("Updated {}: {}", leaf, changed.join(", "));
+                        touched.push(leaf);
+                        updated_count += 1;
+                    }
+                }
+                continue;
+            }
+        }
+
         // Parse fields. The legacy ID column is ignored; the new id is
         // allocated through `db.allocate_id` so the v2 counters stay
         // authoritative. The Project column (fields[7]) is read but not stored
@@ -1984,7 +3987,13 @@ pub fn cmd_import(db: &mut Database, db_path: &Path, input: String, no_backup: b
             milestone: None,
             memories: Vec::new(),
             due,
+            remind_days_before: None,
             parent,
+            order: None,
+            iteration: None,
+            estimate_minutes: None,
+            completed_at_utc: None,
+            pinned: false,
             kind,
             status,
             priority_level: priority,
@@ -1997,55 +4006,659 @@ pub fn cmd_import(db: &mut Database, db_path: &Path, input: String, no_backup: b
             updated_at_utc: Utc::now().timestamp(),
         };
 
+        touched.push(new_task.id);
         db.tasks.push(new_task);
         imported_count += 1;
     }
 
-    // Save database
-    if let Err(e) = db.save(db_path) {
-        eprintln!("Failed to save database: {}", e);
-        std::process::exit(1);
+    // Save database
+    if let Err(e) = db.save(db_path) {
+        eprintln!("Failed to save database: {}", e);
+        std::process::exit(1);
+    }
+    // Reparenting moves the ticket's directory, so it goes through the same
+    // path as `pm move` once the field edits are on disk.
+    for (leaf, pid) in moves {
+        if let Err(e) = relocate_ticket(db, db_path, leaf, Some(pid)) {
+            eprintln!("Failed to move {} under {}: {}", leaf, pid, e);
+            std::process::exit(1);
+        }
+    }
+    record_import(db_path, &input, &touched);
+
+    if update {
+        say!(
+            "Import completed. {} tasks updated, {} imported, {} skipped.",
+            updated_count,
+            imported_count,
+            skipped_count
+        );
+    } else {
+        say!(
+            "Import completed. {} tasks imported, {} skipped.",
+            imported_count,
+            skipped_count
+        );
+    }
+}
+
+/// Commit the tasks a `pm import` added or updated and log an `import`
+/// event for each, as the other batch commands do.
+fn record_import(db_path: &Path, input: &str, touched: &[LeafId]) {
+    let summary = match touched {
+        [] => return,
+        [only] => commit_subject_for(*only, "import", Some(input)),
+        many => format!("pm: import batch ({} tickets from {input})", many.len()),
+    };
+    commit_or_warn(db_path, &summary);
+    for &id in touched {
+        emit_or_warn(db_path, "import", Some(id), Some(input));
+    }
+}
+
+/// Apply one `pm import --update` CSV row to the existing task `leaf`.
+///
+/// Only Title, Status, Priority, Urgency, ProcessStage, Tags, Due, Parent and
+/// Description are read, and a `-` cell leaves the field as it is, so a CSV
+/// can change a value but never clear it. Kind is fixed by the id, Project
+/// follows the parent chain, and the timestamps are bookkeeping; those
+/// columns are ignored, as is everything the CSV has no column for (summary,
+/// user story, requirements, links, artifacts, dependencies, reminders).
+///
+/// Returns the names of the fields that changed, plus a new parent for the
+/// caller to apply with [`relocate_ticket`]. Unparseable cells, and a status
+/// `enforce_status_flow` refuses, are reported and skipped.
+fn update_from_csv_row(
+    db: &mut Database,
+    leaf: LeafId,
+    fields: &[String],
+    line_num: usize,
+    enforce_status_flow: bool,
+) -> (Vec<&'static str>, Option<LeafId>) {
+    let cell = |i: usize| Some(fields[i].trim()).filter(|v| !v.is_empty() && *v != "-");
+    let warn = |column: &str, value: &str| {
+        eprintln!(
+            "Warning: Line {}: unrecognised {} '{}' for {}; left unchanged.",
+            line_num, column, value, leaf
+        );
+    };
+
+    let current = db.get(leaf).expect("caller matched an existing task");
+    if let Some(kind) = cell(2) {
+        if parse_kind(kind) != current.kind {
+            eprintln!(
+                "Warning: Line {}: the Kind of {} is fixed by its id; ignoring '{}'.",
+                line_num, leaf, kind
+            );
+        }
+    }
+    let mut new_parent = None;
+    if let Some(raw) = cell(10) {
+        match raw.parse::<IdInput>().map(|input| input.leaf()) {
+            Ok(pid) if Some(pid) == current.parent => {}
+            Ok(pid) => match check_reparent(db, leaf, pid) {
+                Ok(()) => {
+                    let parent_kind = db.get(pid).expect("checked by check_reparent").kind;
+                    if validate_hierarchy(parent_kind, current.kind) {
+                        new_parent = Some(pid);
+                    } else {
+                        eprintln!(
+                            "Warning: Line {}: {} cannot be a child of {} {}; parent left unchanged.",
+                            line_num,
+                            format_kind(current.kind),
+                            format_kind(parent_kind),
+                            pid
+                        );
+                    }
+                }
+                Err(e) => eprintln!(
+                    "Warning: Line {}: {} for {}; parent left unchanged.",
+                    line_num,
+                    e.trim_end_matches('.'),
+                    leaf
+                ),
+            },
+            Err(_) => warn("Parent", raw),
+        }
+    }
+
+    let task = db.get_mut(leaf).expect("caller matched an existing task");
+    let mut changed = Vec::new();
+    if let Some(title) = cell(1) {
+        if title != task.title {
+            task.title = title.to_string();
+            changed.push("title");
+        }
+    }
+    if let Some(raw) = cell(3) {
+        match parse_status_strict(raw) {
+            Some(status) if status != task.status => {
+                match status_flow_error(leaf, task.status, status).filter(|_| enforce_status_flow) {
+                    Some(e) => eprintln!(
+                        "Warning: Line {}: {} (enforce_status_flow is on); status left unchanged.",
+                        line_num, e
+                    ),
+                    None => {
+                        task.status = status;
+                        changed.push("status");
+                    }
+                }
+            }
+            Some(_) => {}
+            None => warn("Status", raw),
+        }
+    }
+    if let Some(raw) = cell(4) {
+        match parse_priority(raw) {
+            Some(p) if Some(p) != task.priority_level => {
+                task.priority_level = Some(p);
+                changed.push("priority");
+            }
+            Some(_) => {}
+            None => warn("Priority", raw),
+        }
+    }
+    if let Some(raw) = cell(5) {
+        match parse_urgency(raw) {
+            Some(u) if Some(u) != task.urgency => {
+                task.urgency = Some(u);
+                changed.push("urgency");
+            }
+            Some(_) => {}
+            None => warn("Urgency", raw),
+        }
+    }
+    if let Some(raw) = cell(6) {
+        match parse_process_stage(raw) {
+            Some(ps) if Some(ps) != task.process_stage => {
+                task.process_stage = Some(ps);
+                changed.push("stage");
+            }
+            Some(_) => {}
+            None => warn("ProcessStage", raw),
+        }
+    }
+    if let Some(raw) = cell(8) {
+        let tags: Vec<String> = raw
+            .split(';')
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+        if tags != task.tags {
+            task.tags = tags;
+            changed.push("tags");
+        }
+    }
+    if let Some(raw) = cell(9) {
+        match NaiveDate::parse_from_str(raw, "%Y-%m-%d") {
+            Ok(due) if Some(due) != task.due => {
+                task.due = Some(due);
+                changed.push("due");
+            }
+            Ok(_) => {}
+            Err(_) => warn("Due", raw),
+        }
+    }
+    if let Some(desc) = cell(13) {
+        if Some(desc) != task.description.as_deref() {
+            task.description = Some(desc.to_string());
+            changed.push("description");
+        }
+    }
+    if new_parent.is_some() {
+        changed.push("parent");
+    }
+    if !changed.is_empty() {
+        task.updated_at_utc = Utc::now().timestamp();
+    }
+    (changed, new_parent)
+}
+
+/// Merge a JSON [`TaskBundle`] into the database under fresh ids. Parents
+/// outside the bundle are cleared with a warning.
+fn import_bundle(db: &mut Database, db_path: &Path, input: &str, content: &str) {
+    let bundle: TaskBundle = match serde_json::from_str(content) {
+        Ok(bundle) => bundle,
+        Err(e) => {
+            eprintln!("Failed to parse task bundle '{}': {}", input, e);
+            std::process::exit(1);
+        }
+    };
+    let report = match bundle.import_into(db) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Import failed: {e}");
+            std::process::exit(1);
+        }
+    };
+    for (new_id, old_parent) in &report.detached {
+        eprintln!(
+            "Warning: {} referenced parent {} which is not in the bundle; imported without a parent.",
+            new_id, old_parent
+        );
+    }
+    if report.dropped_deps > 0 {
+        eprintln!(
+            "Warning: dropped {} dependency link(s) to tasks outside the bundle.",
+            report.dropped_deps
+        );
+    }
+    if report.dropped_milestones > 0 {
+        eprintln!(
+            "Warning: cleared {} milestone link(s) to milestones outside the bundle.",
+            report.dropped_milestones
+        );
+    }
+
+    if let Err(e) = db.save(db_path) {
+        eprintln!("Failed to save database: {}", e);
+        std::process::exit(1);
+    }
+    let imported: Vec<LeafId> = report.remapped.iter().map(|(_, new)| *new).collect();
+    record_import(db_path, input, &imported);
+    for (old, new) in &report.remapped {
+        println!("  {old} -> {new}");
+    }
+    say!(
+        "Import completed. {} tasks imported, 0 skipped.",
+        report.remapped.len()
+    );
+}
+
+/// Import a Taskwarrior export (`task export`) as new tasks, rebuilding
+/// parent links from the `pm_*` UDAs when the file came from pm.
+fn import_taskwarrior(db: &mut Database, db_path: &Path, input: &str, content: &str) {
+    let items: Vec<taskwarrior::TwTask> = match serde_json::from_str(content) {
+        Ok(items) => items,
+        Err(e) => {
+            eprintln!("Failed to parse Taskwarrior export '{}': {}", input, e);
+            std::process::exit(1);
+        }
+    };
+    let (bundle, tw) = taskwarrior::from_taskwarrior(items);
+    let report = match bundle.import_into(db) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Import failed: {e}");
+            std::process::exit(1);
+        }
+    };
+    if tw.detached > 0 {
+        eprintln!(
+            "Warning: {} task(s) had a pm_parent outside the file; imported without a parent.",
+            tw.detached
+        );
+    }
+    if let Err(e) = db.save(db_path) {
+        eprintln!("Failed to save database: {}", e);
+        std::process::exit(1);
+    }
+    let imported: Vec<LeafId> = report.remapped.iter().map(|(_, new)| *new).collect();
+    record_import(db_path, input, &imported);
+    for (_, new) in &report.remapped {
+        if let Some(task) = db.get(*new) {
+            println!("  {new} {}", task.title);
+        }
+    }
+    say!(
+        "Import completed. {} tasks imported, {} recurring skipped.",
+        report.remapped.len(),
+        tw.skipped_recurring
+    );
+}
+
+/// `pm import --templates`: merge a `pm export --templates-only` file into
+/// the workspace's templates and report what was added, replaced or skipped.
+fn import_templates(
+    db: &mut Database,
+    db_path: &Path,
+    input: &str,
+    content: &str,
+    overwrite: bool,
+) {
+    let incoming: Vec<TaskTemplate> = match serde_json::from_str(content) {
+        Ok(templates) => templates,
+        Err(e) => {
+            eprintln!("Failed to parse templates file '{}': {}", input, e);
+            std::process::exit(1);
+        }
+    };
+    let merge = db.merge_templates(incoming, overwrite);
+    if let Err(e) = db.save(db_path) {
+        eprintln!("Failed to save database: {}", e);
+        std::process::exit(1);
+    }
+    for name in &merge.added {
+        println!("  added {name}");
+    }
+    for name in &merge.replaced {
+        println!("  replaced {name}");
+    }
+    for name in &merge.skipped {
+        println!("  skipped {name} (name in use; --overwrite replaces it)");
+    }
+    say!(
+        "Templates imported: {} added, {} replaced, {} skipped.",
+        merge.added.len(),
+        merge.replaced.len(),
+        merge.skipped.len()
+    );
+}
+
+/// Simple CSV line parser that handles quoted fields.
+fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current_field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => {
+                if in_quotes && chars.peek() == Some(&'"') {
+                    // Escaped quote
+                    current_field.push('"');
+                    chars.next(); // consume the second quote
+                } else {
+                    // Toggle quote state
+                    in_quotes = !in_quotes;
+                }
+            }
+            ',' if !in_quotes => {
+                // Field separator
+                fields.push(current_field);
+                current_field = String::new();
+            }
+            _ => {
+                current_field.push(ch);
+            }
+        }
+    }
+
+    // Don't forget the last field
+    fields.push(current_field);
+    fields
+}
+
+/// How serious a [`cmd_validate_import`] finding is: errors are rows (or
+/// files) `pm import` would skip or get wrong, warnings are cells it would
+/// quietly replace with a default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IssueLevel {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for IssueLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssueLevel::Error => write!(f, "error"),
+            IssueLevel::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// `pm validate-import <file>`: run the import's parsing and checks against
+/// the current workspace and print what it would trip over. Nothing is
+/// written. Exits non-zero when any error is found.
+pub fn cmd_validate_import(db: &Database, input: &str) {
+    let content = match fs::read_to_string(input) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Failed to read '{}': {}", input, e);
+            std::process::exit(1);
+        }
+    };
+
+    let (rows, issues) = if content.trim_start().starts_with('[') {
+        validate_taskwarrior(&content)
+    } else if input.ends_with(".json") || content.trim_start().starts_with('{') {
+        validate_bundle(&content)
+    } else {
+        validate_csv(db, &content)
+    };
+
+    for (line, level, message) in &issues {
+        if *line == 0 {
+            println!("{level}: {message}");
+        } else {
+            println!("Line {line}: {level}: {message}");
+        }
+    }
+    let errors = issues
+        .iter()
+        .filter(|(_, level, _)| *level == IssueLevel::Error)
+        .count();
+    let warnings = issues.len() - errors;
+    if issues.is_empty() {
+        println!("{input}: {rows} rows, no problems found.");
+    } else {
+        println!("{input}: {rows} rows, {errors} errors, {warnings} warnings.");
+    }
+    if errors > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Check every row of an export-format CSV. Returns the number of data rows
+/// and the findings as `(line, level, message)`, line 0 meaning the file.
+fn validate_csv(db: &Database, content: &str) -> (usize, Vec<(usize, IssueLevel, String)>) {
+    let mut issues = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let Some(header) = lines.first() else {
+        issues.push((0, IssueLevel::Error, "file is empty".to_string()));
+        return (0, issues);
+    };
+    if *header != CSV_HEADER {
+        issues.push((
+            1,
+            IssueLevel::Error,
+            format!("invalid header; expected {CSV_HEADER}"),
+        ));
+        return (lines.len() - 1, issues);
+    }
+
+    let mut titles: HashMap<String, usize> = HashMap::new();
+    for (index, line) in lines.iter().enumerate().skip(1) {
+        let line_num = index + 1;
+        let mut report = |level: IssueLevel, message: String| {
+            issues.push((line_num, level, message));
+        };
+        let fields = parse_csv_line(line);
+        if fields.len() != 14 {
+            report(
+                IssueLevel::Error,
+                format!("{} fields, expected 14", fields.len()),
+            );
+            continue;
+        }
+        let cell = |i: usize| Some(fields[i].trim()).filter(|v| !v.is_empty() && *v != "-");
+
+        let title = fields[1].as_str();
+        if title.is_empty() {
+            report(IssueLevel::Error, "empty title".to_string());
+        } else if db.tasks.iter().any(|t| t.title == title) {
+            report(
+                IssueLevel::Error,
+                format!("a task titled '{title}' already exists; the row would be skipped"),
+            );
+        } else if let Some(first) = titles.insert(title.to_string(), line_num) {
+            report(
+                IssueLevel::Error,
+                format!("title '{title}' repeats line {first}; the row would be skipped"),
+            );
+        }
+
+        let kind = match cell(2) {
+            Some(raw) => parse_kind_strict(raw).unwrap_or_else(|| {
+                report(
+                    IssueLevel::Warning,
+                    format!("unrecognised Kind '{raw}'; imports as Task"),
+                );
+                Kind::Task
+            }),
+            None => Kind::Task,
+        };
+        if let Some(raw) = cell(3) {
+            if parse_status_strict(raw).is_none() {
+                report(
+                    IssueLevel::Warning,
+                    format!("unrecognised Status '{raw}'; imports as Open"),
+                );
+            }
+        }
+        let unparsed = [
+            ("Priority", cell(4).filter(|s| parse_priority(s).is_none())),
+            ("Urgency", cell(5).filter(|s| parse_urgency(s).is_none())),
+            (
+                "ProcessStage",
+                cell(6).filter(|s| parse_process_stage(s).is_none()),
+            ),
+        ];
+        for (column, raw) in unparsed {
+            if let Some(raw) = raw {
+                report(
+                    IssueLevel::Warning,
+                    format!("unrecognised {column} '{raw}'; imports unset"),
+                );
+            }
+        }
+        if let Some(raw) = cell(9) {
+            if NaiveDate::parse_from_str(raw, "%Y-%m-%d").is_err() {
+                report(
+                    IssueLevel::Warning,
+                    format!("unrecognised Due '{raw}' (want YYYY-MM-DD); imports unset"),
+                );
+            }
+        }
+        if let Some(raw) = cell(10) {
+            match raw.parse::<IdInput>().map(|input| input.leaf()) {
+                Err(_) => report(
+                    IssueLevel::Warning,
+                    format!("unrecognised Parent '{raw}'; imports without a parent"),
+                ),
+                Ok(pid) => match db.get(pid) {
+                    None => report(
+                        IssueLevel::Error,
+                        format!("parent {pid} does not exist in this workspace"),
+                    ),
+                    Some(parent) if !validate_hierarchy(parent.kind, kind) => report(
+                        IssueLevel::Error,
+                        format!(
+                            "{} cannot be a child of {} {}",
+                            format_kind(kind),
+                            format_kind(parent.kind),
+                            pid
+                        ),
+                    ),
+                    Some(_) => {}
+                },
+            }
+        }
+    }
+    (lines.len() - 1, issues)
+}
+
+/// Check a JSON task bundle by importing it into a scratch database, so the
+/// report matches what `pm import` would do without touching the workspace.
+fn validate_bundle(content: &str) -> (usize, Vec<(usize, IssueLevel, String)>) {
+    match serde_json::from_str(content) {
+        Ok(bundle) => check_bundle(bundle, Vec::new()),
+        Err(e) => (
+            0,
+            vec![(0, IssueLevel::Error, format!("not a task bundle: {e}"))],
+        ),
     }
-
-    println!(
-        "Import completed. {} tasks imported, {} skipped.",
-        imported_count, skipped_count
-    );
 }
 
-/// Simple CSV line parser that handles quoted fields.
-fn parse_csv_line(line: &str) -> Vec<String> {
-    let mut fields = Vec::new();
-    let mut current_field = String::new();
-    let mut in_quotes = false;
-    let mut chars = line.chars().peekable();
+/// Check a Taskwarrior export the same way, after converting it as
+/// `pm import` would.
+fn validate_taskwarrior(content: &str) -> (usize, Vec<(usize, IssueLevel, String)>) {
+    let items: Vec<taskwarrior::TwTask> = match serde_json::from_str(content) {
+        Ok(items) => items,
+        Err(e) => {
+            return (
+                0,
+                vec![(
+                    0,
+                    IssueLevel::Error,
+                    format!("not a Taskwarrior export: {e}"),
+                )],
+            )
+        }
+    };
+    let (bundle, tw) = taskwarrior::from_taskwarrior(items);
+    let mut issues = Vec::new();
+    if tw.skipped_recurring > 0 {
+        issues.push((
+            0,
+            IssueLevel::Warning,
+            format!(
+                "{} recurring task(s) have no pm equivalent and would be skipped",
+                tw.skipped_recurring
+            ),
+        ));
+    }
+    if tw.detached > 0 {
+        issues.push((
+            0,
+            IssueLevel::Warning,
+            format!(
+                "{} task(s) have a pm_parent outside the file; they import without a parent",
+                tw.detached
+            ),
+        ));
+    }
+    check_bundle(bundle, issues)
+}
 
-    while let Some(ch) = chars.next() {
-        match ch {
-            '"' => {
-                if in_quotes && chars.peek() == Some(&'"') {
-                    // Escaped quote
-                    current_field.push('"');
-                    chars.next(); // consume the second quote
-                } else {
-                    // Toggle quote state
-                    in_quotes = !in_quotes;
-                }
+/// Import `bundle` into a scratch database and add what it reports to
+/// `issues`.
+fn check_bundle(
+    bundle: TaskBundle,
+    mut issues: Vec<(usize, IssueLevel, String)>,
+) -> (usize, Vec<(usize, IssueLevel, String)>) {
+    let rows = bundle.tasks.len();
+    match bundle.import_into(&mut Database::default()) {
+        Ok(report) => {
+            for (new_id, old_parent) in &report.detached {
+                let old_id = report
+                    .remapped
+                    .iter()
+                    .find(|(_, new)| new == new_id)
+                    .map_or(*new_id, |(old, _)| *old);
+                issues.push((
+                    0,
+                    IssueLevel::Warning,
+                    format!(
+                        "{old_id}'s parent {old_parent} is not in the bundle; it imports without a parent"
+                    ),
+                ));
             }
-            ',' if !in_quotes => {
-                // Field separator
-                fields.push(current_field);
-                current_field = String::new();
+            if report.dropped_deps > 0 {
+                issues.push((
+                    0,
+                    IssueLevel::Warning,
+                    format!(
+                        "{} dependency link(s) point outside the bundle and would be dropped",
+                        report.dropped_deps
+                    ),
+                ));
             }
-            _ => {
-                current_field.push(ch);
+            if report.dropped_milestones > 0 {
+                issues.push((
+                    0,
+                    IssueLevel::Warning,
+                    format!(
+                        "{} milestone link(s) point outside the bundle and would be cleared",
+                        report.dropped_milestones
+                    ),
+                ));
             }
         }
+        Err(e) => issues.push((0, IssueLevel::Error, e.to_string())),
     }
-
-    // Don't forget the last field
-    fields.push(current_field);
-    fields
+    (rows, issues)
 }
 
 /// Create a backup command implementation.
@@ -2058,7 +4671,7 @@ pub fn cmd_backup(db_path: &Path, all: bool) {
 
     match create_backup(db_path) {
         Ok(backup_path) => {
-            println!("Backup created: {}", backup_path);
+            say!("Backup created: {}", backup_path);
         }
         Err(e) => {
             eprintln!("Failed to create backup: {}", e);
@@ -2067,6 +4680,210 @@ pub fn cmd_backup(db_path: &Path, all: bool) {
     }
 }
 
+/// Save or list named workspace snapshots.
+pub fn cmd_snapshot(db: &Database, pm_dir: &Path, name: Option<String>, list: bool, force: bool) {
+    use crate::store::snapshots;
+
+    if list {
+        let entries = match snapshots::list(pm_dir) {
+            Ok(e) => e,
+            Err(e) => {
+                eprintln!("snapshot: {e}");
+                std::process::exit(1);
+            }
+        };
+        if entries.is_empty() {
+            println!("No snapshots found.");
+            return;
+        }
+        println!("{:<32} Saved", "Name");
+        for s in entries {
+            let saved = s
+                .modified
+                .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_else(|| "-".to_string());
+            println!("{:<32} {}", truncate(&s.name, 32), saved);
+        }
+        return;
+    }
+
+    let Some(name) = name else {
+        eprintln!("snapshot: a name is required unless --list is given");
+        std::process::exit(1);
+    };
+    match snapshots::write(db, pm_dir, &name, force) {
+        Ok(path) => say!(
+            "Saved snapshot '{}' ({} tasks): {}",
+            name,
+            db.tasks.len(),
+            path.display()
+        ),
+        Err(e) => {
+            eprintln!("snapshot: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Restore the workspace from a named snapshot, saving the current state as
+/// an automatic `pre-restore-<timestamp>` snapshot first.
+pub fn cmd_restore(db: &mut Database, pm_dir: &Path, name: &str, no_backup: bool) {
+    use crate::store::snapshots;
+
+    let mut restored = match snapshots::read(pm_dir, name) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("restore: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    if !no_backup && !backup_before(db, pm_dir, "restore") {
+        println!("Restore cancelled.");
+        return;
+    }
+
+    snapshots::merge_allocation_history(&mut restored.state, &db.state);
+    db.tasks = restored.tasks;
+    db.state = restored.state;
+    if let Err(e) = db.save(pm_dir) {
+        eprintln!("Failed to save DB: {e}");
+        std::process::exit(1);
+    }
+    commit_or_warn(pm_dir, &format!("pm: restore snapshot {name}"));
+    emit_or_warn(pm_dir, "restore", None, Some(name));
+    say!("Restored snapshot '{}' ({} tasks)", name, db.tasks.len());
+}
+
+/// Print a task's field-by-field timeline, reconstructed from every backup
+/// and snapshot that parses as a database and ending with the live state.
+pub fn cmd_history(db: &Database, pm_dir: &Path, id: &str) {
+    use crate::store::history::{self, field_changes};
+
+    // A deleted task no longer resolves by title, but its id still does.
+    let leaf = match resolve_task_identifier(id, db) {
+        Ok(leaf) => leaf,
+        Err(e) => match id.parse::<IdInput>() {
+            Ok(input) => input.leaf(),
+            Err(_) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        },
+    };
+
+    let (versions, skipped) = history::collect(pm_dir, leaf);
+    for s in &skipped {
+        eprintln!("history: skipped {}: {}", s.path.display(), s.reason);
+    }
+
+    let title = db
+        .get(leaf)
+        .or_else(|| versions.iter().rev().find_map(|v| v.task.as_ref()))
+        .map(|t| t.title.clone());
+    let Some(title) = title else {
+        eprintln!("history: {leaf} does not exist and appears in no backup or snapshot");
+        std::process::exit(1);
+    };
+    println!("History of {leaf}: {title}");
+    if versions.is_empty() {
+        println!("No backups or snapshots found; showing the current state only.");
+    }
+
+    let mut steps: Vec<(String, Option<&Task>)> = versions
+        .iter()
+        .map(|v| {
+            let when = v
+                .taken
+                .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_else(|| "????-??-?? ??:??".to_string());
+            (format!("{when}  {}", v.source), v.task.as_ref())
+        })
+        .collect();
+    steps.push(("now               current".to_string(), db.get(leaf)));
+
+    let mut previous: Option<&Task> = None;
+    let mut seen = false;
+    for (label, current) in steps {
+        println!("{label}");
+        match (previous, current) {
+            (None, None) if seen => println!("    still absent"),
+            (None, None) => println!("    not present yet"),
+            (None, Some(t)) if seen => println!("    re-appeared: {}", t.title),
+            (None, Some(t)) => println!("    present: {} [{}]", t.title, format_status(t.status)),
+            (Some(_), None) => println!("    deleted"),
+            (Some(before), Some(after)) => {
+                let changes = field_changes(before, after);
+                if changes.is_empty() {
+                    println!("    no changes");
+                }
+                for c in changes {
+                    println!("    {}: {} -> {}", c.field, c.before, c.after);
+                }
+            }
+        }
+        seen |= current.is_some();
+        previous = current;
+    }
+}
+
+/// Print what changed between a backup or snapshot and the current state,
+/// one line per added, removed or changed task field. Every line starts with
+/// `+`, `-` or `~` and the task id, so the output greps well.
+pub fn cmd_diff(db: &Database, pm_dir: &Path, reference: &str) {
+    use crate::store::history::{self, TaskDiff};
+
+    let dump = match history::find_dump(pm_dir, reference) {
+        Ok(dump) => dump,
+        Err(e) => {
+            eprintln!("diff: {e}");
+            std::process::exit(1);
+        }
+    };
+    let when = dump
+        .taken
+        .map(|t| format!(" ({})", t.format("%Y-%m-%d %H:%M")))
+        .unwrap_or_default();
+    println!("Comparing {}{when} with the current workspace", dump.source);
+
+    let diff = history::diff_databases(&dump.db, db);
+    let (mut added, mut removed, mut modified) = (0, 0, 0);
+    for entry in &diff {
+        match entry {
+            TaskDiff::Added(t) => {
+                added += 1;
+                println!(
+                    "+ {} added: {} [{}]",
+                    t.id,
+                    t.title,
+                    format_status(t.status)
+                );
+            }
+            TaskDiff::Removed(t) => {
+                removed += 1;
+                println!(
+                    "- {} removed: {} [{}]",
+                    t.id,
+                    t.title,
+                    format_status(t.status)
+                );
+            }
+            TaskDiff::Modified { id, title, changes } => {
+                modified += 1;
+                println!("~ {id} modified: {title}");
+                for c in changes {
+                    println!("~ {id} {}: {} -> {}", c.field, c.before, c.after);
+                }
+            }
+        }
+    }
+    if diff.is_empty() {
+        println!("No differences.");
+    } else {
+        println!("{added} added, {removed} removed, {modified} modified.");
+    }
+}
+
 /// Backup all projects in the PM directory.
 pub fn cmd_backup_all(pm_dir: &Path) {
     use crate::project::{discover_projects, get_legacy_project};
@@ -2089,10 +4906,14 @@ pub fn cmd_backup_all(pm_dir: &Path) {
     let mut success_count = 0;
     let total_count = projects.len();
 
-    for project in &projects {
-        match create_backup(&project.file_path) {
+    let progress = Progress::new(total_count);
+    for (n, project) in projects.iter().enumerate() {
+        progress.step(n + 1);
+        let backup = create_backup(&project.file_path);
+        progress.clear();
+        match backup {
             Ok(backup_path) => {
-                println!("Backed up {}: {}", project.display_name, backup_path);
+                say!("Backed up {}: {}", project.display_name, backup_path);
                 success_count += 1;
             }
             Err(e) => {
@@ -2101,9 +4922,10 @@ pub fn cmd_backup_all(pm_dir: &Path) {
         }
     }
 
-    println!(
+    say!(
         "Backup completed: {}/{} projects backed up successfully.",
-        success_count, total_count
+        success_count,
+        total_count
     );
 }
 
@@ -2147,11 +4969,13 @@ pub fn cmd_export_all(
     let mut all_rows: Vec<(crate::project::Project, Task, String)> = Vec::new();
 
     // Collect tasks from all projects
-    for project in &projects {
+    let progress = Progress::new(projects.len());
+    for (n, project) in projects.iter().enumerate() {
+        progress.step(n + 1);
         let db = project.load_database();
         for task in &db.tasks {
             // Apply filters
-            if !include_completed && task.status == Status::Done {
+            if !include_completed && task.status.is_closed() {
                 continue;
             }
 
@@ -2165,6 +4989,7 @@ pub fn cmd_export_all(
             all_rows.push((project.clone(), task.clone(), project_col));
         }
     }
+    drop(progress);
 
     // Create CSV content
     let mut csv_content = String::new();
@@ -2238,7 +5063,7 @@ pub fn cmd_export_all(
     // Write to file
     match std::fs::write(&output_path, csv_content) {
         Ok(_) => {
-            println!(
+            say!(
                 "Exported {} task(s) from {} project(s) to {}",
                 task_count,
                 projects.len(),
@@ -2287,7 +5112,7 @@ pub fn cmd_workflow_menu(pm_dir: &Path) {
     if let Some(project) = app.get_selected_project() {
         if app.should_open_workflow() {
             println!("Opening workflow for: {}", project.display_name);
-            cmd_wf(&project.file_path);
+            cmd_wf(&project.file_path, false, None);
         }
     }
 }
@@ -2326,43 +5151,76 @@ pub fn cmd_menu(pm_dir: &Path) {
     if let Some(project) = app.get_selected_project() {
         if app.should_open_workflow() {
             println!("Opening workflow for: {}", project.display_name);
-            cmd_wf(&project.file_path);
+            cmd_wf(&project.file_path, false, None);
         } else {
             println!("Opening project: {}", project.display_name);
-            if let Err(err) = run_tui(&project.file_path) {
-                eprintln!("Error running TUI: {}", err);
-                std::process::exit(1);
-            }
+            run_views(&project.file_path, false, None, TuiView::List(None));
         }
     }
 }
 
-/// Launch the workflow kanban board interface.
-pub fn cmd_wf(db_path: &Path) {
+/// Launch the workflow kanban board interface, with the columns given by
+/// `--stage-columns` when set.
+pub fn cmd_wf(db_path: &Path, compact: bool, stage_columns: Option<&str>) {
+    let board = stage_columns.map(|raw| {
+        parse_stage_columns(raw)
+            .and_then(|spec| board_columns(&spec))
+            .unwrap_or_else(|e| {
+                eprintln!("--stage-columns: {e}");
+                std::process::exit(1);
+            })
+    });
+    run_views(db_path, compact, board.as_deref(), TuiView::Workflow(None));
+}
+
+/// The full-screen view [`run_views`] shows next.
+enum TuiView {
+    List(Option<ViewHandoff>),
+    Workflow(Option<ViewHandoff>),
+}
+
+/// Drive the task list and the workflow board in one process, switching
+/// between them on `w` / `l` and carrying the hierarchy position across.
+fn run_views(db_path: &Path, compact: bool, board: Option<&[BoardColumn]>, start: TuiView) {
+    let mut view = start;
     loop {
-        match run_workflow_tui(db_path) {
-            Ok(WorkflowExit::EditTask(task_id)) => {
-                // User wants to edit a task
-                let db = Database::load(db_path);
-                if let Some(_task) = db.get(task_id) {
-                    // Run the TUI with the task pre-selected for editing
-                    if let Err(err) = run_tui_with_edit(db_path, task_id) {
-                        eprintln!("Error running TUI: {}", err);
+        view = match view {
+            TuiView::List(handoff) => match run_tui(db_path, compact, handoff) {
+                Ok(AppExit::Workflow(handoff)) => TuiView::Workflow(Some(handoff)),
+                Ok(AppExit::Quit) => break,
+                Err(err) => {
+                    eprintln!("UI error: {err}");
+                    std::process::exit(1);
+                }
+            },
+            TuiView::Workflow(handoff) => {
+                match run_workflow_tui(db_path, compact, board, handoff) {
+                    Ok(WorkflowExit::EditTask(task_id))
+                        if Database::load(db_path).get(task_id).is_none() =>
+                    {
+                        TuiView::Workflow(None)
+                    }
+                    Ok(WorkflowExit::EditTask(task_id)) => {
+                        // Edit in the list TUI; quitting it returns to the board,
+                        // `w` returns to the board at the list's position.
+                        match run_tui_with_edit(db_path, task_id, compact) {
+                            Ok(AppExit::Workflow(handoff)) => TuiView::Workflow(Some(handoff)),
+                            Ok(AppExit::Quit) => TuiView::Workflow(None),
+                            Err(err) => {
+                                eprintln!("Error running TUI: {}", err);
+                                std::process::exit(1);
+                            }
+                        }
+                    }
+                    Ok(WorkflowExit::ListView(handoff)) => TuiView::List(Some(handoff)),
+                    Ok(WorkflowExit::Quit) => break,
+                    Err(err) => {
+                        eprintln!("Error running workflow TUI: {}", err);
                         std::process::exit(1);
                     }
-                    // After editing, return to workflow
-                    continue;
                 }
             }
-            Ok(WorkflowExit::Quit) => {
-                // Normal exit
-                break;
-            }
-            Err(err) => {
-                eprintln!("Error running workflow TUI: {}", err);
-                std::process::exit(1);
-            }
-        }
+        };
     }
 }
 
@@ -2390,7 +5248,7 @@ pub fn cmd_init(pm_dir: &Path) {
     commit_or_warn(pm_dir, "pm: init");
     emit_or_warn(pm_dir, "init", None, None);
 
-    println!("Initialised .pm/ workspace at {}", pm_dir.display());
+    say!("Initialised .pm/ workspace at {}", pm_dir.display());
 }
 
 /// Commit any staged workspace changes under `pm_dir` with `message`. Logs a
@@ -2441,12 +5299,16 @@ pub fn cmd_show(db: &Database, id: &str) {
 
 /// Resolve a user-supplied id string against the loaded Database. Accepts the
 /// v2 forms understood by [`crate::store::id::IdInput`] (leaf, address, with
-/// trailing labels). Returns the canonical `LeafId` if it appears in the db.
+/// trailing labels) and the short `T-a3` form. Returns the canonical `LeafId`
+/// if it appears in the db.
 fn resolve_v2_id(input: &str, db: &Database) -> Option<crate::store::LeafId> {
     use crate::store::id::IdInput;
-    let parsed: IdInput = input.parse().ok()?;
-    let leaf = parsed.leaf();
+    let leaf = match input.parse::<IdInput>() {
+        Ok(parsed) => parsed.leaf(),
+        Err(_) => LeafId::parse_short(input)?,
+    };
     if db.get(leaf).is_some() {
+        note_resolved(input, db, leaf);
         Some(leaf)
     } else {
         None
@@ -2466,7 +5328,6 @@ pub fn cmd_move(
     orphan: bool,
 ) {
     use crate::store::id::AddressId;
-    use crate::store::layout::Layout;
 
     let leaf = match resolve_v2_id(id, db) {
         Some(l) => l,
@@ -2516,6 +5377,41 @@ pub fn cmd_move(
         }
     }
 
+    if let Err(e) = relocate_ticket(db, pm_dir, leaf, target_parent) {
+        eprintln!("move: save failed: {e}");
+        std::process::exit(1);
+    }
+
+    let dest_label = target_parent
+        .map(|p| p.to_string())
+        .unwrap_or_else(|| "(orphan)".into());
+    commit_or_warn(
+        pm_dir,
+        &commit_subject_for(leaf, "move", Some(&format!("-> {dest_label}"))),
+    );
+    emit_or_warn(
+        pm_dir,
+        "move",
+        Some(leaf),
+        Some(&format!("-> {dest_label}")),
+    );
+    say!("Moved {leaf} -> {dest_label}");
+
+    // Suppress unused-import warning on `AddressId` if no other site brings it.
+    let _ = std::marker::PhantomData::<AddressId>;
+}
+
+/// Reparent `leaf` and save, removing the directory it vacated and recording
+/// an alias so its old address keeps resolving. Shared by `pm move` and
+/// `pm inbox --triage`; the caller validates the new parent.
+fn relocate_ticket(
+    db: &mut Database,
+    pm_dir: &Path,
+    leaf: LeafId,
+    target_parent: Option<LeafId>,
+) -> std::io::Result<()> {
+    use crate::store::layout::Layout;
+
     // Remember the prior absolute directory so it can be cleaned up after the
     // save writes the new location.
     let old_abs_dir = db
@@ -2533,10 +5429,7 @@ pub fn cmd_move(
         task.updated_at_utc = Utc::now().timestamp();
     }
 
-    if let Err(e) = db.save(pm_dir) {
-        eprintln!("move: save failed: {e}");
-        std::process::exit(1);
-    }
+    db.save(pm_dir)?;
 
     // Clean up the now-vacated directory if it differs from where the save
     // landed. Saved state.items has the new path; compare against the old.
@@ -2566,24 +5459,7 @@ pub fn cmd_move(
             }
         }
     }
-
-    let dest_label = target_parent
-        .map(|p| p.to_string())
-        .unwrap_or_else(|| "(orphan)".into());
-    commit_or_warn(
-        pm_dir,
-        &commit_subject_for(leaf, "move", Some(&format!("-> {dest_label}"))),
-    );
-    emit_or_warn(
-        pm_dir,
-        "move",
-        Some(leaf),
-        Some(&format!("-> {dest_label}")),
-    );
-    println!("Moved {leaf} -> {dest_label}");
-
-    // Suppress unused-import warning on `AddressId` if no other site brings it.
-    let _ = std::marker::PhantomData::<AddressId>;
+    Ok(())
 }
 
 /// Compute the current address chain (parent->child) for a leaf, if every
@@ -2918,7 +5794,7 @@ pub fn cmd_materialise(db: &Database, pm_dir: &Path, id: &str, output: Option<Pa
         eprintln!("materialise: write failed: {e}");
         std::process::exit(1);
     }
-    println!("Wrote composed view to {}", target.display());
+    say!("Wrote composed view to {}", target.display());
 }
 
 /// `pm artifact ...`: thin wrapper over `store::artifacts`.
@@ -2975,7 +5851,7 @@ pub fn cmd_artifact(db: &Database, pm_dir: &Path, action: ArtifactAction) {
                 &commit_subject_for(leaf, "artifact add", Some(&name)),
             );
             emit_or_warn(pm_dir, "artifact-add", Some(leaf), Some(&name));
-            println!("Added artifact {name} to {leaf}");
+            say!("Added artifact {name} to {leaf}");
         }
         ArtifactAction::Rename { id, old, new } => {
             let (leaf, artifacts_dir) = resolve(&id);
@@ -2989,7 +5865,7 @@ pub fn cmd_artifact(db: &Database, pm_dir: &Path, action: ArtifactAction) {
                 &commit_subject_for(leaf, "artifact rename", Some(&detail)),
             );
             emit_or_warn(pm_dir, "artifact-rename", Some(leaf), Some(&detail));
-            println!("Renamed {old} -> {new} on {leaf}");
+            say!("Renamed {old} -> {new} on {leaf}");
         }
         ArtifactAction::List { id } => {
             let (_leaf, artifacts_dir) = resolve(&id);
@@ -3052,12 +5928,81 @@ fn mutate_task_with_summary<F>(
     }
     commit_or_warn(pm_dir, &commit_subject_for(leaf, label, summary));
     emit_or_warn(pm_dir, label, Some(leaf), summary);
-    println!("{label}: {leaf} updated.");
+    say!("{label}: {leaf} updated.");
 }
 
 /// `pm set-status <id> <new-status>`: update front-matter status.
 pub fn cmd_set_status(db: &mut Database, pm_dir: &Path, id: &str, new_status: Status) {
-    mutate_task(db, pm_dir, id, "status", |task| task.status = new_status);
+    if let Some(leaf) = resolve_v2_id(id, db) {
+        check_status_flow(db, pm_dir, &[leaf], new_status);
+    }
+    let workflow = Config::load(pm_dir).workflow;
+    let mut advanced = None;
+    mutate_task(db, pm_dir, id, "status", |task| {
+        let from = task.status;
+        task.status = new_status;
+        advanced = advance_stage(task, from, &workflow);
+    });
+    say_stage_advanced(advanced);
+}
+
+/// `pm pin <id>` / `pm unpin <id>`: add to or drop from the working set.
+pub fn cmd_pin(db: &mut Database, pm_dir: &Path, id: &str, pinned: bool) {
+    let label = if pinned { "pin" } else { "unpin" };
+    mutate_task(db, pm_dir, id, label, |task| task.pinned = pinned);
+}
+
+/// `pm focus [<id>] [--clear]`: set, clear or show the focus root kept in
+/// `.pm/config.json`.
+pub fn cmd_focus(db: &Database, pm_dir: &Path, id: Option<String>, clear: bool) {
+    let root = match id {
+        Some(id) => match resolve_task_identifier(&id, db) {
+            Ok(root) => Some(root),
+            Err(e) => {
+                eprintln!("Error resolving task: {e}");
+                std::process::exit(1);
+            }
+        },
+        None if clear => None,
+        None => {
+            match Config::load(pm_dir).focus {
+                Some(root) => println!("{}", focus_line(db, root)),
+                None => println!("No focus; pm focus <id> sets one."),
+            }
+            return;
+        }
+    };
+    if let Err(e) = Config::save_focus(pm_dir, root) {
+        eprintln!("Failed to save config: {e}");
+        std::process::exit(1);
+    }
+    match root {
+        Some(root) => say!("Focused on {}", focus_line(db, root)),
+        None => say!("Focus cleared"),
+    }
+}
+
+/// `TSK7 Title (N tickets)` for the focus root, or a note that it is gone.
+fn focus_line(db: &Database, root: LeafId) -> String {
+    match db.get(root) {
+        Some(task) => format!(
+            "{root} {} ({} tickets)",
+            task.title,
+            db.subtree_ids(root).len()
+        ),
+        None => format!("{root} (no longer exists; pm focus --clear to leave)"),
+    }
+}
+
+/// The focus root from `.pm/config.json`, if it still exists. A focus on a
+/// deleted ticket is reported once and otherwise ignored.
+fn active_focus(db: &Database, pm_dir: &Path) -> Option<LeafId> {
+    let root = Config::load(pm_dir).focus?;
+    if db.get(root).is_none() {
+        eprintln!("warning: focus {root} no longer exists; pm focus --clear to leave");
+        return None;
+    }
+    Some(root)
 }
 
 /// `pm priority <id> <priority>`: set front-matter priority.
@@ -3114,6 +6059,14 @@ pub fn cmd_tag(db: &mut Database, pm_dir: &Path, id: &str, ops: &[String]) {
         eprintln!("tag: supply at least one op (e.g. `+infra`, `-draft`).");
         std::process::exit(1);
     }
+    let added: Vec<String> = ops
+        .iter()
+        .filter_map(|op| op.strip_prefix('+'))
+        .map(normalise_tag)
+        .collect();
+    for w in tag_spelling_warnings(&db.tasks, &added) {
+        eprintln!("{w}");
+    }
     mutate_task(db, pm_dir, id, "tag", |task| {
         for op in ops {
             if let Some(name) = op.strip_prefix('+') {
@@ -3180,6 +6133,32 @@ pub fn cmd_milestone(db: &mut Database, pm_dir: &Path, id: &str, milestone_id: &
     });
 }
 
+/// `pm migrate-legacy`: move the legacy `tasks.json` workspace next to
+/// `pm_dir` into a named project.
+pub fn cmd_migrate_legacy(pm_dir: &Path, name: &str) {
+    let workspace = crate::project::workspace_dir(pm_dir);
+    match crate::project::migrate_legacy(&workspace, name) {
+        Ok(Some(done)) => {
+            say!(
+                "Migrated {} tasks and {} templates into project '{}' ({})",
+                done.tasks,
+                done.templates,
+                done.project.name,
+                done.project.file_path.display()
+            );
+            say!("Original kept at {}", done.backup.display());
+        }
+        Ok(None) => say!(
+            "No legacy tasks.json in {}; nothing to migrate.",
+            workspace.display()
+        ),
+        Err(e) => {
+            eprintln!("migrate-legacy: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
 /// `pm doctor [--migrate]`: rebuild `state.json` from disk and (with the
 /// `--migrate` flag) import any legacy `tasks.json` files into the workspace
 /// via the Phase 3.5 bridge.
@@ -3353,7 +6332,13 @@ fn run_doctor_migrate(pm_dir: &Path) {
                         milestone: None,
                         memories: Vec::new(),
                         due: None,
+                        remind_days_before: None,
                         parent: step.parent,
+                        order: None,
+                        iteration: None,
+                        estimate_minutes: None,
+                        completed_at_utc: None,
+                        pinned: false,
                         kind: step.kind,
                         status: Status::Open,
                         priority_level: None,
@@ -3520,14 +6505,14 @@ pub fn cmd_checkout(pm_dir: &Path, id: &str, intent: Option<&str>) {
 
     match locks::acquire(pm_dir, &lock, Utc::now()) {
         Ok(AcquireOutcome::Acquired) => {
-            println!("checkout: {leaf} locked by {}.", lock.agent);
+            say!("checkout: {leaf} locked by {}.", lock.agent);
         }
         Ok(AcquireOutcome::Overlapped { previous }) => {
             eprintln!(
                 "checkout: warning - {leaf} was already checked out by {} (soft lock; proceeding).",
                 previous.agent
             );
-            println!("checkout: {leaf} locked by {}.", lock.agent);
+            say!("checkout: {leaf} locked by {}.", lock.agent);
         }
         Ok(AcquireOutcome::Blocked { holder }) => {
             eprintln!(
@@ -3590,17 +6575,17 @@ pub fn cmd_checkin(pm_dir: &Path, id: &str, summary: Option<&str>, granular: boo
             if let Err(e) = crate::store::git::squash_since(pm_dir, &base, &message) {
                 eprintln!("checkin: squash failed, leaving individual commits: {e}");
             } else {
-                println!("checkin: {leaf} released; checkout span squashed.");
+                say!("checkin: {leaf} released; checkout span squashed.");
                 return;
             }
         }
         (true, _) => {
-            println!("checkin: {leaf} released; checkout-span commits kept (--granular).");
+            say!("checkin: {leaf} released; checkout-span commits kept (--granular).");
             return;
         }
         (false, None) => {}
     }
-    println!("checkin: {leaf} released.");
+    say!("checkin: {leaf} released.");
 }
 
 /// `pm heartbeat <id>`: refresh the heartbeat on a held lock so it does not
@@ -3619,7 +6604,7 @@ pub fn cmd_heartbeat(pm_dir: &Path, id: &str) {
         }
     };
     match locks::refresh_heartbeat(pm_dir, leaf, Utc::now()) {
-        Ok(true) => println!("heartbeat: {leaf} refreshed."),
+        Ok(true) => say!("heartbeat: {leaf} refreshed."),
         Ok(false) => {
             eprintln!("heartbeat: no active lock on {leaf}.");
             std::process::exit(1);
@@ -3834,6 +6819,26 @@ pub fn cmd_mcp(pm_dir: &Path) {
     }
 }
 
+/// `pm serve`: run the HTTP server in [`crate::serve`] until stopped. Exits
+/// with code 1 when the address cannot be bound or `pm` was built without
+/// the `server` feature.
+pub fn cmd_serve(pm_dir: &Path, host: &str, port: u16) {
+    #[cfg(feature = "server")]
+    if let Err(e) = crate::serve::run(pm_dir.to_path_buf(), host, port) {
+        eprintln!("pm serve: {e}");
+        std::process::exit(1);
+    }
+    #[cfg(not(feature = "server"))]
+    {
+        let _ = (pm_dir, host, port);
+        eprintln!(
+            "pm serve: this pm was built without the `server` feature; \
+             reinstall with `cargo install project_management --features server`"
+        );
+        std::process::exit(1);
+    }
+}
+
 pub fn cmd_memory(db: &mut Database, pm_dir: &Path, action: MemoryAction) {
     match action {
         MemoryAction::Link { id, name } => memory_link(db, pm_dir, &id, &name),
@@ -4012,7 +7017,7 @@ fn memory_link(db: &mut Database, pm_dir: &Path, id: &str, name: &str) {
             std::process::exit(1);
         }
     }
-    println!("linked {name} [{}] to {leaf}", hit.location.scope.as_str());
+    say!("linked {name} [{}] to {leaf}", hit.location.scope.as_str());
 }
 
 /// `pm memory unlink <id> <name>`: remove every front-matter reference to
@@ -4054,7 +7059,7 @@ fn memory_unlink(db: &mut Database, pm_dir: &Path, id: &str, name: &str) {
         eprintln!("memory unlink: cannot write {}: {e}", claude_path.display());
         std::process::exit(1);
     }
-    println!(
+    say!(
         "unlinked {name} from {leaf} ({} entries removed)",
         before - after
     );
@@ -4144,7 +7149,7 @@ fn memory_write(
         desc.map(|s| s.to_string()),
         content,
     ) {
-        Ok(loc) => println!("wrote {} ({})", loc.file.display(), scope.as_str()),
+        Ok(loc) => say!("wrote {} ({})", loc.file.display(), scope.as_str()),
         Err(e) => {
             eprintln!("memory write: {e}");
             std::process::exit(1);
@@ -4172,13 +7177,13 @@ fn memory_promote(db: &Database, pm_dir: &Path, name: &str, to_arg: &str) {
     };
     match promote_memory(&ctx, name, target) {
         Ok(outcome) => {
-            println!(
+            say!(
                 "promoted {name}: {} -> {}",
                 outcome.source.file.display(),
                 outcome.target.file.display()
             );
             if let Some(backref) = outcome.backref {
-                println!("back-reference written at {}", backref.file.display());
+                say!("back-reference written at {}", backref.file.display());
             }
         }
         Err(e) => {
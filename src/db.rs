@@ -6,21 +6,32 @@
 //! the ticket type via prefix and a monotonic per-type number). ID
 //! allocation goes through the embedded [`State`] counter map.
 
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
 
-use chrono::{Datelike, Duration, Local, NaiveDate};
+use chrono::{Datelike, Duration, Local, NaiveDate, TimeZone, Utc};
+use clap::ValueEnum;
+use regex::{NoExpand, Regex};
 use serde::{Deserialize, Serialize};
 
 use crate::fields::*;
+use crate::output::date_style;
 use crate::store::artifacts::{self, ArtifactsIndex};
 use crate::store::claude_md::{Ticket, CLAUDE_MD};
+use crate::store::config::{
+    AgeingConfig, Config, DateStyle, ProjectDefaults, StageColumn, WorkflowConfig,
+};
 use crate::store::id::{AddressId, IdInput, LeafId, TypePrefix};
 use crate::store::layout::Layout;
 use crate::store::state::{ItemEntry, State};
 use crate::store::task_bridge::{task_from_document, task_to_document};
-use crate::task::Task;
+use crate::store::write_lock::WriteLock;
+use crate::task::{Task, TaskTemplate};
 
 /// In-memory database for storing and managing tasks.
 ///
@@ -28,13 +39,195 @@ use crate::task::Task;
 /// [`Database::allocate_id`], the tombstone set so reused numbers stay out of
 /// circulation, the on-disk path index for each ticket, and the named
 /// [`crate::task::TaskTemplate`] presets used by the template commands.
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Database {
     pub tasks: Vec<Task>,
     #[serde(default)]
     pub state: State,
 }
 
+/// Upper bound on parent-chain walks. The real hierarchy is at most a handful
+/// of levels deep (nested subtasks aside), so hitting this means a cycle.
+pub const MAX_ANCESTOR_DEPTH: usize = 64;
+
+/// A parent-chain walk found a loop. `id` is a ticket on the cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleDetected {
+    pub id: LeafId,
+}
+
+impl std::fmt::Display for CycleDetected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cycle detected involving task {}; run `pm doctor`",
+            self.id
+        )
+    }
+}
+
+impl std::error::Error for CycleDetected {}
+
+/// `state.json` modification times, keyed by workspace, for workspaces
+/// loaded without holding the write lock until exit.
+static SAVE_GUARDS: Mutex<Option<HashMap<PathBuf, Option<SystemTime>>>> = Mutex::new(None);
+
+fn guard_key(pm_dir: &Path) -> PathBuf {
+    fs::canonicalize(pm_dir).unwrap_or_else(|_| pm_dir.to_path_buf())
+}
+
+fn state_stamp(state_path: &Path) -> Option<SystemTime> {
+    fs::metadata(state_path).and_then(|m| m.modified()).ok()
+}
+
+/// Remember how `state.json` in `pm_dir` looks now, so later saves from this
+/// process fail rather than overwrite a save another process made in the
+/// meantime. For commands that wait on stdin between loading and saving and
+/// so can't keep the write lock across the wait; call it under the lock,
+/// right before [`Database::load`]. Long-lived sessions use
+/// [`Database::load_guarded`] and [`Database::save_since`] instead.
+pub fn guard_saves(pm_dir: &Path) {
+    let stamp = state_stamp(&Layout::at(pm_dir).state_path());
+    let mut guards = SAVE_GUARDS.lock().unwrap_or_else(|e| e.into_inner());
+    guards
+        .get_or_insert_with(HashMap::new)
+        .insert(guard_key(pm_dir), stamp);
+}
+
+fn check_save_guard(pm_dir: &Path, state_path: &Path) -> std::io::Result<()> {
+    let guards = SAVE_GUARDS.lock().unwrap_or_else(|e| e.into_inner());
+    match guards.as_ref().and_then(|g| g.get(&guard_key(pm_dir))) {
+        Some(stamp) if *stamp != state_stamp(state_path) => Err(std::io::Error::other(
+            "the workspace changed while pm was waiting for input; run the command again",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn refresh_save_guard(pm_dir: &Path, state_path: &Path) {
+    let mut guards = SAVE_GUARDS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(stamp) = guards.as_mut().and_then(|g| g.get_mut(&guard_key(pm_dir))) {
+        *stamp = state_stamp(state_path);
+    }
+}
+
+/// Apply `rename` to every id `task` points at: its parent, deps and
+/// milestone.
+fn rename_references(task: &mut Task, mut rename: impl FnMut(&mut LeafId)) {
+    if let Some(parent) = task.parent.as_mut() {
+        rename(parent);
+    }
+    task.deps.iter_mut().for_each(&mut rename);
+    if let Some(milestone) = task.milestone.as_mut() {
+        rename(milestone);
+    }
+}
+
+/// A `state.json` that exists but can't be read, as reported by
+/// [`Database::try_load`].
+#[derive(Debug, Clone)]
+pub struct UnreadableState {
+    pub path: PathBuf,
+    pub error: String,
+}
+
+impl std::fmt::Display for UnreadableState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is unreadable: {}", self.path.display(), self.error)
+    }
+}
+
+impl std::error::Error for UnreadableState {}
+
+/// Replace an unreadable `state.json` with the index from the newest backup
+/// or snapshot ([`crate::store::history::latest_dump`]), once `confirm`
+/// agrees to that dump. The bad file is kept beside it as
+/// `state.json.corrupt-<timestamp>`, and id counters are moved past every
+/// ticket directory on disk so tickets created since the backup can't have
+/// their ids handed out again.
+///
+/// With no usable backup, or if `confirm` declines, the process exits rather
+/// than carry on with an empty database; `pm doctor` can then rebuild the
+/// index from the ticket files.
+fn recover_state(
+    pm_dir: &Path,
+    bad: &UnreadableState,
+    confirm: impl FnOnce(&crate::store::history::Dump) -> bool,
+) -> State {
+    let state_path = bad.path.as_path();
+    eprintln!("Error: {bad}");
+    let refuse = || -> ! {
+        eprintln!(
+            "Refusing to continue with an empty database. Run `pm doctor` to rebuild state.json from the ticket files."
+        );
+        std::process::exit(1);
+    };
+    let Some(dump) = crate::store::history::latest_dump(pm_dir) else {
+        eprintln!("No readable backup or snapshot to recover from.");
+        refuse();
+    };
+    if !confirm(&dump) {
+        refuse();
+    }
+
+    let aside = state_path.with_file_name(format!(
+        "state.json.corrupt-{}",
+        Local::now().format("%Y-%m-%d_%H-%M-%S")
+    ));
+    if let Err(e) = fs::rename(state_path, &aside) {
+        eprintln!("Could not move {} aside: {e}", state_path.display());
+        refuse();
+    }
+
+    let mut state = dump.db.state;
+    let mut on_disk = Vec::new();
+    ticket_dir_ids(pm_dir, &mut on_disk);
+    let mut unindexed = 0;
+    for leaf in on_disk {
+        let next = state.next.entry(leaf.prefix()).or_insert(1);
+        *next = (*next).max(leaf.number() + 1);
+        if !state.items.contains_key(&leaf) {
+            unindexed += 1;
+        }
+    }
+    if let Err(e) = state.save(state_path) {
+        eprintln!("Warning: could not write the restored state.json: {e}");
+    }
+    eprintln!(
+        "Restored; the unreadable file is kept at {}.",
+        aside.display()
+    );
+    if unindexed > 0 {
+        eprintln!(
+            "{unindexed} ticket(s) on disk are newer than the backup; run `pm doctor` to index them."
+        );
+    }
+    state
+}
+
+/// Collect the ids of every directory under `dir` named after a ticket.
+fn ticket_dir_ids(dir: &Path, out: &mut Vec<LeafId>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if let Some(leaf) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.parse::<LeafId>().ok())
+        {
+            if path.join(CLAUDE_MD).exists() {
+                out.push(leaf);
+            }
+        }
+        ticket_dir_ids(&path, out);
+    }
+}
+
 impl Database {
     /// Load the database from a `.pm/` workspace directory.
     ///
@@ -45,19 +238,61 @@ impl Database {
     /// present, falling back to a directory listing).
     ///
     /// Returns an empty database when the workspace has not been initialised.
+    /// A `state.json` that exists but cannot be read is never replaced by an
+    /// empty index: it is restored from the newest backup with a warning
+    /// (see [`Database::recover`]). Callers that can ask the user first use
+    /// [`Database::try_load`].
     pub fn load(pm_dir: &Path) -> Self {
+        Database::try_load(pm_dir).unwrap_or_else(|bad| {
+            Database::recover(pm_dir, &bad, |dump| {
+                eprintln!(
+                    "Warning: restoring the index from {} ({}).",
+                    dump.source,
+                    dump.path.display()
+                );
+                true
+            })
+        })
+    }
+
+    /// [`guard_saves`] then [`Database::load`], for sessions such as the TUI
+    /// that keep the database in memory between saves and write it back with
+    /// [`Database::save_since`].
+    pub fn load_guarded(pm_dir: &Path) -> Self {
+        guard_saves(pm_dir);
+        Database::load(pm_dir)
+    }
+
+    /// Like [`Database::load`], but an unreadable `state.json` is returned
+    /// as an error instead of being restored.
+    pub fn try_load(pm_dir: &Path) -> Result<Self, UnreadableState> {
         if !pm_dir.exists() {
-            return Database::default();
+            return Ok(Database::default());
         }
-        let layout = Layout::at(pm_dir);
-        let state = match State::load(&layout.state_path()) {
-            Ok(s) => s,
-            Err(e) => {
-                eprintln!("Error parsing state.json, starting fresh: {e}");
-                State::fresh()
-            }
-        };
+        let state_path = Layout::at(pm_dir).state_path();
+        match State::load(&state_path) {
+            Ok(state) => Ok(Database::load_tickets(pm_dir, state)),
+            Err(e) => Err(UnreadableState {
+                path: state_path,
+                error: e.to_string(),
+            }),
+        }
+    }
 
+    /// Load `pm_dir` after [`Database::try_load`] found `bad`, restoring the
+    /// index from the newest backup or snapshot if `confirm` accepts it. Exits
+    /// when there is no backup or `confirm` declines.
+    pub fn recover(
+        pm_dir: &Path,
+        bad: &UnreadableState,
+        confirm: impl FnOnce(&crate::store::history::Dump) -> bool,
+    ) -> Self {
+        let state = recover_state(pm_dir, bad, confirm);
+        Database::load_tickets(pm_dir, state)
+    }
+
+    /// Read the ticket of every leaf `state` indexes.
+    fn load_tickets(pm_dir: &Path, state: State) -> Self {
         let mut tasks: Vec<Task> = Vec::with_capacity(state.items.len());
         for (_leaf, entry) in &state.items {
             let abs_dir = pm_dir.join(&entry.path);
@@ -96,12 +331,22 @@ impl Database {
     /// that need to compare pre- and post-save paths for a leaf (e.g.
     /// `cmd_move` cleaning up a now-vacated directory) can read
     /// `self.state.items[leaf]` after `save` returns and see the new path.
+    ///
+    /// Runs under the workspace [`WriteLock`], waiting briefly if another
+    /// process holds it. A workspace registered with [`guard_saves`] refuses
+    /// the save if another process saved it since this one last looked.
     pub fn save(&mut self, pm_dir: &Path) -> std::io::Result<()> {
+        let _lock = WriteLock::acquire(pm_dir).map_err(std::io::Error::other)?;
         let layout = Layout::at(pm_dir);
+        check_save_guard(pm_dir, &layout.state_path())?;
         layout
             .init()
             .map_err(|e| std::io::Error::other(format!("layout init: {e}")))?;
 
+        for task in self.tasks.iter_mut() {
+            sync_completed_at(task);
+        }
+
         // Split the borrow into tasks (read) and state (write) so the loop
         // can mutate state.items while still iterating tasks.
         let Database { tasks, state } = self;
@@ -169,9 +414,93 @@ impl Database {
         state
             .save(&layout.state_path())
             .map_err(|e| std::io::Error::other(format!("state.save: {e}")))?;
+        refresh_save_guard(pm_dir, &layout.state_path());
         Ok(())
     }
 
+    /// Save a database loaded with [`Database::load_guarded`] as `base` and
+    /// edited since. If another process saved the workspace in the meantime,
+    /// the edits that turned `base` into `self` are replayed onto a fresh
+    /// load first (see [`Database::rebase`]), so that save is kept rather
+    /// than overwritten from a stale copy.
+    pub fn save_since(&mut self, pm_dir: &Path, base: &Database) -> std::io::Result<()> {
+        let _lock = WriteLock::acquire(pm_dir).map_err(std::io::Error::other)?;
+        let state_path = Layout::at(pm_dir).state_path();
+        if check_save_guard(pm_dir, &state_path).is_err() {
+            let current = Database::try_load(pm_dir).map_err(std::io::Error::other)?;
+            self.rebase(base, current);
+            refresh_save_guard(pm_dir, &state_path);
+        }
+        self.save(pm_dir)
+    }
+
+    /// Replace `self` with `current`, a newer load of the workspace `base`
+    /// came from, plus the edits that turned `base` into `self`. Tasks
+    /// changed or removed here win over `current`; a task changed here but
+    /// deleted in `current` stays deleted. A task added here under an id
+    /// `current` has since handed out is renumbered, along with references
+    /// to it. Id counters keep the higher of both sides and tombstones the
+    /// union, so no id is handed out twice.
+    pub fn rebase(&mut self, base: &Database, mut current: Database) {
+        for (&prefix, &next) in &self.state.next {
+            let theirs = current.state.next.entry(prefix).or_insert(1);
+            *theirs = (*theirs).max(next);
+        }
+        for (&prefix, dead) in &self.state.tombstones {
+            current
+                .state
+                .tombstones
+                .entry(prefix)
+                .or_default()
+                .extend(dead);
+        }
+        if self.state.templates != base.state.templates {
+            current.state.templates = std::mem::take(&mut self.state.templates);
+        }
+
+        let before: HashMap<LeafId, &Task> = base.tasks.iter().map(|t| (t.id, t)).collect();
+        let kept: HashSet<LeafId> = self.tasks.iter().map(|t| t.id).collect();
+        current
+            .tasks
+            .retain(|t| !before.contains_key(&t.id) || kept.contains(&t.id));
+
+        let mut renamed: HashMap<LeafId, LeafId> = HashMap::new();
+        for task in &self.tasks {
+            if !before.contains_key(&task.id) && current.get(task.id).is_some() {
+                renamed.insert(task.id, current.allocate_id(task.id.prefix()));
+            }
+        }
+        let rename = |id: &mut LeafId| {
+            if let Some(&new) = renamed.get(id) {
+                *id = new;
+            }
+        };
+        for mut task in std::mem::take(&mut self.tasks) {
+            match before.get(&task.id) {
+                Some(&old) if *old == task => continue,
+                Some(_) => {
+                    rename_references(&mut task, rename);
+                    if let Some(slot) = current.tasks.iter_mut().find(|t| t.id == task.id) {
+                        *slot = task;
+                    }
+                }
+                None => {
+                    rename(&mut task.id);
+                    rename_references(&mut task, rename);
+                    current.tasks.push(task);
+                }
+            }
+        }
+        *self = current;
+    }
+
+    /// The `artifacts/` directory of a ticket in the workspace at `pm_dir`,
+    /// or `None` when `state.json` has no entry for `id`.
+    pub fn artifacts_dir(&self, pm_dir: &Path, id: LeafId) -> Option<PathBuf> {
+        let entry = self.state.items.get(&id)?;
+        Some(pm_dir.join(&entry.path).join("artifacts"))
+    }
+
     /// Allocate the next monotonic [`LeafId`] for the given type prefix and
     /// return it to the caller. The internal `state` counter is bumped and
     /// any tombstoned numbers are skipped automatically.
@@ -193,12 +522,117 @@ impl Database {
         self.tasks.iter().find(|t| t.id == id)
     }
 
+    /// The dependencies of `task` that are not Done yet, in `deps` order.
+    /// A dependency that no longer exists blocks nothing.
+    pub fn open_blockers(&self, task: &Task) -> Vec<&Task> {
+        task.deps
+            .iter()
+            .filter_map(|&dep| self.get(dep))
+            .filter(|dep| dep.status != Status::Done)
+            .collect()
+    }
+
+    /// `root` and every task below it: the set `pm focus` scopes views to.
+    pub fn subtree_ids(&self, root: LeafId) -> HashSet<LeafId> {
+        let mut ids = HashSet::from([root]);
+        collect_descendants(root, &build_children_map(&self.tasks), &mut ids);
+        ids
+    }
+
     /// Get a mutable reference to a task by id.
     pub fn get_mut(&mut self, id: LeafId) -> Option<&mut Task> {
         let idx = self.tasks.iter().position(|t| t.id == id)?;
         self.tasks.get_mut(idx)
     }
 
+    /// Parent chain of `id`, nearest ancestor first. A parent reference that
+    /// does not resolve ends the chain (the dangling id is still included).
+    ///
+    /// Returns [`CycleDetected`] if the walk revisits a ticket or runs past
+    /// [`MAX_ANCESTOR_DEPTH`] hops, which only happens when hand-edited
+    /// front-matter has introduced a parent loop.
+    pub fn ancestor_ids(&self, id: LeafId) -> Result<Vec<LeafId>, CycleDetected> {
+        let (chain, cycle) = self.walk_ancestors(id);
+        match cycle {
+            Some(c) => Err(c),
+            None => Ok(chain),
+        }
+    }
+
+    /// Depth of `id` in the hierarchy (0 for a root). See
+    /// [`Database::ancestor_ids`] for the cycle semantics.
+    pub fn depth_of(&self, id: LeafId) -> Result<usize, CycleDetected> {
+        self.ancestor_ids(id).map(|chain| chain.len())
+    }
+
+    /// Shared walker behind [`Database::ancestor_ids`]: returns the chain
+    /// collected before any cycle was hit, plus the cycle if there was one.
+    fn walk_ancestors(&self, id: LeafId) -> (Vec<LeafId>, Option<CycleDetected>) {
+        self.walk_up(id, self.get(id).and_then(|t| t.parent))
+    }
+
+    /// [`Database::walk_ancestors`] starting from `parent` rather than the
+    /// stored parent of `id`.
+    fn walk_up(&self, id: LeafId, parent: Option<LeafId>) -> (Vec<LeafId>, Option<CycleDetected>) {
+        let mut chain = Vec::new();
+        let mut seen: HashSet<LeafId> = HashSet::new();
+        seen.insert(id);
+        let mut cur = parent;
+        while let Some(pid) = cur {
+            if !seen.insert(pid) || chain.len() >= MAX_ANCESTOR_DEPTH {
+                return (chain, Some(CycleDetected { id: pid }));
+            }
+            chain.push(pid);
+            cur = self.get(pid).and_then(|p| p.parent);
+        }
+        (chain, None)
+    }
+
+    /// Readable ancestor chain for `id`, root first, as parent titles joined
+    /// with `" > "` ("E-commerce > User Mgmt > Registration"). Tasks without a
+    /// parent render as `-`. A parent loop is marked with a leading
+    /// `(cycle)` segment rather than walked forever.
+    ///
+    /// When the chain is wider than `max_width` characters, middle segments
+    /// collapse into `…` (the root and the immediate parent are kept as long
+    /// as possible) before the result is truncated to fit.
+    pub fn breadcrumb(&self, id: LeafId, max_width: usize) -> String {
+        self.breadcrumb_under(id, self.get(id).and_then(|t| t.parent), max_width)
+    }
+
+    /// [`Database::breadcrumb`] for `id` as if it sat under `parent`, for a
+    /// ticket whose parent is being edited or that is not saved yet.
+    pub fn breadcrumb_under(&self, id: LeafId, parent: Option<LeafId>, max_width: usize) -> String {
+        let (chain, cycle) = self.walk_up(id, parent);
+        if chain.is_empty() && cycle.is_none() {
+            return "-".into();
+        }
+        let mut segments: Vec<String> = chain
+            .iter()
+            .rev()
+            .map(|pid| match self.get(*pid) {
+                Some(p) => p.title.clone(),
+                None => pid.to_string(),
+            })
+            .collect();
+        if cycle.is_some() {
+            segments.insert(0, "(cycle)".into());
+        }
+        let mut joined = segments.join(" > ");
+        while joined.chars().count() > max_width && segments.len() > 2 {
+            if segments[1] == "…" {
+                if segments.len() == 3 {
+                    break;
+                }
+                segments.remove(2);
+            } else {
+                segments[1] = "…".into();
+            }
+            joined = segments.join(" > ");
+        }
+        truncate(&joined, max_width)
+    }
+
     /// Remove tasks by ids and clean up any parent references pointing to removed tasks.
     pub fn remove_ids(&mut self, ids: &HashSet<LeafId>) {
         self.tasks.retain(|t| !ids.contains(&t.id));
@@ -212,6 +646,83 @@ impl Database {
             }
         }
     }
+
+    /// Fold `duplicates` into `keep`: dependencies and milestone links on
+    /// them point at `keep` instead, and they are removed. Their children
+    /// must already have been moved under `keep` the way `pm move` does it,
+    /// so their directories follow; any left behind end up parentless. The
+    /// caller saves.
+    pub fn merge_into(&mut self, keep: LeafId, duplicates: &[LeafId]) {
+        let gone: HashSet<LeafId> = duplicates.iter().copied().filter(|&d| d != keep).collect();
+        for task in self.tasks.iter_mut().filter(|t| !gone.contains(&t.id)) {
+            if task.milestone.is_some_and(|m| gone.contains(&m)) {
+                task.milestone = Some(keep);
+            }
+            if task.deps.iter().any(|d| gone.contains(d)) {
+                let mut deps = Vec::with_capacity(task.deps.len());
+                for dep in task
+                    .deps
+                    .iter()
+                    .map(|d| if gone.contains(d) { keep } else { *d })
+                {
+                    if dep != task.id && !deps.contains(&dep) {
+                        deps.push(dep);
+                    }
+                }
+                task.deps = deps;
+            }
+        }
+        self.remove_ids(&gone);
+    }
+
+    /// Merge `incoming` task templates into the workspace by name, in order.
+    /// A name already in use is skipped, or replaced in place with
+    /// `overwrite`.
+    pub fn merge_templates(
+        &mut self,
+        incoming: Vec<TaskTemplate>,
+        overwrite: bool,
+    ) -> TemplateMerge {
+        let mut merge = TemplateMerge::default();
+        for template in incoming {
+            let existing = self
+                .state
+                .templates
+                .iter_mut()
+                .find(|t| t.name == template.name);
+            match existing {
+                None => {
+                    merge.added.push(template.name.clone());
+                    self.state.templates.push(template);
+                }
+                Some(slot) if overwrite => {
+                    merge.replaced.push(template.name.clone());
+                    *slot = template;
+                }
+                Some(_) => merge.skipped.push(template.name),
+            }
+        }
+        merge
+    }
+}
+
+/// Template names sorted by what [`Database::merge_templates`] did with them.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct TemplateMerge {
+    pub added: Vec<String>,
+    pub replaced: Vec<String>,
+    pub skipped: Vec<String>,
+}
+
+/// Keep `completed_at_utc` in step with the status: a Done task without one
+/// takes its `updated_at_utc`, which is when the status change was saved (or
+/// the `pm complete --at` time), and any other status clears it.
+fn sync_completed_at(task: &mut Task) {
+    if task.status == Status::Done {
+        task.completed_at_utc.get_or_insert(task.updated_at_utc);
+    } else {
+        task.completed_at_utc = None;
+    }
 }
 
 /// Walk a task's parent chain up to the root and return the resulting
@@ -298,6 +809,334 @@ pub fn split_and_normalise_tags(inputs: &[String]) -> Vec<String> {
     tags
 }
 
+/// Where a task's artifact points: absolute paths as they are, file names
+/// from the ticket's `artifacts/` directory (see [`Database::artifacts_dir`]).
+pub fn resolve_artifact_path(artifacts_dir: &Path, artifact: &str) -> PathBuf {
+    let path = Path::new(artifact);
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+    let dir = fs::canonicalize(artifacts_dir).unwrap_or_else(|_| artifacts_dir.to_path_buf());
+    dir.join(path)
+}
+
+/// One field `pm replace` changed on a task, as displayed text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldEdit {
+    pub field: ReplaceField,
+    pub before: String,
+    pub after: String,
+}
+
+/// Replace every match of `pattern` with `to` in the chosen `fields` of
+/// `task`. With `literal`, `to` is inserted as is; otherwise `$1` and
+/// `${name}` refer to capture groups. Returns the changed fields and the
+/// number of matches replaced; the task is left alone when nothing matched.
+/// Tags are rewritten one by one, then normalised, deduplicated and sorted;
+/// a tag replaced by nothing is dropped. A title the replacement would leave
+/// blank is kept as it is and not counted.
+pub fn replace_in_task(
+    task: &mut Task,
+    pattern: &Regex,
+    to: &str,
+    literal: bool,
+    fields: &[ReplaceField],
+) -> (Vec<FieldEdit>, usize) {
+    let rewrite = |text: &str| -> Option<(String, usize)> {
+        let count = pattern.find_iter(text).count();
+        if count == 0 {
+            return None;
+        }
+        let after = if literal {
+            pattern.replace_all(text, NoExpand(to))
+        } else {
+            pattern.replace_all(text, to)
+        };
+        Some((after.into_owned(), count))
+    };
+    let mut edits = Vec::new();
+    let mut replaced = 0;
+    for &field in fields {
+        match field {
+            ReplaceField::Title => {
+                if let Some((after, n)) =
+                    rewrite(&task.title).filter(|(after, _)| !after.trim().is_empty())
+                {
+                    replaced += n;
+                    edits.push(FieldEdit {
+                        field,
+                        before: std::mem::replace(&mut task.title, after.clone()),
+                        after,
+                    });
+                }
+            }
+            ReplaceField::Description => {
+                let Some(before) = task.description.clone() else {
+                    continue;
+                };
+                if let Some((after, n)) = rewrite(&before) {
+                    replaced += n;
+                    task.description = (!after.is_empty()).then(|| after.clone());
+                    edits.push(FieldEdit {
+                        field,
+                        before,
+                        after,
+                    });
+                }
+            }
+            ReplaceField::Tags => {
+                let mut n = 0;
+                let rewritten: Vec<String> = task
+                    .tags
+                    .iter()
+                    .map(|tag| match rewrite(tag) {
+                        Some((after, count)) => {
+                            n += count;
+                            after
+                        }
+                        None => tag.clone(),
+                    })
+                    .collect();
+                if n > 0 {
+                    replaced += n;
+                    let before = task.tags.join(",");
+                    task.tags = split_and_normalise_tags(&rewritten);
+                    edits.push(FieldEdit {
+                        field,
+                        before,
+                        after: task.tags.join(","),
+                    });
+                }
+            }
+        }
+    }
+    (edits, replaced)
+}
+
+/// One line of `pm add --stdin` input after inline markers are stripped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddLine {
+    pub title: String,
+    /// Normalised tags from `#tag` words.
+    pub tags: Vec<String>,
+    /// Priority from a `!must-have` / `!nice-to-have` / `!cut-first` word.
+    pub priority: Option<Priority>,
+}
+
+/// Parse a bulk-capture line. Whitespace-separated `#tag` words become tags
+/// and a `!priority` word sets the priority; every other word is kept as the
+/// title. Words that only look like markers (`#`, `!`, `!urgent`) stay in
+/// the title. Returns `None` for blank lines or lines with no title left.
+pub fn parse_add_line(line: &str) -> Option<AddLine> {
+    let mut title_words = Vec::new();
+    let mut tags = Vec::new();
+    let mut priority = None;
+    for word in line.split_whitespace() {
+        if let Some(tag) = word.strip_prefix('#').filter(|t| !t.is_empty()) {
+            tags.push(tag.to_string());
+        } else if let Some(p) = word
+            .strip_prefix('!')
+            .and_then(|p| Priority::from_str(p, true).ok())
+        {
+            priority = Some(p);
+        } else {
+            title_words.push(word);
+        }
+    }
+    if title_words.is_empty() {
+        return None;
+    }
+    Some(AddLine {
+        title: title_words.join(" "),
+        tags: split_and_normalise_tags(&tags),
+        priority,
+    })
+}
+
+/// Does `tag` satisfy the filter `wanted`? Exact by default; with `expand`,
+/// a filter also matches any dotted descendant (`area` matches
+/// `area.backend` but not `areas`).
+pub fn tag_matches(tag: &str, wanted: &str, expand: bool) -> bool {
+    if tag == wanted {
+        return true;
+    }
+    expand
+        && tag
+            .strip_prefix(wanted)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
+/// True if every tag in `wanted` is matched by at least one of `tags`.
+/// Both sides are expected to be normalised already.
+pub fn has_all_tags(tags: &[String], wanted: &[String], expand: bool) -> bool {
+    wanted
+        .iter()
+        .all(|w| tags.iter().any(|t| tag_matches(t, w, expand)))
+}
+
+/// Group dotted tags into a prefix tree. Each entry is a tag path split on
+/// `.` (`["area", "backend"]`) with the number of tasks tagged at or below
+/// that node. Entries come out in depth-first order, parents first.
+pub fn tag_tree(tasks: &[Task]) -> Vec<(Vec<String>, usize)> {
+    let mut counts: BTreeMap<Vec<String>, usize> = BTreeMap::new();
+    for t in tasks {
+        let mut prefixes: BTreeSet<Vec<String>> = BTreeSet::new();
+        for tag in &t.tags {
+            let segments: Vec<String> = tag.split('.').map(str::to_string).collect();
+            for end in 1..=segments.len() {
+                prefixes.insert(segments[..end].to_vec());
+            }
+        }
+        for p in prefixes {
+            *counts.entry(p).or_default() += 1;
+        }
+    }
+    counts.into_iter().collect()
+}
+
+/// Every distinct tag in use, sorted.
+pub fn known_tags(tasks: &[Task]) -> BTreeSet<String> {
+    tasks.iter().flat_map(|t| t.tags.iter().cloned()).collect()
+}
+
+/// Known tags that complete `prefix`, in sorted order. A tag equal to the
+/// prefix is left out since there is nothing to complete.
+pub fn complete_tag<'a>(known: &'a BTreeSet<String>, prefix: &str) -> Vec<&'a str> {
+    let prefix = normalise_tag(prefix);
+    if prefix.is_empty() {
+        return Vec::new();
+    }
+    known
+        .iter()
+        .filter(|t| t.starts_with(&prefix) && **t != prefix)
+        .map(String::as_str)
+        .collect()
+}
+
+/// Known tags one edit (an inserted, deleted or changed character) away
+/// from `tag`, such as `back-end` for `backend`. Empty when `tag` is
+/// already in use.
+pub fn similar_tags<'a>(known: &'a BTreeSet<String>, tag: &str) -> Vec<&'a str> {
+    if known.contains(tag) {
+        return Vec::new();
+    }
+    known
+        .iter()
+        .filter(|k| edit_distance(k, tag) == 1)
+        .map(String::as_str)
+        .collect()
+}
+
+/// Warnings for each of `tags` that is new to `tasks` but a near miss of a
+/// tag already in use, so typos don't split the tag vocabulary.
+pub fn tag_spelling_warnings(tasks: &[Task], tags: &[String]) -> Vec<String> {
+    let known = known_tags(tasks);
+    tags.iter()
+        .filter_map(|tag| {
+            let similar = similar_tags(&known, tag);
+            (!similar.is_empty()).then(|| {
+                format!(
+                    "warning: new tag '{tag}' is close to existing {}; did you mean that?",
+                    similar
+                        .iter()
+                        .map(|s| format!("'{s}'"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+        })
+        .collect()
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Title as `pm dedupe --fuzzy` compares it: lowercase words with the
+/// punctuation between them dropped.
+fn dedupe_key(title: &str) -> String {
+    title
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether two titles count as duplicates: identical ignoring case and
+/// surrounding whitespace, or with `fuzzy`, equal after [`dedupe_key`] or
+/// within one edit per five characters of the longer one, so `Fix login
+/// bug` matches `fix login bugs` but `Fix` does not match `Mix`.
+fn titles_match(a: &str, b: &str, fuzzy: bool) -> bool {
+    if !fuzzy {
+        return a.trim().to_lowercase() == b.trim().to_lowercase();
+    }
+    let (a, b) = (dedupe_key(a), dedupe_key(b));
+    let longer = a.chars().count().max(b.chars().count());
+    edit_distance(&a, &b) * 5 <= longer
+}
+
+/// Groups of tasks that look like duplicates of each other: same parent,
+/// same kind and matching titles (see [`titles_match`]). Fuzzy matches
+/// chain, so `a ~ b` and `b ~ c` put all three in one group. Each group is
+/// in id order and holds at least two tasks; groups follow their first id.
+pub fn duplicate_groups(tasks: &[Task], fuzzy: bool) -> Vec<Vec<LeafId>> {
+    let mut sorted: Vec<&Task> = tasks.iter().collect();
+    sorted.sort_by_key(|t| t.id);
+    let mut groups: Vec<Vec<&Task>> = Vec::new();
+    for task in sorted {
+        let matching: Vec<usize> = groups
+            .iter()
+            .enumerate()
+            .filter(|(_, g)| {
+                g[0].parent == task.parent
+                    && g[0].kind == task.kind
+                    && g.iter().any(|t| titles_match(&t.title, &task.title, fuzzy))
+            })
+            .map(|(i, _)| i)
+            .collect();
+        let Some((&first, rest)) = matching.split_first() else {
+            groups.push(vec![task]);
+            continue;
+        };
+        // Later groups this task bridges fold into the first one.
+        for &i in rest.iter().rev() {
+            let merged = groups.remove(i);
+            groups[first].extend(merged);
+        }
+        groups[first].push(task);
+        groups[first].sort_by_key(|t| t.id);
+    }
+    groups
+        .into_iter()
+        .filter(|g| g.len() > 1)
+        .map(|g| g.into_iter().map(|t| t.id).collect())
+        .collect()
+}
+
+/// Shift a due date by `days` for the list's `+`/`-` keys. An undated task
+/// can only be pushed forward, which dates it `today`; pulling one back
+/// returns `None` because there is nothing to move.
+pub fn nudge_due(due: Option<NaiveDate>, days: i64, today: NaiveDate) -> Option<NaiveDate> {
+    match due {
+        Some(date) => date.checked_add_signed(Duration::days(days)),
+        None if days > 0 => Some(today),
+        None => None,
+    }
+}
+
 /// Parse human-readable due date input with smart natural language support.
 ///
 /// Supports:
@@ -415,6 +1254,133 @@ pub fn start_end_of_this_week(today: NaiveDate) -> (NaiveDate, NaiveDate) {
     (start, end)
 }
 
+/// Where a dated task stands relative to today.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DueState {
+    /// Not yet due, but within `remind_days_before` days of it.
+    Reminder,
+    /// Due today.
+    Due,
+    /// Past its due date.
+    Overdue,
+}
+
+/// Classify `due` against `today`. Tasks outside their reminder window (or
+/// without a due date) have no state.
+pub fn due_state(
+    due: Option<NaiveDate>,
+    remind_days_before: Option<u32>,
+    today: NaiveDate,
+) -> Option<DueState> {
+    let due = due?;
+    if due < today {
+        Some(DueState::Overdue)
+    } else if due == today {
+        Some(DueState::Due)
+    } else if remind_days_before.is_some_and(|n| (due - today).num_days() <= i64::from(n)) {
+        Some(DueState::Reminder)
+    } else {
+        None
+    }
+}
+
+/// How long a task has gone without an update, as `ui.ageing` buckets it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ageing {
+    Fresh,
+    /// At least `amber_days` old.
+    Stale,
+    /// At least `red_days` old.
+    Neglected,
+}
+
+/// Bucket the time between `updated_at_utc` and `now_utc` (both Unix
+/// seconds) by the configured thresholds.
+pub fn ageing(updated_at_utc: i64, now_utc: i64, thresholds: &AgeingConfig) -> Ageing {
+    let days = (now_utc - updated_at_utc).max(0) / 86_400;
+    if days >= i64::from(thresholds.red_days) {
+        Ageing::Neglected
+    } else if days >= i64::from(thresholds.amber_days) {
+        Ageing::Stale
+    } else {
+        Ageing::Fresh
+    }
+}
+
+/// True when `task` has a value for `field`. Blank text counts as missing,
+/// the same as no value, so `--missing description` also finds a
+/// description of `""`. `assignees` maps ids to their live checkout holder,
+/// as for [`print_table`].
+pub fn field_present(
+    task: &Task,
+    field: PresenceField,
+    assignees: &HashMap<LeafId, String>,
+) -> bool {
+    let text = |s: &Option<String>| s.as_deref().is_some_and(|s| !s.trim().is_empty());
+    match field {
+        PresenceField::Due => task.due.is_some(),
+        PresenceField::Description => text(&task.description),
+        PresenceField::Summary => text(&task.summary),
+        PresenceField::UserStory => text(&task.user_story),
+        PresenceField::Requirements => text(&task.requirements),
+        PresenceField::Tags => task.tags.iter().any(|t| !t.trim().is_empty()),
+        PresenceField::Parent => task.parent.is_some(),
+        PresenceField::Priority => task.priority_level.is_some(),
+        PresenceField::Urgency => task.urgency.is_some(),
+        PresenceField::Stage => task.process_stage.is_some(),
+        PresenceField::Estimate => task.estimate_minutes.is_some(),
+        PresenceField::Iteration => text(&task.iteration),
+        PresenceField::Milestone => task.milestone.is_some(),
+        PresenceField::Deps => !task.deps.is_empty(),
+        PresenceField::IssueLink => text(&task.issue_link),
+        PresenceField::PrLink => text(&task.pr_link),
+        PresenceField::Artifacts => task.artifacts.iter().any(|a| !a.trim().is_empty()),
+        PresenceField::Assignee => assignees.contains_key(&task.id),
+    }
+}
+
+/// True when `due` falls in the `--due` bucket `filter`: exactly today,
+/// within the current ISO week, before today, unset, or inside the
+/// `remind_days_before` window.
+pub fn due_filter_matches(
+    due: Option<NaiveDate>,
+    remind_days_before: Option<u32>,
+    filter: DueFilter,
+    today: NaiveDate,
+) -> bool {
+    match filter {
+        DueFilter::Upcoming => {
+            due_state(due, remind_days_before, today) == Some(DueState::Reminder)
+        }
+        DueFilter::Today => due == Some(today),
+        DueFilter::ThisWeek => {
+            let (week_start, week_end) = start_end_of_this_week(today);
+            due.is_some_and(|d| d >= week_start && d <= week_end)
+        }
+        DueFilter::Overdue => due.is_some_and(|d| d < today),
+        DueFilter::None => due.is_none(),
+    }
+}
+
+/// True when `due` is in the past by at least `min_days` whole days. Tasks
+/// without a due date are never overdue; `min_days` of 0 still requires the
+/// date to be before `today`.
+pub fn overdue_by_at_least(due: Option<NaiveDate>, today: NaiveDate, min_days: u32) -> bool {
+    match due {
+        Some(d) if d < today => (today - d).num_days() >= i64::from(min_days),
+        _ => false,
+    }
+}
+
+/// True when `due` falls between `today` and `today + max_days` inclusive.
+/// Overdue tasks are excluded; pair with `--overdue-by` to cover both sides.
+pub fn due_within(due: Option<NaiveDate>, today: NaiveDate, max_days: u32) -> bool {
+    match due {
+        Some(d) => d >= today && (d - today).num_days() <= i64::from(max_days),
+        None => false,
+    }
+}
+
 /// Format a due date relative to today ("today", "tomorrow", "in 3d", "2d late").
 pub fn format_due_relative(due: Option<NaiveDate>, today: NaiveDate) -> String {
     match due {
@@ -434,6 +1400,135 @@ pub fn format_due_relative(due: Option<NaiveDate>, today: NaiveDate) -> String {
     }
 }
 
+/// Format a due date for detail views: the date, how far away it is, and
+/// the reminder lead time when one is set ("2025-03-10 (in 4d, remind 7d
+/// before)").
+pub fn format_due_detail(
+    due: Option<NaiveDate>,
+    remind_days_before: Option<u32>,
+    today: NaiveDate,
+) -> String {
+    let Some(d) = due else {
+        return "-".into();
+    };
+    let relative = format_due_relative(Some(d), today);
+    match remind_days_before {
+        Some(n) => format!("{d} ({relative}, remind {n}d before)"),
+        None => format!("{d} ({relative})"),
+    }
+}
+
+/// [`format_due_detail`] without the relative part, for `--iso-dates`
+/// ("2025-03-10 (remind 7d before)").
+pub fn format_due_iso(due: Option<NaiveDate>, remind_days_before: Option<u32>) -> String {
+    match (due, remind_days_before) {
+        (None, _) => "-".into(),
+        (Some(d), Some(n)) => format!("{d} (remind {n}d before)"),
+        (Some(d), None) => d.to_string(),
+    }
+}
+
+/// Parse a `pm complete --at` value into a UTC timestamp. Accepts an RFC 3339
+/// timestamp, a local `YYYY-MM-DD HH:MM[:SS]` (or with a `T` separator), or
+/// any date [`parse_due_input`] understands (`2025-03-01`, `yesterday`),
+/// which is taken as local midnight.
+pub fn parse_completion_time(s: &str) -> Option<i64> {
+    let s = s.trim();
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+        return Some(dt.timestamp());
+    }
+    let local = |ndt: chrono::NaiveDateTime| {
+        Local
+            .from_local_datetime(&ndt)
+            .earliest()
+            .map(|d| d.timestamp())
+    };
+    for fmt in [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+    ] {
+        if let Ok(ndt) = chrono::NaiveDateTime::parse_from_str(s, fmt) {
+            return local(ndt);
+        }
+    }
+    local(parse_due_input(s)?.and_hms_opt(0, 0, 0)?)
+}
+
+/// Parse an effort estimate: plain minutes (`90`) or hours and minutes
+/// (`2h`, `1h30m`, `1h 30m`, `45m`). Zero is rejected.
+pub fn parse_estimate(s: &str) -> Option<u32> {
+    let s: String = s.trim().to_lowercase().split_whitespace().collect();
+    if let Ok(minutes) = s.parse::<u32>() {
+        return (minutes > 0).then_some(minutes);
+    }
+    let (hours, rest) = match s.split_once('h') {
+        Some((h, rest)) => (h.parse::<u32>().ok()?, rest),
+        None => (0, s.as_str()),
+    };
+    let minutes = match rest.strip_suffix('m') {
+        Some(m) => m.parse::<u32>().ok()?,
+        None if rest.is_empty() => 0,
+        None => return None,
+    };
+    let total = hours.checked_mul(60)?.checked_add(minutes)?;
+    (total > 0).then_some(total)
+}
+
+/// Format an estimate in minutes as `45m`, `2h` or `1h 30m`.
+pub fn format_estimate(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{m}m"),
+        (h, 0) => format!("{h}h"),
+        (h, m) => format!("{h}h {m}m"),
+    }
+}
+
+/// Format how long ago the unix timestamp `ts` was, relative to `now`:
+/// "just now", "5m ago", "3h ago", "12d ago". Clock skew that puts `ts` in
+/// the future reads as "just now".
+pub fn format_age(ts: i64, now: i64) -> String {
+    let secs = (now - ts).max(0);
+    if secs < 60 {
+        "just now".into()
+    } else if secs < 3600 {
+        format!("{}m ago", secs / 60)
+    } else if secs < 86_400 {
+        format!("{}h ago", secs / 3600)
+    } else {
+        format!("{}d ago", secs / 86_400)
+    }
+}
+
+/// Format a unix timestamp as RFC3339 UTC followed by its age, e.g.
+/// "2025-03-01T09:30:00+00:00 (3d ago)". Out-of-range values render as `-`.
+pub fn format_timestamp_with_age(ts: i64, now: i64) -> String {
+    match Utc.timestamp_opt(ts, 0).single() {
+        Some(t) => format!("{} ({})", t.to_rfc3339(), format_age(ts, now)),
+        None => "-".into(),
+    }
+}
+
+/// Reading speed behind the estimate in [`format_text_stats`], in words per
+/// minute.
+const READING_WORDS_PER_MINUTE: usize = 200;
+
+/// Summarise the size of a long text field: "42 words, 250 chars, 6 lines,
+/// ~1 min read". The reading time rounds up, so any text reads as at least
+/// a minute.
+pub fn format_text_stats(text: &str) -> String {
+    let count = |n: usize, unit: &str| format!("{n} {unit}{}", if n == 1 { "" } else { "s" });
+    let words = text.split_whitespace().count();
+    format!(
+        "{}, {}, {}, ~{} min read",
+        count(words, "word"),
+        count(text.chars().count(), "char"),
+        count(text.lines().count(), "line"),
+        words.div_ceil(READING_WORDS_PER_MINUTE)
+    )
+}
+
 /// Format a task kind for display.
 pub fn format_kind(k: Kind) -> &'static str {
     match k {
@@ -509,6 +1604,141 @@ pub fn format_process_stage(s: Option<ProcessStage>) -> &'static str {
     }
 }
 
+/// Number of process stages, `Ideation` through `Release`.
+pub const PROCESS_STAGE_COUNT: usize = 8;
+
+/// 1-based position of a stage in the process; 0 when no stage is set.
+pub fn process_stage_ordinal(s: Option<ProcessStage>) -> usize {
+    match s {
+        None => 0,
+        Some(ProcessStage::Ideation) => 1,
+        Some(ProcessStage::Design) => 2,
+        Some(ProcessStage::Prototyping) => 3,
+        Some(ProcessStage::ReadyToImplement) => 4,
+        Some(ProcessStage::Implementation) => 5,
+        Some(ProcessStage::Testing) => 6,
+        Some(ProcessStage::Refinement) => 7,
+        Some(ProcessStage::Release) => 8,
+    }
+}
+
+/// Format a process stage with an ASCII progress bar, one cell per stage:
+/// `[#####---] 5/8 Implementation`. An unset stage is an empty bar. ASCII so
+/// it survives pipes and plain terminals.
+pub fn format_stage_progress(s: Option<ProcessStage>) -> String {
+    let done = process_stage_ordinal(s);
+    format!(
+        "[{}{}] {}/{} {}",
+        "#".repeat(done),
+        "-".repeat(PROCESS_STAGE_COUNT - done),
+        done,
+        PROCESS_STAGE_COUNT,
+        format_process_stage(s)
+    )
+}
+
+/// One workflow board column: its title and the stages it shows, `None`
+/// standing for tickets with no stage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardColumn {
+    pub title: String,
+    pub stages: Vec<Option<ProcessStage>>,
+}
+
+/// The board `pm wf` shows when no columns are configured: unassigned
+/// tickets, then one column per stage.
+pub fn default_board_columns() -> Vec<BoardColumn> {
+    std::iter::once(None)
+        .chain(ProcessStage::value_variants().iter().copied().map(Some))
+        .map(|stage| BoardColumn {
+            title: match stage {
+                None => "Unassigned".to_string(),
+                Some(_) => format_process_stage(stage).to_string(),
+            },
+            stages: vec![stage],
+        })
+        .collect()
+}
+
+/// The name a stage goes by in `stage_columns` and `--stage-columns`: its
+/// `--stage` value, or `unassigned`.
+pub fn board_stage_name(stage: Option<ProcessStage>) -> String {
+    match stage.and_then(|s| s.to_possible_value()) {
+        Some(value) => value.get_name().to_string(),
+        None => "unassigned".to_string(),
+    }
+}
+
+/// Resolve configured board columns. Every stage, and `unassigned`, has to
+/// sit in exactly one column so a card always has one home and moving it
+/// sideways is unambiguous.
+pub fn board_columns(spec: &[StageColumn]) -> Result<Vec<BoardColumn>, String> {
+    let mut owner: [Option<&str>; PROCESS_STAGE_COUNT + 1] = Default::default();
+    let mut columns = Vec::with_capacity(spec.len());
+    for column in spec {
+        let title = column.title.trim();
+        if title.is_empty() {
+            return Err("a stage column has no title".to_string());
+        }
+        if column.stages.is_empty() {
+            return Err(format!("column '{title}' has no stages"));
+        }
+        let mut stages = Vec::with_capacity(column.stages.len());
+        for name in &column.stages {
+            let name = name.trim();
+            let stage = match name {
+                "unassigned" => None,
+                _ => Some(
+                    ProcessStage::from_str(name, true)
+                        .map_err(|_| format!("unknown stage '{name}' in column '{title}'"))?,
+                ),
+            };
+            let slot = &mut owner[process_stage_ordinal(stage)];
+            if let Some(other) = slot {
+                return Err(format!("stage '{name}' is in both '{other}' and '{title}'"));
+            }
+            *slot = Some(title);
+            stages.push(stage);
+        }
+        columns.push(BoardColumn {
+            title: title.to_string(),
+            stages,
+        });
+    }
+    let missing: Vec<String> = default_board_columns()
+        .into_iter()
+        .flat_map(|c| c.stages)
+        .filter(|&stage| owner[process_stage_ordinal(stage)].is_none())
+        .map(board_stage_name)
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("not in any column: {}", missing.join(", ")));
+    }
+    Ok(columns)
+}
+
+/// Parse `--stage-columns`: columns separated by `;`, each a title, `=`
+/// and its comma-separated stages, e.g.
+/// `Todo=unassigned,ideation,design;Doing=...;Done=refinement,release`.
+pub fn parse_stage_columns(raw: &str) -> Result<Vec<StageColumn>, String> {
+    raw.split(';')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| {
+            let (title, stages) = part
+                .split_once('=')
+                .ok_or_else(|| format!("'{}' should be TITLE=STAGE,...", part.trim()))?;
+            Ok(StageColumn {
+                title: title.trim().to_string(),
+                stages: stages
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
 /// Validate that a parent-child relationship follows the hierarchical rules.
 pub fn validate_hierarchy(parent_kind: Kind, child_kind: Kind) -> bool {
     match (parent_kind, child_kind) {
@@ -521,12 +1751,76 @@ pub fn validate_hierarchy(parent_kind: Kind, child_kind: Kind) -> bool {
     }
 }
 
+/// The kind one level above `child` in the hierarchy, used when a parent has
+/// to be created on the fly. Projects and milestones have none.
+pub fn parent_kind_for(child: Kind) -> Option<Kind> {
+    match child {
+        Kind::Subtask => Some(Kind::Task),
+        Kind::Task => Some(Kind::Epic),
+        Kind::Epic => Some(Kind::Product),
+        Kind::Product => Some(Kind::Project),
+        _ => None,
+    }
+}
+
+/// The kind one level below `parent`: what `pm add` creates under a focus
+/// root when `--kind` is not given. Subtasks nest under subtasks.
+pub fn child_kind_for(parent: Kind) -> Option<Kind> {
+    match parent {
+        Kind::Project => Some(Kind::Product),
+        Kind::Product => Some(Kind::Epic),
+        Kind::Epic => Some(Kind::Task),
+        Kind::Task | Kind::Subtask => Some(Kind::Subtask),
+        _ => None,
+    }
+}
+
+/// Whether `task` is unfiled: a plain task with no parent, and so no project.
+pub fn is_inbox(task: &Task) -> bool {
+    task.kind == Kind::Task && task.parent.is_none()
+}
+
+/// Whether `task`'s parent has `status`, for `pm list --parent-status`.
+/// A task without a parent (or whose parent is missing) matches only with
+/// `include_rootless`.
+pub fn parent_status_matches(
+    db: &Database,
+    task: &Task,
+    status: Status,
+    include_rootless: bool,
+) -> bool {
+    match task.parent.and_then(|p| db.get(p)) {
+        Some(parent) => parent.status == status,
+        None => include_rootless,
+    }
+}
+
+/// Check that `parent` can become the parent of `task_id`: it exists, is not
+/// the task itself, and does not sit below it.
+pub fn check_reparent(db: &Database, task_id: LeafId, parent: LeafId) -> Result<(), String> {
+    if parent == task_id {
+        return Err("Parent cannot equal child.".to_string());
+    }
+    if db.get(parent).is_none() {
+        return Err(format!("Parent ID {parent} does not exist."));
+    }
+    match db.ancestor_ids(parent) {
+        Ok(chain) if chain.contains(&task_id) => {
+            Err("Setting parent would create a cycle.".to_string())
+        }
+        Ok(_) => Ok(()),
+        Err(cycle) => Err(cycle.to_string()),
+    }
+}
+
 /// Format a task status for display.
 pub fn format_status(s: Status) -> &'static str {
     match s {
         Status::Open => "Open",
         Status::InProgress => "InProgress",
         Status::Done => "Done",
+        Status::Blocked => "Blocked",
+        Status::Cancelled => "Cancelled",
     }
 }
 
@@ -538,9 +1832,9 @@ pub fn project_ancestor<'a>(db: &'a Database, task: &Task) -> Option<&'a Task> {
     let mut guard = 0usize;
     while let Some(pid) = cur {
         guard += 1;
-        if guard > 64 {
+        if guard > MAX_ANCESTOR_DEPTH {
             return None;
-        } // cycle/depth guard
+        } // cycle/depth guard; callers that care use Database::ancestor_ids
         let parent = db.get(pid)?;
         if parent.kind == Kind::Project {
             return Some(parent);
@@ -550,6 +1844,41 @@ pub fn project_ancestor<'a>(db: &'a Database, task: &Task) -> Option<&'a Task> {
     None
 }
 
+/// Defaults from `config.json` for tickets created under `parent`: those of
+/// `parent` itself when it is a Project, else of its project ancestor.
+pub fn project_defaults_for<'a>(
+    db: &Database,
+    config: &'a Config,
+    parent: LeafId,
+) -> Option<&'a ProjectDefaults> {
+    let parent_task = db.get(parent)?;
+    let project = if parent_task.kind == Kind::Project {
+        parent_task
+    } else {
+        project_ancestor(db, parent_task)?
+    };
+    config.project_defaults(&project.id.to_string(), &project.title)
+}
+
+/// One-line summary of a project's add defaults, e.g.
+/// `kind Subtask, priority Must Have, tags sprint-3`.
+pub fn format_project_defaults(defaults: &ProjectDefaults) -> String {
+    let mut parts = Vec::new();
+    if let Some(k) = defaults.kind {
+        parts.push(format!("kind {}", format_kind(k)));
+    }
+    if defaults.priority.is_some() {
+        parts.push(format!("priority {}", format_priority(defaults.priority)));
+    }
+    if defaults.urgency.is_some() {
+        parts.push(format!("urgency {}", format_urgency(defaults.urgency)));
+    }
+    if !defaults.tags.is_empty() {
+        parts.push(format!("tags {}", defaults.tags.join(",")));
+    }
+    parts.join(", ")
+}
+
 /// Human-readable project label for a task: the project ancestor's title, or
 /// `"-"` when none is found.
 pub fn project_label(db: &Database, task: &Task) -> String {
@@ -558,37 +1887,307 @@ pub fn project_label(db: &Database, task: &Task) -> String {
         .unwrap_or_else(|| "-".to_string())
 }
 
-/// Print tasks in a formatted table with optional tree indentation. The
-/// `Project` column is derived from each task's parent chain via
-/// [`project_label`]; the `Task` struct no longer carries a free-form label.
-pub fn print_table(db: &Database, tasks: &[&Task], id_to_depth: Option<&HashMap<LeafId, usize>>) {
-    // Header.
-    println!(
-        "{:<8} {:<10} {:<11} {:<6} {:<12} {:<14} {}",
-        "ID", "Kind", "Status", "Pri", "Due", "Project", "Title [tags]"
-    );
+/// Glyph before a pinned task's title in `pm list` and the TUI list.
+pub const PIN_MARK: &str = "★ ";
+
+/// Marker before the title of an open task in the TUI list while any of its
+/// dependencies is not Done.
+pub const BLOCKED_MARK: &str = "[B] ";
+
+/// Width of the `Parents` column printed by `pm list --breadcrumb`.
+const BREADCRUMB_WIDTH: usize = 40;
+
+/// Columns `pm list` prints when `--fields` is not given.
+pub const DEFAULT_LIST_COLUMNS: &[ListColumn] = &[
+    ListColumn::Id,
+    ListColumn::Kind,
+    ListColumn::Status,
+    ListColumn::Priority,
+    ListColumn::Due,
+    ListColumn::Project,
+    ListColumn::Title,
+];
+
+/// Resolve the columns for `pm list`: the `--fields` selection (first
+/// occurrence wins) or [`DEFAULT_LIST_COLUMNS`], plus `Parents` ahead of the
+/// title when `--breadcrumb` asks for it and the selection lacks it.
+pub fn list_columns(fields: &[ListColumn], breadcrumb: bool) -> Vec<ListColumn> {
+    let requested = if fields.is_empty() {
+        DEFAULT_LIST_COLUMNS
+    } else {
+        fields
+    };
+    let mut columns: Vec<ListColumn> = Vec::new();
+    for &col in requested {
+        if !columns.contains(&col) {
+            columns.push(col);
+        }
+    }
+    if breadcrumb && !columns.contains(&ListColumn::Parents) {
+        let at = columns
+            .iter()
+            .position(|c| *c == ListColumn::Title)
+            .unwrap_or(columns.len());
+        columns.insert(at, ListColumn::Parents);
+    }
+    columns
+}
+
+/// Header text and padded width of a `pm list` column. `tags_inline` is set
+/// when the tags trail the title rather than having their own column.
+fn list_column_header(col: ListColumn, tags_inline: bool) -> (&'static str, usize) {
+    match col {
+        ListColumn::Id => ("ID", 8),
+        ListColumn::Kind => ("Kind", 10),
+        ListColumn::Status => ("Status", 11),
+        ListColumn::Priority => ("Priority", 12),
+        ListColumn::Urgency => ("Urgency", 24),
+        ListColumn::Stage => ("Stage", 18),
+        ListColumn::Due => ("Due", 12),
+        ListColumn::Project => ("Project", 14),
+        ListColumn::Parents => ("Parents", BREADCRUMB_WIDTH),
+        ListColumn::Assignee => ("Assignee", 16),
+        ListColumn::Created => ("Created", 10),
+        ListColumn::Updated => ("Updated", 10),
+        ListColumn::Tags => ("Tags", 20),
+        ListColumn::Title if tags_inline => ("Title [tags]", 40),
+        ListColumn::Title => ("Title", 40),
+    }
+}
+
+/// Print tasks in a formatted table with optional tree indentation, one
+/// column per entry of `columns` (see [`list_columns`]). Every column but
+/// the last is padded to a fixed width and truncated to fit; the last is
+/// printed in full when piped and cut to the terminal width otherwise.
+/// Tree indentation is applied to the title.
+///
+/// The `Project` column is derived from each task's parent chain via
+/// [`project_label`]; `Parents` shows the ancestor chain (see
+/// [`Database::breadcrumb`]); `Assignee` reads from `assignees`, the holder
+/// of each ticket's checkout lock.
+///
+/// [`ListFormat::Plain`] prints the same columns tab-separated and untrimmed
+/// instead, with due dates as `YYYY-MM-DD` and titles without indentation
+/// or inline tags. `header` controls the first row in either format.
+pub fn print_table(
+    db: &Database,
+    tasks: &[&Task],
+    id_to_depth: Option<&HashMap<LeafId, usize>>,
+    columns: &[ListColumn],
+    assignees: &HashMap<LeafId, String>,
+    format: ListFormat,
+    header: bool,
+) {
+    let plain = format == ListFormat::Plain;
+    let tags_inline = !plain && !columns.contains(&ListColumn::Tags);
+    // On a terminal the last column gets whatever the padded ones leave, so
+    // a paragraph-long title ends in an ellipsis instead of wrapping.
+    let last_width = (!plain && std::io::stdout().is_terminal())
+        .then(crossterm::terminal::size)
+        .and_then(Result::ok)
+        .map(|(cols, _)| {
+            let padded: usize = columns
+                .iter()
+                .take(columns.len().saturating_sub(1))
+                .map(|&col| list_column_header(col, tags_inline).1 + 1)
+                .sum();
+            (cols as usize).saturating_sub(padded).max(20)
+        });
+    let render = |cells: Vec<String>| {
+        if plain {
+            // Tabs and newlines inside a value would split the record.
+            let fields: Vec<String> = cells
+                .iter()
+                .map(|c| c.replace(['\t', '\n', '\r'], " "))
+                .collect();
+            println!("{}", fields.join("\t"));
+            return;
+        }
+        let last = cells.len().saturating_sub(1);
+        let line: Vec<String> = cells
+            .into_iter()
+            .zip(columns)
+            .enumerate()
+            .map(|(i, (cell, &col))| {
+                if i == last {
+                    match last_width {
+                        Some(width) => truncate(&cell, width),
+                        None => cell,
+                    }
+                } else {
+                    let width = list_column_header(col, tags_inline).1;
+                    format!("{:<width$}", truncate(&cell, width))
+                }
+            })
+            .collect();
+        println!("{}", line.join(" "));
+    };
+
+    if header {
+        render(
+            columns
+                .iter()
+                .map(|&col| list_column_header(col, tags_inline).0.to_string())
+                .collect(),
+        );
+    }
     let today = Local::now().date_naive();
+    let date = |ts: i64| {
+        Local
+            .timestamp_opt(ts, 0)
+            .single()
+            .filter(|_| ts > 0)
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "-".to_string())
+    };
     for t in tasks {
-        let indent = id_to_depth.and_then(|m| m.get(&t.id).copied()).unwrap_or(0);
-        let indent_str = "  ".repeat(indent);
-        let tags = if t.tags.is_empty() {
-            String::new()
-        } else {
-            format!(" [{}]", t.tags.join(","))
-        };
-        let due = format_due_relative(t.due, today);
-        let project = project_label(db, t);
-        println!(
-            "{:<8} {:<10} {:<11} {:<12} {:<14} {}{}{}",
-            t.id.to_string(),
-            format_kind(t.kind),
-            format_status(t.status),
-            due,
-            truncate(&project, 14),
-            indent_str,
-            t.title,
-            tags
-        );
+        let cells = columns
+            .iter()
+            .map(|&col| match col {
+                ListColumn::Id => t.id.to_string(),
+                ListColumn::Kind => format_kind(t.kind).to_string(),
+                ListColumn::Status => format_status(t.status).to_string(),
+                ListColumn::Priority => format_priority(t.priority_level).to_string(),
+                ListColumn::Urgency => format_urgency(t.urgency).to_string(),
+                ListColumn::Stage => format_process_stage(t.process_stage).to_string(),
+                ListColumn::Due if plain || date_style() == DateStyle::Iso => {
+                    t.due.map_or_else(|| "-".to_string(), |d| d.to_string())
+                }
+                ListColumn::Due => format_due_relative(t.due, today),
+                ListColumn::Project => project_label(db, t),
+                ListColumn::Parents if plain => db.breadcrumb(t.id, usize::MAX),
+                ListColumn::Parents => db.breadcrumb(t.id, BREADCRUMB_WIDTH),
+                ListColumn::Assignee => assignees
+                    .get(&t.id)
+                    .cloned()
+                    .unwrap_or_else(|| "-".to_string()),
+                ListColumn::Created => date(t.created_at_utc),
+                ListColumn::Updated => date(t.updated_at_utc),
+                ListColumn::Tags if t.tags.is_empty() => "-".to_string(),
+                ListColumn::Tags => t.tags.join(","),
+                ListColumn::Title if plain => t.title.clone(),
+                ListColumn::Title => {
+                    let indent = id_to_depth.and_then(|m| m.get(&t.id).copied()).unwrap_or(0);
+                    let tags = if tags_inline && !t.tags.is_empty() {
+                        format!(" [{}]", t.tags.join(","))
+                    } else {
+                        String::new()
+                    };
+                    let pin = if t.pinned { PIN_MARK } else { "" };
+                    format!("{}{pin}{}{}", "  ".repeat(indent), t.title, tags)
+                }
+            })
+            .collect();
+        render(cells);
+    }
+}
+
+/// Sort a slice of tasks in place by the given key. Pinned tasks come first
+/// whatever the key. Ties always fall back to id order so output is stable
+/// across runs; see [`SortKey`] for the full tiebreaker chain of each key.
+pub fn sort_tasks(tasks: &mut [&Task], key: SortKey) {
+    match key {
+        SortKey::Due => tasks.sort_by_key(|t| {
+            (
+                t.due.unwrap_or(NaiveDate::MAX),
+                priority_rank(t.priority_level),
+                std::cmp::Reverse(t.updated_at_utc),
+                t.id,
+            )
+        }),
+        SortKey::Priority => tasks.sort_by_key(|t| {
+            (
+                priority_rank(t.priority_level),
+                urgency_rank(t.urgency),
+                t.id,
+            )
+        }),
+        SortKey::Id => tasks.sort_by_key(|t| t.id),
+        SortKey::Created => tasks.sort_by_key(|t| (std::cmp::Reverse(t.created_at_utc), t.id)),
+        SortKey::Updated => tasks.sort_by_key(|t| (std::cmp::Reverse(t.updated_at_utc), t.id)),
+    }
+    tasks.sort_by_key(|t| !t.pinned);
+}
+
+/// Sort by several keys in turn, each `(key, descending)`, as `pm list
+/// --sort priority,-due` asks. A lone key in its usual direction sorts
+/// exactly as [`sort_tasks`]. Otherwise each key compares only its own
+/// field, tasks without a due date or priority stay last even when that
+/// key is reversed, and remaining ties fall back to id. Pinned tasks still
+/// come first.
+pub fn sort_tasks_by(tasks: &mut [&Task], keys: &[(SortKey, bool)]) {
+    if let [(key, false)] = keys {
+        return sort_tasks(tasks, *key);
+    }
+    tasks.sort_by(|a, b| {
+        keys.iter()
+            .fold(Ordering::Equal, |ord, &(key, descending)| {
+                ord.then_with(|| compare_on(a, b, key, descending))
+            })
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    tasks.sort_by_key(|t| !t.pinned);
+}
+
+/// Compare two tasks on one sort key alone, unset values last.
+fn compare_on(a: &Task, b: &Task, key: SortKey, descending: bool) -> Ordering {
+    let unset = |t: &Task| match key {
+        SortKey::Due => t.due.is_none(),
+        SortKey::Priority => t.priority_level.is_none(),
+        _ => false,
+    };
+    let ord = match key {
+        SortKey::Due => a.due.cmp(&b.due),
+        SortKey::Priority => priority_rank(a.priority_level).cmp(&priority_rank(b.priority_level)),
+        SortKey::Id => a.id.cmp(&b.id),
+        SortKey::Created => b.created_at_utc.cmp(&a.created_at_utc),
+        SortKey::Updated => b.updated_at_utc.cmp(&a.updated_at_utc),
+    };
+    unset(a)
+        .cmp(&unset(b))
+        .then(if descending { ord.reverse() } else { ord })
+}
+
+/// Sort rank of a priority: MustHave=0, NiceToHave=1, CutFirst=2, None=3.
+fn priority_rank(p: Option<Priority>) -> u8 {
+    match p {
+        Some(Priority::MustHave) => 0,
+        Some(Priority::NiceToHave) => 1,
+        Some(Priority::CutFirst) => 2,
+        None => 3,
+    }
+}
+
+/// Sort rank of an urgency, most urgent and important first, unset last.
+fn urgency_rank(u: Option<Urgency>) -> u8 {
+    match u {
+        Some(Urgency::UrgentImportant) => 0,
+        Some(Urgency::UrgentNotImportant) => 1,
+        Some(Urgency::NotUrgentImportant) => 2,
+        Some(Urgency::NotUrgentNotImportant) => 3,
+        None => 4,
+    }
+}
+
+/// Format a sort key for display.
+pub fn format_sort_key(k: SortKey) -> &'static str {
+    match k {
+        SortKey::Due => "Due",
+        SortKey::Priority => "Priority",
+        SortKey::Id => "Id",
+        SortKey::Created => "Created",
+        SortKey::Updated => "Updated",
+    }
+}
+
+/// Format a due filter for display, matching its `--due` spelling.
+pub fn format_due_filter(f: DueFilter) -> &'static str {
+    match f {
+        DueFilter::Today => "today",
+        DueFilter::ThisWeek => "this-week",
+        DueFilter::Overdue => "overdue",
+        DueFilter::None => "none",
+        DueFilter::Upcoming => "upcoming",
     }
 }
 
@@ -609,18 +2208,73 @@ pub fn truncate(s: &str, width: usize) -> String {
     }
 }
 
-/// Build a map of parent task ids to their children's ids.
+/// Warning for a title longer than `max_length` characters, or `None` when
+/// it fits. Long titles are still saved; displays truncate them.
+pub fn title_length_warning(title: &str, max_length: usize) -> Option<String> {
+    let len = title.chars().count();
+    (len > max_length).then(|| {
+        format!(
+            "warning: title is {len} characters, over the {max_length} set by titles.max_length; \
+             it will be truncated in tables and cards"
+        )
+    })
+}
+
+/// Build a map of parent task ids to their children's ids, in sibling
+/// order ([`cmp_siblings`]).
 pub fn build_children_map(tasks: &[Task]) -> BTreeMap<LeafId, Vec<LeafId>> {
-    let mut map: BTreeMap<LeafId, Vec<LeafId>> = BTreeMap::new();
+    let mut map: BTreeMap<LeafId, Vec<&Task>> = BTreeMap::new();
     for t in tasks {
         if let Some(p) = t.parent {
-            map.entry(p).or_default().push(t.id);
+            map.entry(p).or_default().push(t);
         }
     }
-    for v in map.values_mut() {
-        v.sort_unstable();
-    }
-    map
+    map.into_iter()
+        .map(|(p, mut children)| {
+            children.sort_by(|a, b| cmp_siblings(a, b));
+            (p, children.into_iter().map(|t| t.id).collect())
+        })
+        .collect()
+}
+
+/// Where a task sits among its siblings: its manual `order` when set,
+/// otherwise its id number.
+pub fn sibling_position(task: &Task) -> f64 {
+    task.order.unwrap_or(task.id.number() as f64)
+}
+
+/// Sibling order: grouped by kind, then by [`sibling_position`], then by id.
+pub fn cmp_siblings(a: &Task, b: &Task) -> Ordering {
+    a.id.prefix()
+        .cmp(&b.id.prefix())
+        .then(sibling_position(a).total_cmp(&sibling_position(b)))
+        .then(a.id.cmp(&b.id))
+}
+
+/// `count` ascending positions for new tasks placed directly after (or
+/// before) `reference` among its siblings of the same kind. They are spread
+/// evenly between the reference and its neighbour on that side, or step
+/// away from the reference by 1 when it has none.
+pub fn positions_beside(tasks: &[Task], reference: &Task, before: bool, count: usize) -> Vec<f64> {
+    let at = sibling_position(reference);
+    let others = tasks
+        .iter()
+        .filter(|t| {
+            t.id != reference.id && t.kind == reference.kind && t.parent == reference.parent
+        })
+        .map(sibling_position);
+    let neighbour = if before {
+        others.filter(|&p| p < at).max_by(f64::total_cmp)
+    } else {
+        others.filter(|&p| p > at).min_by(f64::total_cmp)
+    };
+    let (lo, step) = match (neighbour, before) {
+        (Some(n), true) => (n, (at - n) / (count + 1) as f64),
+        (Some(n), false) => (at, (n - at) / (count + 1) as f64),
+        (None, true) => (at - (count + 1) as f64, 1.0),
+        (None, false) => (at, 1.0),
+    };
+    (1..=count).map(|i| lo + step * i as f64).collect()
 }
 
 /// Recursively collect all descendant task ids from a root task.
@@ -638,19 +2292,410 @@ pub fn collect_descendants(
     }
 }
 
-/// Collect all ancestor task ids by following parent references.
-pub fn collect_ancestors(mut id: LeafId, db: &Database) -> Vec<LeafId> {
-    let index = db.index();
-    let mut chain = Vec::new();
-    while let Some(t) = index.get(&id).and_then(|&i| db.tasks.get(i)) {
-        if let Some(p) = t.parent {
-            chain.push(p);
-            id = p;
-        } else {
-            break;
+/// Position of every task in a pre-order walk of the hierarchy: each parent
+/// is followed by its whole subtree before its next sibling. Roots (no
+/// parent, or a parent that no longer exists) and siblings go in
+/// [`cmp_siblings`] order. Tasks only reachable through a parent loop come
+/// last, in id order.
+pub fn tree_order(tasks: &[Task]) -> HashMap<LeafId, usize> {
+    let child_map = build_children_map(tasks);
+    let known: HashSet<LeafId> = tasks.iter().map(|t| t.id).collect();
+    let mut roots: Vec<&Task> = tasks
+        .iter()
+        .filter(|t| t.parent.is_none_or(|p| !known.contains(&p)))
+        .collect();
+    roots.sort_by(|a, b| cmp_siblings(a, b));
+    let roots: Vec<LeafId> = roots.into_iter().map(|t| t.id).collect();
+
+    let mut order: HashMap<LeafId, usize> = HashMap::new();
+    // Depth-first with an explicit stack; children are pushed in reverse so
+    // the first sibling is visited first.
+    let mut stack: Vec<LeafId> = roots.into_iter().rev().collect();
+    while let Some(id) = stack.pop() {
+        if order.contains_key(&id) {
+            continue;
+        }
+        order.insert(id, order.len());
+        if let Some(children) = child_map.get(&id) {
+            stack.extend(children.iter().rev().copied());
         }
     }
-    chain
+    let mut stranded: Vec<LeafId> = known
+        .into_iter()
+        .filter(|id| !order.contains_key(id))
+        .collect();
+    stranded.sort_unstable();
+    for id in stranded {
+        order.insert(id, order.len());
+    }
+    order
+}
+
+/// Open (non-Done) descendants of any of `targets` that are not themselves
+/// in `targets`, i.e. what would be left open if `targets` were completed
+/// together. Returns the first blocked target and its open descendants in
+/// id order, or `None` when completing the set leaves nothing open beneath.
+pub fn completion_blocker(db: &Database, targets: &[LeafId]) -> Option<(LeafId, Vec<LeafId>)> {
+    let child_map = build_children_map(&db.tasks);
+    let completing: HashSet<LeafId> = targets.iter().copied().collect();
+    let mut sorted = targets.to_vec();
+    sorted.sort();
+    sorted.into_iter().find_map(|id| {
+        let mut below = HashSet::new();
+        collect_descendants(id, &child_map, &mut below);
+        let mut open: Vec<LeafId> = below
+            .into_iter()
+            .filter(|d| !completing.contains(d))
+            .filter(|d| db.get(*d).is_some_and(|t| !t.status.is_closed()))
+            .collect();
+        open.sort();
+        (!open.is_empty()).then_some((id, open))
+    })
+}
+
+/// Describe a [`completion_blocker`] result, listing at most five ids:
+/// `EPC1 has 2 open descendants: TSK1, TSK2`.
+pub fn format_completion_blocker(id: LeafId, open: &[LeafId]) -> String {
+    const SHOWN: usize = 5;
+    let mut listed: Vec<String> = open.iter().take(SHOWN).map(|d| d.to_string()).collect();
+    if open.len() > SHOWN {
+        listed.push(format!("+{} more", open.len() - SHOWN));
+    }
+    let noun = if open.len() == 1 {
+        "descendant"
+    } else {
+        "descendants"
+    };
+    format!("{id} has {} open {noun}: {}", open.len(), listed.join(", "))
+}
+
+/// The error for a status change `enforce_status_flow` refuses, naming the
+/// moves it does allow: `TSK7 cannot go from Open to Done; next: InProgress,
+/// Cancelled`. `None` when [`Status::can_step_to`] accepts the move.
+pub fn status_flow_error(id: LeafId, from: Status, to: Status) -> Option<String> {
+    if from.can_step_to(to) {
+        return None;
+    }
+    let next: Vec<&str> = Status::value_variants()
+        .iter()
+        .filter(|s| **s != from && from.can_step_to(**s))
+        .map(|s| format_status(*s))
+        .collect();
+    Some(format!(
+        "{id} cannot go from {} to {}; next: {}",
+        format_status(from),
+        format_status(to),
+        next.join(", ")
+    ))
+}
+
+/// Move `task` to the stage `workflow.stage_on_status` maps its new status
+/// to, after a status change from `from`. Nothing happens when the status
+/// did not change or has no mapping. Callers skip this when the same edit
+/// set the stage explicitly, so a chosen stage always wins. Returns the
+/// stage when it changed.
+pub fn advance_stage(
+    task: &mut Task,
+    from: Status,
+    workflow: &WorkflowConfig,
+) -> Option<ProcessStage> {
+    if task.status == from {
+        return None;
+    }
+    let stage = workflow.stage_for(task.kind, task.status)?;
+    if task.process_stage == Some(stage) {
+        return None;
+    }
+    task.process_stage = Some(stage);
+    Some(stage)
+}
+
+/// Estimated minutes across `id` and all its descendants: the total, and
+/// the part still on tasks that are not closed. Tasks without an estimate
+/// count as zero.
+pub fn estimate_rollup(db: &Database, id: LeafId) -> (u32, u32) {
+    let mut subtree = HashSet::new();
+    collect_descendants(id, &build_children_map(&db.tasks), &mut subtree);
+    subtree.insert(id);
+    subtree
+        .into_iter()
+        .filter_map(|d| db.get(d))
+        .fold((0, 0), |(total, open), t| {
+            let est = t.estimate_minutes.unwrap_or(0);
+            let still_open = if t.status.is_closed() { 0 } else { est };
+            (total + est, open + still_open)
+        })
+}
+
+/// When `task` was completed, or `None` while it is not Done. Tasks
+/// completed before `completed_at_utc` was recorded fall back to their last
+/// update.
+pub fn completion_time(task: &Task) -> Option<i64> {
+    (task.status == Status::Done).then(|| task.completed_at_utc.unwrap_or(task.updated_at_utc))
+}
+
+/// One day of a burndown: estimated minutes still open at the end of `date`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BurndownPoint {
+    pub date: NaiveDate,
+    pub remaining_minutes: u32,
+}
+
+/// Remaining estimated work at the end of each day from `from` to `to`
+/// inclusive, with days taken in `tz`.
+///
+/// Each point is an end-of-day snapshot: a task counts towards day D if it
+/// was created before D ended and had not been completed by then. So a
+/// task added and finished on the same day never shows up, and one
+/// completed on D is already gone from D's figure. Only estimated tasks take
+/// part, and cancelled tasks are left out entirely, since when they were
+/// dropped is not recorded.
+pub fn burndown<Tz: TimeZone>(
+    tasks: &[&Task],
+    from: NaiveDate,
+    to: NaiveDate,
+    tz: &Tz,
+) -> Vec<BurndownPoint> {
+    let counted: Vec<(u32, i64, Option<i64>)> = tasks
+        .iter()
+        .filter(|t| t.status != Status::Cancelled)
+        .filter_map(|t| Some((t.estimate_minutes?, t.created_at_utc, completion_time(t))))
+        .collect();
+    from.iter_days()
+        .take_while(|d| *d <= to)
+        .map(|date| {
+            let end_of_day = date
+                .succ_opt()
+                .and_then(|next| next.and_hms_opt(0, 0, 0))
+                .and_then(|midnight| tz.from_local_datetime(&midnight).earliest())
+                .map_or(i64::MAX, |dt| dt.timestamp());
+            let remaining_minutes = counted
+                .iter()
+                .filter(|(_, created, done)| {
+                    *created < end_of_day && done.is_none_or(|d| d >= end_of_day)
+                })
+                .map(|(est, _, _)| est)
+                .sum();
+            BurndownPoint {
+                date,
+                remaining_minutes,
+            }
+        })
+        .collect()
+}
+
+/// Tasks completed in one ISO week, for `pm stats --velocity`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WeekVelocity {
+    /// The Monday the week starts on.
+    pub week_start: NaiveDate,
+    pub completed: usize,
+}
+
+/// Completions per ISO week for the `weeks` weeks ending with the one that
+/// holds `today`, oldest first, with days taken in `tz`. Weeks without a
+/// completion are kept with a count of zero, and weeks before the earliest
+/// representable date are dropped. Completion times come from
+/// [`completion_time`], so only Done tasks count.
+pub fn velocity<Tz: TimeZone>(
+    tasks: &[&Task],
+    weeks: usize,
+    today: NaiveDate,
+    tz: &Tz,
+) -> Vec<WeekVelocity> {
+    let monday = |d: NaiveDate| d - Duration::days(i64::from(d.weekday().num_days_from_monday()));
+    let this_week = monday(today);
+    let mut report: Vec<WeekVelocity> = (0..weeks)
+        .map_while(|back| {
+            let back = Duration::try_weeks(i64::try_from(back).ok()?)?;
+            Some(WeekVelocity {
+                week_start: this_week.checked_sub_signed(back)?,
+                completed: 0,
+            })
+        })
+        .collect();
+    report.reverse();
+    for ts in tasks.iter().filter_map(|t| completion_time(t)) {
+        let Some(done) = tz.timestamp_opt(ts, 0).single() else {
+            continue;
+        };
+        let start = monday(done.date_naive());
+        if let Some(week) = report.iter_mut().find(|w| w.week_start == start) {
+            week.completed += 1;
+        }
+    }
+    report
+}
+
+/// A milestone and the work due ahead of it, for `pm milestones`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MilestoneReadiness {
+    pub id: LeafId,
+    /// Non-milestone tasks due strictly before the milestone's date,
+    /// cancelled ones left out. Zero when the milestone has no date.
+    pub due_before: usize,
+    /// How many of those are Done.
+    pub done_before: usize,
+}
+
+/// Every milestone, soonest first (undated ones last, by id), with how much
+/// of the work due before it is done.
+pub fn milestone_timeline(db: &Database) -> Vec<MilestoneReadiness> {
+    let mut milestones: Vec<&Task> = db
+        .tasks
+        .iter()
+        .filter(|t| t.kind == Kind::Milestone)
+        .collect();
+    milestones.sort_by_key(|m| (m.due.is_none(), m.due, m.id));
+    milestones
+        .into_iter()
+        .map(|m| {
+            let ahead: Vec<&Task> = match m.due {
+                Some(date) => db
+                    .tasks
+                    .iter()
+                    .filter(|t| t.kind != Kind::Milestone && t.status != Status::Cancelled)
+                    .filter(|t| t.due.is_some_and(|d| d < date))
+                    .collect(),
+                None => Vec::new(),
+            };
+            MilestoneReadiness {
+                id: m.id,
+                due_before: ahead.len(),
+                done_before: ahead.iter().filter(|t| t.status == Status::Done).count(),
+            }
+        })
+        .collect()
+}
+
+/// How far a parent's direct children have got, for `pm complete`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParentProgress {
+    pub parent: LeafId,
+    /// Direct children, cancelled ones left out.
+    pub children: usize,
+    /// How many of those are Done.
+    pub done: usize,
+}
+
+/// Progress of every parent with a child in `completed`, by parent id.
+/// Parents that are in `completed` themselves are left out; their own
+/// parent reports for them.
+pub fn parent_progress(db: &Database, completed: &HashSet<LeafId>) -> Vec<ParentProgress> {
+    let parents: BTreeSet<LeafId> = completed
+        .iter()
+        .filter_map(|id| db.get(*id)?.parent)
+        .filter(|pid| !completed.contains(pid) && db.get(*pid).is_some())
+        .collect();
+    parents
+        .into_iter()
+        .map(|parent| {
+            let children: Vec<&Task> = db
+                .tasks
+                .iter()
+                .filter(|t| t.parent == Some(parent) && t.status != Status::Cancelled)
+                .collect();
+            ParentProgress {
+                parent,
+                children: children.len(),
+                done: children.iter().filter(|t| t.status == Status::Done).count(),
+            }
+        })
+        .collect()
+}
+
+/// One group in `pm count --by` output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CountRow {
+    pub value: String,
+    pub count: usize,
+}
+
+/// Group `tasks` on `by` and count each group, largest first with ties in
+/// name order. A task missing the dimension (no tags, no project, nobody
+/// holding it) counts under `-`. `assignees` maps a ticket to the agent
+/// holding its checkout lock.
+pub fn count_by(
+    db: &Database,
+    tasks: &[&Task],
+    by: CountBy,
+    assignees: &HashMap<LeafId, String>,
+) -> Vec<CountRow> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for t in tasks {
+        let values: Vec<String> = match by {
+            CountBy::Status => vec![format_status(t.status).to_string()],
+            CountBy::Kind => vec![format_kind(t.kind).to_string()],
+            CountBy::Project => vec![project_label(db, t)],
+            CountBy::ProcessStage => vec![format_process_stage(t.process_stage).to_string()],
+            CountBy::Tag if t.tags.is_empty() => vec!["-".to_string()],
+            CountBy::Tag => t.tags.clone(),
+            CountBy::Assignee => vec![assignees
+                .get(&t.id)
+                .cloned()
+                .unwrap_or_else(|| "-".to_string())],
+        };
+        for value in values {
+            *counts.entry(value).or_default() += 1;
+        }
+    }
+    let mut rows: Vec<CountRow> = counts
+        .into_iter()
+        .map(|(value, count)| CountRow { value, count })
+        .collect();
+    rows.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+    rows
+}
+
+/// Tickets related to an anchor, for `pm list --ancestors-of`,
+/// `--children-of` and `--descendants-of`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    /// The parent chain.
+    Ancestors,
+    /// Direct children only.
+    Children,
+    /// The whole subtree below the anchor.
+    Descendants,
+}
+
+/// Ids standing in `relation` to `anchor`. Ancestors come root first and
+/// leave out a dangling parent reference; children and descendants are in
+/// id order. The anchor itself is never included.
+pub fn related_ids(db: &Database, anchor: LeafId, relation: Relation) -> Vec<LeafId> {
+    match relation {
+        Relation::Ancestors => {
+            let mut chain = collect_ancestors(anchor, db);
+            chain.retain(|&id| db.get(id).is_some());
+            chain.reverse();
+            chain
+        }
+        Relation::Children => {
+            let mut ids: Vec<LeafId> = db
+                .tasks
+                .iter()
+                .filter(|t| t.parent == Some(anchor))
+                .map(|t| t.id)
+                .collect();
+            ids.sort();
+            ids
+        }
+        Relation::Descendants => {
+            let mut found = HashSet::new();
+            collect_descendants(anchor, &build_children_map(&db.tasks), &mut found);
+            found.remove(&anchor);
+            let mut ids: Vec<LeafId> = found.into_iter().collect();
+            ids.sort();
+            ids
+        }
+    }
+}
+
+/// Collect all ancestor task ids by following parent references.
+/// Stops at a parent cycle rather than looping; use
+/// [`Database::ancestor_ids`] to find out whether one was hit.
+pub fn collect_ancestors(id: LeafId, db: &Database) -> Vec<LeafId> {
+    db.walk_ancestors(id).0
 }
 
 /// Resolve a task identifier to a [`LeafId`].
@@ -658,11 +2703,44 @@ pub fn collect_ancestors(mut id: LeafId, db: &Database) -> Vec<LeafId> {
 /// Accepts:
 /// - Address-form ids (`TSK7`, `PRJ1-PRD1-EPC3-TSK7`, `TSK7-some-label`) -
 ///   parsed via [`IdInput`] and reduced to the terminal leaf.
+/// - The short display form (`T-a3`, see [`LeafId::short`]) when it names an
+///   existing task; otherwise the input is tried as a title, so a ticket
+///   called "T-shirt" still resolves.
 /// - Exact title match - case-insensitive comparison against `task.title`.
 ///
 /// Reports a clear error on no-match, an unknown-leaf match, or a multi-title
 /// collision (with the ambiguous ids listed so the caller can disambiguate).
+/// Under `--verbose` the task it settles on is noted on stderr.
 pub fn resolve_task_identifier(identifier: &str, db: &Database) -> Result<LeafId, String> {
+    let resolved = find_task_identifier(identifier, db);
+    if let Ok(id) = resolved {
+        note_resolved(identifier, db, id);
+    }
+    resolved
+}
+
+/// Under `--verbose`, print on stderr which task a command argument named.
+pub fn note_resolved(input: &str, db: &Database, id: LeafId) {
+    if !crate::output::verbose() {
+        return;
+    }
+    let Some(task) = db.get(id) else { return };
+    let path = db
+        .state
+        .items
+        .get(&id)
+        .map_or("-".to_string(), |e| e.path.display().to_string());
+    eprintln!(
+        "Resolved '{input}' to {id}: {} \"{}\" ({}, {}, {path})",
+        format_kind(task.kind),
+        task.title,
+        format_status(task.status),
+        project_label(db, task)
+    );
+}
+
+/// [`resolve_task_identifier`] without the verbose note.
+fn find_task_identifier(identifier: &str, db: &Database) -> Result<LeafId, String> {
     // Try parsing as a typed id first.
     if let Ok(input) = identifier.parse::<IdInput>() {
         let leaf = input.leaf();
@@ -671,6 +2749,11 @@ pub fn resolve_task_identifier(identifier: &str, db: &Database) -> Result<LeafId
         }
         return Err(format!("Task with id {} not found", leaf));
     }
+    if let Some(leaf) = LeafId::parse_short(identifier) {
+        if db.get(leaf).is_some() {
+            return Ok(leaf);
+        }
+    }
 
     // Search by title (case-insensitive).
     let matches: Vec<&Task> = db
@@ -703,26 +2786,45 @@ pub fn resolve_task_identifier(identifier: &str, db: &Database) -> Result<LeafId
     }
 }
 
+/// Normalise a CSV enum cell so both the kebab-case spelling and the
+/// display form written by `pm export` ("Must Have", "Ready to Implement")
+/// parse the same way.
+fn csv_token(s: &str) -> String {
+    s.trim().to_lowercase().replace([' ', '_'], "-")
+}
+
 /// Parse a kind string from CSV format.
 pub fn parse_kind(s: &str) -> Kind {
-    match s.to_lowercase().as_str() {
-        "project" => Kind::Project,
-        "product" => Kind::Product,
-        "epic" => Kind::Epic,
-        "task" => Kind::Task,
-        "subtask" => Kind::Subtask,
-        "milestone" => Kind::Milestone,
-        _ => Kind::Task, // Default fallback
+    parse_kind_strict(s).unwrap_or(Kind::Task) // Default fallback
+}
+
+/// Parse a kind string from CSV format, or `None` if it is not one.
+pub fn parse_kind_strict(s: &str) -> Option<Kind> {
+    match csv_token(s).as_str() {
+        "project" => Some(Kind::Project),
+        "product" => Some(Kind::Product),
+        "epic" => Some(Kind::Epic),
+        "task" => Some(Kind::Task),
+        "subtask" => Some(Kind::Subtask),
+        "milestone" => Some(Kind::Milestone),
+        _ => None,
     }
 }
 
 /// Parse a status string from CSV format.
 pub fn parse_status(s: &str) -> Status {
-    match s.to_lowercase().as_str() {
-        "open" => Status::Open,
-        "in-progress" => Status::InProgress,
-        "done" => Status::Done,
-        _ => Status::Open, // Default fallback
+    parse_status_strict(s).unwrap_or(Status::Open) // Default fallback
+}
+
+/// Parse a status string from CSV format, or `None` if it is not one.
+pub fn parse_status_strict(s: &str) -> Option<Status> {
+    match csv_token(s).as_str() {
+        "open" => Some(Status::Open),
+        "in-progress" | "inprogress" => Some(Status::InProgress),
+        "done" => Some(Status::Done),
+        "blocked" => Some(Status::Blocked),
+        "cancelled" | "canceled" => Some(Status::Cancelled),
+        _ => None,
     }
 }
 
@@ -731,7 +2833,7 @@ pub fn parse_priority(s: &str) -> Option<Priority> {
     if s == "-" {
         return None;
     }
-    match s.to_lowercase().as_str() {
+    match csv_token(s).as_str() {
         "must-have" => Some(Priority::MustHave),
         "nice-to-have" => Some(Priority::NiceToHave),
         "cut-first" => Some(Priority::CutFirst),
@@ -744,7 +2846,7 @@ pub fn parse_urgency(s: &str) -> Option<Urgency> {
     if s == "-" {
         return None;
     }
-    match s.to_lowercase().as_str() {
+    match csv_token(s).as_str() {
         "urgent-important" => Some(Urgency::UrgentImportant),
         "urgent-not-important" => Some(Urgency::UrgentNotImportant),
         "not-urgent-important" => Some(Urgency::NotUrgentImportant),
@@ -758,10 +2860,11 @@ pub fn parse_process_stage(s: &str) -> Option<ProcessStage> {
     if s == "-" {
         return None;
     }
-    match s.to_lowercase().as_str() {
+    match csv_token(s).as_str() {
         "ideation" => Some(ProcessStage::Ideation),
         "design" => Some(ProcessStage::Design),
         "prototyping" => Some(ProcessStage::Prototyping),
+        "ready-to-implement" => Some(ProcessStage::ReadyToImplement),
         "implementation" => Some(ProcessStage::Implementation),
         "testing" => Some(ProcessStage::Testing),
         "refinement" => Some(ProcessStage::Refinement),
@@ -7,7 +7,7 @@ use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 
 /// Hierarchical task types that define the organisational structure.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, ValueEnum, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ValueEnum, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "kebab-case")]
 pub enum Kind {
     #[serde(alias = "Project")]
@@ -58,7 +58,7 @@ pub enum ProcessStage {
 }
 
 /// Task completion status.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, ValueEnum, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ValueEnum, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "kebab-case")]
 pub enum Status {
     #[serde(alias = "Open")]
@@ -67,21 +67,209 @@ pub enum Status {
     InProgress,
     #[serde(alias = "Done")]
     Done,
+    /// Waiting on something outside the task; still open work.
+    #[serde(alias = "Blocked")]
+    Blocked,
+    /// Abandoned. Kept for history and hidden like `Done`.
+    #[serde(alias = "Cancelled")]
+    Cancelled,
 }
 
-/// Available sorting options for task lists.
-#[derive(Debug, Clone, Copy, ValueEnum)]
+impl Status {
+    /// True for statuses that end a task's life: `Done` and `Cancelled`.
+    /// Closed tasks are hidden from default listings and never block
+    /// completion of their parents.
+    pub fn is_closed(self) -> bool {
+        matches!(self, Status::Done | Status::Cancelled)
+    }
+
+    /// Whether `enforce_status_flow` lets a ticket move from `self` to `to`:
+    /// one step forward along Open -> InProgress -> Done, back to Open from
+    /// anywhere, between InProgress and Blocked, or into Cancelled. Staying
+    /// put is always allowed.
+    pub fn can_step_to(self, to: Status) -> bool {
+        self == to
+            || matches!(
+                (self, to),
+                (Status::Open, Status::InProgress)
+                    | (Status::InProgress, Status::Done)
+                    | (Status::InProgress, Status::Blocked)
+                    | (Status::Blocked, Status::InProgress)
+                    | (_, Status::Open)
+                    | (_, Status::Cancelled)
+            )
+    }
+}
+
+/// Available sorting options for task lists. Every key ends its tiebreaker
+/// chain on id, so equal rows keep a stable order.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
 pub enum SortKey {
+    /// Earliest due first, undated last; ties by priority, then most
+    /// recently updated, then id.
     Due,
+    /// Must-have first, unset last; ties by urgency, then id.
     Priority,
     Id,
+    /// Newest first; ties by id.
+    Created,
+    /// Most recently touched first; ties by id.
+    Updated,
+}
+
+/// One or more sort keys for `pm list --sort`, comma-separated and applied
+/// in order (`priority,-due,id`). A leading `-` reverses that key's usual
+/// direction; tasks with no value for it stay last either way. A
+/// `custom:` prefix is accepted and ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortSpec(pub Vec<(SortKey, bool)>);
+
+impl SortSpec {
+    /// The spec for a single key in its usual direction.
+    pub fn single(key: SortKey) -> Self {
+        SortSpec(vec![(key, false)])
+    }
+}
+
+impl std::str::FromStr for SortSpec {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let raw = raw.strip_prefix("custom:").unwrap_or(raw);
+        let valid = || {
+            SortKey::value_variants()
+                .iter()
+                .filter_map(|k| k.to_possible_value())
+                .map(|v| v.get_name().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut keys = Vec::new();
+        for part in raw.split(',').map(str::trim) {
+            let (descending, name) = match part.strip_prefix('-') {
+                Some(name) => (true, name.trim()),
+                None => (false, part),
+            };
+            if name.is_empty() {
+                return Err(format!(
+                    "empty sort key in '{raw}'; expected one of {}",
+                    valid()
+                ));
+            }
+            let key = SortKey::from_str(name, true)
+                .map_err(|_| format!("unknown sort key '{name}'; expected one of {}", valid()))?;
+            keys.push((key, descending));
+        }
+        Ok(SortSpec(keys))
+    }
+}
+
+/// Columns printed by `pm list`, selected and ordered with `--fields`.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum ListColumn {
+    Id,
+    Kind,
+    Status,
+    Priority,
+    Urgency,
+    Stage,
+    Due,
+    Project,
+    /// Ancestor titles, as added by `--breadcrumb`.
+    Parents,
+    /// Agent holding the ticket's checkout lock.
+    Assignee,
+    Created,
+    Updated,
+    /// Tags on their own. Without this column they trail the title.
+    Tags,
+    Title,
+}
+
+/// Output styles for `pm list`.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum ListFormat {
+    /// Aligned, truncated columns for reading in a terminal.
+    Table,
+    /// Tab-separated fields in full: ISO due dates, bare titles, no
+    /// indentation. Meant for `cut` and `awk`.
+    Plain,
+}
+
+/// Dimensions `pm count --by` groups tasks on.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum CountBy {
+    Status,
+    Kind,
+    Project,
+    ProcessStage,
+    /// One count per tag, so a task with two tags is counted twice.
+    Tag,
+    /// Agent holding the ticket's checkout lock.
+    Assignee,
+}
+
+/// File formats written by `pm export`.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Spreadsheet-friendly rows; re-importable with `pm import`.
+    Csv,
+    /// A self-contained task bundle that keeps parent links and every field.
+    Json,
+    /// The JSON array `task import` reads. pm fields without a Taskwarrior
+    /// counterpart travel as `pm_*` UDAs.
+    Taskwarrior,
+}
+
+/// File formats written by `pm burndown`.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum ChartFormat {
+    /// `date,remaining_minutes` rows.
+    Csv,
+    /// An array of `{date, remaining_minutes}` points.
+    Json,
+}
+
+/// Task fields `pm replace` rewrites.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReplaceField {
+    Title,
+    Description,
+    /// Each tag on its own; the results are normalised like `--tag` input.
+    Tags,
 }
 
 /// Filtering options for tasks based on due dates.
+/// Fields `pm list --missing` and `--has` check for a value.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum PresenceField {
+    Due,
+    Description,
+    Summary,
+    UserStory,
+    Requirements,
+    Tags,
+    Parent,
+    Priority,
+    Urgency,
+    Stage,
+    Estimate,
+    Iteration,
+    Milestone,
+    Deps,
+    IssueLink,
+    PrLink,
+    Artifacts,
+    /// Agent holding the ticket's checkout lock.
+    Assignee,
+}
+
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum DueFilter {
     Today,
     ThisWeek,
     Overdue,
     None,
+    /// Inside the reminder window set by `remind_days_before`, not yet due.
+    Upcoming,
 }
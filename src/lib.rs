@@ -11,7 +11,10 @@ pub mod db;
 pub mod fields;
 pub mod mcp;
 pub mod memory;
+pub mod output;
 pub mod project;
+#[cfg(feature = "server")]
+pub mod serve;
 pub mod store;
 pub mod task;
 pub mod views;
@@ -20,6 +23,8 @@ pub mod tui {
     pub mod colors;
     pub mod enums;
     pub mod input;
+    pub mod keyhelp;
+    pub mod keymap;
     pub mod menu;
     pub mod run;
     pub mod task_form;
@@ -65,35 +65,88 @@
 //! - `pm export` - Export to CSV for reporting/backup
 //! - `pm backup` - Create timestamped project backups
 //!
-//! Data is stored locally in `~/.pm/` with each project as a separate JSON file.
+//! Data is stored in the nearest `.pm/` directory at or above the current
+//! directory (created by `pm init`), falling back to `~/.pm/`.
 //! We recommend you source control this folder via `git init` and back it up periodically.
 
-use std::path::PathBuf;
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
 
 use clap::Parser;
 
 use project_management::cli::Cli;
 use project_management::cmd::*;
 use project_management::db::*;
+use project_management::fields::ExportFormat;
+use project_management::output::{set_date_style, set_verbosity, verbose, Verbosity};
+use project_management::project::find_project;
+use project_management::store::layout::{discover, PM_DIR_NAME};
+use project_management::store::write_lock::WriteLock;
+use project_management::store::{Config, DateStyle};
 
 fn main() {
     let cli = Cli::parse();
+    set_verbosity(if cli.quiet {
+        Verbosity::Quiet
+    } else if cli.verbose {
+        Verbosity::Verbose
+    } else {
+        Verbosity::Normal
+    });
 
-    // Resolve the .pm/ workspace. The --db flag now points at the workspace
+    // Resolve the .pm/ workspace. The --db flag points at the workspace
     // directory itself; in v2 the storage is the `.pm/` tree, not a single
-    // JSON file. With no flag, default to `~/.pm/` so existing global-scope
-    // installations keep working.
-    let pm_dir = if let Some(db_path) = cli.db.as_ref() {
-        db_path.clone()
+    // JSON file. Without the flag, use the nearest `.pm/` at or above the
+    // current directory, like git finds `.git/`, so a repo can keep its
+    // tasks next to its code. `pm init` sets one up in the current directory.
+    // Failing both, fall back to the global `~/.pm/`.
+    let cwd = std::env::current_dir().ok();
+    let (pm_dir, found_by) = if let Some(db_path) = cli.db.as_ref() {
+        (db_path.clone(), "--db")
+    } else if let (Commands::Init, Some(cwd)) = (&cli.command, cwd.as_ref()) {
+        (cwd.join(PM_DIR_NAME), "pm init in the current directory")
+    } else if let Some(found) = cwd.as_deref().and_then(discover) {
+        (found, "nearest .pm/ at or above the current directory")
     } else {
         let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
-        let pm_dir = PathBuf::from(home).join(".pm");
+        let pm_dir = PathBuf::from(home).join(PM_DIR_NAME);
         if let Err(e) = std::fs::create_dir_all(&pm_dir) {
             eprintln!("Failed to create pm directory {}: {}", pm_dir.display(), e);
             std::process::exit(1);
         }
-        pm_dir
+        (
+            pm_dir,
+            "no .pm/ found above the current directory; using ~/.pm/",
+        )
+    };
+    // `--project` picks one project file inside that workspace. An explicit
+    // `--db` already names the database, so it wins.
+    let (pm_dir, found_by) = match cli.project.as_deref() {
+        Some(name) if cli.db.is_some() => {
+            if verbose() {
+                eprintln!("Ignoring --project {name}: --db names the database");
+            }
+            (pm_dir, found_by.to_string())
+        }
+        Some(name) => match find_project(&pm_dir, name) {
+            Ok(project) => (project.file_path, format!("--project {name}")),
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        },
+        None => (pm_dir, found_by.to_string()),
     };
+    if verbose() {
+        eprintln!("Workspace: {} ({found_by})", pm_dir.display());
+    }
+    set_date_style(if cli.iso_dates {
+        DateStyle::Iso
+    } else if cli.relative_dates {
+        DateStyle::Relative
+    } else {
+        Config::load(&pm_dir).output.dates
+    });
 
     // Handle commands that don't need a loaded Database.
     match &cli.command {
@@ -101,17 +154,38 @@ fn main() {
             cmd_menu(&pm_dir);
             return;
         }
+        Commands::HelpDump => {
+            cmd_help_dump();
+            return;
+        }
         Commands::Backup { all: true } => {
             cmd_backup_all(&pm_dir);
             return;
         }
+        Commands::MigrateLegacy { name } => {
+            cmd_migrate_legacy(&pm_dir, name);
+            return;
+        }
+        // Doctor rebuilds state.json, so it must run even when loading it
+        // would fail.
+        Commands::Doctor { migrate } => {
+            let _write_lock = lock_workspace(&pm_dir);
+            cmd_doctor(&pm_dir, *migrate);
+            return;
+        }
         Commands::Export {
             output,
             all_projects: true,
             all,
             project,
             tag,
+            format,
+            ..
         } => {
+            if *format != ExportFormat::Csv {
+                eprintln!("--all-projects exports CSV only; drop --format.");
+                std::process::exit(1);
+            }
             cmd_export_all(&pm_dir, output.clone(), *all, project.clone(), tag.clone());
             return;
         }
@@ -122,93 +196,74 @@ fn main() {
     // pick-a-project-file flow collapses into "open the workspace"; project
     // selection happens inside the TUI now via PRJ tickets.
     match &cli.command {
-        Commands::Ui => {
-            cmd_ui(&pm_dir);
+        Commands::Ui { compact } => {
+            cmd_ui(&pm_dir, *compact);
             return;
         }
-        Commands::Wf => {
-            cmd_wf(&pm_dir);
+        Commands::Wf {
+            compact,
+            stage_columns,
+        } => {
+            cmd_wf(&pm_dir, *compact, stage_columns.as_deref());
             return;
         }
         _ => {}
     }
 
-    let mut db = Database::load(&pm_dir);
+    // Hold the write lock from load until exit, so another pm can't save
+    // between this one's load and save. Read-only commands and the
+    // long-running tail, MCP and HTTP servers don't need it (the servers
+    // take it per save), and a workspace that doesn't exist yet has nothing
+    // to guard. Commands that may wait on stdin hold it only while loading,
+    // and their saves check nothing else saved during the wait.
+    let (_write_lock, mut db) = match cli.command {
+        Commands::Tv { .. } | Commands::Mcp | Commands::Serve { .. } => {
+            (None, Database::load(&pm_dir))
+        }
+        _ if !pm_dir.is_dir() || is_read_only(&cli.command) => (None, load_workspace(&pm_dir)),
+        _ if reads_stdin(&cli.command) => {
+            let _write_lock = lock_workspace(&pm_dir);
+            guard_saves(&pm_dir);
+            (None, load_workspace(&pm_dir))
+        }
+        _ => {
+            let write_lock = lock_workspace(&pm_dir);
+            (Some(write_lock), load_workspace(&pm_dir))
+        }
+    };
 
     match cli.command {
-        Commands::Ui => unreachable!("UI command handled above"),
-        Commands::Wf => unreachable!("Workflow command handled above"),
-        Commands::Add {
-            title,
-            template,
-            desc,
-            tags,
-            due,
-            parent,
-            kind,
-            priority_level,
-            urgency,
-            process_stage,
-            issue_link,
-            pr_link,
-            summary,
-            user_story,
-            requirements,
-            artifacts,
-            status,
-        } => cmd_add(
-            &mut db,
-            &pm_dir,
-            title,
-            template,
-            desc,
-            tags,
-            due,
-            parent,
-            kind,
-            priority_level,
-            urgency,
-            process_stage,
-            issue_link,
-            pr_link,
-            summary,
-            user_story,
-            requirements,
-            artifacts,
-            status,
-        ),
+        Commands::Ui { .. } => unreachable!("UI command handled above"),
+        Commands::Wf { .. } => unreachable!("Workflow command handled above"),
+        Commands::Add(opts) => cmd_add(&mut db, &pm_dir, opts),
 
-        Commands::List {
-            all,
-            status,
-            kind,
-            project,
-            tags,
-            due,
-            tree,
-            sort,
-            limit,
-        } => cmd_list(
-            &db, all, status, kind, project, tags, due, tree, sort, limit,
-        ),
+        Commands::List(opts) => cmd_list(&db, &pm_dir, opts),
 
         Commands::View {
             id,
             children,
             parents,
-        } => cmd_view(&db, id, children, parents),
+            artifacts,
+        } => cmd_view(&db, &pm_dir, id, children, parents, artifacts),
 
         Commands::Update {
             id,
             title,
             desc,
             due,
+            remind,
+            iteration,
+            estimate,
             parent,
             kind,
             status,
+            process_stage,
             add_tags,
             rm_tags,
             clear_due,
+            clear_remind,
+            clear_iteration,
+            clear_estimate,
             clear_parent,
         } => cmd_update(
             &mut db,
@@ -217,22 +272,52 @@ fn main() {
             title,
             desc,
             due,
+            remind,
+            iteration,
+            estimate,
             parent,
             kind,
             status,
+            process_stage,
             add_tags,
             rm_tags,
             clear_due,
+            clear_remind,
+            clear_iteration,
+            clear_estimate,
             clear_parent,
         ),
 
+        Commands::Replace {
+            from,
+            to,
+            fields,
+            regex,
+            dry_run,
+        } => cmd_replace(
+            &mut db,
+            &pm_dir,
+            &from,
+            &to,
+            fields,
+            regex,
+            dry_run,
+            cli.no_backup,
+        ),
+
+        Commands::Dedupe { fuzzy } => cmd_dedupe(&mut db, &pm_dir, fuzzy, cli.no_backup),
+
         Commands::Complete {
             id,
             recurse,
             tag,
             project,
             status,
-        } => cmd_complete(&mut db, &pm_dir, id, recurse, tag, project, status),
+            at,
+            force,
+        } => cmd_complete(
+            &mut db, &pm_dir, id, recurse, tag, project, status, at, force,
+        ),
 
         Commands::Reopen { id } => cmd_reopen(&mut db, &pm_dir, id),
 
@@ -242,11 +327,69 @@ fn main() {
             tag,
             project,
             status,
-        } => cmd_delete(&mut db, &pm_dir, id, cascade, tag, project, status),
+            yes,
+        } => cmd_delete(
+            &mut db,
+            &pm_dir,
+            id,
+            cascade,
+            tag,
+            project,
+            status,
+            yes,
+            cli.no_backup,
+        ),
+
+        Commands::Inbox {
+            triage,
+            tags,
+            sort,
+            limit,
+        } => cmd_inbox(&mut db, &pm_dir, triage, tags, sort, limit),
+
+        Commands::Projects => cmd_projects(&db, &pm_dir, cli.verbose),
+
+        Commands::Tags { tree } => cmd_tags(&db, tree),
+        Commands::Count {
+            by,
+            all,
+            status,
+            kind,
+            project,
+            tags,
+            expand_tags,
+            due,
+            iteration,
+            json,
+        } => cmd_count(
+            &db,
+            &pm_dir,
+            by,
+            all,
+            status,
+            kind,
+            project,
+            tags,
+            expand_tags,
+            due,
+            iteration,
+            json,
+        ),
 
-        Commands::Projects => cmd_projects(&db),
+        Commands::Iteration { name } => cmd_iteration(&db, name),
+        Commands::Milestones => cmd_milestones(&db),
+        Commands::Burndown {
+            iteration,
+            format,
+            output,
+        } => cmd_burndown(&db, iteration, format, output),
 
-        Commands::Tags => cmd_tags(&db),
+        Commands::Stats {
+            velocity,
+            weeks,
+            project,
+            iteration,
+        } => cmd_stats(&db, velocity, weeks, project, iteration),
 
         Commands::Completions { shell } => cmd_completions(shell),
 
@@ -254,20 +397,50 @@ fn main() {
 
         Commands::Export {
             output,
+            format,
+            templates_only: true,
+            ..
+        } => cmd_export_templates(&db, output, format),
+        Commands::Export {
+            output,
+            id,
+            recurse,
+            format,
             all,
             all_projects,
             project,
             tag,
+            templates_only: false,
         } => {
             // all_projects: true case is handled earlier, this handles all_projects: false
             assert!(!all_projects, "all_projects case should be handled earlier");
-            cmd_export(&db, output, all, project, tag);
+            cmd_export(&db, output, id, recurse, format, all, project, tag);
         }
 
-        Commands::Import { input, no_backup } => cmd_import(&mut db, &pm_dir, input, no_backup),
+        Commands::Import {
+            input,
+            update,
+            templates,
+            overwrite,
+        } => cmd_import(
+            &mut db,
+            &pm_dir,
+            input,
+            cli.no_backup,
+            update,
+            templates,
+            overwrite,
+        ),
+
+        Commands::ValidateImport { input } => cmd_validate_import(&db, &input),
 
         Commands::Backup { all } => cmd_backup(&pm_dir, all),
 
+        Commands::Snapshot { name, list, force } => cmd_snapshot(&db, &pm_dir, name, list, force),
+        Commands::Restore { name } => cmd_restore(&mut db, &pm_dir, &name, cli.no_backup),
+        Commands::History { id } => cmd_history(&db, &pm_dir, &id),
+        Commands::Diff { reference } => cmd_diff(&db, &pm_dir, &reference),
+
         Commands::Menu => cmd_menu(&pm_dir),
 
         // v2 lifecycle
@@ -299,9 +472,14 @@ fn main() {
         Commands::Milestone { id, milestone_id } => {
             cmd_milestone(&mut db, &pm_dir, &id, &milestone_id);
         }
+        Commands::Pin { id } => cmd_pin(&mut db, &pm_dir, &id, true),
+        Commands::Unpin { id } => cmd_pin(&mut db, &pm_dir, &id, false),
+        Commands::Focus { id, clear } => cmd_focus(&db, &pm_dir, id, clear),
 
         // v2 views / maintenance
-        Commands::Doctor { migrate } => cmd_doctor(&pm_dir, migrate),
+        Commands::Doctor { .. } => unreachable!("Doctor command handled above"),
+        Commands::HelpDump => unreachable!("Help dump handled above"),
+        Commands::MigrateLegacy { .. } => unreachable!("migrate-legacy handled above"),
         Commands::Search { query } => cmd_search(&pm_dir, &query),
 
         // Phase 6: lock protocol + activity feed
@@ -320,5 +498,85 @@ fn main() {
         Commands::Log { id } => cmd_log(&pm_dir, &id),
         Commands::Memory { action } => cmd_memory(&mut db, &pm_dir, action),
         Commands::Mcp => cmd_mcp(&pm_dir),
+        Commands::Serve { port, host } => cmd_serve(&pm_dir, &host, port),
+    }
+}
+
+/// Commands that never save the workspace, so they need no write lock.
+fn is_read_only(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::List(_)
+            | Commands::View { .. }
+            | Commands::Projects
+            | Commands::Tags { .. }
+            | Commands::Count { .. }
+            | Commands::Iteration { .. }
+            | Commands::Milestones
+            | Commands::Burndown { .. }
+            | Commands::Stats { .. }
+            | Commands::Completions { .. }
+            | Commands::Export { .. }
+            | Commands::ValidateImport { .. }
+            | Commands::History { .. }
+            | Commands::Diff { .. }
+            | Commands::Show { .. }
+            | Commands::Context { .. }
+            | Commands::Search { .. }
+            | Commands::Locks
+            | Commands::Log { .. }
+    )
+}
+
+/// Commands that can wait on stdin (a prompt, or `--stdin` input) before
+/// they save.
+fn reads_stdin(command: &Commands) -> bool {
+    match command {
+        Commands::Dedupe { .. }
+        | Commands::Delete { .. }
+        | Commands::Import { .. }
+        | Commands::Replace { .. }
+        | Commands::Restore { .. } => true,
+        Commands::Inbox { triage, .. } => *triage,
+        Commands::Add(opts) => opts.stdin,
+        _ => false,
+    }
+}
+
+/// Load the workspace for a CLI command. An unreadable `state.json` is
+/// restored from the newest backup, after asking when there is a terminal
+/// to ask on. The servers skip this: their stdin isn't the user's.
+fn load_workspace(pm_dir: &Path) -> Database {
+    Database::try_load(pm_dir).unwrap_or_else(|bad| {
+        Database::recover(pm_dir, &bad, |dump| {
+            if !(std::io::stdin().is_terminal() && std::io::stderr().is_terminal()) {
+                eprintln!(
+                    "Warning: restoring the index from {} ({}).",
+                    dump.source,
+                    dump.path.display()
+                );
+                return true;
+            }
+            eprint!(
+                "Restore the index from {} ({})? [Y/n] ",
+                dump.source,
+                dump.path.display()
+            );
+            std::io::stderr().flush().ok();
+            let mut answer = String::new();
+            std::io::stdin().read_line(&mut answer).is_ok()
+                && !answer.trim().to_lowercase().starts_with('n')
+        })
+    })
+}
+
+/// Take the workspace write lock, or exit when another process keeps it.
+fn lock_workspace(pm_dir: &Path) -> WriteLock {
+    match WriteLock::acquire(pm_dir) {
+        Ok(lock) => lock,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
     }
 }
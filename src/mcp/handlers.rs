@@ -14,11 +14,14 @@ use std::path::PathBuf;
 use chrono::{DateTime, Utc};
 use serde_json::{json, Value};
 
-use crate::db::Database;
+use crate::db::{
+    advance_stage, completion_blocker, format_completion_blocker, status_flow_error, Database,
+};
 use crate::fields::{Kind, Status};
 use crate::memory::store::MemoryContext;
 use crate::memory::{lookup_by_name, write_memory, MemoryHit, MemoryType, Scope};
 use crate::store::claude_md::Ticket;
+use crate::store::config::Config;
 use crate::store::events::{actor as default_actor, append_event, read_events, Event};
 use crate::store::front_matter::MemoryRef;
 use crate::store::id::{IdInput, LeafId};
@@ -436,12 +439,29 @@ fn handle_complete(ctx: &mut Context, args: &Value) -> Result<Value, String> {
     }
     let id = require_str(args, "id")?;
     let leaf = resolve_leaf(&ctx.db, id).ok_or_else(|| format!("not found: {id}"))?;
+    let config = Config::load(&ctx.pm_dir);
+    if config.complete.block_parent_completion_with_open_children {
+        if let Some((blocked, open)) = completion_blocker(&ctx.db, &[leaf]) {
+            return Err(format!(
+                "complete: {}; complete them first",
+                format_completion_blocker(blocked, &open)
+            ));
+        }
+    }
+    if config.workflow.enforce_status_flow {
+        let from = ctx.db.get(leaf).map(|t| t.status);
+        if let Some(e) = from.and_then(|from| status_flow_error(leaf, from, Status::Done)) {
+            return Err(format!("complete: {e}"));
+        }
+    }
     {
         let task = ctx
             .db
             .get_mut(leaf)
             .ok_or_else(|| "missing task".to_string())?;
+        let from = task.status;
         task.status = Status::Done;
+        advance_stage(task, from, &config.workflow);
         task.updated_at_utc = Utc::now().timestamp();
     }
     ctx.db.save(&ctx.pm_dir).map_err(|e| format!("save: {e}"))?;
@@ -547,7 +567,13 @@ fn handle_add(ctx: &mut Context, args: &Value) -> Result<Value, String> {
         milestone: None,
         memories: Vec::new(),
         due: None,
+        remind_days_before: None,
         parent,
+        order: None,
+        iteration: None,
+        estimate_minutes: None,
+        completed_at_utc: None,
+        pinned: false,
         kind,
         status: Status::Open,
         priority_level: None,
@@ -659,6 +685,8 @@ fn parse_status(s: &str) -> Result<Status, String> {
         "open" => Ok(Status::Open),
         "in-progress" | "in_progress" => Ok(Status::InProgress),
         "done" => Ok(Status::Done),
+        "blocked" => Ok(Status::Blocked),
+        "cancelled" | "canceled" => Ok(Status::Cancelled),
         _ => Err(format!("unknown status: {s}")),
     }
 }
@@ -680,6 +708,8 @@ fn status_label(s: Status) -> &'static str {
         Status::Open => "open",
         Status::InProgress => "in-progress",
         Status::Done => "done",
+        Status::Blocked => "blocked",
+        Status::Cancelled => "cancelled",
     }
 }
 
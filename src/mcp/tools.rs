@@ -36,7 +36,7 @@ pub fn tool_catalog() -> Vec<ToolDef> {
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "status": {"type": "string", "description": "Filter by status: open, in-progress, done"},
+                    "status": {"type": "string", "description": "Filter by status: open, in-progress, done, blocked, cancelled"},
                     "kind": {"type": "string", "description": "Filter by kind: project, product, epic, task, subtask, milestone"},
                     "parent": {"type": "string", "description": "Filter to children of this ticket id"},
                     "tag": {"type": "string", "description": "Filter to tickets carrying this tag"},
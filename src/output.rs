@@ -0,0 +1,128 @@
+//! How chatty commands are, set from the global `-q` / `-v` flags.
+//!
+//! `main` sets the level once before dispatching. Confirmations such as
+//! "Added task TSK7" go through [`say!`](crate::say), which `--quiet` drops;
+//! errors and warnings are `eprintln!`ed as usual and always shown, and a
+//! command's actual output (tables, `pm view`, an export to stdout) is never
+//! suppressed. `--verbose` adds notes on stderr, such as the workspace in
+//! use and what a task argument resolved to, leaving stdout unchanged.
+//!
+//! Due dates in tables and `pm view` follow [`date_style`]: relative by
+//! default, ISO with `--iso-dates` or `output.dates` in `config.json`.
+//!
+//! Commands that walk every project show a [`Progress`] counter on stderr
+//! while they work. It only draws on a terminal and never under `--quiet`.
+
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+use crate::store::DateStyle;
+
+/// Output level for the current process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+/// Set the output level; called once from `main`.
+pub fn set_verbosity(level: Verbosity) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// The current output level.
+pub fn verbosity() -> Verbosity {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Verbosity::Quiet,
+        2 => Verbosity::Verbose,
+        _ => Verbosity::Normal,
+    }
+}
+
+/// `--quiet` is in effect.
+pub fn quiet() -> bool {
+    verbosity() == Verbosity::Quiet
+}
+
+/// `--verbose` is in effect.
+pub fn verbose() -> bool {
+    verbosity() == Verbosity::Verbose
+}
+
+static ISO_DATES: AtomicBool = AtomicBool::new(false);
+
+/// Set how due dates print; called once from `main`.
+pub fn set_date_style(style: DateStyle) {
+    ISO_DATES.store(style == DateStyle::Iso, Ordering::Relaxed);
+}
+
+/// How due dates print in human-readable output.
+pub fn date_style() -> DateStyle {
+    if ISO_DATES.load(Ordering::Relaxed) {
+        DateStyle::Iso
+    } else {
+        DateStyle::Relative
+    }
+}
+
+/// `println!` a confirmation, unless `--quiet`.
+#[macro_export]
+macro_rules! say {
+    ($($arg:tt)*) => {
+        if !$crate::output::quiet() {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// "Processing project 3/12…": the progress text shared by the CLI counter
+/// and the `pm menu` status line. `done` counts from 1.
+pub fn progress_line(done: usize, total: usize) -> String {
+    format!("Processing project {done}/{total}…")
+}
+
+/// A one-line progress counter on stderr, redrawn in place and erased when
+/// dropped. Drawing is off under `--quiet` or when stdout or stderr is not
+/// a terminal, so scripts and logs never see it.
+pub struct Progress {
+    total: usize,
+    enabled: bool,
+}
+
+impl Progress {
+    /// A counter for `total` steps.
+    pub fn new(total: usize) -> Self {
+        Progress {
+            total,
+            enabled: !quiet() && std::io::stdout().is_terminal() && std::io::stderr().is_terminal(),
+        }
+    }
+
+    /// Show that step `done` (from 1) is under way.
+    pub fn step(&self, done: usize) {
+        if self.enabled {
+            let mut err = std::io::stderr();
+            let _ = write!(err, "\r\x1b[2K{}", progress_line(done, self.total));
+            let _ = err.flush();
+        }
+    }
+
+    /// Erase the counter so the next line starts clean, e.g. before a
+    /// message printed part-way through.
+    pub fn clear(&self) {
+        if self.enabled {
+            let mut err = std::io::stderr();
+            let _ = write!(err, "\r\x1b[2K");
+            let _ = err.flush();
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
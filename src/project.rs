@@ -5,6 +5,12 @@
 //! the naming convention: `<project_name>_tasks.json`.
 
 use crate::db::Database;
+use crate::store::artifacts::{sweep_dir, ARTIFACTS_MD};
+use crate::store::state::atomic_write;
+use crate::store::write_lock::WriteLock;
+use crate::store::{BundleImport, Layout, LeafId, MenuSort, TaskBundle};
+use chrono::{Local, NaiveDate, Utc};
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -60,6 +66,39 @@ impl Project {
     pub fn load_database(&self) -> Database {
         Database::load(&self.file_path)
     }
+
+    /// Load the database and count its tasks as of today.
+    pub fn summary(&self) -> ProjectSummary {
+        ProjectSummary::of(&self.load_database(), Local::now().date_naive())
+    }
+}
+
+/// Task counts shown next to a project in the menu.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProjectSummary {
+    pub total: usize,
+    /// Tasks that are neither done nor cancelled.
+    pub open: usize,
+    /// Open tasks whose due date is before `today`.
+    pub overdue: usize,
+}
+
+impl ProjectSummary {
+    /// Count the tasks in `db`.
+    pub fn of(db: &Database, today: NaiveDate) -> Self {
+        let mut summary = ProjectSummary::default();
+        for task in &db.tasks {
+            summary.total += 1;
+            if task.status.is_closed() {
+                continue;
+            }
+            summary.open += 1;
+            if task.due.is_some_and(|d| d < today) {
+                summary.overdue += 1;
+            }
+        }
+        summary
+    }
 }
 
 /// Convert a display name to a safe project name for file naming.
@@ -87,7 +126,9 @@ pub fn sanitize_project_name(display_name: &str) -> String {
         .join("_")
 }
 
-/// Discover all existing projects in the PM directory.
+/// Discover all existing projects in the PM directory: legacy
+/// `<name>_tasks.json` files, and the workspace directories the v2 store
+/// saves under that name (see [`create_project`]).
 pub fn discover_projects(pm_dir: &Path) -> Result<Vec<Project>, std::io::Error> {
     let mut projects = Vec::new();
 
@@ -97,12 +138,8 @@ pub fn discover_projects(pm_dir: &Path) -> Result<Vec<Project>, std::io::Error>
 
     for entry in fs::read_dir(pm_dir)? {
         let entry = entry?;
-        let path = entry.path();
-
-        if path.is_file() {
-            if let Some(project) = Project::from_file(path) {
-                projects.push(project);
-            }
+        if let Some(project) = Project::from_file(entry.path()) {
+            projects.push(project);
         }
     }
 
@@ -126,6 +163,243 @@ pub fn get_legacy_project(pm_dir: &Path) -> Option<Project> {
     }
 }
 
+/// Find the project called `name` among those in `pm_dir`, for the global
+/// `pm --project` flag. The name is compared in its sanitised file form, so
+/// `"My App"`, `my-app` and `my_app` are the same project. An exact match
+/// wins; otherwise `name` may be the start of exactly one project's name.
+/// The legacy `tasks.json` answers to `default`.
+pub fn find_project(pm_dir: &Path, name: &str) -> Result<Project, String> {
+    let wanted = sanitize_project_name(name);
+    if wanted.is_empty() {
+        return Err(format!("'{name}' is not a project name"));
+    }
+    let mut projects = discover_projects(pm_dir)
+        .map_err(|e| format!("Cannot read projects in {}: {e}", pm_dir.display()))?;
+    projects.extend(get_legacy_project(pm_dir));
+
+    if let Some(exact) = projects.iter().find(|p| p.name == wanted) {
+        return Ok(exact.clone());
+    }
+    let mut matches: Vec<&Project> = projects
+        .iter()
+        .filter(|p| p.name.starts_with(&wanted))
+        .collect();
+    match matches.len() {
+        1 => Ok(matches.remove(0).clone()),
+        0 if projects.is_empty() => Err(format!(
+            "No project named '{name}': {} has no projects",
+            pm_dir.display()
+        )),
+        0 => Err(format!(
+            "No project named '{name}'. Projects: {}",
+            project_names(&projects)
+        )),
+        _ => Err(format!(
+            "Project name '{name}' is ambiguous; it could be {}",
+            project_names(&matches.into_iter().cloned().collect::<Vec<_>>())
+        )),
+    }
+}
+
+fn project_names(projects: &[Project]) -> String {
+    projects
+        .iter()
+        .map(|p| p.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The directory holding the projects that `db_path` sits among: the parent
+/// of a project or the legacy `tasks.json`, else `db_path` itself.
+pub fn workspace_dir(db_path: &Path) -> PathBuf {
+    let is_project = Project::from_file(db_path.to_path_buf()).is_some()
+        || db_path.file_name().is_some_and(|n| n == "tasks.json");
+    match db_path.parent() {
+        Some(parent) if is_project => parent.to_path_buf(),
+        _ => db_path.to_path_buf(),
+    }
+}
+
+/// Move `root` and its descendants out of the database at `db_path` into
+/// `target`, where they get fresh ids as `pm import` gives a bundle (see
+/// [`TaskBundle::import_into`]). The moved root loses its parent, artifact
+/// files follow their tickets, and links from tickets left behind to moved
+/// ones are dropped. `target` is saved first, so a failure part-way leaves
+/// the tickets in both projects rather than in neither.
+pub fn move_to_project(
+    db: &mut Database,
+    db_path: &Path,
+    root: LeafId,
+    target: &Project,
+) -> Result<BundleImport, std::io::Error> {
+    let bundle = TaskBundle::from_subtree(db, root, true).map_err(std::io::Error::other)?;
+    let moved: HashSet<LeafId> = bundle.tasks.iter().map(|t| t.id).collect();
+    let mut target_db = target.load_database();
+    let report = bundle
+        .import_into(&mut target_db)
+        .map_err(std::io::Error::other)?;
+    target_db.save(&target.file_path)?;
+
+    for &(old, new) in &report.remapped {
+        let (Some(from), Some(to)) = (
+            db.artifacts_dir(db_path, old),
+            target_db.artifacts_dir(&target.file_path, new),
+        ) else {
+            continue;
+        };
+        let Ok(entries) = fs::read_dir(&from) else {
+            continue;
+        };
+        let mut copied = false;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_file() && entry.file_name() != ARTIFACTS_MD {
+                fs::copy(&path, to.join(entry.file_name()))?;
+                copied = true;
+            }
+        }
+        if copied {
+            sweep_dir(&to, new).map_err(std::io::Error::other)?;
+        }
+    }
+
+    db.remove_ids(&moved);
+    for task in &mut db.tasks {
+        task.deps.retain(|d| !moved.contains(d));
+        if task.milestone.is_some_and(|m| moved.contains(&m)) {
+            task.milestone = None;
+        }
+    }
+    db.save(db_path)?;
+    Ok(report)
+}
+
+/// What [`migrate_legacy`] did.
+#[derive(Debug, Clone)]
+pub struct LegacyMigration {
+    /// The named project the legacy workspace became.
+    pub project: Project,
+    pub tasks: usize,
+    pub templates: usize,
+    /// Where the original `tasks.json` was moved to.
+    pub backup: PathBuf,
+}
+
+/// Turn the legacy `tasks.json` workspace in `pm_dir` into the project
+/// `display_name`, as `pm migrate-legacy` does. The workspace is copied
+/// whole, so ids, templates, artifacts and history come along unchanged;
+/// the copy goes to a staging directory first and takes the project's name
+/// only once complete and checked. The original then moves under
+/// `.legacy-backup/`.
+///
+/// Returns `Ok(None)` when there is no legacy workspace, so running it
+/// again after a migration does nothing. An existing project of that name
+/// is never overwritten; if it holds exactly the legacy workspace's tasks,
+/// it is taken to be the copy from a run that stopped before moving the
+/// original aside, and the migration finishes from there. A v0.9
+/// `tasks.json` file is left to `pm doctor --migrate`.
+pub fn migrate_legacy(
+    pm_dir: &Path,
+    display_name: &str,
+) -> Result<Option<LegacyMigration>, std::io::Error> {
+    let Some(legacy) = get_legacy_project(pm_dir) else {
+        return Ok(None);
+    };
+    if legacy.file_path.is_file() {
+        return Err(std::io::Error::other(format!(
+            "{} is a v0.9 task file; run `pm doctor --migrate` to import it",
+            legacy.file_path.display()
+        )));
+    }
+    let project = Project::new(display_name, pm_dir);
+    if project.name.is_empty() {
+        return Err(std::io::Error::other(format!(
+            "'{display_name}' is not a project name"
+        )));
+    }
+
+    let lock = WriteLock::acquire(&legacy.file_path).map_err(std::io::Error::other)?;
+    let source = legacy.load_database();
+    let task_ids = |db: &Database| {
+        let mut ids: Vec<LeafId> = db.tasks.iter().map(|t| t.id).collect();
+        ids.sort();
+        ids
+    };
+    let copied = if project.file_path.exists() {
+        let finished = Database::try_load(&project.file_path)
+            .ok()
+            .filter(|existing| task_ids(existing) == task_ids(&source));
+        let Some(existing) = finished else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!(
+                    "project '{}' already exists; pick another name with --name",
+                    project.name
+                ),
+            ));
+        };
+        existing
+    } else {
+        // A staging directory left by an interrupted run is incomplete;
+        // start it over.
+        let staging = pm_dir.join(format!(".{}_tasks.json.partial", project.name));
+        if staging.exists() {
+            fs::remove_dir_all(&staging)?;
+        }
+        copy_tree(&legacy.file_path, &staging)?;
+
+        // Check the copy before it takes the project's name, so a bad one
+        // never shows up as a project.
+        let copied = Database::load(&staging);
+        if copied.tasks.len() != source.tasks.len() {
+            fs::remove_dir_all(&staging)?;
+            return Err(std::io::Error::other(format!(
+                "{} has {} tasks but the copy had {}; left it in place",
+                legacy.file_path.display(),
+                source.tasks.len(),
+                copied.tasks.len()
+            )));
+        }
+        fs::rename(&staging, &project.file_path)?;
+        copied
+    };
+    drop(lock);
+
+    let backup_dir = pm_dir.join(".legacy-backup");
+    fs::create_dir_all(&backup_dir)?;
+    let backup = backup_dir.join(format!(
+        "tasks.json-{}",
+        Local::now().format("%Y-%m-%d_%H-%M-%S")
+    ));
+    fs::rename(&legacy.file_path, &backup)?;
+    Ok(Some(LegacyMigration {
+        project,
+        tasks: copied.tasks.len(),
+        templates: copied.state.templates.len(),
+        backup,
+    }))
+}
+
+/// Copy the directory `from` to `to`, recursively, leaving out the write
+/// lock file, which belongs to the process holding it.
+fn copy_tree(from: &Path, to: &Path) -> Result<(), std::io::Error> {
+    fs::create_dir_all(to)?;
+    let lock = Layout::at(from).write_lock_path();
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path == lock {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            copy_tree(&path, &to.join(entry.file_name()))?;
+        } else {
+            fs::copy(&path, to.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
 /// Create a new project with the given name.
 pub fn create_project(
     display_name: &str,
@@ -183,6 +457,42 @@ pub fn get_most_recent_project(pm_dir: &Path) -> Result<Option<Project>, std::io
     Ok(most_recent.map(|(project, _)| project))
 }
 
+/// File under the PM directory recording when each project was last opened
+/// from the menu, as `{ "<project name>": <unix seconds> }`.
+pub const RECENT_PROJECTS_FILE: &str = "recent_projects.json";
+
+/// When each project was last opened from the menu, by project name. Empty
+/// when nothing has been recorded or the file can't be read.
+pub fn last_opened(pm_dir: &Path) -> HashMap<String, i64> {
+    fs::read_to_string(pm_dir.join(RECENT_PROJECTS_FILE))
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+/// Note that `project` was opened just now, for [`MenuSort::Opened`].
+pub fn record_project_opened(pm_dir: &Path, project: &Project) -> Result<(), std::io::Error> {
+    let mut opened = last_opened(pm_dir);
+    opened.insert(project.name.clone(), Utc::now().timestamp());
+    let json = serde_json::to_string_pretty(&opened).map_err(std::io::Error::other)?;
+    atomic_write(&pm_dir.join(RECENT_PROJECTS_FILE), json.as_bytes())
+}
+
+/// Order `projects` for the menu. Recency orders put the newest first and
+/// fall back to the name for ties and for projects with no time on record.
+pub fn sort_projects(projects: &mut [Project], sort: MenuSort, opened: &HashMap<String, i64>) {
+    projects.sort_by(|a, b| a.display_name.cmp(&b.display_name));
+    match sort {
+        MenuSort::Name => {}
+        MenuSort::Modified => projects.sort_by_cached_key(|p| {
+            std::cmp::Reverse(fs::metadata(&p.file_path).and_then(|m| m.modified()).ok())
+        }),
+        MenuSort::Opened => {
+            projects.sort_by_key(|p| std::cmp::Reverse(opened.get(&p.name).copied()))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -204,4 +514,126 @@ mod tests {
         );
         assert_eq!(sanitize_project_name(""), "");
     }
+
+    #[test]
+    fn test_find_project_by_name_or_prefix() {
+        let dir = std::env::temp_dir().join(format!(
+            "pm-find-project-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["web_app", "web_api", "mobile"] {
+            fs::write(dir.join(format!("{name}_tasks.json")), "[]").unwrap();
+        }
+
+        assert_eq!(find_project(&dir, "Web App").unwrap().name, "web_app");
+        assert_eq!(find_project(&dir, "mob").unwrap().name, "mobile");
+        let ambiguous = find_project(&dir, "web").unwrap_err();
+        assert!(ambiguous.contains("web_api, web_app"), "{ambiguous}");
+        let missing = find_project(&dir, "desktop").unwrap_err();
+        assert!(missing.contains("mobile, web_api, web_app"), "{missing}");
+        assert!(find_project(&dir, "default").is_err());
+
+        fs::write(dir.join("tasks.json"), "[]").unwrap();
+        assert_eq!(
+            find_project(&dir, "default").unwrap().file_path,
+            dir.join("tasks.json")
+        );
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_sort_projects_by_last_opened() {
+        let dir = std::env::temp_dir().join(format!(
+            "pm-project-sort-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let mut projects: Vec<Project> = ["Zeta", "Alpha", "Mid"]
+            .iter()
+            .map(|n| Project::new(n, &dir))
+            .collect();
+        let names = |ps: &[Project]| ps.iter().map(|p| p.name.clone()).collect::<Vec<_>>();
+
+        sort_projects(&mut projects, MenuSort::Name, &HashMap::new());
+        assert_eq!(names(&projects), ["alpha", "mid", "zeta"]);
+
+        record_project_opened(&dir, &projects[2]).unwrap();
+        let mut opened = last_opened(&dir);
+        assert!(opened.contains_key("zeta"));
+        opened.insert("mid".to_string(), opened["zeta"] - 60);
+        sort_projects(&mut projects, MenuSort::Opened, &opened);
+        // Never-opened projects trail in name order.
+        assert_eq!(names(&projects), ["zeta", "mid", "alpha"]);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_project_summary_counts() {
+        use crate::fields::{Kind, Status};
+        use crate::store::id::{LeafId, TypePrefix};
+        use crate::task::Task;
+
+        let today = NaiveDate::from_ymd_opt(2025, 6, 10).unwrap();
+        let task = |n: u64, status: Status, due: Option<NaiveDate>| Task {
+            id: LeafId::new(TypePrefix::Task, n),
+            title: format!("t{n}"),
+            summary: None,
+            description: None,
+            user_story: None,
+            requirements: None,
+            tags: Vec::new(),
+            deps: Vec::new(),
+            milestone: None,
+            memories: Vec::new(),
+            due,
+            remind_days_before: None,
+            parent: None,
+            order: None,
+            iteration: None,
+            estimate_minutes: None,
+            completed_at_utc: None,
+            pinned: false,
+            kind: Kind::Task,
+            status,
+            priority_level: None,
+            urgency: None,
+            process_stage: None,
+            issue_link: None,
+            pr_link: None,
+            artifacts: Vec::new(),
+            created_at_utc: 0,
+            updated_at_utc: 0,
+        };
+        let yesterday = NaiveDate::from_ymd_opt(2025, 6, 9);
+        let db = Database {
+            tasks: vec![
+                task(1, Status::Open, yesterday),
+                task(2, Status::InProgress, Some(today)),
+                task(3, Status::Done, yesterday),
+                task(4, Status::Cancelled, None),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            ProjectSummary::of(&db, today),
+            ProjectSummary {
+                total: 4,
+                open: 2,
+                overdue: 1,
+            }
+        );
+        assert_eq!(
+            ProjectSummary::of(&Database::default(), today),
+            ProjectSummary::default()
+        );
+    }
 }
@@ -0,0 +1,249 @@
+//! `pm serve`: a small HTTP/JSON server for editor plugins and dashboards.
+//!
+//! Only built with the `server` cargo feature. It binds to `127.0.0.1`
+//! unless `--host` says otherwise, and has no authentication, so anything
+//! that can reach the port can read and add tickets.
+//!
+//! | Route                        | Reply                                    |
+//! |------------------------------|------------------------------------------|
+//! | `GET /tasks`                 | every task, in its stored serde form     |
+//! | `GET /tasks/{id}`            | one task                                 |
+//! | `GET /projects`              | the project-kind tasks                   |
+//! | `POST /tasks`                | `{"title", "kind"?, "parent"?}`: 201 and the new task |
+//! | `POST /tasks/{id}/complete`  | marks it Done and returns it             |
+//!
+//! The workspace is reloaded on every request, so edits made by the CLI or
+//! the TUI show up without a restart. The two mutations go through the MCP
+//! tool handlers, so the hierarchy check, the completion guards and the
+//! event log behave exactly as they do for `pm mcp`. Errors come back as
+//! `{"error": "..."}` with a 4xx status.
+//!
+//! So that a web page open in the user's browser can't drive the server,
+//! [`screen`] turns away requests whose `Host` isn't this machine (DNS
+//! rebinding), any cross-origin request, and a POST not sent as
+//! `application/json` (a plain form post needs no CORS preflight).
+
+use std::net::IpAddr;
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Response, Server};
+
+use crate::db::{resolve_task_identifier, Database};
+use crate::fields::Kind;
+use crate::mcp::handlers::{dispatch, Context};
+use crate::store::id::{IdInput, LeafId};
+
+/// A routed reply: HTTP status and JSON body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reply {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Reply {
+    fn ok(body: Value) -> Self {
+        Reply { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Reply {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+/// The request headers [`screen`] checks, as they arrived.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Headers<'a> {
+    pub host: Option<&'a str>,
+    pub origin: Option<&'a str>,
+    pub content_type: Option<&'a str>,
+}
+
+/// Refuse a request before routing it: 403 when `Host` names anything but
+/// `localhost`, a loopback address or the address being served (`bound`;
+/// any IP literal when that is `0.0.0.0` or `::`), 403 when an `Origin` is
+/// not that same host, and 415 for a POST whose `Content-Type` isn't
+/// `application/json`.
+pub fn screen(method: &str, headers: &Headers, bound: IpAddr) -> Result<(), Reply> {
+    let host = headers
+        .host
+        .map(str::trim)
+        .filter(|host| host_allowed(host, bound))
+        .ok_or_else(|| Reply::error(403, "the Host header must name this machine"))?;
+    if let Some(origin) = headers.origin {
+        if !origin
+            .trim()
+            .eq_ignore_ascii_case(&format!("http://{host}"))
+        {
+            return Err(Reply::error(403, "cross-origin requests are not allowed"));
+        }
+    }
+    if method == "POST" {
+        let media_type = headers
+            .content_type
+            .and_then(|ct| ct.split(';').next())
+            .map(str::trim);
+        if !media_type.is_some_and(|mt| mt.eq_ignore_ascii_case("application/json")) {
+            return Err(Reply::error(
+                415,
+                "POST bodies must be sent as application/json",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Whether a `Host` value (with or without a port) names this machine.
+fn host_allowed(host: &str, bound: IpAddr) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(rest) => match rest.split_once(']') {
+            Some((v6, port)) if port.is_empty() || port.starts_with(':') => v6,
+            _ => return false,
+        },
+        None => host.rsplit_once(':').map_or(host, |(name, _)| name),
+    };
+    if name.eq_ignore_ascii_case("localhost") {
+        return true;
+    }
+    match name.parse::<IpAddr>() {
+        Ok(ip) => ip.is_loopback() || ip == bound || bound.is_unspecified(),
+        Err(_) => false,
+    }
+}
+
+/// Answer one request that has passed [`screen`]. `path` may carry a query
+/// string, which is ignored; `body` is the raw request body. Kept apart from
+/// the socket so tests can drive the routes directly.
+pub fn route(ctx: &mut Context, method: &str, path: &str, body: &str) -> Reply {
+    ctx.reload();
+    let path = path.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    match (method, segments.as_slice()) {
+        ("GET", ["tasks"]) => Reply::ok(json!(ctx.db.tasks)),
+        ("GET", ["projects"]) => Reply::ok(json!(ctx
+            .db
+            .tasks
+            .iter()
+            .filter(|t| t.kind == Kind::Project)
+            .collect::<Vec<_>>())),
+        ("GET", ["tasks", id]) => match resolve_task_identifier(id, &ctx.db) {
+            Ok(leaf) => task_reply(&ctx.db, leaf, 200),
+            Err(e) => Reply::error(404, e),
+        },
+        ("POST", ["tasks"]) => create_task(ctx, body),
+        ("POST", ["tasks", id, "complete"]) => {
+            let leaf = match resolve_task_identifier(id, &ctx.db) {
+                Ok(leaf) => leaf,
+                Err(e) => return Reply::error(404, e),
+            };
+            match dispatch(ctx, "complete", &json!({ "id": leaf.to_string() })) {
+                Ok(_) => task_reply(&ctx.db, leaf, 200),
+                Err(e) => Reply::error(409, e),
+            }
+        }
+        (_, ["tasks"] | ["projects"] | ["tasks", _] | ["tasks", _, "complete"]) => {
+            Reply::error(405, format!("{method} is not allowed on {path}"))
+        }
+        _ => Reply::error(404, format!("no route for {path}")),
+    }
+}
+
+/// `POST /tasks`: add a ticket through the MCP `add` handler. `kind`
+/// defaults to `task`.
+fn create_task(ctx: &mut Context, body: &str) -> Reply {
+    let mut args: Value = match serde_json::from_str(body) {
+        Ok(Value::Object(map)) => Value::Object(map),
+        Ok(_) => return Reply::error(400, "expected a JSON object"),
+        Err(e) => return Reply::error(400, format!("invalid JSON: {e}")),
+    };
+    if args.get("kind").is_none() {
+        args["kind"] = json!("task");
+    }
+    let created = match dispatch(ctx, "add", &args) {
+        Ok(created) => created,
+        Err(e) => return Reply::error(400, e),
+    };
+    let leaf = created
+        .get("id")
+        .and_then(Value::as_str)
+        .and_then(|id| id.parse::<IdInput>().ok())
+        .map(|input| input.leaf());
+    match leaf {
+        Some(leaf) => task_reply(&ctx.db, leaf, 201),
+        None => Reply {
+            status: 201,
+            body: created,
+        },
+    }
+}
+
+fn task_reply(db: &Database, leaf: LeafId, status: u16) -> Reply {
+    match db.get(leaf) {
+        Some(task) => Reply {
+            status,
+            body: json!(task),
+        },
+        None => Reply::error(404, format!("Task with id {leaf} not found")),
+    }
+}
+
+/// Serve the workspace at `pm_dir` on `host:port` until the process is
+/// stopped. Port 0 picks a free port; the address actually bound is printed
+/// on stdout before the first request is taken.
+pub fn run(pm_dir: PathBuf, host: &str, port: u16) -> Result<(), String> {
+    let server =
+        Server::http((host, port)).map_err(|e| format!("cannot bind {host}:{port}: {e}"))?;
+    let addr = server
+        .server_addr()
+        .to_ip()
+        .ok_or_else(|| "not listening on an IP address".to_string())?;
+    if !addr.ip().is_loopback() {
+        eprintln!(
+            "warning: serving on {addr}; pm serve has no authentication, so anyone who can \
+             reach this address can read and add tickets"
+        );
+    }
+    println!(
+        "Serving {} on http://{addr} (Ctrl+C to stop)",
+        pm_dir.display()
+    );
+
+    let mut ctx = Context::for_pm_dir(pm_dir);
+    let json_header =
+        Header::from_bytes("Content-Type", "application/json").expect("static header is valid");
+    for mut request in server.incoming_requests() {
+        let header = |name: &'static str| {
+            request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv(name))
+                .map(|h| h.value.as_str().to_string())
+        };
+        let (host, origin, content_type) =
+            (header("Host"), header("Origin"), header("Content-Type"));
+        let headers = Headers {
+            host: host.as_deref(),
+            origin: origin.as_deref(),
+            content_type: content_type.as_deref(),
+        };
+        let method = request.method().as_str().to_string();
+        let mut body = String::new();
+        let reply = match screen(&method, &headers, addr.ip()) {
+            Err(refused) => refused,
+            Ok(()) => match request.as_reader().read_to_string(&mut body) {
+                Ok(_) => route(&mut ctx, &method, request.url(), &body),
+                Err(e) => Reply::error(400, format!("cannot read the request body: {e}")),
+            },
+        };
+        let response = Response::from_string(reply.body.to_string())
+            .with_status_code(reply.status)
+            .with_header(json_header.clone());
+        if let Err(e) = request.respond(response) {
+            eprintln!("pm serve: {e}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,304 @@
+//! Self-contained task bundles written by `pm export --format json`.
+//!
+//! A bundle carries a handful of tasks - typically one ticket and, with
+//! `--recurse`, its descendants - with their original ids and parent links,
+//! so it can be handed to a collaborator and merged into another workspace.
+//! Importing never trusts the bundle's ids: every task is allocated a fresh
+//! id in the target database and parent, dependency and milestone references
+//! are remapped through the old-to-new table. References that point outside the bundle
+//! are cleared and reported rather than left dangling.
+//!
+//! ```json
+//! { "version": 1, "root": "EPC3", "tasks": [ { "id": "EPC3", ... } ] }
+//! ```
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::id::LeafId;
+use crate::db::{build_children_map, kind_to_prefix, Database};
+use crate::task::Task;
+
+/// Current bundle format version.
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// Errors emitted when building or importing a bundle.
+#[derive(Debug)]
+pub enum BundleError {
+    /// The requested root is not in the database.
+    UnknownRoot(LeafId),
+    /// The bundle was written by a newer version of pm.
+    UnsupportedVersion(u32),
+    /// The same id appears twice in the bundle.
+    DuplicateId(LeafId),
+}
+
+impl std::fmt::Display for BundleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BundleError::UnknownRoot(id) => write!(f, "task {id} not found"),
+            BundleError::UnsupportedVersion(v) => write!(
+                f,
+                "bundle version {v} is newer than this pm supports ({BUNDLE_VERSION})"
+            ),
+            BundleError::DuplicateId(id) => write!(f, "bundle lists {id} more than once"),
+        }
+    }
+}
+
+impl std::error::Error for BundleError {}
+
+/// A portable set of tasks with their original ids.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskBundle {
+    pub version: u32,
+    /// The ticket the bundle was exported from, when it is a subtree.
+    #[serde(default)]
+    pub root: Option<LeafId>,
+    pub tasks: Vec<Task>,
+}
+
+/// What [`TaskBundle::import_into`] did.
+#[derive(Debug, Default)]
+pub struct BundleImport {
+    /// Bundle id to newly allocated id, in bundle order.
+    pub remapped: Vec<(LeafId, LeafId)>,
+    /// New ids whose parent was outside the bundle and has been cleared,
+    /// paired with the parent reference that was dropped.
+    pub detached: Vec<(LeafId, LeafId)>,
+    /// Dependency references dropped because they pointed outside the bundle.
+    pub dropped_deps: usize,
+    /// Milestone references cleared because the milestone was not in the
+    /// bundle.
+    pub dropped_milestones: usize,
+}
+
+impl TaskBundle {
+    /// Bundle arbitrary tasks, e.g. the result of the usual export filters.
+    pub fn from_tasks(tasks: Vec<Task>) -> Self {
+        TaskBundle {
+            version: BUNDLE_VERSION,
+            root: None,
+            tasks,
+        }
+    }
+
+    /// Bundle `root`, plus every descendant when `recurse` is set. Tasks are
+    /// listed parents-first so the file reads top-down.
+    pub fn from_subtree(db: &Database, root: LeafId, recurse: bool) -> Result<Self, BundleError> {
+        let Some(root_task) = db.get(root) else {
+            return Err(BundleError::UnknownRoot(root));
+        };
+        let mut tasks = vec![root_task.clone()];
+        if recurse {
+            let children = build_children_map(&db.tasks);
+            let mut seen: HashSet<LeafId> = HashSet::from([root]);
+            let mut queue = vec![root];
+            while !queue.is_empty() {
+                let mut next = Vec::new();
+                for id in queue {
+                    for &child in children.get(&id).into_iter().flatten() {
+                        // A parent loop would otherwise revisit the root.
+                        if seen.insert(child) {
+                            if let Some(t) = db.get(child) {
+                                tasks.push(t.clone());
+                            }
+                            next.push(child);
+                        }
+                    }
+                }
+                queue = next;
+            }
+        }
+        Ok(TaskBundle {
+            version: BUNDLE_VERSION,
+            root: Some(root),
+            tasks,
+        })
+    }
+
+    /// Append the bundle's tasks to `db` under fresh ids, remapping parent,
+    /// dependency and milestone links. The caller saves the database.
+    pub fn import_into(self, db: &mut Database) -> Result<BundleImport, BundleError> {
+        if self.version > BUNDLE_VERSION {
+            return Err(BundleError::UnsupportedVersion(self.version));
+        }
+        let mut mapping: HashMap<LeafId, LeafId> = HashMap::new();
+        let mut seen: HashSet<LeafId> = HashSet::new();
+        for task in &self.tasks {
+            if !seen.insert(task.id) {
+                return Err(BundleError::DuplicateId(task.id));
+            }
+        }
+
+        let mut report = BundleImport::default();
+        for task in &self.tasks {
+            let new_id = db.allocate_id(kind_to_prefix(task.kind));
+            mapping.insert(task.id, new_id);
+            report.remapped.push((task.id, new_id));
+        }
+
+        let mut detached: BTreeMap<LeafId, LeafId> = BTreeMap::new();
+        for mut task in self.tasks {
+            let new_id = mapping[&task.id];
+            task.id = new_id;
+            task.parent = match task.parent {
+                Some(old) => match mapping.get(&old) {
+                    Some(&p) => Some(p),
+                    None => {
+                        detached.insert(new_id, old);
+                        None
+                    }
+                },
+                None => None,
+            };
+            let before = task.deps.len();
+            task.deps = task
+                .deps
+                .iter()
+                .filter_map(|d| mapping.get(d).copied())
+                .collect();
+            report.dropped_deps += before - task.deps.len();
+            if let Some(old) = task.milestone {
+                task.milestone = mapping.get(&old).copied();
+                report.dropped_milestones += usize::from(task.milestone.is_none());
+            }
+            db.tasks.push(task);
+        }
+        report.detached = detached.into_iter().collect();
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::{Kind, Status};
+    use crate::store::id::TypePrefix;
+    use crate::store::State;
+
+    fn task(id: LeafId, parent: Option<LeafId>, kind: Kind) -> Task {
+        Task {
+            id,
+            title: id.to_string(),
+            summary: None,
+            description: None,
+            user_story: None,
+            requirements: None,
+            tags: Vec::new(),
+            deps: Vec::new(),
+            milestone: None,
+            memories: Vec::new(),
+            due: None,
+            remind_days_before: None,
+            parent,
+            order: None,
+            iteration: None,
+            estimate_minutes: None,
+            completed_at_utc: None,
+            pinned: false,
+            kind,
+            status: Status::Open,
+            priority_level: None,
+            urgency: None,
+            process_stage: None,
+            issue_link: None,
+            pr_link: None,
+            artifacts: Vec::new(),
+            created_at_utc: 0,
+            updated_at_utc: 0,
+        }
+    }
+
+    fn sample() -> (Database, LeafId, LeafId, LeafId) {
+        let prj = LeafId::new(TypePrefix::Project, 1);
+        let epc = LeafId::new(TypePrefix::Epic, 1);
+        let tsk = LeafId::new(TypePrefix::Task, 1);
+        let db = Database {
+            tasks: vec![
+                task(prj, None, Kind::Project),
+                task(epc, Some(prj), Kind::Epic),
+                task(tsk, Some(epc), Kind::Task),
+            ],
+            state: State::fresh(),
+        };
+        (db, prj, epc, tsk)
+    }
+
+    #[test]
+    fn subtree_includes_descendants_only_with_recurse() {
+        let (db, _, epc, tsk) = sample();
+        let single = TaskBundle::from_subtree(&db, epc, false).unwrap();
+        assert_eq!(single.tasks.len(), 1);
+        let tree = TaskBundle::from_subtree(&db, epc, true).unwrap();
+        let ids: Vec<LeafId> = tree.tasks.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![epc, tsk]);
+        assert!(matches!(
+            TaskBundle::from_subtree(&db, LeafId::new(TypePrefix::Epic, 9), true),
+            Err(BundleError::UnknownRoot(_))
+        ));
+    }
+
+    #[test]
+    fn import_renumbers_and_detaches_outside_parents() {
+        let (db, prj, epc, _) = sample();
+        let bundle = TaskBundle::from_subtree(&db, epc, true).unwrap();
+        let json = serde_json::to_string(&bundle).unwrap();
+
+        let (mut target, ..) = sample();
+        target.state.allocate(TypePrefix::Epic);
+        target.state.allocate(TypePrefix::Task);
+        let back: TaskBundle = serde_json::from_str(&json).unwrap();
+        let report = back.import_into(&mut target).unwrap();
+
+        let new_epc = report.remapped[0].1;
+        let new_tsk = report.remapped[1].1;
+        assert_ne!(new_epc, epc);
+        assert_eq!(report.detached, vec![(new_epc, prj)]);
+        assert_eq!(target.get(new_epc).unwrap().parent, None);
+        assert_eq!(target.get(new_tsk).unwrap().parent, Some(new_epc));
+        assert_eq!(target.tasks.len(), 5);
+    }
+
+    #[test]
+    fn import_remaps_milestones_in_the_bundle_and_clears_the_rest() {
+        let (mut db, prj, epc, tsk) = sample();
+        let mls = LeafId::new(TypePrefix::Milestone, 1);
+        db.tasks.push(task(mls, Some(epc), Kind::Milestone));
+        db.get_mut(tsk).unwrap().milestone = Some(mls);
+        db.get_mut(epc).unwrap().milestone = Some(LeafId::new(TypePrefix::Milestone, 7));
+        let bundle = TaskBundle::from_subtree(&db, epc, true).unwrap();
+
+        let mut target = Database {
+            tasks: vec![task(prj, None, Kind::Project)],
+            state: State::fresh(),
+        };
+        target.state.allocate(TypePrefix::Milestone);
+        let report = bundle.import_into(&mut target).unwrap();
+
+        let new = |old: LeafId| report.remapped.iter().find(|(o, _)| *o == old).unwrap().1;
+        assert_ne!(new(mls), mls);
+        assert_eq!(target.get(new(tsk)).unwrap().milestone, Some(new(mls)));
+        assert_eq!(target.get(new(epc)).unwrap().milestone, None);
+        assert_eq!(report.dropped_milestones, 1);
+    }
+
+    #[test]
+    fn import_rejects_newer_versions_and_duplicates() {
+        let (db, _, epc, _) = sample();
+        let mut bundle = TaskBundle::from_subtree(&db, epc, false).unwrap();
+        bundle.version = BUNDLE_VERSION + 1;
+        let mut target = Database::default();
+        assert!(matches!(
+            bundle.clone().import_into(&mut target),
+            Err(BundleError::UnsupportedVersion(_))
+        ));
+        bundle.version = BUNDLE_VERSION;
+        bundle.tasks.push(bundle.tasks[0].clone());
+        assert!(matches!(
+            bundle.import_into(&mut target),
+            Err(BundleError::DuplicateId(_))
+        ));
+    }
+}
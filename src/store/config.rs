@@ -0,0 +1,638 @@
+//! Workspace preferences at `.pm/config.json`.
+//!
+//! Optional, human-edited settings for presentation and workflow rules.
+//! Every field has a default, so a missing file, a missing key, or a file
+//! from an older version all load cleanly. A malformed file is reported once
+//! on stderr and treated as defaults rather than blocking the command.
+//!
+//! ```json
+//! {
+//!   "ui": { "compact": true, "short_ids": true, "ageing": { "amber_days": 5 },
+//!           "menu_sort": "opened" },
+//!   "complete": { "block_parent_completion_with_open_children": true },
+//!   "workflow": { "enforce_status_flow": true,
+//!                 "stage_on_status": { "in-progress": "implementation", "done": "release" },
+//!                 "stage_on_status_by_kind": { "epic": { "in-progress": "design" } } },
+//!   "titles": { "max_length": 80 },
+//!   "keys": { "complete": "x" },
+//!   "focus": "EPC3",
+//!   "projects": {
+//!     "PRJ1": { "defaults": { "kind": "subtask", "tags": ["sprint-3"] } },
+//!     "PRJ2": { "stage_columns": [
+//!       { "title": "Todo", "stages": ["unassigned", "ideation", "design"] },
+//!       { "title": "Doing", "stages": ["prototyping", "ready-to-implement",
+//!                                      "implementation", "testing"] },
+//!       { "title": "Done", "stages": ["refinement", "release"] }
+//!     ] }
+//!   }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::id::LeafId;
+use super::layout::Layout;
+use crate::fields::{Kind, Priority, ProcessStage, Status, Urgency};
+
+/// Top-level `config.json` shape.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Config {
+    /// Terminal UI presentation (`pm ui`, `pm wf`, `pm menu`).
+    #[serde(default)]
+    pub ui: UiConfig,
+    /// Rules applied when a ticket is marked Done.
+    #[serde(default)]
+    pub complete: CompleteConfig,
+    /// Rules applied to every status change.
+    #[serde(default)]
+    pub workflow: WorkflowConfig,
+    /// Title length checked by `pm add` / `pm update`.
+    #[serde(default)]
+    pub titles: TitlesConfig,
+    /// How CLI tables and `pm view` print dates.
+    #[serde(default)]
+    pub output: OutputConfig,
+    /// Per-project settings keyed by the project's leaf id (`PRJ1`) or its
+    /// title.
+    #[serde(default)]
+    pub projects: BTreeMap<String, ProjectConfig>,
+    /// TUI key overrides, action name to a single character. Resolved and
+    /// checked for conflicts by `tui::keymap::Keymap::from_config`.
+    #[serde(default)]
+    pub keys: BTreeMap<String, String>,
+    /// Root of the subtree `pm focus` scopes `pm list`, `pm ui`, `pm wf`
+    /// and `pm add` to; set and cleared by `pm focus`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub focus: Option<LeafId>,
+}
+
+/// `ui` section of `config.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UiConfig {
+    /// Drop the three-row header banner from the ticket list, workflow
+    /// board and menu. Same effect as `pm ui --compact` / `pm wf --compact`.
+    #[serde(default)]
+    pub compact: bool,
+    /// Show ids in the short `T-a3` form in the ticket list, detail view
+    /// and workflow board. Storage and CLI output keep `TSK370`; the CLI
+    /// accepts either form.
+    #[serde(default)]
+    pub short_ids: bool,
+    /// How long a workflow board card may go without an update before its
+    /// background turns amber, then red.
+    #[serde(default)]
+    pub ageing: AgeingConfig,
+    /// Order of the project lists in `pm menu`. Tab in a list cycles it and
+    /// writes the choice back here.
+    #[serde(default)]
+    pub menu_sort: MenuSort,
+    /// Up on the first row of the ticket list, or the first card of a
+    /// workflow board column, goes to the last, and Down on the last goes
+    /// to the first. Off by default: the cursor stops at either end.
+    #[serde(default)]
+    pub wrap_navigation: bool,
+}
+
+/// How `pm menu` orders its project lists.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MenuSort {
+    /// Alphabetical by display name.
+    #[default]
+    Name,
+    /// Most recently changed project file first.
+    Modified,
+    /// Most recently opened from the menu first; never-opened ones last.
+    Opened,
+}
+
+impl MenuSort {
+    /// The next order in the Tab cycle.
+    pub fn next(self) -> Self {
+        match self {
+            MenuSort::Name => MenuSort::Modified,
+            MenuSort::Modified => MenuSort::Opened,
+            MenuSort::Opened => MenuSort::Name,
+        }
+    }
+
+    /// Short label for list titles and status messages.
+    pub fn label(self) -> &'static str {
+        match self {
+            MenuSort::Name => "name",
+            MenuSort::Modified => "last modified",
+            MenuSort::Opened => "last opened",
+        }
+    }
+}
+
+/// `ui.ageing` section of `config.json`, in whole days since the ticket was
+/// last updated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgeingConfig {
+    #[serde(default = "default_amber_days")]
+    pub amber_days: u32,
+    #[serde(default = "default_red_days")]
+    pub red_days: u32,
+}
+
+fn default_amber_days() -> u32 {
+    7
+}
+
+fn default_red_days() -> u32 {
+    30
+}
+
+impl Default for AgeingConfig {
+    fn default() -> Self {
+        AgeingConfig {
+            amber_days: default_amber_days(),
+            red_days: default_red_days(),
+        }
+    }
+}
+
+/// `complete` section of `config.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompleteConfig {
+    /// Refuse to mark a ticket Done while any descendant is still open,
+    /// unless the descendants are completed with it (`pm complete
+    /// --recurse`). Applies to `pm complete` and the TUI completion keys.
+    #[serde(default)]
+    pub block_parent_completion_with_open_children: bool,
+}
+
+/// `workflow` section of `config.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowConfig {
+    /// Only allow the status moves [`Status::can_step_to`] accepts, so a
+    /// ticket goes Open -> InProgress -> Done one step at a time. Applies to
+    /// `pm update --status`, `pm set-status`, `pm complete`, the TUI status
+    /// keys and edit form, and `pm import --update`.
+    ///
+    /// [`Status::can_step_to`]: crate::fields::Status::can_step_to
+    #[serde(default)]
+    pub enforce_status_flow: bool,
+    /// Process stage a ticket moves to when its status changes to the key,
+    /// e.g. `in-progress` to `implementation`. Empty leaves stages alone.
+    /// Applies wherever a status changes, unless the same edit also set the
+    /// stage.
+    #[serde(default)]
+    pub stage_on_status: BTreeMap<Status, ProcessStage>,
+    /// Per-kind entries that take precedence over [`Self::stage_on_status`]
+    /// for tickets of that kind.
+    #[serde(default)]
+    pub stage_on_status_by_kind: BTreeMap<Kind, BTreeMap<Status, ProcessStage>>,
+}
+
+impl WorkflowConfig {
+    /// The stage a `kind` ticket should take on entering `status`, if any.
+    pub fn stage_for(&self, kind: Kind, status: Status) -> Option<ProcessStage> {
+        self.stage_on_status_by_kind
+            .get(&kind)
+            .and_then(|stages| stages.get(&status))
+            .or_else(|| self.stage_on_status.get(&status))
+            .copied()
+    }
+}
+
+/// `output` section of `config.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputConfig {
+    /// Due dates in `pm list` tables and `pm view` as "in 3d" or as
+    /// `2025-03-10`. `--relative-dates` / `--iso-dates` override it.
+    #[serde(default)]
+    pub dates: DateStyle,
+}
+
+/// How human-readable output shows due dates. JSON, CSV and `--format
+/// plain` always use ISO dates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DateStyle {
+    /// "today", "in 3d", "2d late"; `pm view` adds them after the date.
+    #[default]
+    Relative,
+    /// `YYYY-MM-DD` only.
+    Iso,
+}
+
+/// `titles` section of `config.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TitlesConfig {
+    /// Titles longer than this many characters are saved with a warning.
+    /// Display paths truncate with an ellipsis regardless.
+    #[serde(default = "default_max_title_length")]
+    pub max_length: usize,
+}
+
+/// Default for [`TitlesConfig::max_length`].
+pub const DEFAULT_MAX_TITLE_LENGTH: usize = 120;
+
+fn default_max_title_length() -> usize {
+    DEFAULT_MAX_TITLE_LENGTH
+}
+
+impl Default for TitlesConfig {
+    fn default() -> Self {
+        TitlesConfig {
+            max_length: DEFAULT_MAX_TITLE_LENGTH,
+        }
+    }
+}
+
+/// One entry of the `projects` section.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectConfig {
+    #[serde(default)]
+    pub defaults: ProjectDefaults,
+    /// Workflow board columns for the project's tickets, left to right.
+    /// Empty keeps one column per stage.
+    #[serde(default)]
+    pub stage_columns: Vec<StageColumn>,
+}
+
+/// One workflow board column and the process stages it shows, by their
+/// `--stage` names. `unassigned` stands for tickets with no stage. Checked
+/// by `db::board_columns`: every stage must sit in exactly one column.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageColumn {
+    pub title: String,
+    pub stages: Vec<String>,
+}
+
+/// Metadata filled in for new tickets under a project when neither a flag
+/// nor a template sets it. A default kind that cannot sit under the chosen
+/// parent is ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectDefaults {
+    #[serde(default)]
+    pub kind: Option<Kind>,
+    #[serde(default)]
+    pub priority: Option<Priority>,
+    #[serde(default)]
+    pub urgency: Option<Urgency>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl ProjectDefaults {
+    /// True when no default is set.
+    pub fn is_empty(&self) -> bool {
+        self.kind.is_none()
+            && self.priority.is_none()
+            && self.urgency.is_none()
+            && self.tags.is_empty()
+    }
+}
+
+impl Config {
+    /// Defaults configured for the project with leaf id `id` and `title`.
+    /// A key matching the id wins over one matching the title; title keys
+    /// compare case-insensitively.
+    pub fn project_defaults(&self, id: &str, title: &str) -> Option<&ProjectDefaults> {
+        let key = self.project_key(id, title)?;
+        self.projects.get(key).map(|p| &p.defaults)
+    }
+
+    /// Board columns configured for a project, matched like
+    /// [`Config::project_defaults`]. `None` when none are set.
+    pub fn project_stage_columns(&self, id: &str, title: &str) -> Option<&[StageColumn]> {
+        let key = self.project_key(id, title)?;
+        let columns = &self.projects.get(key)?.stage_columns;
+        (!columns.is_empty()).then_some(columns.as_slice())
+    }
+
+    /// The `projects` key that applies to a project, as
+    /// [`Config::project_defaults`] resolves it.
+    pub fn project_key(&self, id: &str, title: &str) -> Option<&str> {
+        if let Some((key, _)) = self.projects.get_key_value(id) {
+            return Some(key);
+        }
+        self.projects
+            .keys()
+            .find(|key| key.eq_ignore_ascii_case(title))
+            .map(String::as_str)
+    }
+
+    /// Load `<pm_dir>/config.json`, falling back to defaults when the file is
+    /// absent or unreadable.
+    pub fn load(pm_dir: &Path) -> Self {
+        let path = Layout::at(pm_dir).config_path();
+        let Ok(raw) = fs::read_to_string(&path) else {
+            return Config::default();
+        };
+        if raw.trim().is_empty() {
+            return Config::default();
+        }
+        match serde_json::from_str(&raw) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                eprintln!("warning: ignoring {}: {e}", path.display());
+                Config::default()
+            }
+        }
+    }
+
+    /// Write `ui.menu_sort` into `<pm_dir>/config.json`, keeping every
+    /// other key in the file as it was.
+    pub fn save_menu_sort(pm_dir: &Path, sort: MenuSort) -> std::io::Result<()> {
+        let sort = serde_json::to_value(sort).map_err(std::io::Error::other)?;
+        Self::edit_file(pm_dir, |root| {
+            let ui = root.entry("ui").or_insert_with(|| serde_json::json!({}));
+            if !ui.is_object() {
+                *ui = serde_json::json!({});
+            }
+            ui["menu_sort"] = sort;
+        })
+    }
+
+    /// Write `focus` into `<pm_dir>/config.json`, or remove it for `None`,
+    /// keeping every other key in the file as it was.
+    pub fn save_focus(pm_dir: &Path, focus: Option<LeafId>) -> std::io::Result<()> {
+        Self::edit_file(pm_dir, |root| match focus {
+            Some(id) => {
+                root.insert("focus".to_string(), serde_json::json!(id));
+            }
+            None => {
+                root.remove("focus");
+            }
+        })
+    }
+
+    /// Apply `change` to the top-level object of `<pm_dir>/config.json` and
+    /// write it back.
+    fn edit_file(
+        pm_dir: &Path,
+        change: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>),
+    ) -> std::io::Result<()> {
+        let path = Layout::at(pm_dir).config_path();
+        // A file that doesn't parse is left for the user to fix rather than
+        // replaced.
+        let mut root = match fs::read_to_string(&path) {
+            Ok(raw) if !raw.trim().is_empty() => serde_json::from_str::<serde_json::Value>(&raw)
+                .ok()
+                .filter(|v| v.is_object())
+                .ok_or_else(|| {
+                    std::io::Error::other(format!("{} is not a JSON object", path.display()))
+                })?,
+            _ => serde_json::json!({}),
+        };
+        change(root.as_object_mut().expect("checked above"));
+        let json = serde_json::to_string_pretty(&root).map_err(std::io::Error::other)?;
+        super::state::atomic_write(&path, format!("{json}\n").as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn tmp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "pm-store-config-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn missing_file_loads_defaults() {
+        let dir = tmp_dir();
+        assert_eq!(Config::load(&dir), Config::default());
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn reads_ui_compact() {
+        let dir = tmp_dir();
+        fs::write(dir.join("config.json"), r#"{ "ui": { "compact": true } }"#).unwrap();
+        assert!(Config::load(&dir).ui.compact);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn reads_ui_wrap_navigation() {
+        let dir = tmp_dir();
+        assert!(!Config::load(&dir).ui.wrap_navigation);
+        fs::write(
+            dir.join("config.json"),
+            r#"{ "ui": { "wrap_navigation": true } }"#,
+        )
+        .unwrap();
+        assert!(Config::load(&dir).ui.wrap_navigation);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn saving_the_menu_sort_keeps_other_keys() {
+        let dir = tmp_dir();
+        Config::save_menu_sort(&dir, MenuSort::Modified).unwrap();
+        assert_eq!(Config::load(&dir).ui.menu_sort, MenuSort::Modified);
+
+        fs::write(
+            dir.join("config.json"),
+            r#"{ "ui": { "compact": true }, "keys": { "complete": "x" } }"#,
+        )
+        .unwrap();
+        Config::save_menu_sort(&dir, MenuSort::Opened).unwrap();
+        let cfg = Config::load(&dir);
+        assert_eq!(cfg.ui.menu_sort, MenuSort::Opened);
+        assert!(cfg.ui.compact);
+        assert_eq!(cfg.keys["complete"], "x");
+
+        fs::write(dir.join("config.json"), "{ not json").unwrap();
+        assert!(Config::save_menu_sort(&dir, MenuSort::Name).is_err());
+        assert_eq!(
+            fs::read_to_string(dir.join("config.json")).unwrap(),
+            "{ not json"
+        );
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn saving_and_clearing_the_focus_keeps_other_keys() {
+        let dir = tmp_dir();
+        fs::write(dir.join("config.json"), r#"{ "ui": { "compact": true } }"#).unwrap();
+        let root: LeafId = "EPC3".parse::<crate::store::IdInput>().unwrap().leaf();
+        Config::save_focus(&dir, Some(root)).unwrap();
+        let cfg = Config::load(&dir);
+        assert_eq!(cfg.focus, Some(root));
+        assert!(cfg.ui.compact);
+
+        Config::save_focus(&dir, None).unwrap();
+        assert_eq!(Config::load(&dir).focus, None);
+        let text = fs::read_to_string(dir.join("config.json")).unwrap();
+        assert!(!text.contains("focus"), "{text}");
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn malformed_or_partial_files_fall_back_to_defaults() {
+        let dir = tmp_dir();
+        fs::write(dir.join("config.json"), "{ not json").unwrap();
+        assert_eq!(Config::load(&dir), Config::default());
+        fs::write(dir.join("config.json"), r#"{ "ui": {} }"#).unwrap();
+        assert!(!Config::load(&dir).ui.compact);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn project_defaults_match_by_id_then_title() {
+        let dir = tmp_dir();
+        fs::write(
+            dir.join("config.json"),
+            r#"{ "projects": {
+                "PRJ1": { "defaults": { "kind": "subtask", "priority": "must-have" } },
+                "website": { "defaults": { "tags": ["sprint-3"] } }
+            } }"#,
+        )
+        .unwrap();
+        let cfg = Config::load(&dir);
+        let by_id = cfg.project_defaults("PRJ1", "Website").unwrap();
+        assert_eq!(by_id.kind, Some(Kind::Subtask));
+        assert_eq!(by_id.priority, Some(Priority::MustHave));
+        let by_title = cfg.project_defaults("PRJ2", "Website").unwrap();
+        assert_eq!(by_title.tags, vec!["sprint-3".to_string()]);
+        assert!(cfg.project_defaults("PRJ3", "Other").is_none());
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn stage_columns_are_per_project() {
+        let dir = tmp_dir();
+        fs::write(
+            dir.join("config.json"),
+            r#"{ "projects": {
+                "PRJ1": { "stage_columns": [
+                    { "title": "Todo", "stages": ["unassigned", "ideation"] }
+                ] },
+                "PRJ2": { "defaults": { "tags": ["x"] } }
+            } }"#,
+        )
+        .unwrap();
+        let cfg = Config::load(&dir);
+        let columns = cfg.project_stage_columns("PRJ1", "Website").unwrap();
+        assert_eq!(columns[0].title, "Todo");
+        assert_eq!(columns[0].stages, vec!["unassigned", "ideation"]);
+        assert!(cfg.project_stage_columns("PRJ2", "Other").is_none());
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn title_limit_defaults_when_unset() {
+        let dir = tmp_dir();
+        assert_eq!(
+            Config::load(&dir).titles.max_length,
+            DEFAULT_MAX_TITLE_LENGTH
+        );
+        fs::write(
+            dir.join("config.json"),
+            r#"{ "titles": { "max_length": 60 } }"#,
+        )
+        .unwrap();
+        assert_eq!(Config::load(&dir).titles.max_length, 60);
+        fs::write(dir.join("config.json"), r#"{ "titles": {} }"#).unwrap();
+        assert_eq!(
+            Config::load(&dir).titles.max_length,
+            DEFAULT_MAX_TITLE_LENGTH
+        );
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn reads_complete_gate_alongside_ui() {
+        let dir = tmp_dir();
+        fs::write(
+            dir.join("config.json"),
+            r#"{ "complete": { "block_parent_completion_with_open_children": true } }"#,
+        )
+        .unwrap();
+        let cfg = Config::load(&dir);
+        assert!(cfg.complete.block_parent_completion_with_open_children);
+        assert!(!cfg.ui.compact);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn status_flow_is_opt_in() {
+        let dir = tmp_dir();
+        assert!(!Config::load(&dir).workflow.enforce_status_flow);
+        fs::write(
+            dir.join("config.json"),
+            r#"{ "workflow": { "enforce_status_flow": true } }"#,
+        )
+        .unwrap();
+        assert!(Config::load(&dir).workflow.enforce_status_flow);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn stage_on_status_prefers_the_kind_entry() {
+        let dir = tmp_dir();
+        let workflow = Config::load(&dir).workflow;
+        assert_eq!(workflow.stage_for(Kind::Task, Status::Done), None);
+        fs::write(
+            dir.join("config.json"),
+            r#"{ "workflow": {
+                "stage_on_status": { "in-progress": "implementation", "done": "release" },
+                "stage_on_status_by_kind": { "epic": { "in-progress": "design" } }
+            } }"#,
+        )
+        .unwrap();
+        let workflow = Config::load(&dir).workflow;
+        assert_eq!(
+            workflow.stage_for(Kind::Task, Status::InProgress),
+            Some(ProcessStage::Implementation)
+        );
+        assert_eq!(
+            workflow.stage_for(Kind::Epic, Status::InProgress),
+            Some(ProcessStage::Design)
+        );
+        assert_eq!(
+            workflow.stage_for(Kind::Epic, Status::Done),
+            Some(ProcessStage::Release)
+        );
+        assert_eq!(workflow.stage_for(Kind::Task, Status::Blocked), None);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn ageing_thresholds_default_per_key() {
+        let dir = tmp_dir();
+        assert_eq!(Config::load(&dir).ui.ageing, AgeingConfig::default());
+        fs::write(
+            dir.join("config.json"),
+            r#"{ "ui": { "ageing": { "amber_days": 3 } } }"#,
+        )
+        .unwrap();
+        let ageing = Config::load(&dir).ui.ageing;
+        assert_eq!((ageing.amber_days, ageing.red_days), (3, 30));
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn reads_key_overrides() {
+        let dir = tmp_dir();
+        fs::write(
+            dir.join("config.json"),
+            r#"{ "keys": { "complete": "x", "checkout": "C" } }"#,
+        )
+        .unwrap();
+        let cfg = Config::load(&dir);
+        assert_eq!(cfg.keys.get("complete").map(String::as_str), Some("x"));
+        assert_eq!(cfg.keys.get("checkout").map(String::as_str), Some("C"));
+        fs::remove_dir_all(&dir).ok();
+    }
+}
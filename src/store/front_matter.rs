@@ -54,6 +54,31 @@ pub struct FrontMatter {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub due: Option<NaiveDate>,
 
+    /// Lead time in days: the ticket is flagged as upcoming from
+    /// `due - remind_days_before` until it falls due.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remind_days_before: Option<u32>,
+
+    /// Manual position among siblings (see `pm add --after`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<f64>,
+
+    /// Sprint or iteration the ticket is planned into (`pm iteration`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iteration: Option<String>,
+
+    /// Estimated effort in minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<u32>,
+
+    /// When the ticket was marked done (UTC ISO 8601).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed: Option<DateTime<Utc>>,
+
+    /// In the working set (`pm pin`). Written only when set.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
+
     /// Free-form tags.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
@@ -117,6 +142,12 @@ impl FrontMatter {
             urgency: None,
             process_stage: None,
             due: None,
+            remind_days_before: None,
+            order: None,
+            iteration: None,
+            estimate: None,
+            completed: None,
+            pinned: false,
             tags: Vec::new(),
             deps: Vec::new(),
             milestone: None,
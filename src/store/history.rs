@@ -0,0 +1,662 @@
+//! Approximate per-task history from whole-database dumps already on disk.
+//!
+//! There is no audit log, but the timestamped copies under `backup/` and the
+//! named checkpoints under `.pm/snapshots/` are each a full [`Database`]. Read
+//! in chronological order they give a coarse version history: [`collect`]
+//! pulls one task's state out of every dump and [`field_changes`] diffs two
+//! consecutive states field by field.
+//!
+//! Files that don't parse as a database (for example pre-v2 `tasks.json`
+//! backups with numeric ids) are reported as skipped rather than failing the
+//! whole timeline. [`latest_dump`] serves [`Database::load`] when
+//! `state.json` is corrupt, and [`diff_databases`] compares a whole dump with
+//! the current workspace for `pm diff`, or the TUI's copy with the disk when
+//! it reloads ([`DiffSummary`]).
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+
+use super::id::LeafId;
+use super::snapshots;
+use crate::db::{
+    format_kind, format_priority, format_process_stage, format_status, format_urgency, Database,
+};
+use crate::task::Task;
+
+/// Directory, beside the database path, that `pm backup` writes into.
+pub const BACKUP_DIR: &str = "backup";
+
+/// Timestamp prefix `pm backup` puts on every file name.
+const BACKUP_STAMP_FORMAT: &str = "%Y-%m-%d_%H-%M-%S";
+
+/// Where a version came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A timestamped file under `backup/`; carries the file name.
+    Backup(String),
+    /// A named snapshot under `.pm/snapshots/`.
+    Snapshot(String),
+}
+
+impl Source {
+    /// The file or snapshot name, without the kind.
+    pub fn name(&self) -> &str {
+        match self {
+            Source::Backup(name) | Source::Snapshot(name) => name,
+        }
+    }
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Backup(name) => write!(f, "backup {name}"),
+            Source::Snapshot(name) => write!(f, "snapshot {name}"),
+        }
+    }
+}
+
+/// One task's state as recorded in a single dump. `task` is `None` when the
+/// dump predates the task or was taken after it was deleted.
+#[derive(Debug, Clone)]
+pub struct Version {
+    pub source: Source,
+    pub taken: Option<DateTime<Local>>,
+    pub task: Option<Task>,
+}
+
+/// A dump that could not be read, with the reason.
+#[derive(Debug, Clone)]
+pub struct Skipped {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+/// A single field that differs between two versions of a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+/// Parse the `YYYY-MM-DD_HH-MM-SS_` prefix `pm backup` gives its files.
+pub fn backup_timestamp(file_name: &str) -> Option<DateTime<Local>> {
+    let stamp = file_name.get(..19)?;
+    let naive = NaiveDateTime::parse_from_str(stamp, BACKUP_STAMP_FORMAT).ok()?;
+    Local.from_local_datetime(&naive).earliest()
+}
+
+/// How one task differs between two databases.
+#[derive(Debug, Clone)]
+pub enum TaskDiff {
+    /// Only in the newer database.
+    Added(Task),
+    /// Only in the older database.
+    Removed(Task),
+    /// In both, with at least one field changed.
+    Modified {
+        id: LeafId,
+        title: String,
+        changes: Vec<FieldChange>,
+    },
+}
+
+/// A whole-database dump found under `backup/` or `.pm/snapshots/`.
+#[derive(Debug)]
+pub struct Dump {
+    pub source: Source,
+    pub taken: Option<DateTime<Local>>,
+    pub path: PathBuf,
+    pub db: Database,
+}
+
+/// Every backup and snapshot that parses as a database, oldest first.
+/// Backups are dated by their file-name stamp (falling back to mtime);
+/// snapshots by mtime. Undated dumps sort first.
+pub fn dumps(pm_dir: &Path) -> (Vec<Dump>, Vec<Skipped>) {
+    let mut found = Vec::new();
+    let mut skipped = Vec::new();
+
+    let backup_dir = pm_dir
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(BACKUP_DIR);
+    if let Ok(entries) = fs::read_dir(&backup_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            let taken = backup_timestamp(&name).or_else(|| modified(&path));
+            match read_database(&path) {
+                Ok(db) => found.push(Dump {
+                    source: Source::Backup(name),
+                    taken,
+                    path,
+                    db,
+                }),
+                Err(reason) => skipped.push(Skipped { path, reason }),
+            }
+        }
+    }
+
+    if let Ok(list) = snapshots::list(pm_dir) {
+        for info in list {
+            match read_database(&info.path) {
+                Ok(db) => found.push(Dump {
+                    source: Source::Snapshot(info.name),
+                    taken: info.modified,
+                    path: info.path,
+                    db,
+                }),
+                Err(reason) => skipped.push(Skipped {
+                    path: info.path,
+                    reason,
+                }),
+            }
+        }
+    }
+
+    found.sort_by_key(|d| d.taken);
+    (found, skipped)
+}
+
+/// The newest readable dump, used to recover from a corrupt `state.json`.
+pub fn latest_dump(pm_dir: &Path) -> Option<Dump> {
+    dumps(pm_dir).0.pop()
+}
+
+/// The dump `reference` names: a snapshot name or backup file name, or else
+/// the start of exactly one of them (a backup's `YYYY-MM-DD` stamp, say).
+/// A space and colons in the reference match the `_` and `-` of a backup
+/// stamp, so `2025-01-02 09:00` finds `2025-01-02_09-00-00_.pm`.
+pub fn find_dump(pm_dir: &Path, reference: &str) -> Result<Dump, String> {
+    let (found, _) = dumps(pm_dir);
+    if found.is_empty() {
+        return Err("no readable backups or snapshots".to_string());
+    }
+    let stamp = reference.replace(' ', "_").replace(':', "-");
+    let mut found: Vec<Dump> = found
+        .into_iter()
+        .filter(|d| d.source.name().starts_with(reference) || d.source.name().starts_with(&stamp))
+        .collect();
+    if let Some(i) = found.iter().position(|d| d.source.name() == reference) {
+        return Ok(found.swap_remove(i));
+    }
+    match found.len() {
+        0 => Err(format!("no backup or snapshot matches '{reference}'")),
+        1 => Ok(found.remove(0)),
+        _ => Err(format!(
+            "'{reference}' matches several dumps: {}",
+            found
+                .iter()
+                .map(|d| d.source.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Every task that differs between `before` and `after`, matched by id, in
+/// id order.
+pub fn diff_databases(before: &Database, after: &Database) -> Vec<TaskDiff> {
+    let old: BTreeMap<LeafId, &Task> = before.tasks.iter().map(|t| (t.id, t)).collect();
+    let new: BTreeMap<LeafId, &Task> = after.tasks.iter().map(|t| (t.id, t)).collect();
+    let ids: BTreeSet<LeafId> = old.keys().chain(new.keys()).copied().collect();
+    ids.into_iter()
+        .filter_map(|id| match (old.get(&id), new.get(&id)) {
+            (None, Some(t)) => Some(TaskDiff::Added((*t).clone())),
+            (Some(t), None) => Some(TaskDiff::Removed((*t).clone())),
+            (Some(b), Some(a)) => {
+                let changes = field_changes(b, a);
+                (!changes.is_empty()).then(|| TaskDiff::Modified {
+                    id,
+                    title: a.title.clone(),
+                    changes,
+                })
+            }
+            (None, None) => None,
+        })
+        .collect()
+}
+
+/// Counts of a [`diff_databases`] result, for a one-line report such as
+/// the TUI's "Reloaded: +2 new, 1 completed, 3 updated".
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DiffSummary {
+    pub added: usize,
+    pub removed: usize,
+    /// Modified tasks whose status changed to Done or Cancelled.
+    pub completed: usize,
+    /// Every other modified task.
+    pub updated: usize,
+}
+
+impl DiffSummary {
+    /// Tally `diff`, taken against `after`, the newer database.
+    pub fn of(diff: &[TaskDiff], after: &Database) -> Self {
+        let mut summary = DiffSummary::default();
+        for entry in diff {
+            match entry {
+                TaskDiff::Added(_) => summary.added += 1,
+                TaskDiff::Removed(_) => summary.removed += 1,
+                TaskDiff::Modified { id, changes, .. } => {
+                    let closed = after.get(*id).is_some_and(|t| t.status.is_closed());
+                    if closed && changes.iter().any(|c| c.field == "status") {
+                        summary.completed += 1;
+                    } else {
+                        summary.updated += 1;
+                    }
+                }
+            }
+        }
+        summary
+    }
+
+    /// True when nothing differs.
+    pub fn is_empty(&self) -> bool {
+        *self == DiffSummary::default()
+    }
+}
+
+impl std::fmt::Display for DiffSummary {
+    /// `+2 new, 1 completed, 3 updated, 1 removed`, leaving out zero counts,
+    /// or `no changes`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return f.write_str("no changes");
+        }
+        let parts: Vec<String> = [
+            (self.added, "+", "new"),
+            (self.completed, "", "completed"),
+            (self.updated, "", "updated"),
+            (self.removed, "", "removed"),
+        ]
+        .into_iter()
+        .filter(|(n, _, _)| *n > 0)
+        .map(|(n, sign, label)| format!("{sign}{n} {label}"))
+        .collect();
+        f.write_str(&parts.join(", "))
+    }
+}
+
+/// Every dump reduced to the state of task `id`, oldest first.
+pub fn collect(pm_dir: &Path, id: LeafId) -> (Vec<Version>, Vec<Skipped>) {
+    let (found, skipped) = dumps(pm_dir);
+    let versions = found
+        .into_iter()
+        .map(|d| Version {
+            source: d.source,
+            taken: d.taken,
+            task: d.db.get(id).cloned(),
+        })
+        .collect();
+    (versions, skipped)
+}
+
+fn modified(path: &Path) -> Option<DateTime<Local>> {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .map(DateTime::<Local>::from)
+}
+
+fn read_database(path: &Path) -> Result<Database, String> {
+    let raw = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&raw).map_err(|e| format!("not a v2 database: {e}"))
+}
+
+fn opt<T: std::fmt::Display>(v: &Option<T>) -> String {
+    v.as_ref()
+        .map(|x| x.to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn list<T: std::fmt::Display>(items: &[T]) -> String {
+    if items.is_empty() {
+        "-".to_string()
+    } else {
+        items
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Long free-text fields are summarised by size so the timeline stays one
+/// line per change.
+fn text(v: &Option<String>) -> String {
+    match v.as_deref() {
+        None | Some("") => "-".to_string(),
+        Some(s) => format!("({} chars)", s.chars().count()),
+    }
+}
+
+/// Fields of `after` that differ from `before`, in display order. Timestamps
+/// are left out; they change on every save and carry no information here.
+pub fn field_changes(before: &Task, after: &Task) -> Vec<FieldChange> {
+    let pairs: [(&'static str, String, String); 21] = [
+        ("title", before.title.clone(), after.title.clone()),
+        (
+            "kind",
+            format_kind(before.kind).to_string(),
+            format_kind(after.kind).to_string(),
+        ),
+        (
+            "status",
+            format_status(before.status).to_string(),
+            format_status(after.status).to_string(),
+        ),
+        ("parent", opt(&before.parent), opt(&after.parent)),
+        (
+            "priority",
+            format_priority(before.priority_level).to_string(),
+            format_priority(after.priority_level).to_string(),
+        ),
+        (
+            "urgency",
+            format_urgency(before.urgency).to_string(),
+            format_urgency(after.urgency).to_string(),
+        ),
+        (
+            "stage",
+            format_process_stage(before.process_stage).to_string(),
+            format_process_stage(after.process_stage).to_string(),
+        ),
+        ("due", opt(&before.due), opt(&after.due)),
+        (
+            "remind",
+            opt(&before.remind_days_before),
+            opt(&after.remind_days_before),
+        ),
+        ("iteration", opt(&before.iteration), opt(&after.iteration)),
+        (
+            "estimate",
+            opt(&before.estimate_minutes),
+            opt(&after.estimate_minutes),
+        ),
+        (
+            "pinned",
+            before.pinned.to_string(),
+            after.pinned.to_string(),
+        ),
+        ("tags", list(&before.tags), list(&after.tags)),
+        ("deps", list(&before.deps), list(&after.deps)),
+        ("milestone", opt(&before.milestone), opt(&after.milestone)),
+        ("summary", opt(&before.summary), opt(&after.summary)),
+        (
+            "description",
+            text(&before.description),
+            text(&after.description),
+        ),
+        (
+            "user_story",
+            text(&before.user_story),
+            text(&after.user_story),
+        ),
+        (
+            "requirements",
+            text(&before.requirements),
+            text(&after.requirements),
+        ),
+        ("issue", opt(&before.issue_link), opt(&after.issue_link)),
+        ("pr", opt(&before.pr_link), opt(&after.pr_link)),
+    ];
+    let mut changes: Vec<FieldChange> = pairs
+        .into_iter()
+        .filter(|(_, b, a)| b != a)
+        .map(|(field, before, after)| FieldChange {
+            field,
+            before,
+            after,
+        })
+        .collect();
+    // Same-length edits to a long text field would otherwise be invisible.
+    for (field, b, a) in [
+        ("description", &before.description, &after.description),
+        ("user_story", &before.user_story, &after.user_story),
+        ("requirements", &before.requirements, &after.requirements),
+    ] {
+        if b != a && !changes.iter().any(|c| c.field == field) {
+            changes.push(FieldChange {
+                field,
+                before: text(b),
+                after: format!("{} (edited)", text(a)),
+            });
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::{Kind, Status};
+    use crate::store::id::TypePrefix;
+    use crate::store::state::State;
+    use chrono::{Datelike, Timelike};
+
+    fn task(n: u64, title: &str) -> Task {
+        Task {
+            id: LeafId::new(TypePrefix::Task, n),
+            title: title.to_string(),
+            summary: None,
+            description: None,
+            user_story: None,
+            requirements: None,
+            tags: Vec::new(),
+            deps: Vec::new(),
+            milestone: None,
+            memories: Vec::new(),
+            due: None,
+            remind_days_before: None,
+            parent: None,
+            order: None,
+            iteration: None,
+            estimate_minutes: None,
+            completed_at_utc: None,
+            pinned: false,
+            kind: Kind::Task,
+            status: Status::Open,
+            priority_level: None,
+            urgency: None,
+            process_stage: None,
+            issue_link: None,
+            pr_link: None,
+            artifacts: Vec::new(),
+            created_at_utc: 0,
+            updated_at_utc: 0,
+        }
+    }
+
+    fn tmp_pm_dir() -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!(
+                "pm-store-history-{}-{}",
+                std::process::id(),
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_nanos()
+            ))
+            .join(".pm");
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_backup(pm_dir: &Path, name: &str, tasks: Vec<Task>) {
+        let dir = pm_dir.parent().unwrap().join(BACKUP_DIR);
+        fs::create_dir_all(&dir).unwrap();
+        let db = Database {
+            tasks,
+            state: State::fresh(),
+        };
+        fs::write(dir.join(name), serde_json::to_string(&db).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn backup_stamp_is_read_from_the_file_name() {
+        let t = backup_timestamp("2025-03-04_10-20-30_.pm").unwrap();
+        assert_eq!((t.year(), t.month(), t.day()), (2025, 3, 4));
+        assert_eq!((t.hour(), t.minute(), t.second()), (10, 20, 30));
+        assert!(backup_timestamp("notes.txt").is_none());
+    }
+
+    #[test]
+    fn unchanged_tasks_have_no_changes() {
+        let t = task(1, "Same");
+        let mut later = t.clone();
+        later.updated_at_utc = 99;
+        assert!(field_changes(&t, &later).is_empty());
+    }
+
+    #[test]
+    fn changed_fields_are_listed_in_order() {
+        let before = task(1, "Old");
+        let mut after = before.clone();
+        after.title = "New".to_string();
+        after.status = Status::Done;
+        after.tags = vec!["ui".to_string()];
+        let changes = field_changes(&before, &after);
+        let fields: Vec<&str> = changes.iter().map(|c| c.field).collect();
+        assert_eq!(fields, vec!["title", "status", "tags"]);
+        assert_eq!(changes[0].before, "Old");
+        assert_eq!(changes[0].after, "New");
+        assert_eq!(changes[2].before, "-");
+        assert_eq!(changes[2].after, "ui");
+    }
+
+    #[test]
+    fn same_length_text_edits_are_still_reported() {
+        let mut before = task(1, "T");
+        before.description = Some("abc".to_string());
+        let mut after = before.clone();
+        after.description = Some("xyz".to_string());
+        let changes = field_changes(&before, &after);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "description");
+        assert_eq!(changes[0].after, "(3 chars) (edited)");
+    }
+
+    #[test]
+    fn collect_orders_backups_and_marks_absent_versions() {
+        let pm_dir = tmp_pm_dir();
+        let id = LeafId::new(TypePrefix::Task, 1);
+        write_backup(&pm_dir, "2025-01-03_09-00-00_.pm", vec![]);
+        write_backup(&pm_dir, "2025-01-01_09-00-00_.pm", vec![]);
+        write_backup(&pm_dir, "2025-01-02_09-00-00_.pm", vec![task(1, "Born")]);
+        let bad = pm_dir.parent().unwrap().join(BACKUP_DIR);
+        fs::write(bad.join("2025-01-04_09-00-00_tasks.json"), "{ nope").unwrap();
+
+        let (versions, skipped) = collect(&pm_dir, id);
+        let present: Vec<bool> = versions.iter().map(|v| v.task.is_some()).collect();
+        assert_eq!(present, vec![false, true, false]);
+        assert_eq!(
+            versions[0].source,
+            Source::Backup("2025-01-01_09-00-00_.pm".to_string())
+        );
+        assert_eq!(skipped.len(), 1);
+        fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+    }
+
+    #[test]
+    fn diff_matches_tasks_by_id() {
+        let before = Database {
+            tasks: vec![task(1, "Kept"), task(2, "Gone"), task(3, "Same")],
+            state: State::fresh(),
+        };
+        let mut renamed = task(1, "Kept, renamed");
+        renamed.status = Status::Done;
+        let after = Database {
+            tasks: vec![task(4, "New"), task(3, "Same"), renamed],
+            state: State::fresh(),
+        };
+        let diff = diff_databases(&before, &after);
+        assert_eq!(diff.len(), 3);
+        match &diff[0] {
+            TaskDiff::Modified { id, changes, .. } => {
+                assert_eq!(id.number(), 1);
+                let fields: Vec<&str> = changes.iter().map(|c| c.field).collect();
+                assert_eq!(fields, vec!["title", "status"]);
+            }
+            other => panic!("expected a modification, got {other:?}"),
+        }
+        assert!(matches!(&diff[1], TaskDiff::Removed(t) if t.title == "Gone"));
+        assert!(matches!(&diff[2], TaskDiff::Added(t) if t.title == "New"));
+    }
+
+    #[test]
+    fn summary_counts_completions_apart_from_updates() {
+        let before = Database {
+            tasks: vec![task(1, "Closing"), task(2, "Renamed"), task(3, "Gone")],
+            state: State::fresh(),
+        };
+        let mut closed = task(1, "Closing");
+        closed.status = Status::Done;
+        let after = Database {
+            tasks: vec![
+                closed,
+                task(2, "Renamed again"),
+                task(4, "New"),
+                task(5, "New"),
+            ],
+            state: State::fresh(),
+        };
+        let summary = DiffSummary::of(&diff_databases(&before, &after), &after);
+        assert_eq!(
+            summary,
+            DiffSummary {
+                added: 2,
+                removed: 1,
+                completed: 1,
+                updated: 1
+            }
+        );
+        assert_eq!(
+            summary.to_string(),
+            "+2 new, 1 completed, 1 updated, 1 removed"
+        );
+        let same = DiffSummary::of(&diff_databases(&after, &after), &after);
+        assert_eq!(same.to_string(), "no changes");
+    }
+
+    #[test]
+    fn find_dump_accepts_names_and_unique_prefixes() {
+        let pm_dir = tmp_pm_dir();
+        write_backup(&pm_dir, "2025-01-01_09-00-00_.pm", vec![]);
+        write_backup(&pm_dir, "2025-01-02_09-00-00_.pm", vec![task(1, "Kept")]);
+        write_backup(&pm_dir, "2025-01-02_17-30-00_.pm", vec![]);
+
+        let d = find_dump(&pm_dir, "2025-01-01").unwrap();
+        assert_eq!(d.source.name(), "2025-01-01_09-00-00_.pm");
+        let d = find_dump(&pm_dir, "2025-01-02 09:00").unwrap();
+        assert_eq!(d.db.tasks.len(), 1);
+        let err = find_dump(&pm_dir, "2025-01-02").unwrap_err();
+        assert!(err.contains("matches several"), "{err}");
+        assert!(find_dump(&pm_dir, "2024").is_err());
+        fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+    }
+
+    #[test]
+    fn latest_dump_is_the_newest_readable_one() {
+        let pm_dir = tmp_pm_dir();
+        write_backup(&pm_dir, "2025-01-01_09-00-00_.pm", vec![]);
+        write_backup(&pm_dir, "2025-01-02_09-00-00_.pm", vec![task(1, "Kept")]);
+        let dir = pm_dir.parent().unwrap().join(BACKUP_DIR);
+        fs::write(dir.join("2025-01-03_09-00-00_.pm"), "{ trunc").unwrap();
+
+        let dump = latest_dump(&pm_dir).expect("a readable backup");
+        assert_eq!(
+            dump.source,
+            Source::Backup("2025-01-02_09-00-00_.pm".to_string())
+        );
+        assert_eq!(dump.db.tasks.len(), 1);
+        fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+    }
+}
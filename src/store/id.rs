@@ -10,6 +10,10 @@
 //!
 //! The parser keys on `(PRJ|PRD|EPC|TSK|SBT|MLS)\d+` and ignores any trailing
 //! label text after the digits.
+//!
+//! A short display form, `<letter>-<base36>` (`TSK370` is `T-aa`), is offered
+//! for the UI and for saying ids aloud. It is never stored; [`LeafId::short`]
+//! renders it and [`LeafId::parse_short`] reads it back.
 
 use std::fmt;
 use std::str::FromStr;
@@ -68,6 +72,27 @@ impl TypePrefix {
         }
     }
 
+    /// Single-letter tag used by the short id form. Project takes `P`, so
+    /// Product falls back to `D`.
+    pub fn short_letter(&self) -> char {
+        match self {
+            TypePrefix::Project => 'P',
+            TypePrefix::Product => 'D',
+            TypePrefix::Epic => 'E',
+            TypePrefix::Task => 'T',
+            TypePrefix::Subtask => 'S',
+            TypePrefix::Milestone => 'M',
+        }
+    }
+
+    /// Inverse of [`TypePrefix::short_letter`]. Case insensitive.
+    pub fn from_short_letter(c: char) -> Option<Self> {
+        TypePrefix::all()
+            .iter()
+            .copied()
+            .find(|p| p.short_letter() == c.to_ascii_uppercase())
+    }
+
     /// Singular display name for human-readable output.
     pub fn display_singular(&self) -> &'static str {
         match self {
@@ -140,6 +165,44 @@ impl LeafId {
     pub fn as_string(&self) -> String {
         format!("{}{}", self.prefix.as_str(), self.number)
     }
+
+    /// Render the short display form: kind letter, `-`, lowercase base-36
+    /// counter (`TSK370` -> `"T-aa"`).
+    pub fn short(&self) -> String {
+        const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+        let mut n = self.number;
+        let mut digits = Vec::new();
+        loop {
+            digits.push(DIGITS[(n % 36) as usize]);
+            n /= 36;
+            if n == 0 {
+                break;
+            }
+        }
+        digits.reverse();
+        format!(
+            "{}-{}",
+            self.prefix.short_letter(),
+            String::from_utf8(digits).expect("base-36 digits are ASCII")
+        )
+    }
+
+    /// Parse the short display form produced by [`LeafId::short`]. Case
+    /// insensitive; returns `None` for anything else, including the
+    /// canonical `TSK7` form.
+    pub fn parse_short(s: &str) -> Option<LeafId> {
+        let (letter, digits) = s.trim().split_once('-')?;
+        let mut chars = letter.chars();
+        let prefix = TypePrefix::from_short_letter(chars.next()?)?;
+        if chars.next().is_some()
+            || digits.is_empty()
+            || !digits.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return None;
+        }
+        let number = u64::from_str_radix(&digits.to_ascii_lowercase(), 36).ok()?;
+        Some(LeafId::new(prefix, number))
+    }
 }
 
 impl fmt::Display for LeafId {
@@ -575,4 +638,30 @@ mod tests {
         let err: Result<LeafId, _> = "TSK18446744073709551616".parse();
         assert!(matches!(err, Err(IdParseError::NumberOverflow(_))));
     }
+
+    #[test]
+    fn short_form_round_trips() {
+        let cases = [
+            (LeafId::new(TypePrefix::Task, 7), "T-7"),
+            (LeafId::new(TypePrefix::Task, 370), "T-aa"),
+            (LeafId::new(TypePrefix::Project, 0), "P-0"),
+            (LeafId::new(TypePrefix::Product, 35), "D-z"),
+            (LeafId::new(TypePrefix::Subtask, 36), "S-10"),
+        ];
+        for (leaf, short) in cases {
+            assert_eq!(leaf.short(), short);
+            assert_eq!(LeafId::parse_short(short), Some(leaf));
+        }
+        assert_eq!(
+            LeafId::parse_short("t-AA"),
+            Some(LeafId::new(TypePrefix::Task, 370))
+        );
+    }
+
+    #[test]
+    fn short_form_rejects_other_inputs() {
+        for raw in ["TSK7", "T-", "-7", "TS-7", "Q-7", "T-7!", "T-+7", "T7"] {
+            assert_eq!(LeafId::parse_short(raw), None, "{raw}");
+        }
+    }
 }
@@ -7,6 +7,7 @@
 //! ├── state.json
 //! ├── aliases.json
 //! ├── events.log         # touched but unused until Phase 6
+//! ├── write.lock         # held while a process saves; see write_lock
 //! ├── locks/
 //! ├── projects/
 //! ├── products/          # orphan products
@@ -42,6 +43,15 @@ pub const TYPE_FOLDER_ROOTS: &[(TypePrefix, &str)] = &[
     (TypePrefix::Milestone, "milestones"),
 ];
 
+/// Find the nearest `.pm/` directory at or above `start`, walking up the
+/// way git looks for `.git/`. Returns the `.pm/` directory itself.
+pub fn discover(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(PM_DIR_NAME))
+        .find(|candidate| candidate.is_dir())
+}
+
 /// Owns paths and provides scaffolding for a `.pm/` directory.
 #[derive(Debug, Clone)]
 pub struct Layout {
@@ -68,6 +78,9 @@ impl Layout {
     pub fn state_path(&self) -> PathBuf {
         self.root.join("state.json")
     }
+    pub fn config_path(&self) -> PathBuf {
+        self.root.join("config.json")
+    }
     pub fn aliases_path(&self) -> PathBuf {
         self.root.join("aliases.json")
     }
@@ -77,6 +90,9 @@ impl Layout {
     pub fn locks_dir(&self) -> PathBuf {
         self.root.join("locks")
     }
+    pub fn write_lock_path(&self) -> PathBuf {
+        self.root.join("write.lock")
+    }
 
     /// Path to a top-level type folder (e.g. `tasks/` for orphan tasks).
     pub fn type_folder_root(&self, prefix: TypePrefix) -> PathBuf {
@@ -191,6 +207,25 @@ mod tests {
         dir
     }
 
+    #[test]
+    fn discover_walks_up_to_the_nearest_pm_dir() {
+        let base = tmp_dir();
+        let nested = base.join("repo").join("src").join("deep");
+        fs::create_dir_all(&nested).unwrap();
+        assert_eq!(discover(&nested), None);
+
+        Layout::under(base.join("repo")).init().unwrap();
+        assert_eq!(discover(&nested), Some(base.join("repo").join(".pm")));
+
+        // A closer workspace shadows the outer one.
+        Layout::under(base.join("repo").join("src")).init().unwrap();
+        assert_eq!(
+            discover(&nested),
+            Some(base.join("repo").join("src").join(".pm"))
+        );
+        fs::remove_dir_all(&base).ok();
+    }
+
     #[test]
     fn init_creates_full_layout() {
         let base = tmp_dir();
@@ -9,27 +9,38 @@
 
 pub mod aliases;
 pub mod artifacts;
+pub mod bundle;
 pub mod claude_md;
+pub mod config;
 pub mod events;
 pub mod front_matter;
 pub mod git;
+pub mod history;
 pub mod id;
 pub mod layout;
 pub mod locks;
 pub mod migrate;
 pub mod resolver;
 pub mod sections;
+pub mod snapshots;
 pub mod state;
 pub mod task_bridge;
+pub mod taskwarrior;
 pub mod templates;
 pub mod watcher;
+pub mod write_lock;
 
 pub use aliases::Aliases;
 pub use artifacts::{
     rename_artifact, sweep_dir, ArtifactEntry, ArtifactError, ArtifactsIndex, SweepReport,
     ARTIFACTS_MD,
 };
+pub use bundle::{BundleError, BundleImport, TaskBundle, BUNDLE_VERSION};
 pub use claude_md::{Ticket, TicketError, ARTIFACTS_IMPORT, CLAUDE_MD};
+pub use config::{
+    AgeingConfig, CompleteConfig, Config, DateStyle, MenuSort, OutputConfig, ProjectConfig,
+    ProjectDefaults, StageColumn, TitlesConfig, UiConfig, WorkflowConfig, DEFAULT_MAX_TITLE_LENGTH,
+};
 pub use events::{actor, emit_event, read_events, Event, EventError, EventResult};
 pub use front_matter::{split_front_matter, Document, FrontMatter, FrontMatterError, MemoryRef};
 pub use git::{
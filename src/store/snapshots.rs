@@ -0,0 +1,240 @@
+//! Named workspace checkpoints at `.pm/snapshots/<name>.json`.
+//!
+//! A snapshot is the whole in-memory [`Database`] - every task plus the
+//! `state.json` index, counters, tombstones and templates - serialised as a
+//! single JSON file. Unlike the timestamped copies under `backup/`, snapshots
+//! carry a user-chosen name ("before-refactor") and are restored with
+//! `pm restore <name>`.
+//!
+//! Restoring never rewinds id allocation: the per-type counters and
+//! tombstones are merged with the live state so ids minted after the
+//! snapshot was taken stay out of circulation.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+
+use super::state::{atomic_write, State};
+use crate::db::Database;
+
+/// Directory under `.pm/` that holds named snapshots.
+pub const SNAPSHOTS_DIR: &str = "snapshots";
+
+/// Errors emitted by the snapshot layer.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// Name is empty or contains characters outside `[A-Za-z0-9._-]`.
+    InvalidName(String),
+    /// A snapshot with this name already exists and `force` was not set.
+    Exists(String),
+    /// No snapshot with this name.
+    NotFound(String),
+    Io(std::io::Error),
+    Serialise(serde_json::Error),
+}
+
+impl std::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnapshotError::InvalidName(n) => write!(
+                f,
+                "invalid snapshot name {n:?}: use letters, digits, '.', '-' or '_'"
+            ),
+            SnapshotError::Exists(n) => {
+                write!(
+                    f,
+                    "snapshot {n:?} already exists (use --force to overwrite)"
+                )
+            }
+            SnapshotError::NotFound(n) => write!(f, "no snapshot named {n:?}"),
+            SnapshotError::Io(e) => write!(f, "snapshot io: {e}"),
+            SnapshotError::Serialise(e) => write!(f, "snapshot serialise: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// Result type used across the snapshot layer.
+pub type SnapshotResult<T> = Result<T, SnapshotError>;
+
+/// A snapshot on disk, as reported by [`list`].
+#[derive(Debug, Clone)]
+pub struct SnapshotInfo {
+    pub name: String,
+    pub path: PathBuf,
+    pub modified: Option<DateTime<Local>>,
+}
+
+/// Reject names that are empty, start with a dot, or contain anything other
+/// than ASCII letters, digits, `.`, `-` and `_`. Keeps names safe as file
+/// stems on every platform and rules out path traversal.
+pub fn validate_name(name: &str) -> SnapshotResult<()> {
+    let ok = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'));
+    if ok {
+        Ok(())
+    } else {
+        Err(SnapshotError::InvalidName(name.to_string()))
+    }
+}
+
+/// Path of the snapshot file for `name` under `pm_dir`.
+pub fn snapshot_path(pm_dir: &Path, name: &str) -> PathBuf {
+    pm_dir.join(SNAPSHOTS_DIR).join(format!("{name}.json"))
+}
+
+/// Serialise `db` to `.pm/snapshots/<name>.json`. Refuses to overwrite an
+/// existing snapshot unless `force` is set.
+pub fn write(db: &Database, pm_dir: &Path, name: &str, force: bool) -> SnapshotResult<PathBuf> {
+    validate_name(name)?;
+    let path = snapshot_path(pm_dir, name);
+    if path.exists() && !force {
+        return Err(SnapshotError::Exists(name.to_string()));
+    }
+    fs::create_dir_all(pm_dir.join(SNAPSHOTS_DIR)).map_err(SnapshotError::Io)?;
+    let json = serde_json::to_string_pretty(db).map_err(SnapshotError::Serialise)?;
+    atomic_write(&path, json.as_bytes()).map_err(SnapshotError::Io)?;
+    Ok(path)
+}
+
+/// Read the snapshot `name` back into a [`Database`].
+pub fn read(pm_dir: &Path, name: &str) -> SnapshotResult<Database> {
+    validate_name(name)?;
+    let path = snapshot_path(pm_dir, name);
+    if !path.exists() {
+        return Err(SnapshotError::NotFound(name.to_string()));
+    }
+    let raw = fs::read_to_string(&path).map_err(SnapshotError::Io)?;
+    serde_json::from_str(&raw).map_err(SnapshotError::Serialise)
+}
+
+/// All snapshots under `pm_dir`, sorted by name. A missing snapshots
+/// directory reads as an empty list.
+pub fn list(pm_dir: &Path) -> SnapshotResult<Vec<SnapshotInfo>> {
+    let dir = pm_dir.join(SNAPSHOTS_DIR);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut out = Vec::new();
+    for entry in fs::read_dir(&dir).map_err(SnapshotError::Io)?.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let modified = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .map(DateTime::<Local>::from);
+        out.push(SnapshotInfo {
+            name: name.to_string(),
+            path: path.clone(),
+            modified,
+        });
+    }
+    out.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(out)
+}
+
+/// Fold the live id-allocation history into a restored state: each counter
+/// keeps the higher of the two values and tombstones are unioned, so no id
+/// minted since the snapshot can be handed out again.
+pub fn merge_allocation_history(restored: &mut State, live: &State) {
+    for (prefix, next) in &live.next {
+        let counter = restored.next.entry(*prefix).or_insert(*next);
+        *counter = (*counter).max(*next);
+    }
+    for (prefix, tombs) in &live.tombstones {
+        restored
+            .tombstones
+            .entry(*prefix)
+            .or_default()
+            .extend(tombs.iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::id::TypePrefix;
+
+    fn tmp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "pm-store-snapshots-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn names_are_restricted_to_safe_characters() {
+        assert!(validate_name("before-refactor").is_ok());
+        assert!(validate_name("v1.2_rc").is_ok());
+        assert!(validate_name("").is_err());
+        assert!(validate_name(".hidden").is_err());
+        assert!(validate_name("../escape").is_err());
+        assert!(validate_name("has space").is_err());
+    }
+
+    #[test]
+    fn write_refuses_overwrite_without_force() {
+        let dir = tmp_dir();
+        let db = Database::default();
+        write(&db, &dir, "cp", false).unwrap();
+        assert!(matches!(
+            write(&db, &dir, "cp", false),
+            Err(SnapshotError::Exists(_))
+        ));
+        assert!(write(&db, &dir, "cp", true).is_ok());
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn snapshot_round_trips_and_lists() {
+        let dir = tmp_dir();
+        let mut db = Database {
+            tasks: Vec::new(),
+            state: State::fresh(),
+        };
+        db.allocate_id(TypePrefix::Task);
+        write(&db, &dir, "b", false).unwrap();
+        write(&db, &dir, "a", false).unwrap();
+
+        let back = read(&dir, "b").unwrap();
+        assert_eq!(back.state.next.get(&TypePrefix::Task), Some(&2));
+
+        let names: Vec<String> = list(&dir).unwrap().into_iter().map(|s| s.name).collect();
+        assert_eq!(names, vec!["a".to_string(), "b".to_string()]);
+        assert!(matches!(
+            read(&dir, "missing"),
+            Err(SnapshotError::NotFound(_))
+        ));
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn merge_never_rewinds_counters() {
+        let mut restored = State::fresh();
+        let mut live = State::fresh();
+        live.allocate(TypePrefix::Task);
+        live.allocate(TypePrefix::Task);
+        live.tombstone(crate::store::LeafId::new(TypePrefix::Task, 1));
+
+        merge_allocation_history(&mut restored, &live);
+        assert_eq!(restored.next.get(&TypePrefix::Task), Some(&3));
+        assert!(restored.tombstones[&TypePrefix::Task].contains(&1));
+    }
+}
@@ -114,6 +114,18 @@ impl State {
         LeafId::new(prefix, chosen)
     }
 
+    /// The id the next [`State::allocate`] for `prefix` would return,
+    /// without advancing the counter.
+    pub fn peek(&self, prefix: TypePrefix) -> LeafId {
+        let mut number = self.next.get(&prefix).copied().unwrap_or(1);
+        if let Some(tombs) = self.tombstones.get(&prefix) {
+            while tombs.contains(&number) {
+                number += 1;
+            }
+        }
+        LeafId::new(prefix, number)
+    }
+
     /// Tombstone a leaf id and remove it from the items index. Idempotent.
     pub fn tombstone(&mut self, leaf: LeafId) {
         self.items.remove(&leaf);
@@ -243,6 +255,18 @@ mod tests {
         assert_eq!(after.to_string(), "TSK4");
     }
 
+    #[test]
+    fn peek_matches_allocate_without_advancing() {
+        let mut s = State::fresh();
+        let t1 = s.allocate(TypePrefix::Task);
+        s.tombstone(LeafId::new(TypePrefix::Task, 2));
+        let peeked = s.peek(TypePrefix::Task);
+        assert_eq!(peeked, s.peek(TypePrefix::Task));
+        assert_eq!(peeked.to_string(), "TSK3");
+        assert_eq!(s.allocate(TypePrefix::Task), peeked);
+        assert_ne!(t1, peeked);
+    }
+
     #[test]
     fn tombstone_removes_from_items() {
         let mut s = State::fresh();
@@ -351,6 +375,8 @@ mod tests {
             name: "spike".to_string(),
             title_template: Some("Spike: {title}".to_string()),
             description_template: Some("Time-boxed exploration.".to_string()),
+            user_story_template: None,
+            requirements_template: Some("- [ ] Findings written up".to_string()),
             tags: vec!["research".to_string()],
             kind: Kind::Task,
             priority_level: Some(Priority::NiceToHave),
@@ -368,6 +394,39 @@ mod tests {
         assert_eq!(t.kind, Kind::Task);
         assert_eq!(t.priority_level, Some(Priority::NiceToHave));
         assert_eq!(t.tags, vec!["research".to_string()]);
+        assert_eq!(
+            t.requirements_template.as_deref(),
+            Some("- [ ] Findings written up")
+        );
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn templates_without_text_seeds_still_load() {
+        let dir = tmp_dir();
+        let path = dir.join("state.json");
+        // A template saved before user_story/requirements seeds existed.
+        fs::write(
+            &path,
+            r#"{ "templates": [ {
+                "name": "bug",
+                "title_template": null,
+                "description_template": "Steps to reproduce",
+                "tags": ["bug"],
+                "kind": "task",
+                "priority_level": null,
+                "urgency": null,
+                "process_stage": null,
+                "status": "open"
+            } ] }"#,
+        )
+        .unwrap();
+
+        let loaded = State::load(&path).unwrap();
+        assert_eq!(loaded.templates.len(), 1);
+        assert!(loaded.templates[0].user_story_template.is_none());
+        assert!(loaded.templates[0].requirements_template.is_none());
 
         fs::remove_dir_all(&dir).ok();
     }
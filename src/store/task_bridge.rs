@@ -4,8 +4,10 @@
 //! On write, a `Task` becomes:
 //! - A [`FrontMatter`] populated from the task's metadata fields. The `id`,
 //!   `parent`, `status`, `priority`, `urgency`, `process_stage`, `due`,
-//!   `tags`, `created`, and `updated` fields map directly. `issue_link` and
-//!   `pr_link` go into the `links` map under the keys `"issue"` and `"pr"`.
+//!   `remind_days_before`, `order`, `iteration`, `pinned`, `tags`, `created`, and `updated` fields map
+//!   directly, as do `estimate` and `completed` from `estimate_minutes` and
+//!   `completed_at_utc`. `issue_link` and `pr_link` go into the `links` map under the
+//!   keys `"issue"` and `"pr"`.
 //! - A [`ParsedBody`] whose sections carry the task's prose fields: `# Summary`
 //!   for `summary`, `# Description` for `description`, `# User Story` for
 //!   `user_story`, `# Requirements` for `requirements`. Empty/`None` fields
@@ -54,6 +56,12 @@ pub fn task_to_document(task: &Task) -> (FrontMatter, ParsedBody) {
     fm.urgency = task.urgency;
     fm.process_stage = task.process_stage;
     fm.due = task.due;
+    fm.remind_days_before = task.remind_days_before;
+    fm.order = task.order;
+    fm.iteration = task.iteration.clone();
+    fm.estimate = task.estimate_minutes;
+    fm.completed = task.completed_at_utc.map(unix_to_utc);
+    fm.pinned = task.pinned;
     fm.tags = task.tags.clone();
     fm.deps = task.deps.clone();
     fm.milestone = task.milestone;
@@ -105,7 +113,13 @@ pub fn task_from_document(fm: &FrontMatter, body: &ParsedBody, artifacts: Vec<St
         milestone: fm.milestone,
         memories: fm.memories.clone(),
         due: fm.due,
+        remind_days_before: fm.remind_days_before,
         parent: fm.parent,
+        order: fm.order,
+        iteration: fm.iteration.clone(),
+        estimate_minutes: fm.estimate,
+        completed_at_utc: fm.completed.map(|c| c.timestamp()),
+        pinned: fm.pinned,
         kind: prefix_to_kind(fm.id.prefix()),
         status: fm.status,
         priority_level: fm.priority,
@@ -205,7 +219,13 @@ mod tests {
                 MemoryRef::Project("auth-stack-conventions".to_string()),
             ],
             due: NaiveDate::from_ymd_opt(2026, 5, 25),
+            remind_days_before: Some(3),
             parent: Some(LeafId::new(TypePrefix::Epic, 3)),
+            order: Some(2.5),
+            iteration: Some("2024-S3".to_string()),
+            estimate_minutes: Some(90),
+            completed_at_utc: Some(1_715_920_000),
+            pinned: true,
             kind: Kind::Task,
             status: Status::InProgress,
             priority_level: Some(Priority::MustHave),
@@ -236,6 +256,12 @@ mod tests {
         assert_eq!(back.milestone, original.milestone);
         assert_eq!(back.memories, original.memories);
         assert_eq!(back.due, original.due);
+        assert_eq!(back.remind_days_before, original.remind_days_before);
+        assert_eq!(back.order, original.order);
+        assert_eq!(back.iteration, original.iteration);
+        assert_eq!(back.estimate_minutes, original.estimate_minutes);
+        assert_eq!(back.completed_at_utc, original.completed_at_utc);
+        assert_eq!(back.pinned, original.pinned);
         assert_eq!(back.parent, original.parent);
         assert_eq!(back.kind, original.kind);
         assert_eq!(back.status, original.status);
@@ -264,7 +290,13 @@ mod tests {
             milestone: None,
             memories: Vec::new(),
             due: None,
+            remind_days_before: None,
             parent: None,
+            order: None,
+            iteration: None,
+            estimate_minutes: None,
+            completed_at_utc: None,
+            pinned: false,
             kind: Kind::Task,
             status: Status::Open,
             priority_level: None,
@@ -320,7 +352,13 @@ mod tests {
             milestone: None,
             memories: Vec::new(),
             due: None,
+            remind_days_before: None,
             parent: None,
+            order: None,
+            iteration: None,
+            estimate_minutes: None,
+            completed_at_utc: None,
+            pinned: false,
             kind: Kind::Project,
             status: Status::Open,
             priority_level: None,
@@ -380,7 +418,13 @@ mod tests {
             milestone: None,
             memories: Vec::new(),
             due: None,
+            remind_days_before: None,
             parent,
+            order: None,
+            iteration: None,
+            estimate_minutes: None,
+            completed_at_utc: None,
+            pinned: false,
             kind,
             status: Status::Open,
             priority_level: None,
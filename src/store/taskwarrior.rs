@@ -0,0 +1,344 @@
+//! Taskwarrior JSON, written by `pm export --format taskwarrior` and read by
+//! `pm import`.
+//!
+//! The file is the JSON array `task export` prints and `task import` reads.
+//! Fields with a Taskwarrior counterpart map onto it; the rest travel as
+//! `pm_*` user-defined attributes (UDAs) so a round trip through pm keeps
+//! them, while Taskwarrior shows or ignores them as its `uda.*` settings say.
+//!
+//! The README's "Taskwarrior" section has the field-by-field mapping.
+//!
+//! Taskwarrior's own `urgency` score, `id`, `start`, `wait` and recurrence
+//! have no pm field; recurring templates are skipped on import. Every task
+//! is exported with a uuid derived from its pm id and creation time, so
+//! exporting again and re-running `task import` updates the same tasks.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::bundle::TaskBundle;
+use super::id::{IdInput, LeafId};
+use crate::db::{kind_to_prefix, project_ancestor, split_and_normalise_tags, Database};
+use crate::fields::{Kind, Priority, ProcessStage, Status, Urgency};
+use crate::task::Task;
+
+/// Timestamp format of Taskwarrior's JSON: UTC, no separators.
+const TW_DATE: &str = "%Y%m%dT%H%M%SZ";
+
+/// One task as Taskwarrior's JSON has it, plus the `pm_*` UDAs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TwTask {
+    #[serde(default)]
+    pub uuid: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entry: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<TwAnnotation>,
+    /// Uuids of the tasks this one depends on. Taskwarrior before 2.6
+    /// wrote one comma-separated string; both forms are read.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "uuid_list"
+    )]
+    pub depends: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pm_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pm_kind: Option<Kind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pm_parent: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pm_status: Option<Status>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pm_stage: Option<ProcessStage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pm_urgency: Option<Urgency>,
+}
+
+/// A note attached to a Taskwarrior task.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TwAnnotation {
+    #[serde(default)]
+    pub entry: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+fn uuid_list<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Depends {
+        List(Vec<String>),
+        Joined(String),
+    }
+    Ok(match Depends::deserialize(d)? {
+        Depends::List(list) => list,
+        Depends::Joined(joined) => joined
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect(),
+    })
+}
+
+/// What [`from_taskwarrior`] left out.
+#[derive(Debug, Default)]
+pub struct TwImport {
+    /// Recurring parent tasks, which pm has no equivalent for.
+    pub skipped_recurring: usize,
+    /// Tasks whose `pm_parent` is not in the file; they import without a
+    /// parent, with their project as a tag.
+    pub detached: usize,
+}
+
+/// The stable uuid `task` is exported under: FNV-1a over its id and
+/// creation time, laid out as a version 4, variant 1 uuid.
+pub fn tw_uuid(task: &Task) -> String {
+    let key = format!("{}@{}", task.id, task.created_at_utc);
+    let fnv = |seed: u64| {
+        key.bytes().fold(seed, |h, b| {
+            (h ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+    };
+    let hi = fnv(0xcbf2_9ce4_8422_2325);
+    let lo = fnv(0x6c62_272e_07bb_0142);
+    let hi = (hi & 0xffff_ffff_ffff_0fff) | 0x4000;
+    let lo = (lo & 0x3fff_ffff_ffff_ffff) | 0x8000_0000_0000_0000;
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        hi >> 32,
+        (hi >> 16) & 0xffff,
+        hi & 0xffff,
+        lo >> 48,
+        lo & 0xffff_ffff_ffff
+    )
+}
+
+fn tw_time(secs: i64) -> Option<String> {
+    Utc.timestamp_opt(secs, 0)
+        .single()
+        .map(|t| t.format(TW_DATE).to_string())
+}
+
+fn parse_tw_time(raw: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(raw, TW_DATE)
+        .ok()
+        .map(|t| t.and_utc())
+}
+
+/// `tasks` as Taskwarrior JSON objects. Dependencies on tasks outside
+/// `tasks` still carry their uuids, which Taskwarrior keeps as dangling.
+pub fn to_taskwarrior(db: &Database, tasks: &[&Task]) -> Vec<TwTask> {
+    tasks
+        .iter()
+        .map(|t| TwTask {
+            uuid: tw_uuid(t),
+            description: t.title.clone(),
+            status: match t.status {
+                Status::Done => "completed",
+                Status::Cancelled => "deleted",
+                _ => "pending",
+            }
+            .to_string(),
+            project: project_ancestor(db, t)
+                .filter(|p| p.id != t.id)
+                .map(|p| p.title.clone()),
+            tags: t.tags.clone(),
+            priority: t.priority_level.map(|p| {
+                match p {
+                    Priority::MustHave => "H",
+                    Priority::NiceToHave => "M",
+                    Priority::CutFirst => "L",
+                }
+                .to_string()
+            }),
+            due: t
+                .due
+                .map(|d| d.and_hms_opt(0, 0, 0).unwrap().format(TW_DATE).to_string()),
+            entry: tw_time(t.created_at_utc),
+            modified: tw_time(t.updated_at_utc),
+            end: match t.status {
+                Status::Done => t.completed_at_utc.or(Some(t.updated_at_utc)),
+                Status::Cancelled => Some(t.updated_at_utc),
+                _ => None,
+            }
+            .and_then(tw_time),
+            annotations: t
+                .description
+                .iter()
+                .filter(|d| !d.trim().is_empty())
+                .map(|d| TwAnnotation {
+                    entry: tw_time(t.created_at_utc).unwrap_or_default(),
+                    description: d.clone(),
+                })
+                .collect(),
+            depends: t
+                .deps
+                .iter()
+                .filter_map(|&d| db.get(d))
+                .map(tw_uuid)
+                .collect(),
+            pm_id: Some(t.id.to_string()),
+            pm_kind: Some(t.kind),
+            pm_parent: t.parent.map(|p| p.to_string()),
+            pm_status: Some(t.status),
+            pm_stage: t.process_stage,
+            pm_urgency: t.urgency,
+        })
+        .collect()
+}
+
+/// Turn a Taskwarrior export into a [`TaskBundle`] for
+/// [`TaskBundle::import_into`]. Parents are linked through `pm_parent` and
+/// dependencies through `depends` when the other end is in the same file.
+pub fn from_taskwarrior(items: Vec<TwTask>) -> (TaskBundle, TwImport) {
+    let mut report = TwImport::default();
+    let items: Vec<TwTask> = items
+        .into_iter()
+        .filter(|item| {
+            let recurring = item.status == "recurring";
+            report.skipped_recurring += usize::from(recurring);
+            !recurring
+        })
+        .collect();
+
+    // The bundle only needs ids that are unique within it; import
+    // allocates the real ones. Number every task by its position.
+    let ids: Vec<LeafId> = items
+        .iter()
+        .enumerate()
+        .map(|(n, item)| {
+            LeafId::new(
+                kind_to_prefix(item.pm_kind.unwrap_or(Kind::Task)),
+                n as u64 + 1,
+            )
+        })
+        .collect();
+    let by_pm_id: HashMap<LeafId, LeafId> = items
+        .iter()
+        .zip(&ids)
+        .filter_map(|(item, &id)| {
+            let pm_id = item.pm_id.as_deref()?.parse::<IdInput>().ok()?.leaf();
+            Some((pm_id, id))
+        })
+        .collect();
+    let by_uuid: HashMap<&str, LeafId> = items
+        .iter()
+        .zip(&ids)
+        .filter(|(item, _)| !item.uuid.is_empty())
+        .map(|(item, &id)| (item.uuid.as_str(), id))
+        .collect();
+
+    let now = Utc::now().timestamp();
+    let mut detached = 0;
+    let tasks = items
+        .iter()
+        .zip(&ids)
+        .map(|(item, &id)| {
+            let parent = item
+                .pm_parent
+                .as_deref()
+                .and_then(|p| p.parse::<IdInput>().ok())
+                .and_then(|p| by_pm_id.get(&p.leaf()).copied());
+            detached += usize::from(item.pm_parent.is_some() && parent.is_none());
+            let mut tags = item.tags.clone();
+            if parent.is_none() {
+                tags.extend(item.project.clone());
+            }
+            // Taskwarrior's own status wins: `pm_status` only says which
+            // kind of open a `pending` task is, so a task completed,
+            // deleted or reopened in Taskwarrior comes back that way.
+            let status = match (item.status.as_str(), item.pm_status) {
+                ("completed", _) => Status::Done,
+                ("deleted", _) => Status::Cancelled,
+                (_, Some(status)) if !status.is_closed() => status,
+                _ => Status::Open,
+            };
+            let time = |raw: &Option<String>| {
+                raw.as_deref()
+                    .and_then(parse_tw_time)
+                    .map(|t| t.timestamp())
+            };
+            let created = time(&item.entry).unwrap_or(now);
+            let description = item
+                .annotations
+                .iter()
+                .map(|a| a.description.as_str())
+                .collect::<Vec<_>>()
+                .join("\n");
+            Task {
+                id,
+                title: item.description.clone(),
+                summary: None,
+                description: (!description.is_empty()).then_some(description),
+                user_story: None,
+                requirements: None,
+                tags: split_and_normalise_tags(&tags),
+                deps: item
+                    .depends
+                    .iter()
+                    .filter_map(|u| by_uuid.get(u.as_str()).copied())
+                    .collect(),
+                milestone: None,
+                memories: Vec::new(),
+                due: item
+                    .due
+                    .as_deref()
+                    .and_then(parse_tw_time)
+                    .map(|t| t.date_naive())
+                    .or_else(|| {
+                        item.due
+                            .as_deref()
+                            .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+                    }),
+                remind_days_before: None,
+                parent,
+                order: None,
+                iteration: None,
+                estimate_minutes: None,
+                completed_at_utc: if status == Status::Done {
+                    time(&item.end).or(Some(created))
+                } else {
+                    None
+                },
+                pinned: false,
+                kind: item.pm_kind.unwrap_or(Kind::Task),
+                status,
+                priority_level: item.priority.as_deref().and_then(|p| match p {
+                    "H" => Some(Priority::MustHave),
+                    "M" => Some(Priority::NiceToHave),
+                    "L" => Some(Priority::CutFirst),
+                    _ => None,
+                }),
+                urgency: item.pm_urgency,
+                process_stage: item.pm_stage,
+                issue_link: None,
+                pr_link: None,
+                artifacts: Vec::new(),
+                created_at_utc: created,
+                updated_at_utc: time(&item.modified).unwrap_or(created),
+            }
+        })
+        .collect();
+    report.detached = detached;
+    (TaskBundle::from_tasks(tasks), report)
+}
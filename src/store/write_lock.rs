@@ -0,0 +1,142 @@
+//! Workspace-wide write lock at `.pm/write.lock`.
+//!
+//! Every write goes through a full load-modify-save of the workspace, so two
+//! `pm` processes saving at once would each write back the tickets they
+//! loaded and drop the other's changes. Holding an exclusive advisory lock
+//! on `write.lock` around the whole cycle serialises them.
+//!
+//! The CLI takes the lock before loading and keeps it until it exits, except
+//! for read-only commands, which skip it, and commands that wait on stdin,
+//! which hold it only while loading and rely on [`guard_saves`] instead. The
+//! TUI and the MCP server only take it inside [`Database::save`]; the TUI
+//! saves through [`Database::save_since`], which replays its edits onto
+//! anything the CLI saved while it was open. The lock
+//! is re-entrant within a process, so a save made while the CLI already
+//! holds it does not wait on itself. A busy lock is retried with a short
+//! backoff for [`LOCK_WAIT`] before giving up with [`WriteLockError::Busy`].
+//!
+//! Unrelated to the per-ticket checkout locks in [`super::locks`], which
+//! are claims on work rather than a guard on the files.
+//!
+//! [`Database::save`]: crate::db::Database::save
+//! [`Database::save_since`]: crate::db::Database::save_since
+//! [`guard_saves`]: crate::db::guard_saves
+
+use std::collections::HashMap;
+use std::fs::{self, File, TryLockError};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::layout::Layout;
+
+/// How long to keep retrying a lock held by another process.
+pub const LOCK_WAIT: Duration = Duration::from_secs(5);
+
+/// First retry delay; doubled after each attempt up to [`MAX_BACKOFF`].
+const FIRST_BACKOFF: Duration = Duration::from_millis(10);
+const MAX_BACKOFF: Duration = Duration::from_millis(250);
+
+/// Locks this process holds, by lock path, with their nesting depth. The
+/// open file is what carries the OS lock, so it lives here until the
+/// outermost guard drops.
+static HELD: Mutex<Option<HashMap<PathBuf, (File, usize)>>> = Mutex::new(None);
+
+/// Errors taking the write lock.
+#[derive(Debug)]
+pub enum WriteLockError {
+    /// Another process kept the lock for the whole of [`LOCK_WAIT`].
+    Busy { path: PathBuf, waited: Duration },
+    /// The lock file could not be created or locked.
+    Io(PathBuf, std::io::Error),
+}
+
+impl std::fmt::Display for WriteLockError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WriteLockError::Busy { path, waited } => write!(
+                f,
+                "another pm process is writing to this workspace ({} is locked); \
+                 gave up after {}s, try again once it finishes",
+                path.display(),
+                waited.as_secs()
+            ),
+            WriteLockError::Io(path, e) => write!(f, "cannot lock {}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for WriteLockError {}
+
+/// Proof the workspace write lock is held. Released on drop.
+#[derive(Debug)]
+pub struct WriteLock {
+    path: PathBuf,
+}
+
+impl WriteLock {
+    /// Take the write lock for the workspace at `pm_dir`, waiting up to
+    /// [`LOCK_WAIT`] for another process to release it. Creates `pm_dir`
+    /// and the lock file if needed.
+    pub fn acquire(pm_dir: &Path) -> Result<WriteLock, WriteLockError> {
+        // The same workspace may be reached by relative and absolute paths.
+        let root = fs::create_dir_all(pm_dir)
+            .and_then(|()| fs::canonicalize(pm_dir))
+            .map_err(|e| WriteLockError::Io(pm_dir.to_path_buf(), e))?;
+        let path = Layout::at(root).write_lock_path();
+        if let Some((_, depth)) = held().get_or_insert_with(HashMap::new).get_mut(&path) {
+            *depth += 1;
+            return Ok(WriteLock { path });
+        }
+
+        let io_err = |e| WriteLockError::Io(path.clone(), e);
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .map_err(io_err)?;
+
+        let started = Instant::now();
+        let mut backoff = FIRST_BACKOFF;
+        loop {
+            match file.try_lock() {
+                Ok(()) => break,
+                Err(TryLockError::WouldBlock) if started.elapsed() < LOCK_WAIT => {
+                    thread::sleep(backoff);
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                }
+                Err(TryLockError::WouldBlock) => {
+                    return Err(WriteLockError::Busy {
+                        path,
+                        waited: started.elapsed(),
+                    })
+                }
+                Err(TryLockError::Error(e)) => return Err(io_err(e)),
+            }
+        }
+        held()
+            .get_or_insert_with(HashMap::new)
+            .insert(path.clone(), (file, 1));
+        Ok(WriteLock { path })
+    }
+}
+
+fn held() -> MutexGuard<'static, Option<HashMap<PathBuf, (File, usize)>>> {
+    HELD.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl Drop for WriteLock {
+    fn drop(&mut self) {
+        let mut held = held();
+        let Some(held) = held.as_mut() else { return };
+        if let Some((_, depth)) = held.get_mut(&self.path) {
+            *depth -= 1;
+            if *depth == 0 {
+                // Closing the file releases the OS lock.
+                held.remove(&self.path);
+            }
+        }
+    }
+}
@@ -17,7 +17,7 @@ use crate::store::MemoryRef;
 /// Tasks support hierarchical organisation (Project > Product > Epic > Task >
 /// Subtask) plus cross-cutting milestones. Project membership is derived from
 /// the parent chain on disk; there is no separate label field.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Task {
     pub id: LeafId,
     pub title: String,
@@ -37,7 +37,27 @@ pub struct Task {
     #[serde(default)]
     pub memories: Vec<MemoryRef>,
     pub due: Option<NaiveDate>,
+    /// Days before `due` the task enters its reminder window.
+    #[serde(default)]
+    pub remind_days_before: Option<u32>,
     pub parent: Option<LeafId>,
+    /// Manual position among siblings. Unset tasks sit at their id number,
+    /// so siblings without one keep id order.
+    #[serde(default)]
+    pub order: Option<f64>,
+    /// Sprint or iteration the task is planned into, e.g. `2024-S3`.
+    #[serde(default)]
+    pub iteration: Option<String>,
+    /// Estimated effort in minutes (`pm add --estimate 1h30m`).
+    #[serde(default)]
+    pub estimate_minutes: Option<u32>,
+    /// When the task was marked Done. Unlike `updated_at_utc`, later edits
+    /// leave it alone; cleared again if the task is reopened.
+    #[serde(default)]
+    pub completed_at_utc: Option<i64>,
+    /// In the working set (`pm pin`): listed first under any sort.
+    #[serde(default)]
+    pub pinned: bool,
     pub kind: Kind,
     pub status: Status,
     pub priority_level: Option<Priority>,
@@ -52,11 +72,18 @@ pub struct Task {
 }
 
 /// A template for creating tasks with predefined values.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskTemplate {
     pub name: String,
     pub title_template: Option<String>,
     pub description_template: Option<String>,
+    /// Seed text for the fullscreen user-story editor.
+    #[serde(default)]
+    pub user_story_template: Option<String>,
+    /// Seed text for the fullscreen requirements editor, typically an
+    /// acceptance-criteria checklist.
+    #[serde(default)]
+    pub requirements_template: Option<String>,
     pub tags: Vec<String>,
     pub kind: Kind,
     pub priority_level: Option<Priority>,
@@ -64,3 +91,58 @@ pub struct TaskTemplate {
     pub process_stage: Option<ProcessStage>,
     pub status: Status,
 }
+
+/// What a [`TaskTemplate`]'s placeholders stand for when `pm add
+/// --template` creates a task.
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateValues<'a> {
+    /// `{title}`: the title given to `pm add`.
+    pub title: &'a str,
+    /// `{date}`: today, as `YYYY-MM-DD`.
+    pub date: NaiveDate,
+    /// `{project}`: title of the project the task is added under, or empty.
+    pub project: &'a str,
+}
+
+/// Replace the `{title}`, `{date}` and `{project}` placeholders in `text`.
+/// Other braces are left as written, and substituted values are not
+/// scanned again, so a title containing `{date}` stays as typed.
+pub fn fill_placeholders(text: &str, values: &TemplateValues) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        rest = &rest[open..];
+        let value = rest.find('}').and_then(|close| match &rest[1..close] {
+            "title" => Some((values.title.to_string(), close)),
+            "date" => Some((values.date.format("%Y-%m-%d").to_string(), close)),
+            "project" => Some((values.project.to_string(), close)),
+            _ => None,
+        });
+        match value {
+            Some((value, close)) => {
+                out.push_str(&value);
+                rest = &rest[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+impl TaskTemplate {
+    /// The title of a task added as `title` with this template. A title
+    /// template without `{title}` can't take the given title, so it is
+    /// ignored; this covers templates saved from a task with `pm template
+    /// save`, whose title template is that task's title.
+    pub fn fill_title(&self, values: &TemplateValues) -> String {
+        match &self.title_template {
+            Some(template) if template.contains("{title}") => fill_placeholders(template, values),
+            _ => values.title.to_string(),
+        }
+    }
+}
@@ -0,0 +1,396 @@
+//! Vim-style `:` command line for the ticket list. `:` switches the list into
+//! `InputMode::Command`; this module owns the keystrokes while the line is
+//! being typed (Tab completes command names), parses the confirmed line into
+//! a [`TuiCommand`], and applies it through the same database helpers the
+//! single-key bindings use.
+//!
+//! Mutating commands act on the highlighted ticket. A leading `%` (Vim's
+//! whole-buffer range) applies them to every visible row instead:
+//! `:%complete`, `:%tag release`.
+
+use clap::ValueEnum;
+use crossterm::event::KeyCode;
+
+use crate::db::{
+    advance_stage, format_due_filter, format_kind, format_sort_key, format_status,
+    split_and_normalise_tags, validate_hierarchy,
+};
+use crate::fields::{DueFilter, Kind, SortKey, Status};
+use crate::store::{events, Config, LeafId};
+use crate::tui::enums::{AppState, EditAction, InputMode};
+
+use super::App;
+
+/// Command names offered by Tab completion, in completion order.
+const COMMANDS: &[&str] = &[
+    "complete", "delete", "filter", "kind", "quit", "sort", "status", "tag", "untag",
+];
+
+/// A parsed `:` command line.
+#[derive(Debug)]
+enum TuiCommand {
+    Complete {
+        all: bool,
+    },
+    Delete,
+    Status {
+        all: bool,
+        status: Status,
+    },
+    Kind {
+        all: bool,
+        kind: Kind,
+    },
+    Tag {
+        all: bool,
+        tags: Vec<String>,
+    },
+    Untag {
+        all: bool,
+        tags: Vec<String>,
+    },
+    /// `None` restores database order (`:sort off`).
+    Sort(Option<SortKey>),
+    /// `None` clears the due filter (`:filter off`).
+    Filter(Option<DueFilter>),
+    Quit,
+}
+
+/// Parse a clap value-enum argument case-insensitively, listing the accepted
+/// spellings on failure.
+fn parse_value<T: ValueEnum>(cmd: &str, arg: Option<&str>) -> Result<T, String> {
+    let choices = || {
+        T::value_variants()
+            .iter()
+            .filter_map(|v| v.to_possible_value())
+            .map(|v| v.get_name().to_string())
+            .collect::<Vec<_>>()
+            .join(" | ")
+    };
+    let Some(arg) = arg else {
+        return Err(format!(":{cmd} needs one of {}", choices()));
+    };
+    T::from_str(arg, true).map_err(|_| format!(":{cmd}: unknown value '{arg}' ({})", choices()))
+}
+
+/// Parse a command line (without the leading `:`). Unique prefixes of
+/// command names are accepted, so `:comp` runs `:complete`.
+fn parse_command(line: &str) -> Result<TuiCommand, String> {
+    let line = line.trim();
+    let (all, line) = match line.strip_prefix('%') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, line),
+    };
+    let mut words = line.split_whitespace();
+    let Some(word) = words.next() else {
+        return Err("Empty command".to_string());
+    };
+    let name = match word {
+        "q" => "quit",
+        "done" => "complete",
+        _ => resolve_command_name(word)?,
+    };
+    let arg = words.next();
+    let rest: Vec<String> = arg.into_iter().chain(words).map(str::to_string).collect();
+
+    let off = matches!(arg, Some("off" | "clear"));
+    let cmd = match name {
+        "complete" => TuiCommand::Complete { all },
+        "delete" => TuiCommand::Delete,
+        "status" => TuiCommand::Status {
+            all,
+            status: parse_value(name, arg)?,
+        },
+        "kind" => TuiCommand::Kind {
+            all,
+            kind: parse_value(name, arg)?,
+        },
+        "tag" | "untag" => {
+            let tags = split_and_normalise_tags(&rest);
+            if tags.is_empty() {
+                return Err(format!(":{name} needs at least one tag"));
+            }
+            if name == "tag" {
+                TuiCommand::Tag { all, tags }
+            } else {
+                TuiCommand::Untag { all, tags }
+            }
+        }
+        "sort" if off => TuiCommand::Sort(None),
+        "sort" => TuiCommand::Sort(Some(parse_value(name, arg)?)),
+        "filter" if off => TuiCommand::Filter(None),
+        "filter" => TuiCommand::Filter(Some(parse_value(name, arg)?)),
+        "quit" => TuiCommand::Quit,
+        _ => unreachable!("resolve_command_name only returns COMMANDS entries"),
+    };
+    if all && matches!(cmd, TuiCommand::Delete) {
+        return Err(":%delete is not supported; delete tickets one at a time".to_string());
+    }
+    Ok(cmd)
+}
+
+/// Expand a typed command word to the single command it is a prefix of.
+fn resolve_command_name(word: &str) -> Result<&'static str, String> {
+    if let Some(exact) = COMMANDS.iter().find(|c| **c == word) {
+        return Ok(exact);
+    }
+    let matches: Vec<&'static str> = COMMANDS
+        .iter()
+        .copied()
+        .filter(|c| c.starts_with(word))
+        .collect();
+    match matches.as_slice() {
+        [one] => Ok(one),
+        [] => Err(format!("Unknown command ':{word}'")),
+        many => Err(format!("Ambiguous ':{word}': {}", many.join(", "))),
+    }
+}
+
+/// Tab-complete the command name in `buffer`. Returns the completed buffer
+/// and, when the prefix is still ambiguous, the candidates to show.
+fn complete_command(buffer: &str) -> (String, Vec<&'static str>) {
+    let (range, word) = match buffer.strip_prefix('%') {
+        Some(rest) => ("%", rest),
+        None => ("", buffer),
+    };
+    // Only the command word completes; arguments are left alone.
+    if word.contains(char::is_whitespace) {
+        return (buffer.to_string(), Vec::new());
+    }
+    let candidates: Vec<&'static str> = COMMANDS
+        .iter()
+        .copied()
+        .filter(|c| c.starts_with(word))
+        .collect();
+    match candidates.as_slice() {
+        [] => (buffer.to_string(), Vec::new()),
+        [one] => (format!("{range}{one} "), Vec::new()),
+        many => {
+            // Extend to the longest prefix shared by every candidate.
+            let mut common = many[0].to_string();
+            for c in &many[1..] {
+                let shared = common
+                    .chars()
+                    .zip(c.chars())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                common.truncate(shared);
+            }
+            (format!("{range}{common}"), many.to_vec())
+        }
+    }
+}
+
+impl App {
+    /// Handle a keystroke while the `:` command line is open.
+    ///
+    /// Returns true if the command asked the application to quit.
+    pub(super) fn handle_command_input(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::None;
+                self.command_buffer.clear();
+            }
+            KeyCode::Enter => {
+                self.input_mode = InputMode::None;
+                let line = std::mem::take(&mut self.command_buffer);
+                match parse_command(&line) {
+                    Ok(cmd) => return self.run_command(cmd),
+                    Err(e) => self.set_status_message(e),
+                }
+            }
+            KeyCode::Tab => {
+                let (completed, candidates) = complete_command(&self.command_buffer);
+                self.command_buffer = completed;
+                if candidates.is_empty() {
+                    self.clear_status_message();
+                } else {
+                    self.set_status_message(candidates.join("  "));
+                }
+            }
+            // Backspace on an empty line leaves command mode, as in Vim.
+            KeyCode::Backspace if self.command_buffer.is_empty() => {
+                self.input_mode = InputMode::None;
+            }
+            KeyCode::Backspace => {
+                self.command_buffer.pop();
+            }
+            KeyCode::Char(c) => self.command_buffer.push(c),
+            _ => {}
+        }
+        false
+    }
+
+    /// Tickets a mutating command applies to: every visible row for `%`,
+    /// otherwise the highlighted one.
+    fn command_targets(&self, all: bool) -> Vec<LeafId> {
+        if all {
+            self.filtered_tasks.clone()
+        } else {
+            self.selected_task_id().into_iter().collect()
+        }
+    }
+
+    /// Apply a parsed command. Returns true for `:quit`.
+    fn run_command(&mut self, cmd: TuiCommand) -> bool {
+        match cmd {
+            TuiCommand::Quit => return true,
+            TuiCommand::Sort(key) => {
+                self.list_sort = key;
+                self.update_filtered_tasks();
+                self.set_status_message(match key {
+                    Some(k) => format!("Sorted by {} (Done last)", format_sort_key(k)),
+                    None => "Sort cleared".to_string(),
+                });
+            }
+            TuiCommand::Filter(df) => {
+                self.due_filter = df;
+                self.update_filtered_tasks();
+                self.set_status_message(match df {
+                    Some(f) => format!(
+                        "Due filter {} ({} tasks)",
+                        format_due_filter(f),
+                        self.filtered_tasks.len()
+                    ),
+                    None => "Due filter cleared".to_string(),
+                });
+            }
+            TuiCommand::Delete => match self.selected_task_id() {
+                Some(task_id) => {
+                    // Same confirmation path as the `d` key.
+                    self.selected_task = Some(task_id);
+                    self.confirm_action = Some(format!("Delete task #{}", task_id));
+                    self.state = AppState::Confirm;
+                }
+                None => self.set_status_message("No ticket selected".to_string()),
+            },
+            TuiCommand::Complete { all: false } => match self.selected_task_id() {
+                Some(id) => self.run_edit(id, EditAction::Complete),
+                None => self.set_status_message("No ticket selected".to_string()),
+            },
+            TuiCommand::Complete { all: true } => {
+                let targets = self.command_targets(true);
+                if self.completion_blocked(&targets)
+                    || self.status_flow_blocked(&targets, Status::Done)
+                {
+                    return false;
+                }
+                self.apply_to_targets(true, "complete", "Completed", |t| {
+                    t.status = Status::Done;
+                });
+                self.last_edit = Some(EditAction::Complete);
+            }
+            TuiCommand::Status { all, status } => {
+                let targets = self.command_targets(all);
+                if status == Status::Done && self.completion_blocked(&targets) {
+                    return false;
+                }
+                if self.status_flow_blocked(&targets, status) {
+                    return false;
+                }
+                let summary = format!("Status {}", format_status(status));
+                self.apply_to_targets(all, "update", &summary, |t| {
+                    t.status = status;
+                });
+                self.note_edit(":status");
+            }
+            TuiCommand::Kind { all, kind } => {
+                let parent_kinds: Vec<(LeafId, Option<Kind>)> = self
+                    .command_targets(all)
+                    .into_iter()
+                    .map(|id| {
+                        let parent = self.db.get(id).and_then(|t| t.parent);
+                        (id, parent.and_then(|p| self.db.get(p)).map(|p| p.kind))
+                    })
+                    .collect();
+                if let Some((id, Some(pk))) = parent_kinds
+                    .iter()
+                    .find(|(_, pk)| pk.is_some_and(|pk| !validate_hierarchy(pk, kind)))
+                {
+                    self.set_status_message(format!(
+                        "{id}: {} cannot be child of {}",
+                        format_kind(kind),
+                        format_kind(*pk)
+                    ));
+                    return false;
+                }
+                let summary = format!("Kind {}", format_kind(kind));
+                self.apply_to_targets(all, "update", &summary, |t| {
+                    t.kind = kind;
+                });
+                self.note_edit(":kind");
+            }
+            TuiCommand::Tag { all: false, tags } => match self.selected_task_id() {
+                Some(id) => self.run_edit(id, EditAction::AddTags(tags)),
+                None => self.set_status_message("No ticket selected".to_string()),
+            },
+            TuiCommand::Tag { all: true, tags } => {
+                self.apply_to_targets(true, "update", "Tagged", |t| {
+                    for tag in &tags {
+                        if !t.tags.contains(tag) {
+                            t.tags.push(tag.clone());
+                        }
+                    }
+                    t.tags.sort();
+                });
+                self.last_edit = Some(EditAction::AddTags(tags));
+            }
+            TuiCommand::Untag { all, tags } => {
+                self.apply_to_targets(all, "update", "Untagged", |t| {
+                    t.tags.retain(|existing| !tags.contains(existing));
+                });
+                self.note_edit(":untag");
+            }
+        }
+        false
+    }
+
+    /// Run `edit` against each target ticket, save once, and emit `verb` per
+    /// ticket. Reports `summary` with the ticket (or count) in the status bar.
+    fn apply_to_targets(
+        &mut self,
+        all: bool,
+        verb: &str,
+        summary: &str,
+        edit: impl FnMut(&mut crate::task::Task),
+    ) {
+        let targets = self.command_targets(all);
+        self.apply_to_ids(&targets, verb, summary, edit);
+    }
+
+    /// [`Self::apply_to_targets`] for an explicit set of tickets. A status
+    /// the edit changes also moves the stage per `workflow.stage_on_status`.
+    pub(super) fn apply_to_ids(
+        &mut self,
+        targets: &[LeafId],
+        verb: &str,
+        summary: &str,
+        mut edit: impl FnMut(&mut crate::task::Task),
+    ) {
+        if targets.is_empty() {
+            self.set_status_message("No ticket selected".to_string());
+            return;
+        }
+        let now = chrono::Utc::now().timestamp();
+        let workflow = Config::load(&self.db_path).workflow;
+        for &id in targets {
+            if let Some(task) = self.db.get_mut(id) {
+                let from = task.status;
+                edit(task);
+                advance_stage(task, from, &workflow);
+                task.updated_at_utc = now;
+            }
+        }
+        if let Err(e) = self.save_db() {
+            self.set_status_message(format!("Error saving: {}", e));
+            return;
+        }
+        for &id in targets {
+            let _ = events::emit_event(&self.pm_dir, verb, Some(id), None);
+        }
+        self.set_status_message(match targets {
+            [one] => format!("{summary}: {one}"),
+            many => format!("{summary}: {} tickets", many.len()),
+        });
+    }
+}
@@ -49,6 +49,7 @@ impl App {
 
             self.db.remove_ids(&to_delete);
             self.save_db()?;
+            self.note_edit("a delete");
             self.set_status_message(format!("Deleted {} task(s)", to_delete.len()));
         }
         Ok(())
@@ -2,6 +2,11 @@
 //! Story and Requirements prose fields, which the slimmer Phase 8 quick-entry
 //! form does not surface. Owns the dialog cursor model, its keystroke
 //! handling, and the rendering of the editor with instruction footer.
+//!
+//! Long lines soft-wrap on screen (Ctrl+W switches to horizontal scrolling
+//! instead) beside a line-number gutter (Ctrl+L hides it). The cursor is
+//! kept as a logical line and byte offset into `dialog_text`; rows on
+//! screen come from [`editor_rows`].
 
 use std::io;
 
@@ -9,12 +14,14 @@ use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style},
-    text::Line,
+    text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
+use crate::db::format_text_stats;
 use crate::tui::enums::{AppState, InputMode};
+use crate::tui::utils::{editor_cursor, editor_offset, editor_rows, EditorRow};
 
 use super::App;
 
@@ -44,6 +51,13 @@ impl App {
                 };
                 self.input_mode = InputMode::Text;
             }
+            KeyCode::Char('w') if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.dialog_wrap = !self.dialog_wrap;
+                self.dialog_scroll_x = 0;
+            }
+            KeyCode::Char('l') if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.dialog_line_numbers = !self.dialog_line_numbers;
+            }
             KeyCode::Char(c) => {
                 // Insert character at cursor position
                 let cursor_pos = self.get_dialog_cursor_position();
@@ -157,28 +171,49 @@ impl App {
         }
     }
 
-    /// Move the dialog cursor up by one line.
+    /// Move the dialog cursor up by one row on screen: a wrapped row of the
+    /// same line when wrapping, otherwise the line above.
     pub(super) fn move_dialog_cursor_up(&mut self) {
-        if self.dialog_cursor_y > 0 {
-            self.dialog_cursor_y -= 1;
-            let lines: Vec<&str> = self.dialog_text.lines().collect();
-            if let Some(new_line) = lines.get(self.dialog_cursor_y) {
-                self.dialog_cursor_x = self.dialog_cursor_x.min(new_line.len());
-            }
-        }
+        self.move_dialog_cursor_rows(false);
     }
 
-    /// Move the dialog cursor down by one line.
+    /// Move the dialog cursor down by one row on screen.
     pub(super) fn move_dialog_cursor_down(&mut self) {
-        let lines: Vec<&str> = self.dialog_text.lines().collect();
-        if self.dialog_cursor_y + 1 < lines.len() {
-            self.dialog_cursor_y += 1;
-            if let Some(new_line) = lines.get(self.dialog_cursor_y) {
-                self.dialog_cursor_x = self.dialog_cursor_x.min(new_line.len());
+        self.move_dialog_cursor_rows(true);
+    }
+
+    fn move_dialog_cursor_rows(&mut self, down: bool) {
+        let rows = self.dialog_rows();
+        let (row, col) = editor_cursor(
+            &rows,
+            &self.dialog_text,
+            self.dialog_cursor_y,
+            self.dialog_cursor_x,
+        );
+        let target = if down {
+            row + 1
+        } else {
+            match row.checked_sub(1) {
+                Some(target) => target,
+                None => return,
             }
+        };
+        if target < rows.len() {
+            (self.dialog_cursor_y, self.dialog_cursor_x) =
+                editor_offset(&rows, &self.dialog_text, target, col);
         }
     }
 
+    /// The editor's rows at the width measured by the last render. One
+    /// column is kept free so the cursor fits after a full row.
+    fn dialog_rows(&self) -> Vec<EditorRow> {
+        editor_rows(
+            &self.dialog_text,
+            self.dialog_text_width.saturating_sub(1),
+            self.dialog_wrap,
+        )
+    }
+
     /// Initialize dialog cursor position when opening a dialog.
     pub(super) fn init_dialog_cursor(&mut self) {
         let lines: Vec<&str> = self.dialog_text.lines().collect();
@@ -190,6 +225,7 @@ impl App {
             self.dialog_cursor_x = lines.last().unwrap_or(&"").len();
         }
         self.dialog_scroll_y = 0;
+        self.dialog_scroll_x = 0;
     }
 
     /// Render a fullscreen text editing dialog for user stories or requirements.
@@ -201,30 +237,71 @@ impl App {
 
         // Main text area
         let block = Block::default()
-            .title(format!("{} - Fullscreen Editor", title))
+            .title(format!(
+                "{} - Fullscreen Editor{}",
+                title,
+                if self.dialog_wrap { "" } else { " (no wrap)" }
+            ))
             .borders(Borders::ALL)
             .style(Style::default().fg(Color::White).bg(Color::Blue));
 
         let inner = block.inner(chunks[0]);
         f.render_widget(block, chunks[0]);
 
-        // Split text into lines and handle scrolling
-        let lines: Vec<&str> = self.dialog_text.lines().collect();
+        // The gutter fits the highest line number plus a space.
+        let lines: Vec<&str> = self.dialog_text.split('\n').collect();
+        let gutter = if self.dialog_line_numbers {
+            lines.len().to_string().len() + 1
+        } else {
+            0
+        };
+        let text_width = (inner.width as usize).saturating_sub(gutter);
+        self.dialog_text_width = text_width;
+        let rows = self.dialog_rows();
+        let (cursor_row, cursor_col) = editor_cursor(
+            &rows,
+            &self.dialog_text,
+            self.dialog_cursor_y,
+            self.dialog_cursor_x,
+        );
         let visible_height = inner.height as usize;
 
         // Adjust scroll to keep cursor visible
-        if self.dialog_cursor_y >= self.dialog_scroll_y + visible_height {
-            self.dialog_scroll_y = self.dialog_cursor_y.saturating_sub(visible_height - 1);
-        } else if self.dialog_cursor_y < self.dialog_scroll_y {
-            self.dialog_scroll_y = self.dialog_cursor_y;
+        if cursor_row >= self.dialog_scroll_y + visible_height {
+            self.dialog_scroll_y = cursor_row.saturating_sub(visible_height.saturating_sub(1));
+        } else if cursor_row < self.dialog_scroll_y {
+            self.dialog_scroll_y = cursor_row;
+        }
+        if self.dialog_wrap {
+            self.dialog_scroll_x = 0;
+        } else if cursor_col >= self.dialog_scroll_x + text_width {
+            self.dialog_scroll_x = cursor_col.saturating_sub(text_width.saturating_sub(1));
+        } else if cursor_col < self.dialog_scroll_x {
+            self.dialog_scroll_x = cursor_col;
         }
 
-        // Get visible lines based on scroll position
-        let visible_lines: Vec<Line> = lines
+        // Get visible rows based on scroll position; continuation rows of
+        // a wrapped line leave the gutter blank.
+        let gutter_style = Style::default().fg(Color::Cyan);
+        let visible_lines: Vec<Line> = rows
             .iter()
             .skip(self.dialog_scroll_y)
             .take(visible_height)
-            .map(|&line| Line::from(line))
+            .map(|row| {
+                let number = if gutter == 0 {
+                    String::new()
+                } else if row.start == 0 {
+                    format!("{:>width$} ", row.line + 1, width = gutter - 1)
+                } else {
+                    " ".repeat(gutter)
+                };
+                let text: String = lines[row.line][row.start..row.end]
+                    .chars()
+                    .skip(self.dialog_scroll_x)
+                    .take(text_width)
+                    .collect();
+                Line::from(vec![Span::styled(number, gutter_style), Span::raw(text)])
+            })
             .collect();
 
         let paragraph = Paragraph::new(visible_lines);
@@ -232,20 +309,24 @@ impl App {
 
         // Instructions with improved text
         let instructions = Paragraph::new(
-            "Arrow keys to navigate • Type to edit • Enter for new line • Backspace/Delete • Ctrl+Backspace/Delete to clear all • Home/End • Esc to save and return",
+            "Arrow keys to navigate • Type to edit • Enter for new line • Backspace/Delete • Ctrl+Backspace/Delete to clear all • Home/End • Ctrl+W wrap • Ctrl+L line numbers • Esc to save and return",
         )
-        .block(Block::default().borders(Borders::ALL).title("Instructions"))
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "Instructions - {}",
+            format_text_stats(&self.dialog_text)
+        )))
         .alignment(Alignment::Center);
         f.render_widget(instructions, chunks[1]);
 
         // Calculate cursor position relative to visible area
-        let cursor_y_visible = self.dialog_cursor_y.saturating_sub(self.dialog_scroll_y);
-        let cursor_x_clamped = self.dialog_cursor_x.min(inner.width as usize);
+        let cursor_y_visible = cursor_row.saturating_sub(self.dialog_scroll_y);
+        let cursor_x_visible = (gutter + cursor_col.saturating_sub(self.dialog_scroll_x))
+            .min((inner.width as usize).saturating_sub(1));
 
         // Only show cursor if it's in the visible area
         if cursor_y_visible < visible_height {
             f.set_cursor_position((
-                inner.x + cursor_x_clamped as u16,
+                inner.x + cursor_x_visible as u16,
                 inner.y + cursor_y_visible as u16,
             ));
         }
@@ -1,10 +1,15 @@
 //! Filtered task list maintenance. Owns `update_filtered_tasks` (recomputes
 //! `App.filtered_tasks` from the current `Database` against the active
-//! navigation context, completion-visibility toggle, and text filter) and
-//! `refresh_tasks` (reload from disk + refilter).
+//! navigation context, `pm focus` subtree, completion-visibility toggles, `:filter` / `:sort`
+//! choices, and text filter), `refresh_tasks` (reload from disk + refilter)
+//! and `reload_from_disk`, the `r` key, which also reports what changed.
 
-use crate::db::{project_label, Database};
-use crate::fields::{Kind, Status};
+use chrono::Local;
+
+use crate::db::{due_filter_matches, project_label, sort_tasks, Database};
+use crate::fields::Kind;
+use crate::store::history::{diff_databases, DiffSummary};
+use crate::task::Task;
 use crate::tui::enums::HierarchyLevel;
 
 use super::App;
@@ -12,14 +17,31 @@ use super::App;
 impl App {
     /// Reload the database from disk and refresh the filtered task list.
     pub(super) fn refresh_tasks(&mut self) {
-        self.db = Database::load(&self.db_path);
+        self.db = Database::load_guarded(&self.db_path);
+        self.loaded = self.db.clone();
+        self.update_filtered_tasks();
+    }
+
+    /// [`Self::refresh_tasks`], reporting on the status line what changed on
+    /// disk since the last load: `Reloaded: +2 new, 1 completed, 3 updated`.
+    pub(super) fn reload_from_disk(&mut self) {
+        let before = std::mem::replace(&mut self.db, Database::load_guarded(&self.db_path));
+        self.loaded = self.db.clone();
+        let summary = DiffSummary::of(&diff_databases(&before, &self.db), &self.db);
         self.update_filtered_tasks();
+        self.set_status_message(format!("Reloaded: {summary}"));
     }
 
     /// Update the filtered task list based on current filters and navigation context.
     ///
     /// Applies completion status filter, hierarchy level filter, parent context filter,
-    /// and search text filter. Attempts to preserve selection when possible.
+    /// `:filter` due bucket, and search text filter. Attempts to preserve selection
+    /// when possible.
+    ///
+    /// Ordering is explicit: every non-Done ticket comes first, then the Done
+    /// tickets, so completed work sinks to the bottom instead of interleaving
+    /// with open rows. Within each group rows follow the `:sort` key, or
+    /// database order when none is set.
     pub(super) fn update_filtered_tasks(&mut self) {
         // Remember the currently selected task ID if any
         let old_selected_id = self
@@ -28,13 +50,25 @@ impl App {
             .and_then(|idx| self.filtered_tasks.get(idx))
             .copied();
 
-        self.filtered_tasks = self
+        let today = Local::now().date_naive();
+        let in_focus = self
+            .focus
+            .filter(|&root| self.db.get(root).is_some())
+            .map(|root| self.db.subtree_ids(root));
+        let mut visible: Vec<&Task> = self
             .db
             .tasks
             .iter()
             .filter(|t| {
+                if in_focus.as_ref().is_some_and(|ids| !ids.contains(&t.id)) {
+                    return false;
+                }
+
                 // Filter by completion status
-                if !self.show_completed && t.status == Status::Done {
+                if !self.show_completed && t.status.is_closed() {
+                    return false;
+                }
+                if self.collapse_done_children && t.status.is_closed() && t.parent.is_some() {
                     return false;
                 }
 
@@ -58,6 +92,12 @@ impl App {
                     }
                 }
 
+                if let Some(df) = self.due_filter {
+                    if !due_filter_matches(t.due, t.remind_days_before, df, today) {
+                        return false;
+                    }
+                }
+
                 // Filter by search text
                 if !self.filter_text.is_empty() {
                     let filter_lower = self.filter_text.to_lowercase();
@@ -74,8 +114,12 @@ impl App {
                 }
                 true
             })
-            .map(|t| t.id)
             .collect();
+        if let Some(key) = self.list_sort {
+            sort_tasks(&mut visible, key);
+        }
+        visible.sort_by_key(|t| (!t.pinned, t.status.is_closed()));
+        self.filtered_tasks = visible.into_iter().map(|t| t.id).collect();
 
         // Try to restore selection, or reset to first item
         if let Some(old_id) = old_selected_id {
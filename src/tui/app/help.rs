@@ -10,14 +10,16 @@ use ratatui::{
     Frame,
 };
 
-use crate::tui::enums::{Mode, Overlay};
+use crate::tui::enums::{AppState, Mode, Overlay};
+use crate::tui::keyhelp::{Binding, GLOBAL, TASK_FORM, TICKET_DETAIL, TICKET_LIST};
+use crate::tui::keymap::{Action, KeyContext};
 use crate::tui::utils::centered_rect;
 
 use super::App;
 
 impl App {
-    /// Handle a keystroke while the help overlay is open. `?`, `Esc`, `h`,
-    /// and `F1` close it; `Up`/`Down` scroll. Mode-switch keys are handled
+    /// Handle a keystroke while the help overlay is open. `Esc`, `F1`, `h`
+    /// and `?` close it; `Up`/`Down` scroll. Mode-switch keys are handled
     /// before this is reached, so they close help and switch in one stroke.
     pub(super) fn handle_help_overlay_input(&mut self, key: KeyCode) {
         match key {
@@ -39,8 +41,8 @@ impl App {
     }
 
     /// Render the modal help overlay. Mode-independent: drawn over whatever
-    /// the current mode produced. Scrollable with Up/Down; closed with `?`,
-    /// `Esc`, `h`, or `F1`. Layout follows PM_DESIGN.md Section 8.3.5 -
+    /// the current mode produced. Scrollable with Up/Down; closed with `F1`,
+    /// `Esc`, `h`, or `?`. Layout follows PM_DESIGN.md Section 8.3.5 -
     /// current-mode keybindings first, then a concepts panel, then workflows.
     pub(super) fn render_help(&mut self, f: &mut Frame, area: Rect) {
         let heading = |text: &str| {
@@ -56,28 +58,12 @@ impl App {
         lines.push(heading(&format!("{} - keybindings", self.mode.label())));
         match self.mode {
             Mode::Tickets => {
-                lines.push(Line::from("  <- ->        Traverse hierarchy levels"));
-                lines.push(Line::from("  ^ v          Move within the list"));
-                lines.push(Line::from("  Enter        Drill into the selected ticket"));
-                lines.push(Line::from(
-                    "  e            Open the ticket's CLAUDE.md in $EDITOR",
-                ));
-                lines.push(Line::from("  f            Open the quick-entry form"));
-                lines.push(Line::from("  n            Add a child ticket"));
-                lines.push(Line::from(
-                    "  c / i        Checkout / checkin the selected ticket",
-                ));
-                lines.push(Line::from("  a            Add an artifact"));
-                lines.push(Line::from("  m            Toggle the memory side-panel"));
-                lines.push(Line::from("  d            Delete the selected ticket"));
-                lines.push(Line::from(
-                    "  s            Cycle status   p   cycle process stage",
-                ));
-                lines.push(Line::from(
-                    "  t            Toggle show/hide completed   r refresh",
-                ));
+                for section in [TICKET_LIST, TICKET_DETAIL, TASK_FORM] {
+                    lines.push(Line::from(format!("  {}", section.title)));
+                    lines.extend(self.binding_lines(section.bindings));
+                }
                 lines.push(Line::from(
-                    "  /            Filter by title / tags / project",
+                    "  Letter keys are defaults; remap them under \"keys\" in config.json",
                 ));
             }
             Mode::Documents => {
@@ -89,12 +75,8 @@ impl App {
                 lines.push(Line::from("  q            Exit to the launcher"));
             }
         }
-        lines.push(Line::from(
-            "  Tab / S-Tab  Cycle modes      1 / 2 / 3  jump to a mode",
-        ));
-        lines.push(Line::from(
-            "  ? / F1       Toggle this help   q  back to launcher",
-        ));
+        lines.push(Line::from(format!("  {}", GLOBAL.title)));
+        lines.extend(self.binding_lines(GLOBAL.bindings));
         lines.push(Line::from(""));
 
         lines.push(heading("Concepts"));
@@ -141,13 +123,141 @@ impl App {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Help - ^v scroll, ? or Esc to close"),
+                    .title("Help - ^v scroll, F1 or Esc to close"),
             )
             .wrap(Wrap { trim: false })
             .scroll((self.help_scroll(), 0));
         f.render_widget(paragraph, overlay);
     }
 
+    /// Help-overlay rows for `bindings`, with remappable keys shown as
+    /// currently bound.
+    fn binding_lines(&self, bindings: &[Binding]) -> Vec<Line<'static>> {
+        bindings
+            .iter()
+            .map(|binding| {
+                Line::from(format!(
+                    "    {:<13}{}",
+                    binding.key_label(&self.keymap),
+                    binding.description()
+                ))
+            })
+            .collect()
+    }
+
+    /// Height of the key-hint footer: one row while `?` has it on and the
+    /// current view has hints, otherwise none.
+    pub(super) fn hint_rows(&self) -> u16 {
+        u16::from(self.show_hints && !self.key_hints().is_empty())
+    }
+
+    /// The most common keys for the current mode and [`AppState`], as
+    /// `(key, action)` pairs. The Activity view prints its own key row, so it
+    /// has none.
+    pub(super) fn key_hints(&self) -> &'static [(&'static str, &'static str)] {
+        match self.mode {
+            Mode::Tickets => match self.state {
+                AppState::TaskList => &[
+                    ("Enter", "open"),
+                    ("<- ->", "level"),
+                    ("n", "add child"),
+                    ("f", "form"),
+                    ("e", "edit"),
+                    ("d", "del"),
+                    ("s", "status"),
+                    ("p", "stage"),
+                    (".", "repeat"),
+                    ("/", "filter"),
+                    ("o", "sort"),
+                    ("F1", "help"),
+                ],
+                AppState::TaskDetail => &[
+                    ("Esc", "back"),
+                    ("e", "edit"),
+                    ("d", "del"),
+                    ("p", "parent"),
+                    ("c", "first child"),
+                    ("1-9", "open child"),
+                    ("n", "new child"),
+                    ("a o", "artifact"),
+                    ("+ -", "tags"),
+                    ("F1", "help"),
+                ],
+                AppState::AddTask => &[
+                    ("Tab S-Tab", "field"),
+                    ("Enter", "save"),
+                    ("^T", "template"),
+                    ("^P", "preview"),
+                    ("Esc", "cancel"),
+                ],
+                AppState::EditTask => &[
+                    ("Tab S-Tab", "field"),
+                    ("Enter", "save"),
+                    ("^P", "preview"),
+                    ("Esc", "cancel"),
+                ],
+                AppState::UserStoryDialog | AppState::RequirementsDialog => {
+                    &[("Esc", "save & return")]
+                }
+                AppState::Confirm => &[("y Enter", "confirm"), ("n Esc", "cancel")],
+            },
+            Mode::Documents => &[
+                ("^ v", "move"),
+                ("<- ->", "level"),
+                ("Enter", "open"),
+                ("a", "artifact"),
+                ("m", "memories"),
+                ("r", "rename/move"),
+                ("F1", "help"),
+            ],
+            Mode::Activity => &[],
+        }
+    }
+
+    /// Render the key-hint footer into its one-row band.
+    pub(super) fn render_key_hints(&self, f: &mut Frame, area: Rect) {
+        if area.height == 0 {
+            return;
+        }
+        let mut spans: Vec<Span> = Vec::new();
+        for (i, (key, action)) in self.key_hints().iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw("  "));
+            }
+            spans.push(Span::styled(
+                self.bound_hint_key(key),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::styled(
+                format!(":{action}"),
+                Style::default().fg(Color::Gray),
+            ));
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    /// A hint key as currently bound. Single-character defaults in the
+    /// list and detail view follow the keymap, so a remapped action shows
+    /// its configured key.
+    fn bound_hint_key(&self, key: &str) -> String {
+        let context = match (self.mode, self.state) {
+            (Mode::Tickets, AppState::TaskList) => KeyContext::TaskList,
+            (Mode::Tickets, AppState::TaskDetail) => KeyContext::Detail,
+            _ => return key.to_string(),
+        };
+        let mut chars = key.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return key.to_string();
+        };
+        Action::ALL
+            .into_iter()
+            .find(|a| a.context() == context && a.default_key() == Some(c))
+            .and_then(|a| self.keymap.key(a))
+            .map_or_else(|| key.to_string(), String::from)
+    }
+
     /// Current help-overlay scroll offset. Returns 0 when the overlay is not
     /// the help variant; callers should only invoke `render_help` while it is.
     pub(super) fn help_scroll(&self) -> u16 {
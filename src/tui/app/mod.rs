@@ -32,7 +32,7 @@ use chrono::Utc;
 use crate::store::events;
 use crate::store::git;
 use crate::store::locks::{self, AcquireOutcome, LockFile, LockMode, DEFAULT_TTL_SECONDS};
-use crate::store::{IdInput, LeafId, MemoryRef};
+use crate::store::{Config, IdInput, LeafId, MemoryRef};
 use crate::task::Task;
 use crate::views::events_view::{ActivityAction, ActivityView};
 use crate::{
@@ -42,9 +42,12 @@ use crate::{
     },
     tui::{
         enums::{
-            AppState, DocumentsState, InputMode, MemoryLinkRow, MemoryLinkState, Mode,
-            NavigationContext, Overlay, PendingAction, PromptState, PromptType,
+            AppState, DocumentsState, EditAction, HierarchyLevel, InputMode, MemoryLinkRow,
+            MemoryLinkState, Mode, NavigationContext, Overlay, PendingAction, PromptState,
+            PromptType, ViewHandoff,
         },
+        input::InputField,
+        keymap::{Action, KeyContext, Keymap},
         task_form::{
             TaskForm, ARTIFACTS_GLOBAL_ORDER, DESCRIPTION_GLOBAL_ORDER, DUE_GLOBAL_ORDER,
             ISSUE_LINK_GLOBAL_ORDER, KIND_GLOBAL_ORDER, PARENT_GLOBAL_ORDER, PRIORITY_GLOBAL_ORDER,
@@ -52,12 +55,12 @@ use crate::{
             REQUIREMENTS_GLOBAL_ORDER, STATUS_GLOBAL_ORDER, SUMMARY_GLOBAL_ORDER,
             TAGS_GLOBAL_ORDER, TITLE_GLOBAL_ORDER, URGENCY_GLOBAL_ORDER, USER_STORY_GLOBAL_ORDER,
         },
-        utils::centered_rect,
+        utils::{centered_rect, id_label, layered_spans, match_ranges, step_index, tag_suffix},
     },
 };
 use crate::{
     fields::*,
-    tui::colors::{DARK_GREEN, DARK_PURPLE, DARK_RED, GOLD},
+    tui::colors::{colors_enabled, due_state_color, DARK_GREEN, DARK_PURPLE, DARK_RED, GOLD},
 };
 
 /// State snapshot for navigation history. `pub(super)` so the navigation
@@ -81,6 +84,9 @@ pub struct App {
     pub(super) mode: Mode,
     pub(super) state: AppState,
     pub(super) db: Database,
+    // `db` as last loaded or saved; `save_db` replays the edits since onto
+    // whatever the CLI saved in the meantime.
+    pub(super) loaded: Database,
     pub(super) db_path: std::path::PathBuf,
     pub(super) task_list_state: TableState,
     pub(super) filtered_tasks: Vec<LeafId>,
@@ -89,13 +95,32 @@ pub struct App {
     pub(super) input_mode: InputMode,
     pub(super) status_message: String,
     pub(super) show_completed: bool,
+    /// With `show_completed` on, still hide Done tickets that have a parent
+    /// so finished subtasks don't crowd the open work (`T`).
+    pub(super) collapse_done_children: bool,
     pub(super) filter_text: String,
     pub(super) filter_active: bool,
+    /// Text typed after `:` while in [`InputMode::Command`].
+    pub(super) command_buffer: String,
+    /// Explicit ordering chosen with `:sort`; `None` keeps database order.
+    pub(super) list_sort: Option<SortKey>,
+    /// Due-date bucket chosen with `:filter`.
+    pub(super) due_filter: Option<DueFilter>,
     pub(super) confirm_action: Option<String>,
     pub(super) dialog_text: String,
     pub(super) dialog_cursor_x: usize,
     pub(super) dialog_cursor_y: usize,
+    /// First visible row of the fullscreen editor, counting wrapped rows.
     pub(super) dialog_scroll_y: usize,
+    /// First visible column of the fullscreen editor when wrapping is off.
+    pub(super) dialog_scroll_x: usize,
+    /// Soft-wrap long lines in the fullscreen editor (Ctrl+W).
+    pub(super) dialog_wrap: bool,
+    /// Show the line-number gutter in the fullscreen editor (Ctrl+L).
+    pub(super) dialog_line_numbers: bool,
+    /// Text columns beside the gutter, measured at the last render so
+    /// Up/Down can move by wrapped row.
+    pub(super) dialog_text_width: usize,
     pub(super) navigation_context: NavigationContext,
     pub(super) navigation_stack: Vec<NavigationContext>,
     pub(super) navigation_history: Vec<NavigationSnapshot>,
@@ -107,6 +132,8 @@ pub struct App {
     /// A deferred terminal-suspending action picked up by the run loop. Kept
     /// separate from `overlay` because it is an action to run, not a surface.
     pub(super) pending_action: Option<PendingAction>,
+    /// The most recent ticket edit, replayed on the selection by `.`.
+    pub(super) last_edit: Option<EditAction>,
     /// State for Mode 2 - the Document Workspace. Maintained across mode
     /// switches so the cursor and crumb persist when the user returns.
     pub(super) documents: DocumentsState,
@@ -116,34 +143,85 @@ pub struct App {
     /// The mode we came from on the most recent mode switch. Mode 3's `q`
     /// returns here rather than exiting the TUI.
     pub(super) prev_mode: Mode,
+    /// Hide the header banner so the table gets every row (`--compact`).
+    pub(super) compact: bool,
+    /// Show ids in the short `T-a3` form (`ui.short_ids`).
+    pub(super) short_ids: bool,
+    /// Up/Down wrap round the ends of the list (`ui.wrap_navigation`).
+    pub(super) wrap_navigation: bool,
+    /// Root of the subtree `pm focus` limits the list to, if it exists.
+    pub(super) focus: Option<LeafId>,
+    /// Show the one-line key-hint footer above the status bar (`?`).
+    pub(super) show_hints: bool,
+    /// Show the rendered preview beside the add/edit form (Ctrl+P).
+    pub(super) form_preview: bool,
+    /// Set by `w`: the run loop exits so the caller can open the workflow
+    /// board at the current navigation context.
+    pub(super) open_workflow: bool,
+    /// Single-key commands for the list and detail view, the defaults with
+    /// the config `keys` table applied.
+    pub(super) keymap: Keymap,
+    /// First child shown in the detail view's children sub-list; the
+    /// visible rows are the ones `1`-`9` jump to.
+    pub(super) detail_child_scroll: usize,
+    /// The artifact `o` opens in the detail view.
+    pub(super) detail_artifact: usize,
+    /// Why the last add/edit form submit could not be written to disk. The
+    /// form stays open with its contents while this is set, and Ctrl+R
+    /// retries the save.
+    pub(super) save_error: Option<String>,
+    /// The ticket whose detail view opened the add form with `n`; saving
+    /// or leaving the form returns to its detail view.
+    pub(super) child_form_parent: Option<LeafId>,
+}
+
+/// Return value for the task list app to indicate what should happen next,
+/// the counterpart of [`crate::tui::workflow::WorkflowExit`].
+#[derive(Debug)]
+pub enum AppExit {
+    Quit,
+    Workflow(ViewHandoff),
 }
 
 // Per-concern submodules. Each extends `impl App` with the methods that
 // belong to that axis - rendering, input handling, or state mutation for
 // one screen or feature - while the orchestration (run loop, render
 // dispatch, mode switch) stays here in mod.rs.
+mod command;
 mod confirm;
 mod dialog;
 mod filter;
 mod help;
 mod navigation;
 mod prompt;
+mod repeat;
 mod ticket_detail;
 
 impl App {
     /// Create a new App instance, loading the database from the specified path.
     pub fn new(db_path: &Path) -> io::Result<Self> {
-        let db = Database::load(db_path);
-        let navigation_context = NavigationContext::new_all_projects();
+        let db = Database::load_guarded(db_path);
         let pm_dir = db_path
             .parent()
             .unwrap_or_else(|| Path::new("."))
             .to_path_buf();
         let activity = ActivityView::new(pm_dir.clone());
+        let config = Config::load(db_path);
+        // Under `pm focus`, open at the focus root's own level.
+        let focus = config.focus.filter(|&root| db.get(root).is_some());
+        let navigation_context = match focus.and_then(|root| db.get(root)) {
+            Some(root) => NavigationContext::new_all_level(HierarchyLevel::of_kind(root.kind)),
+            None => NavigationContext::new_all_projects(),
+        };
+        let (keymap, key_problems) = match Keymap::from_config(&config.keys) {
+            Ok(keymap) => (keymap, Vec::new()),
+            Err(problems) => (Keymap::default(), problems),
+        };
 
         let mut app = App {
             mode: Mode::Tickets,
             state: AppState::TaskList,
+            loaded: db.clone(),
             db,
             db_path: db_path.to_path_buf(),
             task_list_state: TableState::default(),
@@ -153,13 +231,21 @@ impl App {
             input_mode: InputMode::None,
             status_message: String::new(),
             show_completed: false,
+            collapse_done_children: false,
             filter_text: String::new(),
             filter_active: false,
+            command_buffer: String::new(),
+            list_sort: None,
+            due_filter: None,
             confirm_action: None,
             dialog_text: String::new(),
             dialog_cursor_x: 0,
             dialog_cursor_y: 0,
             dialog_scroll_y: 0,
+            dialog_scroll_x: 0,
+            dialog_wrap: true,
+            dialog_line_numbers: true,
+            dialog_text_width: 0,
             navigation_context,
             navigation_stack: Vec::new(),
             navigation_history: Vec::new(),
@@ -167,12 +253,31 @@ impl App {
             pm_dir,
             overlay: Overlay::None,
             pending_action: None,
+            last_edit: None,
             documents: DocumentsState::default(),
             activity,
             prev_mode: Mode::Tickets,
+            compact: false,
+            short_ids: config.ui.short_ids,
+            wrap_navigation: config.ui.wrap_navigation,
+            focus,
+            show_hints: false,
+            form_preview: true,
+            open_workflow: false,
+            keymap,
+            save_error: None,
+            child_form_parent: None,
+            detail_child_scroll: 0,
+            detail_artifact: 0,
         };
 
         app.update_filtered_tasks();
+        if !key_problems.is_empty() {
+            app.set_status_message(format!(
+                "Ignoring keys in config.json, using defaults: {}",
+                key_problems.join("; ")
+            ));
+        }
         Ok(app)
     }
 
@@ -188,6 +293,30 @@ impl App {
         }
     }
 
+    /// Hide or show the header banner above the ticket list.
+    pub fn set_compact(&mut self, compact: bool) {
+        self.compact = compact;
+    }
+
+    /// Open at the hierarchy position handed over by the workflow board.
+    pub fn set_navigation(&mut self, handoff: ViewHandoff) {
+        self.navigation_context = handoff.context;
+        self.navigation_stack = handoff.stack;
+        self.update_filtered_tasks();
+    }
+
+    /// Get the exit action requested by the user.
+    pub fn get_exit_action(&self) -> AppExit {
+        if self.open_workflow {
+            AppExit::Workflow(ViewHandoff {
+                context: self.navigation_context.clone(),
+                stack: self.navigation_stack.clone(),
+            })
+        } else {
+            AppExit::Quit
+        }
+    }
+
     /// Open a specific task for editing.
     pub fn open_task_for_edit(&mut self, task_id: LeafId) {
         if let Some(task) = self.db.get(task_id) {
@@ -203,11 +332,55 @@ impl App {
 
     /// Save the database to disk and refresh the task list.
     fn save_db(&mut self) -> io::Result<()> {
-        self.db.save(&self.db_path)?;
+        self.db.save_since(&self.db_path, &self.loaded)?;
         self.refresh_tasks();
         Ok(())
     }
 
+    /// Apply the workspace's definition-of-done gate before marking `targets`
+    /// Done. Returns true (and explains why in the status bar) when
+    /// `block_parent_completion_with_open_children` is on and a target still
+    /// has open descendants outside the set.
+    fn completion_blocked(&mut self, targets: &[LeafId]) -> bool {
+        if !Config::load(&self.db_path)
+            .complete
+            .block_parent_completion_with_open_children
+        {
+            return false;
+        }
+        match completion_blocker(&self.db, targets) {
+            Some((id, open)) => {
+                self.set_status_message(format!(
+                    "{}; complete them first",
+                    format_completion_blocker(id, &open)
+                ));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Apply `enforce_status_flow` before moving `targets` to `to`. Returns
+    /// true (and names the refused move in the status bar) when the flow is
+    /// enforced and a target would skip a step.
+    fn status_flow_blocked(&mut self, targets: &[LeafId], to: Status) -> bool {
+        if !Config::load(&self.db_path).workflow.enforce_status_flow {
+            return false;
+        }
+        let refused = targets.iter().find_map(|&id| {
+            self.db
+                .get(id)
+                .and_then(|t| status_flow_error(id, t.status, to))
+        });
+        match refused {
+            Some(e) => {
+                self.set_status_message(e);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Get a reference to the currently selected task.
     fn get_selected_task(&self) -> Option<&Task> {
         self.selected_task.and_then(|id| self.db.get(id))
@@ -276,6 +449,52 @@ impl App {
         }
     }
 
+    /// Pin or unpin the highlighted ticket. Pinned tickets list first under
+    /// every sort, so the list is re-sorted and the selection follows.
+    fn toggle_selected_pin(&mut self) {
+        let Some(task_id) = self.selected_task_id() else {
+            self.set_status_message("No ticket selected".to_string());
+            return;
+        };
+        let Some(task) = self.db.get_mut(task_id) else {
+            return;
+        };
+        task.pinned = !task.pinned;
+        task.updated_at_utc = Utc::now().timestamp();
+        let pinned = task.pinned;
+        self.note_edit("a pin");
+        if let Err(e) = self.save_db() {
+            self.set_status_message(format!("Error saving: {}", e));
+            return;
+        }
+        self.update_filtered_tasks();
+        let id = id_label(task_id, self.short_ids);
+        self.set_status_message(if pinned {
+            format!("{id} pinned")
+        } else {
+            format!("{id} unpinned")
+        });
+    }
+
+    /// Advance the task-list ordering: database order, then due, priority,
+    /// id, created and updated (the `pm list --sort` keys), then back.
+    /// Done tickets still sink below the rest whichever key is active.
+    fn cycle_list_sort(&mut self) {
+        self.list_sort = match self.list_sort {
+            None => Some(SortKey::Due),
+            Some(SortKey::Due) => Some(SortKey::Priority),
+            Some(SortKey::Priority) => Some(SortKey::Id),
+            Some(SortKey::Id) => Some(SortKey::Created),
+            Some(SortKey::Created) => Some(SortKey::Updated),
+            Some(SortKey::Updated) => None,
+        };
+        self.update_filtered_tasks();
+        self.set_status_message(match self.list_sort {
+            Some(key) => format!("Sorted by {}", format_sort_key(key)),
+            None => "Sort: database order".to_string(),
+        });
+    }
+
     /// Set a status message to display in the status bar.
     fn set_status_message(&mut self, msg: String) {
         self.status_message = msg;
@@ -294,6 +513,9 @@ impl App {
         key: KeyCode,
         modifiers: KeyModifiers,
     ) -> io::Result<bool> {
+        if matches!(self.input_mode, InputMode::Command) {
+            return Ok(self.handle_command_input(key));
+        }
         if self.filter_active {
             match key {
                 KeyCode::Esc => {
@@ -353,21 +575,17 @@ impl App {
                 }
             }
 
-            KeyCode::Up => {
+            KeyCode::Up | KeyCode::Down => {
+                let len = self.filtered_tasks.len();
                 if let Some(selected) = self.task_list_state.selected() {
-                    if selected > 0 {
-                        self.task_list_state.select(Some(selected - 1));
-                    }
-                } else if !self.filtered_tasks.is_empty() {
-                    self.task_list_state.select(Some(0));
-                }
-            }
-            KeyCode::Down => {
-                if let Some(selected) = self.task_list_state.selected() {
-                    if selected + 1 < self.filtered_tasks.len() {
-                        self.task_list_state.select(Some(selected + 1));
-                    }
-                } else if !self.filtered_tasks.is_empty() {
+                    let down = key == KeyCode::Down;
+                    self.task_list_state.select(Some(step_index(
+                        selected,
+                        len,
+                        down,
+                        self.wrap_navigation,
+                    )));
+                } else if len > 0 {
                     self.task_list_state.select(Some(0));
                 }
             }
@@ -392,21 +610,38 @@ impl App {
             KeyCode::Enter | KeyCode::Char(' ') => {
                 if let Some(selected) = self.task_list_state.selected() {
                     if let Some(&task_id) = self.filtered_tasks.get(selected) {
-                        self.selected_task = Some(task_id);
+                        self.show_in_detail(task_id);
                         self.push_state(AppState::TaskDetail, None);
                     }
                 }
             }
-            // `n` opens the quick-entry form for a new child ticket.
-            KeyCode::Char('n') => {
-                self.task_form =
-                    TaskForm::new_with_context_and_pm_dir(&self.navigation_context, &self.pm_dir);
-                self.task_form.update_active_field();
-                self.push_state(AppState::AddTask, None);
-                self.input_mode = InputMode::Text;
+            KeyCode::Char(c) => {
+                if let Some(action) = self.keymap.action(KeyContext::TaskList, c) {
+                    return self.run_list_action(action);
+                }
             }
-            // `f` opens the quick-entry form on the selected ticket.
-            KeyCode::Char('f') => {
+            _ => {}
+        }
+        Ok(false)
+    }
+
+    /// Run a ticket-list [`Action`] resolved through the keymap.
+    ///
+    /// Returns true if the application should quit.
+    fn run_list_action(&mut self, action: Action) -> io::Result<bool> {
+        match action {
+            // Swaps to the workflow board at the same hierarchy position.
+            Action::Workflow => {
+                self.open_workflow = true;
+                return Ok(true);
+            }
+            // Opens the quick-entry form for a new child ticket.
+            Action::NewChild => {
+                self.child_form_parent = None;
+                self.open_add_form(&self.navigation_context.clone());
+            }
+            // Opens the quick-entry form on the selected ticket.
+            Action::Form => {
                 if let Some(selected) = self.task_list_state.selected() {
                     if let Some(&task_id) = self.filtered_tasks.get(selected) {
                         if let Some(task) = self.db.get(task_id) {
@@ -419,17 +654,17 @@ impl App {
                     }
                 }
             }
-            // `e` opens the selected ticket's CLAUDE.md in `$EDITOR`. The run
+            // Opens the selected ticket's CLAUDE.md in `$EDITOR`. The run
             // loop performs the terminal suspend/resume around the handoff.
-            KeyCode::Char('e') => {
+            Action::Edit => {
                 if let Some(task_id) = self.selected_task_id() {
                     self.pending_action = Some(PendingAction::EditTicket(task_id));
                 } else {
                     self.set_status_message("No ticket selected".to_string());
                 }
             }
-            // `a` adds an artifact to the selected ticket via a path prompt.
-            KeyCode::Char('a') => {
+            // Adds an artifact to the selected ticket via a path prompt.
+            Action::Artifact => {
                 if let Some(task_id) = self.selected_task_id() {
                     self.overlay = Overlay::Prompt(PromptState {
                         prompt_type: PromptType::ArtifactPath(task_id),
@@ -439,15 +674,15 @@ impl App {
                     self.set_status_message("No ticket selected".to_string());
                 }
             }
-            KeyCode::Char('i') => self.do_checkin(),
-            KeyCode::Char('m') => {
+            Action::Checkin => self.do_checkin(),
+            Action::Memories => {
                 self.overlay = if matches!(self.overlay, Overlay::MemoryPanel) {
                     Overlay::None
                 } else {
                     Overlay::MemoryPanel
                 };
             }
-            KeyCode::Char('d') => {
+            Action::Delete => {
                 if let Some(selected) = self.task_list_state.selected() {
                     if let Some(&task_id) = self.filtered_tasks.get(selected) {
                         self.selected_task = Some(task_id);
@@ -456,17 +691,34 @@ impl App {
                     }
                 }
             }
-            KeyCode::Char('s') => {
+            Action::CycleStatus => {
                 if let Some(selected) = self.task_list_state.selected() {
                     if let Some(&task_id) = self.filtered_tasks.get(selected) {
+                        let Some(current) = self.db.get(task_id).map(|t| t.status) else {
+                            return Ok(false);
+                        };
+                        // Cycle Open -> InProgress -> Done -> Open. Blocked
+                        // resumes as InProgress and Cancelled reopens; both
+                        // are set with `:status`.
+                        let new_status = match current {
+                            Status::Open => Status::InProgress,
+                            Status::InProgress => Status::Done,
+                            Status::Done | Status::Cancelled => Status::Open,
+                            Status::Blocked => Status::InProgress,
+                        };
+                        if new_status == Status::Done && self.completion_blocked(&[task_id]) {
+                            return Ok(false);
+                        }
+                        if self.status_flow_blocked(&[task_id], new_status) {
+                            return Ok(false);
+                        }
+                        let workflow = Config::load(&self.db_path).workflow;
                         if let Some(task) = self.db.get_mut(task_id) {
-                            // Cycle through all three status states: Open -> InProgress -> Done -> Open
-                            let new_status = match task.status {
-                                Status::Open => Status::InProgress,
-                                Status::InProgress => Status::Done,
-                                Status::Done => Status::Open,
-                            };
+                            let from = task.status;
                             task.status = new_status;
+                            advance_stage(task, from, &workflow);
+                            task.updated_at_utc = chrono::Utc::now().timestamp();
+                            self.note_edit("a status cycle");
                             if let Err(e) = self.save_db() {
                                 self.set_status_message(format!("Error saving: {}", e));
                             } else {
@@ -479,41 +731,18 @@ impl App {
                     }
                 }
             }
-            // `c` checks out the selected ticket (acquires a soft lock).
-            // Status toggling lives on `s`, which cycles through Done.
-            KeyCode::Char('c') => self.do_checkout(),
-            KeyCode::Char('p') => {
-                if let Some(selected) = self.task_list_state.selected() {
-                    if let Some(&task_id) = self.filtered_tasks.get(selected) {
-                        if let Some(task) = self.db.get_mut(task_id) {
-                            // Cycle through process stages: Ideation -> Design -> Prototyping -> Ready to Implement -> Implementation -> Testing -> Refinement -> Release -> Ideation
-                            let new_stage = match task.process_stage {
-                                Some(ProcessStage::Ideation) => ProcessStage::Design,
-                                Some(ProcessStage::Design) => ProcessStage::Prototyping,
-                                Some(ProcessStage::Prototyping) => ProcessStage::ReadyToImplement,
-                                Some(ProcessStage::ReadyToImplement) => {
-                                    ProcessStage::Implementation
-                                }
-                                Some(ProcessStage::Implementation) => ProcessStage::Testing,
-                                Some(ProcessStage::Testing) => ProcessStage::Refinement,
-                                Some(ProcessStage::Refinement) => ProcessStage::Release,
-                                Some(ProcessStage::Release) => ProcessStage::Ideation,
-                                None => ProcessStage::Ideation, // Start with Ideation if no stage set
-                            };
-                            task.process_stage = Some(new_stage);
-                            if let Err(e) = self.save_db() {
-                                self.set_status_message(format!("Error saving: {}", e));
-                            } else {
-                                self.set_status_message(format!(
-                                    "Process stage updated to {}",
-                                    format_process_stage(Some(new_stage))
-                                ));
-                            }
-                        }
-                    }
+            // Checks out the selected ticket (acquires a soft lock).
+            // Status toggling lives on `status`, which cycles through Done.
+            Action::Checkout => self.do_checkout(),
+            Action::CycleStage => {
+                if let Some(task_id) = self.selected_task_id() {
+                    self.run_edit(task_id, EditAction::CycleStage);
                 }
             }
-            KeyCode::Char('t') => {
+            // Replays the last repeatable edit on the selected ticket.
+            Action::Repeat => self.repeat_last_edit(),
+            Action::Pin => self.toggle_selected_pin(),
+            Action::ToggleCompleted => {
                 self.show_completed = !self.show_completed;
                 self.update_filtered_tasks();
                 self.set_status_message(if self.show_completed {
@@ -525,7 +754,28 @@ impl App {
                     )
                 });
             }
-            KeyCode::Char('/') => {
+            Action::CollapseDone => {
+                self.collapse_done_children = !self.collapse_done_children;
+                self.update_filtered_tasks();
+                self.set_status_message(if self.collapse_done_children {
+                    format!(
+                        "Collapsing completed subtasks ({} visible)",
+                        self.filtered_tasks.len()
+                    )
+                } else {
+                    format!(
+                        "Expanding completed subtasks ({} visible)",
+                        self.filtered_tasks.len()
+                    )
+                });
+            }
+            Action::Sort => self.cycle_list_sort(),
+            Action::Command => {
+                self.command_buffer.clear();
+                self.input_mode = InputMode::Command;
+                self.clear_status_message();
+            }
+            Action::Filter => {
                 self.filter_active = true;
                 self.input_mode = InputMode::Text;
                 self.set_status_message(
@@ -533,34 +783,96 @@ impl App {
                         .to_string(),
                 );
             }
-            KeyCode::Char('h') => {
+            Action::Help => {
                 self.overlay = Overlay::Help { scroll: 0 };
             }
-            KeyCode::Char('r') => {
-                self.refresh_tasks();
-                self.set_status_message("Tasks refreshed".to_string());
-            }
+            Action::Refresh => self.reload_from_disk(),
+            Action::Complete => match self.selected_task_id() {
+                Some(task_id) => self.run_edit(task_id, EditAction::Complete),
+                None => self.set_status_message("No ticket selected".to_string()),
+            },
+            Action::DueLater => self.nudge_selected_due(1),
+            Action::DueEarlier => self.nudge_selected_due(-1),
+            Action::DueLaterWeek => self.nudge_selected_due(7),
+            Action::DueEarlierWeek => self.nudge_selected_due(-7),
             _ => {}
         }
         Ok(false)
     }
 
+    /// Move the selected ticket's due date by `days` and save straight
+    /// away. An undated ticket is dated today by a forward nudge; a
+    /// backward one leaves it alone and says so.
+    fn nudge_selected_due(&mut self, days: i64) {
+        let Some(task_id) = self.selected_task_id() else {
+            self.set_status_message("No ticket selected".to_string());
+            return;
+        };
+        let today = Local::now().date_naive();
+        let Some(task) = self.db.get_mut(task_id) else {
+            return;
+        };
+        let Some(new_due) = nudge_due(task.due, days, today) else {
+            let later = self.keymap.key(Action::DueLater).unwrap_or('+');
+            self.set_status_message(format!(
+                "{task_id} has no due date; {later} sets it to today"
+            ));
+            return;
+        };
+        let was_undated = task.due.is_none();
+        task.due = Some(new_due);
+        task.updated_at_utc = Utc::now().timestamp();
+        self.note_edit("a due date nudge");
+        if let Err(e) = self.save_db() {
+            self.set_status_message(format!("Error saving: {}", e));
+        } else if was_undated {
+            self.set_status_message(format!("{task_id} had no due date; now due {new_due}"));
+        } else {
+            self.set_status_message(format!(
+                "{task_id} due {new_due} ({})",
+                format_due_relative(Some(new_due), today)
+            ));
+        }
+    }
+
     /// Handle keyboard input when in task creation or editing forms.
     ///
     /// Returns true if the application should quit.
     fn handle_form_input(
         &mut self,
         key: KeyCode,
-        _modifiers: KeyModifiers,
+        modifiers: KeyModifiers,
         is_edit: bool,
     ) -> io::Result<bool> {
         match key {
+            // Ctrl+T cycles through saved templates on the add form, seeding
+            // description, user story, requirements and metadata defaults.
+            KeyCode::Char('t') if modifiers.contains(KeyModifiers::CONTROL) && !is_edit => {
+                self.cycle_form_template();
+            }
+            // Ctrl+P shows or hides the preview pane beside the form.
+            KeyCode::Char('p') if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.form_preview = !self.form_preview;
+            }
+            // Ctrl+R retries a save that failed to reach disk.
+            KeyCode::Char('r')
+                if modifiers.contains(KeyModifiers::CONTROL) && self.save_error.is_some() =>
+            {
+                self.submit_form(is_edit);
+            }
             KeyCode::Esc => {
-                self.state = AppState::TaskList;
-                self.input_mode = InputMode::None;
+                self.close_form();
+                if self.save_error.take().is_some() {
+                    self.set_status_message("Unsaved changes discarded".to_string());
+                }
             }
+            // Tab in the tags field accepts the first suggested tag, if any.
             KeyCode::Tab => {
-                self.task_form.next_field();
+                let known = known_tags(&self.db.tasks);
+                match self.task_form.tag_suggestions(&known).first() {
+                    Some(tag) => self.task_form.accept_tag(tag),
+                    None => self.task_form.next_field(),
+                }
             }
             KeyCode::BackTab => {
                 self.task_form.prev_field();
@@ -612,37 +924,7 @@ impl App {
                         self.init_dialog_cursor();
                         return Ok(false);
                     }
-                    _ => {
-                        // Regular form submission
-                        if self.task_form.title.value.trim().is_empty() {
-                            self.set_status_message("Title is required".to_string());
-                            return Ok(false);
-                        }
-
-                        let result = if is_edit {
-                            self.update_task()
-                        } else {
-                            self.create_task()
-                        };
-
-                        match result {
-                            Ok(_) => {
-                                self.state = AppState::TaskList;
-                                self.input_mode = InputMode::None;
-                                self.set_status_message(
-                                    if is_edit {
-                                        "Task updated"
-                                    } else {
-                                        "Task created"
-                                    }
-                                    .to_string(),
-                                );
-                            }
-                            Err(e) => {
-                                self.set_status_message(format!("Error: {}", e));
-                            }
-                        }
-                    }
+                    _ => self.submit_form(is_edit),
                 }
             }
             KeyCode::Char(c) => {
@@ -653,11 +935,103 @@ impl App {
         Ok(false)
     }
 
+    /// Open the add form with `context`'s parent and child kind filled in,
+    /// plus the parent's project defaults.
+    pub(super) fn open_add_form(&mut self, context: &NavigationContext) {
+        self.task_form = TaskForm::new_with_context_and_pm_dir(context, &self.pm_dir);
+        if let Some(pid) = context.parent_id {
+            let config = Config::load(&self.db_path);
+            if let Some(defaults) = project_defaults_for(&self.db, &config, pid) {
+                let parent_kind = self.db.get(pid).map(|p| p.kind);
+                self.task_form.apply_project_defaults(defaults, parent_kind);
+            }
+        }
+        self.task_form.update_active_field();
+        self.push_state(AppState::AddTask, None);
+        self.input_mode = InputMode::Text;
+    }
+
+    /// Close the add/edit form: back to the detail view it was opened from
+    /// with `n`, else to the list.
+    fn close_form(&mut self) {
+        self.input_mode = InputMode::None;
+        match self.child_form_parent.take() {
+            Some(parent) if self.db.get(parent).is_some() => {
+                self.show_in_detail(parent);
+                self.state = AppState::TaskDetail;
+            }
+            _ => self.state = AppState::TaskList,
+        }
+    }
+
+    /// Save the add/edit form. Validation problems are reported and leave
+    /// the form as it is; a failed write also records `save_error` so the
+    /// form offers a retry instead of dropping the user's input.
+    fn submit_form(&mut self, is_edit: bool) {
+        if self.task_form.title.value.trim().is_empty() {
+            self.set_status_message("Title is required".to_string());
+            return;
+        }
+
+        let result = if is_edit {
+            self.update_task()
+        } else {
+            self.create_task()
+        };
+
+        match result {
+            Ok(_) => {
+                self.save_error = None;
+                self.close_form();
+                self.set_status_message(
+                    if is_edit {
+                        "Task updated"
+                    } else {
+                        "Task created"
+                    }
+                    .to_string(),
+                );
+            }
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput => {
+                self.set_status_message(format!("Error: {}", e));
+            }
+            Err(e) => {
+                self.set_status_message(format!(
+                    "Save failed: {e} - Ctrl+R to retry, Esc to discard"
+                ));
+                self.save_error = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Apply the next saved template to the add form.
+    fn cycle_form_template(&mut self) {
+        if self.db.state.templates.is_empty() {
+            self.set_status_message("No templates saved (see `pm template create`)".to_string());
+            return;
+        }
+        let next = self
+            .task_form
+            .template_index
+            .map_or(0, |i| (i + 1) % self.db.state.templates.len());
+        let template = self.db.state.templates[next].clone();
+        self.task_form.apply_template(&template);
+        self.task_form.template_index = Some(next);
+        self.set_status_message(format!(
+            "Applied template '{}' ({}/{}, Ctrl+T for next)",
+            template.name,
+            next + 1,
+            self.db.state.templates.len()
+        ));
+    }
+
     /// Create a new task from the current form data.
     ///
     /// Validates input, enforces hierarchy rules, and adds the task to the database.
     fn create_task(&mut self) -> io::Result<()> {
-        let now_utc = chrono::Utc::now().timestamp();
+        if let Some(problem) = self.task_form.first_invalid_field() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, problem));
+        }
         let task_kind = self.task_form.kinds[self.task_form.kind];
         let id = self.db.allocate_id(kind_to_prefix(task_kind));
 
@@ -702,73 +1076,50 @@ impl App {
             }
         };
 
-        let due = if self.task_form.due.value.trim().is_empty() {
-            None
-        } else {
-            parse_due_input(&self.task_form.due.value)
-        };
+        let task = self.form_task(id, parent);
+        self.db.tasks.push(task);
+        // A failed write leaves the ticket only in memory; drop it so a
+        // retry from the still-open form does not add it twice.
+        self.save_db().inspect_err(|_| {
+            self.db.tasks.pop();
+        })
+    }
 
-        let task = Task {
+    /// A new ticket `id` under `parent` holding the form's values.
+    fn form_task(&self, id: LeafId, parent: Option<LeafId>) -> Task {
+        let now_utc = chrono::Utc::now().timestamp();
+        let mut task = Task {
             id,
-            title: self.task_form.title.value.trim().to_string(),
-            summary: if self.task_form.summary.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.summary.value.trim().to_string())
-            },
-            description: if self.task_form.description.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.description.value.trim().to_string())
-            },
-            user_story: if self.task_form.user_story.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.user_story.value.trim().to_string())
-            },
-            requirements: if self.task_form.requirements.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.requirements.value.trim().to_string())
-            },
-            tags: split_and_normalise_tags(&[self.task_form.tags.value.clone()]),
+            title: String::new(),
+            summary: None,
+            description: None,
+            user_story: None,
+            requirements: None,
+            tags: Vec::new(),
             deps: Vec::new(),
             milestone: None,
             memories: Vec::new(),
-            due,
+            due: None,
+            remind_days_before: None,
             parent,
-            kind: task_kind,
-            status: self.task_form.statuses[self.task_form.status],
-            priority_level: self.task_form.priorities[self.task_form.priority_level],
-            urgency: self.task_form.urgencies[self.task_form.urgency],
-            process_stage: self.task_form.process_stages[self.task_form.process_stage],
-            issue_link: if self.task_form.issue_link.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.issue_link.value.trim().to_string())
-            },
-            pr_link: if self.task_form.pr_link.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.pr_link.value.trim().to_string())
-            },
-            artifacts: if self.task_form.artifacts.value.trim().is_empty() {
-                Vec::new()
-            } else {
-                self.task_form
-                    .artifacts
-                    .value
-                    .split(',')
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect()
-            },
+            order: None,
+            iteration: None,
+            estimate_minutes: None,
+            completed_at_utc: None,
+            pinned: false,
+            kind: self.task_form.kinds[self.task_form.kind],
+            status: Status::Open,
+            priority_level: None,
+            urgency: None,
+            process_stage: None,
+            issue_link: None,
+            pr_link: None,
+            artifacts: Vec::new(),
             created_at_utc: now_utc,
             updated_at_utc: now_utc,
         };
-
-        self.db.tasks.push(task);
-        self.save_db()
+        self.task_form.apply_to(&mut task);
+        task
     }
 
     /// Update the selected task with data from the current form.
@@ -778,6 +1129,9 @@ impl App {
         let task_id = self
             .selected_task
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "No task selected"))?;
+        if let Some(problem) = self.task_form.first_invalid_field() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, problem));
+        }
 
         let parent = if self.task_form.parent.value.trim().is_empty() {
             None
@@ -808,67 +1162,37 @@ impl App {
             }
         };
 
-        let due = if self.task_form.due.value.trim().is_empty() {
-            None
-        } else {
-            parse_due_input(&self.task_form.due.value)
-        };
+        let workflow = Config::load(&self.db_path).workflow;
+        if workflow.enforce_status_flow {
+            let to = self.task_form.statuses[self.task_form.status];
+            if let Some(e) = self
+                .db
+                .get(task_id)
+                .and_then(|t| status_flow_error(task_id, t.status, to))
+            {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, e));
+            }
+        }
 
+        let before = self.db.get(task_id).cloned();
         if let Some(task) = self.db.get_mut(task_id) {
-            task.title = self.task_form.title.value.trim().to_string();
-            task.description = if self.task_form.description.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.description.value.trim().to_string())
-            };
-            task.tags = split_and_normalise_tags(&[self.task_form.tags.value.clone()]);
-            task.due = due;
+            let (from, stage) = (task.status, task.process_stage);
+            self.task_form.apply_to(task);
+            // A stage picked in the form wins over the status mapping.
+            if task.process_stage == stage {
+                advance_stage(task, from, &workflow);
+            }
             task.parent = parent;
-            task.kind = self.task_form.kinds[self.task_form.kind];
-            task.status = self.task_form.statuses[self.task_form.status];
-            task.priority_level = self.task_form.priorities[self.task_form.priority_level];
-            task.urgency = self.task_form.urgencies[self.task_form.urgency];
-            task.process_stage = self.task_form.process_stages[self.task_form.process_stage];
-            task.summary = if self.task_form.summary.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.summary.value.trim().to_string())
-            };
-            task.user_story = if self.task_form.user_story.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.user_story.value.trim().to_string())
-            };
-            task.requirements = if self.task_form.requirements.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.requirements.value.trim().to_string())
-            };
-            task.issue_link = if self.task_form.issue_link.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.issue_link.value.trim().to_string())
-            };
-            task.pr_link = if self.task_form.pr_link.value.trim().is_empty() {
-                None
-            } else {
-                Some(self.task_form.pr_link.value.trim().to_string())
-            };
-            task.artifacts = if self.task_form.artifacts.value.trim().is_empty() {
-                Vec::new()
-            } else {
-                self.task_form
-                    .artifacts
-                    .value
-                    .split(',')
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect()
-            };
             task.updated_at_utc = chrono::Utc::now().timestamp();
         }
 
-        self.save_db()
+        // Restore the in-memory ticket when the write fails, so the list
+        // never shows edits that are not on disk; the form keeps them.
+        self.save_db().inspect_err(|_| {
+            if let (Some(before), Some(task)) = (before, self.db.get_mut(task_id)) {
+                *task = before;
+            }
+        })
     }
 
     /// Handle keyboard input when viewing the help screen.
@@ -879,7 +1203,7 @@ impl App {
     /// input prompt. Mode-switch keys and the help shortcut are suppressed
     /// in this situation.
     fn is_capturing_text(&self) -> bool {
-        matches!(self.input_mode, InputMode::Text)
+        matches!(self.input_mode, InputMode::Text | InputMode::Command)
             || self.filter_active
             || matches!(self.overlay, Overlay::Prompt(_))
     }
@@ -890,7 +1214,10 @@ impl App {
         if self.is_capturing_text() {
             return false;
         }
+        // In the ticket detail view the digits pick a listed child instead.
+        let in_detail = self.mode == Mode::Tickets && self.state == AppState::TaskDetail;
         let target = match key {
+            KeyCode::Char('1'..='3') if in_detail => None,
             KeyCode::Tab => Some(self.mode.next()),
             KeyCode::BackTab => Some(self.mode.prev()),
             KeyCode::Char('1') => Some(Mode::Tickets),
@@ -962,12 +1289,20 @@ impl App {
                     return Ok(false);
                 }
 
-                // `?` / `F1` open the help overlay from any mode.
-                if !self.is_capturing_text()
-                    && matches!(key.code, KeyCode::Char('?') | KeyCode::F(1))
-                {
-                    self.overlay = Overlay::Help { scroll: 0 };
-                    return Ok(false);
+                // `F1` opens the help overlay from any mode; `?` toggles the
+                // key-hint footer.
+                if !self.is_capturing_text() {
+                    match key.code {
+                        KeyCode::F(1) => {
+                            self.overlay = Overlay::Help { scroll: 0 };
+                            return Ok(false);
+                        }
+                        KeyCode::Char('?') => {
+                            self.show_hints = !self.show_hints;
+                            return Ok(false);
+                        }
+                        _ => {}
+                    }
                 }
 
                 let should_quit = match self.mode {
@@ -1022,25 +1357,36 @@ impl App {
         let today = Local::now().date_naive();
         let hierarchy_color = self.get_hierarchy_color();
 
-        // Split the area to accommodate the ASCII header
+        // Split the area to accommodate the ASCII header. Compact mode gives
+        // the header no rows at all so the table starts on the first line.
+        let header_height = if self.compact { 0 } else { 3 };
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // ASCII header height
-                Constraint::Min(0),    // Rest for the table
+                Constraint::Length(header_height), // ASCII header height
+                Constraint::Min(0),                // Rest for the table
             ])
             .split(area);
 
         // Render ASCII header with consistent app styling and context
         let project_name = self.get_current_project_name();
-        let context_display = format!(
+        let mut context_display = format!(
             "Current Project: {}  Current View: {}",
             project_name,
             self.navigation_context.get_display_name()
         );
+        if let Some(root) = self.focus {
+            context_display.push_str(&format!("  Focus: {root}"));
+        }
+        // Borders, the mode label and "PROJECT MANAGEMENT" take the rest of
+        // the line; long titles end in an ellipsis instead of overflowing.
+        let mode_label = format!("[ {} ]", self.mode.label());
+        let room =
+            (chunks[0].width as usize).saturating_sub(2 + mode_label.chars().count() + 2 + 18 + 2);
+        let context_display = truncate(&context_display, room);
         let header_text = vec![Line::from(vec![
             Span::styled(
-                format!("[ {} ]", self.mode.label()),
+                mode_label,
                 Style::default()
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
@@ -1059,10 +1405,12 @@ impl App {
             ),
         ])];
 
-        let header_block = Paragraph::new(header_text)
-            .block(Block::default().borders(Borders::ALL))
-            .alignment(Alignment::Center);
-        f.render_widget(header_block, chunks[0]);
+        if !self.compact {
+            let header_block = Paragraph::new(header_text)
+                .block(Block::default().borders(Borders::ALL))
+                .alignment(Alignment::Center);
+            f.render_widget(header_block, chunks[0]);
+        }
 
         let header_cells = [
             "ID", "Kind", "Status", "Priority", "Urgency", "Stage", "Due", "Project", "Lock",
@@ -1084,18 +1432,21 @@ impl App {
 
         // Calculate depth map for tree view
         let mut depth_map: HashMap<LeafId, usize> = HashMap::new();
+        let mut cycle = None;
         for task in &self.db.tasks {
-            let mut depth = 0usize;
-            let mut cur = task.parent;
-            while let Some(pid) = cur {
-                depth += 1;
-                cur = self.db.get(pid).and_then(|p| p.parent);
-                if depth > 64 {
-                    break;
-                } // cycle guard
-            }
+            let depth = self.db.depth_of(task.id).unwrap_or_else(|c| {
+                cycle.get_or_insert(c);
+                0
+            });
             depth_map.insert(task.id, depth);
         }
+        // Surface a parent loop instead of silently flattening it. Only
+        // fills an empty status line so it never hides a fresher message.
+        if let Some(c) = cycle {
+            if self.status_message.is_empty() {
+                self.set_status_message(c.to_string());
+            }
+        }
 
         // Load active locks once per render, keyed by ticket id, so each row
         // can show its lock state without a per-row directory read.
@@ -1106,23 +1457,64 @@ impl App {
             .collect();
         let now = Utc::now();
 
+        // Wide enough for the longest id on screen, never narrower than the
+        // header.
+        let id_width = self
+            .filtered_tasks
+            .iter()
+            .map(|&id| id_label(id, self.short_ids).len())
+            .max()
+            .unwrap_or(0)
+            .max(4) as u16;
+        let widths = [
+            Constraint::Length(id_width), // ID
+            Constraint::Length(10),       // Kind
+            Constraint::Length(12),       // Status
+            Constraint::Length(15),       // Priority
+            Constraint::Length(18),       // Urgency
+            Constraint::Length(13),       // Stage
+            Constraint::Length(12),       // Due
+            Constraint::Length(12),       // Project
+            Constraint::Length(16),       // Lock
+            Constraint::Min(25),          // Title
+        ];
+
+        // Whatever the fixed columns, the borders, the highlight symbol and
+        // the one-cell column gaps leave over is the title's; titles that
+        // do not fit end in an ellipsis rather than being clipped.
+        let fixed: usize = widths
+            .iter()
+            .map(|c| match c {
+                Constraint::Length(n) => *n as usize,
+                _ => 0,
+            })
+            .sum();
+        let title_width = (chunks[1].width as usize)
+            .saturating_sub(2 + 3 + fixed + (widths.len() - 1))
+            .max(25);
+
+        let chip_colors = colors_enabled();
         let rows: Vec<Row> = self
             .filtered_tasks
             .iter()
             .filter_map(|&id| self.db.get(id))
             .map(|task| {
                 let due_str = format_due_relative(task.due, today);
+                // Completed tasks keep the row's dimmed style.
+                let due_cell = match due_state(task.due, task.remind_days_before, today) {
+                    Some(state) if !task.status.is_closed() => {
+                        ratatui::widgets::Cell::from(due_str)
+                            .style(Style::default().fg(due_state_color(state)))
+                    }
+                    _ => ratatui::widgets::Cell::from(due_str),
+                };
                 let project_label_str = project_label(&self.db, task);
                 let project_str = if project_label_str == "-" {
                     "-".to_string()
                 } else {
                     project_label_str
                 };
-                let tags_str = if task.tags.is_empty() {
-                    String::new()
-                } else {
-                    format!(" [{}]", task.tags.join(","))
-                };
+                let (tags_str, chips) = tag_suffix(&task.tags, chip_colors);
 
                 // Determine hierarchy color
                 let hierarchy_color = match task.kind {
@@ -1134,13 +1526,27 @@ impl App {
                     Kind::Milestone => DARK_PURPLE, // Magenta for milestones
                 };
 
+                // Open work waiting on an unfinished dependency is dimmed
+                // and marked, to discourage starting it.
+                let blocked = !task.status.is_closed() && !self.db.open_blockers(task).is_empty();
                 let style = match task.status {
-                    Status::Done => Style::default().fg(Color::DarkGray),
+                    Status::Done => Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::DIM),
+                    Status::Cancelled => Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::DIM | Modifier::CROSSED_OUT),
+                    Status::Blocked => Style::default().fg(Color::LightRed),
                     Status::InProgress => Style::default()
                         .fg(hierarchy_color)
                         .add_modifier(Modifier::BOLD),
                     _ => Style::default().fg(Color::White),
                 };
+                let style = if blocked {
+                    style.add_modifier(Modifier::DIM)
+                } else {
+                    style
+                };
 
                 let depth = depth_map.get(&task.id).copied().unwrap_or(0);
                 let indent_str = " ".repeat(depth);
@@ -1152,7 +1558,63 @@ impl App {
                 } else {
                     format!("  M:{}", task.memories.len())
                 };
-                let title_with_tags = format!("{}{}{}", task.title, tags_str, memory_badge);
+                // Tags follow the title as coloured chips. With a `/` filter
+                // active, each match in the title and tags is marked too so
+                // it is clear why the row is listed.
+                let pin = if task.pinned { PIN_MARK } else { "" };
+                let blocked_mark = if blocked { BLOCKED_MARK } else { "" };
+                let full = format!("{}{}", task.title, tags_str);
+                let title_and_tags = truncate(
+                    &full,
+                    title_width.saturating_sub(
+                        depth
+                            + pin.chars().count()
+                            + blocked_mark.len()
+                            + memory_badge.chars().count(),
+                    ),
+                );
+                // Bytes of `full` still shown; a cut ends in a one-char `…`.
+                let kept = if title_and_tags == full {
+                    full.len()
+                } else {
+                    title_and_tags.len() - '…'.len_utf8()
+                };
+                let offset = task.title.len();
+                let mut layers: Vec<(usize, usize, Style)> = chips
+                    .into_iter()
+                    .map(|(start, end, color)| {
+                        (
+                            offset + start,
+                            (offset + end).min(kept),
+                            Style::default().fg(Color::Black).bg(color),
+                        )
+                    })
+                    .filter(|&(start, end, _)| start < end)
+                    .collect();
+                layers.extend(
+                    match_ranges(&title_and_tags, &self.filter_text)
+                        .into_iter()
+                        .map(|(start, end)| {
+                            (
+                                start,
+                                end,
+                                Style::default()
+                                    .fg(Color::Black)
+                                    .bg(GOLD)
+                                    // The selected row's highlight repaints
+                                    // colours; the modifiers keep the match
+                                    // visible there.
+                                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                            )
+                        }),
+                );
+                let mut title_spans = vec![
+                    Span::raw(indent_str),
+                    Span::styled(pin, Style::default().fg(GOLD)),
+                    Span::styled(blocked_mark, Style::default().fg(Color::LightRed)),
+                ];
+                title_spans.extend(layered_spans(&title_and_tags, &layers));
+                title_spans.push(Span::raw(memory_badge));
 
                 // Lock state: empty when free, STALE past the TTL window,
                 // otherwise the holding agent (truncated to the column).
@@ -1165,44 +1627,28 @@ impl App {
                 };
 
                 Row::new(vec![
-                    ratatui::widgets::Cell::from(task.id.to_string()),
+                    ratatui::widgets::Cell::from(id_label(task.id, self.short_ids)),
                     ratatui::widgets::Cell::from(format_kind(task.kind)),
                     ratatui::widgets::Cell::from(format_status(task.status)),
                     ratatui::widgets::Cell::from(format_priority(task.priority_level)),
                     ratatui::widgets::Cell::from(format_urgency(task.urgency)),
                     ratatui::widgets::Cell::from(format_process_stage(task.process_stage)),
-                    ratatui::widgets::Cell::from(due_str),
+                    due_cell,
                     ratatui::widgets::Cell::from(project_str),
                     lock_cell,
-                    ratatui::widgets::Cell::from(if depth == 0 {
-                        title_with_tags
-                    } else {
-                        format!("{}{}", indent_str, title_with_tags)
-                    }),
+                    ratatui::widgets::Cell::from(Line::from(title_spans)),
                 ])
                 .style(style)
             })
             .collect();
 
-        let widths = [
-            Constraint::Length(4),  // ID
-            Constraint::Length(10), // Kind
-            Constraint::Length(12), // Status
-            Constraint::Length(15), // Priority
-            Constraint::Length(18), // Urgency
-            Constraint::Length(13), // Stage
-            Constraint::Length(12), // Due
-            Constraint::Length(12), // Project
-            Constraint::Length(16), // Lock
-            Constraint::Min(25),    // Title
-        ];
-
         let table = Table::new(rows, widths)
             .header(header)
             .block(Block::default().borders(Borders::ALL).title(format!(
-                "Tasks ({}/{}) - Press 'h' for help",
+                "Tasks ({}/{}) - Sort: {} (o) - Press 'h' for help",
                 self.filtered_tasks.len(),
-                self.db.tasks.len()
+                self.db.tasks.len(),
+                self.list_sort.map(format_sort_key).unwrap_or("Default")
             )))
             .row_highlight_style(Style::default().bg(Color::Gray).fg(Color::Black))
             .highlight_symbol(">> ");
@@ -1210,8 +1656,60 @@ impl App {
         f.render_stateful_widget(table, chunks[1], &mut self.task_list_state);
     }
 
-    /// Render the task creation or editing form.
+    /// The ticket the form would save, for the preview pane: the edited
+    /// ticket with the form's values over it, or a new one under the id it
+    /// would be given. A parent that does not resolve yet is left out.
+    fn form_preview_task(&self, is_edit: bool) -> Task {
+        let parent = self
+            .task_form
+            .parent
+            .value
+            .trim()
+            .parse::<IdInput>()
+            .ok()
+            .map(|parsed| parsed.leaf())
+            .filter(|pid| self.db.get(*pid).is_some());
+        let editing = self
+            .selected_task
+            .filter(|_| is_edit)
+            .and_then(|id| self.db.get(id));
+        match editing {
+            Some(task) => {
+                let mut task = task.clone();
+                self.task_form.apply_to(&mut task);
+                task.parent = parent;
+                task
+            }
+            None => {
+                let kind = self.task_form.kinds[self.task_form.kind];
+                self.form_task(self.db.state.peek(kind_to_prefix(kind)), parent)
+            }
+        }
+    }
+
+    /// Render the task creation or editing form, with the preview pane
+    /// beside it unless Ctrl+P has hidden it.
     fn render_task_form(&mut self, f: &mut Frame, area: Rect, is_edit: bool) {
+        let area = if self.form_preview {
+            let halves = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(62), Constraint::Percentage(38)])
+                .split(area);
+            let preview_area = halves[1];
+            let task = self.form_preview_task(is_edit);
+            let preview = Paragraph::new(self.detail_lines(&task, preview_area.width))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Preview - Ctrl+P hides"),
+                )
+                .wrap(Wrap { trim: true });
+            f.render_widget(preview, preview_area);
+            halves[0]
+        } else {
+            area
+        };
+
         // Split into two columns to fit all fields
         let main_chunks = Layout::default()
             .direction(Direction::Horizontal)
@@ -1333,25 +1831,26 @@ impl App {
         f.render_widget(tags_input, left_chunks[4]);
 
         // Due Date (field 5)
-        let due_style = if self.task_form.current_field == DUE_GLOBAL_ORDER {
-            Style::default().fg(GOLD)
-        } else {
-            Style::default()
-        };
+        let due_style = typed_field_style(
+            &self.task_form.due,
+            self.task_form.current_field == DUE_GLOBAL_ORDER,
+        );
         let due_input = Paragraph::new(self.task_form.due.value.as_str()).block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Due (YYYY-MM-DD, today, tomorrow, in Nd)")
+                .title(typed_field_title(
+                    "Due (YYYY-MM-DD, today, tomorrow, in Nd)",
+                    &self.task_form.due,
+                ))
                 .border_style(due_style),
         );
         f.render_widget(due_input, left_chunks[5]);
 
         // Parent ID (field 6)
-        let parent_style = if self.task_form.current_field == PARENT_GLOBAL_ORDER {
-            Style::default().fg(GOLD)
-        } else {
-            Style::default()
-        };
+        let parent_style = typed_field_style(
+            &self.task_form.parent,
+            self.task_form.current_field == PARENT_GLOBAL_ORDER,
+        );
 
         // Add parent navigation info
         let parent_title = if !self.task_form.parent.value.trim().is_empty() {
@@ -1372,35 +1871,33 @@ impl App {
         let parent_input = Paragraph::new(self.task_form.parent.value.as_str()).block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(parent_title.as_str())
+                .title(typed_field_title(&parent_title, &self.task_form.parent))
                 .border_style(parent_style),
         );
         f.render_widget(parent_input, left_chunks[6]);
 
         // Issue Link (field 7)
-        let issue_style = if self.task_form.current_field == ISSUE_LINK_GLOBAL_ORDER {
-            Style::default().fg(GOLD)
-        } else {
-            Style::default()
-        };
+        let issue_style = typed_field_style(
+            &self.task_form.issue_link,
+            self.task_form.current_field == ISSUE_LINK_GLOBAL_ORDER,
+        );
         let issue_input = Paragraph::new(self.task_form.issue_link.value.as_str()).block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Issue Link")
+                .title(typed_field_title("Issue Link", &self.task_form.issue_link))
                 .border_style(issue_style),
         );
         f.render_widget(issue_input, left_chunks[7]);
 
         // PR Link (field 8)
-        let pr_style = if self.task_form.current_field == PR_LINK_GLOBAL_ORDER {
-            Style::default().fg(GOLD)
-        } else {
-            Style::default()
-        };
+        let pr_style = typed_field_style(
+            &self.task_form.pr_link,
+            self.task_form.current_field == PR_LINK_GLOBAL_ORDER,
+        );
         let pr_input = Paragraph::new(self.task_form.pr_link.value.as_str()).block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("PR Link")
+                .title(typed_field_title("PR Link", &self.task_form.pr_link))
                 .border_style(pr_style),
         );
         f.render_widget(pr_input, left_chunks[8]);
@@ -1557,16 +2054,68 @@ impl App {
 
         // Instructions at bottom of left column
         let help_text = if is_edit {
-            "Tab/↑↓/jk: Navigate • ← →: Change selectors • Enter: Save/Dialog • Esc: Cancel • User Story & Requirements have fullscreen dialogs!"
+            "Tab/↑↓/jk: Navigate • ← →: Change selectors • Enter: Save/Dialog • Ctrl+P: Preview • Esc: Cancel • User Story & Requirements have fullscreen dialogs!"
         } else {
-            "Tab/↑↓/jk: Navigate • ← →: Change selectors • Enter: Create/Dialog • Esc: Cancel • User Story & Requirements have fullscreen dialogs!"
+            "Tab/↑↓/jk: Navigate • ← →: Change selectors • Enter: Create/Dialog • Ctrl+T: Template • Ctrl+P: Preview • Esc: Cancel • User Story & Requirements have fullscreen dialogs!"
         };
 
-        let instructions = Paragraph::new(help_text)
+        let mut lines = Vec::new();
+        if let Some(error) = &self.save_error {
+            lines.push(Line::from(Span::styled(
+                format!("Save failed: {error}. Ctrl+R: Retry • Esc: Discard"),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )));
+        }
+        lines.push(Line::from(help_text));
+        let instructions = Paragraph::new(lines)
             .block(Block::default().borders(Borders::ALL).title("Instructions"))
             .wrap(Wrap { trim: true });
         f.render_widget(instructions, right_chunks[2]);
 
+        // Suggestions for the tag being typed drop down over the fields
+        // below the tags box.
+        let known = known_tags(&self.db.tasks);
+        let suggestions = self.task_form.tag_suggestions(&known);
+        if !suggestions.is_empty() {
+            let shown = suggestions.len().min(5);
+            let tags_area = left_chunks[4];
+            let width = suggestions
+                .iter()
+                .map(|t| t.chars().count())
+                .max()
+                .unwrap_or(0)
+                .max(14) as u16
+                + 2;
+            let popup = Rect {
+                x: tags_area.x + 1,
+                y: tags_area.bottom(),
+                width: width.min(tags_area.width.saturating_sub(2)),
+                height: (shown as u16 + 2).min(area.bottom().saturating_sub(tags_area.bottom())),
+            };
+            let lines: Vec<Line> = suggestions
+                .iter()
+                .take(shown)
+                .enumerate()
+                .map(|(i, tag)| {
+                    let style = if i == 0 {
+                        Style::default().fg(Color::Black).bg(GOLD)
+                    } else {
+                        Style::default()
+                    };
+                    Line::from(Span::styled(*tag, style))
+                })
+                .collect();
+            f.render_widget(Clear, popup);
+            f.render_widget(
+                Paragraph::new(lines).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Tab: complete"),
+                ),
+                popup,
+            );
+        }
+
         // Render cursor for active text fields
         let cursor_field = match self.task_form.current_field {
             TITLE_GLOBAL_ORDER => Some((left_chunks[0], &self.task_form.title)),
@@ -1592,10 +2141,17 @@ impl App {
 
     /// Render the context-sensitive help row at the bottom of the screen.
     fn render_status_bar(&mut self, f: &mut Frame, area: Rect) {
-        let status_text = if !self.status_message.is_empty() {
+        let status_text = if matches!(self.input_mode, InputMode::Command) {
+            // Completion candidates from Tab ride alongside the typed line.
+            if self.status_message.is_empty() {
+                format!(":{}", self.command_buffer)
+            } else {
+                format!(":{}    {}", self.command_buffer, self.status_message)
+            }
+        } else if !self.status_message.is_empty() {
             self.status_message.clone()
         } else if matches!(self.overlay, Overlay::Help { .. }) {
-            "Help: ^v scroll   ? / Esc close   Tab / 1 / 2 / 3 switch mode".to_string()
+            "Help: ^v scroll   F1 / Esc close   Tab / 1 / 2 / 3 switch mode".to_string()
         } else if self.filter_active {
             format!(
                 "Search: {} (Esc to clear, Enter to confirm)",
@@ -1603,7 +2159,7 @@ impl App {
             )
         } else if !self.filter_text.is_empty() {
             format!(
-                "Tasks: {} (filtered by '{}') | ? for help",
+                "Tasks: {} (filtered by '{}') | F1 help | ? hints",
                 self.filtered_tasks.len(),
                 self.filter_text
             )
@@ -1611,7 +2167,7 @@ impl App {
             match self.mode {
                 Mode::Documents | Mode::Activity => {
                     format!(
-                        "{}   Tab / 1 / 2 / 3 switch mode   ? help   q exit",
+                        "{}   Tab / 1 / 2 / 3 switch mode   F1 help   ? hints   q exit",
                         self.mode.label()
                     )
                 }
@@ -1622,9 +2178,17 @@ impl App {
                         } else {
                             ""
                         };
+                        let mut view_tip = String::new();
+                        if let Some(key) = self.list_sort {
+                            view_tip.push_str(&format!(" | Sort: {}", format_sort_key(key)));
+                        }
+                        if let Some(df) = self.due_filter {
+                            view_tip.push_str(&format!(" | Due: {}", format_due_filter(df)));
+                        }
                         format!(
-                            "Tasks: {} | ? for help | Tab / 1 / 2 / 3 mode{}",
+                            "Tasks: {}{} | F1 help | ? hints | : command | Tab / 1 / 2 / 3 mode{}",
                             self.filtered_tasks.len(),
+                            view_tip,
                             back_tip
                         )
                     }
@@ -1660,13 +2224,15 @@ impl App {
     fn render(&mut self, f: &mut Frame) {
         match self.mode {
             Mode::Tickets => {
-                // Three-band layout: content / activity-footer tail / status.
+                // Three-band layout: content / activity-footer tail / status,
+                // plus the key-hint row above the status when it is on.
                 let chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .constraints(
                         [
                             Constraint::Min(0),
                             Constraint::Length(5),
+                            Constraint::Length(self.hint_rows()),
                             Constraint::Length(1),
                         ]
                         .as_ref(),
@@ -1694,7 +2260,8 @@ impl App {
                 }
 
                 self.render_activity_footer(f, chunks[1]);
-                self.render_status_bar(f, chunks[2]);
+                self.render_key_hints(f, chunks[2]);
+                self.render_status_bar(f, chunks[3]);
             }
             Mode::Documents => {
                 // Same three-band layout as Tickets - the document workspace
@@ -1705,6 +2272,7 @@ impl App {
                         [
                             Constraint::Min(0),
                             Constraint::Length(5),
+                            Constraint::Length(self.hint_rows()),
                             Constraint::Length(1),
                         ]
                         .as_ref(),
@@ -1713,7 +2281,8 @@ impl App {
 
                 self.render_documents(f, chunks[0]);
                 self.render_activity_footer(f, chunks[1]);
-                self.render_status_bar(f, chunks[2]);
+                self.render_key_hints(f, chunks[2]);
+                self.render_status_bar(f, chunks[3]);
             }
             Mode::Activity => {
                 // Two-band layout: the activity view owns its own filter and
@@ -1738,6 +2307,8 @@ impl App {
                 PromptType::RenameTicket(_) => {
                     "Rename or move - new title, or `move <ADDRESS>` (Enter / Esc)"
                 }
+                PromptType::AddTags(_) => "Add tags - comma or space separated (Enter / Esc)",
+                PromptType::RemoveTags(_) => "Remove tags - comma or space separated (Enter / Esc)",
             };
             let area = centered_rect(70, 20, f.area());
             f.render_widget(Clear, area);
@@ -1875,8 +2446,9 @@ impl App {
         f.render_widget(widget, panel);
     }
 
-    /// Input dispatch for Mode 2 - the Document Workspace. Mode-switch keys
-    /// and `?` / `F1` for help are already consumed before this is reached.
+    /// Input dispatch for Mode 2 - the Document Workspace. Mode-switch keys,
+    /// `F1` for help and `?` for hints are already consumed before this is
+    /// reached.
     /// Later commits in Phase 8 layer on $EDITOR shell-out and the artifact
     /// / memory / rename modals.
     fn handle_documents_input(
@@ -2854,3 +3426,24 @@ fn memory_ref_label(reference: &MemoryRef) -> String {
         MemoryRef::Ticket(name) => format!("@{name}  [ticket]"),
     }
 }
+
+/// Border style for a typed form field: red while the value does not match
+/// the field's kind, gold while focused, plain otherwise.
+fn typed_field_style(field: &InputField, focused: bool) -> Style {
+    if !field.is_valid() {
+        Style::default().fg(Color::Red)
+    } else if focused {
+        Style::default().fg(GOLD)
+    } else {
+        Style::default()
+    }
+}
+
+/// Field title with the expected format appended while the value is invalid.
+fn typed_field_title(label: &str, field: &InputField) -> String {
+    if field.is_valid() {
+        label.to_string()
+    } else {
+        format!("{label} - expected {}", field.kind.hint())
+    }
+}
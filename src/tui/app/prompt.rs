@@ -5,9 +5,9 @@
 
 use crossterm::event::KeyCode;
 
-use crate::db::format_kind;
+use crate::db::{format_kind, split_and_normalise_tags};
 use crate::store::{aliases::Aliases, artifacts, events, layout::Layout};
-use crate::tui::enums::{Overlay, PromptState, PromptType};
+use crate::tui::enums::{EditAction, Overlay, PromptState, PromptType};
 
 use super::App;
 
@@ -73,6 +73,7 @@ impl App {
                             Some(&name),
                         );
                         self.refresh_tasks();
+                        self.note_edit("an artifact add");
                         self.set_status_message(format!("Added artifact {name} to {leaf}"));
                     }
                     Err(e) => self.set_status_message(format!("artifact add failed: {e}")),
@@ -97,7 +98,63 @@ impl App {
                     self.rename_prompt_title(leaf, raw);
                 }
             }
+            PromptType::AddTags(leaf) => {
+                let tags = prompt_tags(&prompt.buffer);
+                if !tags.is_empty() {
+                    self.run_edit(leaf, EditAction::AddTags(tags));
+                }
+            }
+            PromptType::RemoveTags(leaf) => {
+                let tags = prompt_tags(&prompt.buffer);
+                if !tags.is_empty() {
+                    self.edit_tags(leaf, tags, false);
+                    self.note_edit("a tag removal");
+                }
+            }
+        }
+    }
+
+    /// Add or remove `requested` tags on a ticket. Tags already present (or
+    /// already absent) are reported rather than treated as errors; nothing is
+    /// saved when no tag actually changes.
+    pub(super) fn edit_tags(
+        &mut self,
+        leaf: crate::store::LeafId,
+        requested: Vec<String>,
+        add: bool,
+    ) {
+        let Some(task) = self.db.get_mut(leaf) else {
+            self.set_status_message(format!("tag: {leaf} not in db"));
+            return;
+        };
+        let (changed, unchanged): (Vec<String>, Vec<String>) = requested
+            .into_iter()
+            .partition(|tag| task.tags.contains(tag) != add);
+        if changed.is_empty() {
+            let state = if add { "already tagged" } else { "not tagged" };
+            self.set_status_message(format!("{leaf}: {state} {}", unchanged.join(", ")));
+            return;
+        }
+        if add {
+            task.tags.extend(changed.iter().cloned());
+            task.tags.sort();
+        } else {
+            task.tags.retain(|tag| !changed.contains(tag));
         }
+        task.updated_at_utc = chrono::Utc::now().timestamp();
+        if let Err(e) = self.save_db() {
+            self.set_status_message(format!("tag: save failed: {e}"));
+            return;
+        }
+        let verb = if add { "tagged" } else { "untagged" };
+        let summary = format!("{verb} {}", changed.join(", "));
+        let _ = events::emit_event(&self.pm_dir, "update", Some(leaf), Some(&summary));
+        self.set_status_message(if unchanged.is_empty() {
+            format!("{leaf}: {summary}")
+        } else {
+            let skipped = if add { "already had" } else { "did not have" };
+            format!("{leaf}: {summary} ({skipped} {})", unchanged.join(", "))
+        });
     }
 
     /// Rewrite the focused ticket's title in front-matter. Emits a `rename`
@@ -117,15 +174,13 @@ impl App {
             return;
         }
         let _ = events::emit_event(&self.pm_dir, "rename", Some(leaf), Some(&new_title));
+        self.note_edit("a rename");
         self.refresh_tasks();
         self.set_status_message(format!("{leaf}: renamed to {new_title}"));
     }
 
-    /// Reparent the ticket under `target`, mirroring `cmd_move`'s semantics
-    /// without the `println!` / `process::exit` side effects.
+    /// Parse the `move <ADDRESS>` target and reparent the ticket under it.
     fn rename_prompt_move(&mut self, leaf: crate::store::LeafId, target: &str) {
-        use crate::db::validate_hierarchy;
-
         // Parse the target as a leaf id directly. Tolerate the same address
         // and label forms the resolver does; pull the last segment as the
         // new parent.
@@ -136,27 +191,38 @@ impl App {
                 return;
             }
         };
-        let target_parent = input.leaf();
+        self.run_edit(leaf, EditAction::MoveUnder(input.leaf()));
+    }
+
+    /// Reparent the ticket under `target_parent`, mirroring `cmd_move`'s
+    /// semantics without the `println!` / `process::exit` side effects.
+    pub(super) fn move_ticket(
+        &mut self,
+        leaf: crate::store::LeafId,
+        target_parent: crate::store::LeafId,
+    ) {
+        use crate::db::validate_hierarchy;
+
         if target_parent == leaf {
-            self.set_status_message("rename: parent cannot equal the ticket itself".to_string());
+            self.set_status_message("move: parent cannot equal the ticket itself".to_string());
             return;
         }
         if self.db.get(target_parent).is_none() {
-            self.set_status_message(format!("rename: target {target_parent} not found"));
+            self.set_status_message(format!("move: target {target_parent} not found"));
             return;
         }
 
         let task_kind = match self.db.get(leaf) {
             Some(t) => t.kind,
             None => {
-                self.set_status_message(format!("rename: {leaf} not in db"));
+                self.set_status_message(format!("move: {leaf} not in db"));
                 return;
             }
         };
         let parent_kind = self.db.get(target_parent).unwrap().kind;
         if !validate_hierarchy(parent_kind, task_kind) {
             self.set_status_message(format!(
-                "rename: invalid hierarchy: {} cannot be child of {}",
+                "move: invalid hierarchy: {} cannot be child of {}",
                 format_kind(task_kind),
                 format_kind(parent_kind),
             ));
@@ -176,7 +242,7 @@ impl App {
             task.updated_at_utc = chrono::Utc::now().timestamp();
         }
         if let Err(e) = self.db.save(&self.pm_dir) {
-            self.set_status_message(format!("rename: save failed: {e}"));
+            self.set_status_message(format!("move: save failed: {e}"));
             return;
         }
 
@@ -202,7 +268,7 @@ impl App {
                     let mut aliases = Aliases::load(&aliases_path).unwrap_or_default();
                     aliases.add(old.to_string(), new.to_string());
                     if let Err(e) = aliases.save(&aliases_path) {
-                        self.set_status_message(format!("rename: alias write failed: {e}"));
+                        self.set_status_message(format!("move: alias write failed: {e}"));
                         return;
                     }
                 }
@@ -220,6 +286,15 @@ impl App {
     }
 }
 
+/// Split a `+` / `-` prompt buffer into normalised tags.
+fn prompt_tags(raw: &str) -> Vec<String> {
+    split_and_normalise_tags(
+        &raw.split_whitespace()
+            .map(str::to_string)
+            .collect::<Vec<_>>(),
+    )
+}
+
 /// Walk the parent chain to compute a ticket's current address. Returns
 /// `None` if the chain breaks. Duplicate of the helper in cmd.rs - kept
 /// local so the prompt path does not have to import a CLI module.
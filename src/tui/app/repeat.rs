@@ -0,0 +1,89 @@
+//! `.` repeats the last ticket edit on the selected ticket, Vim-style.
+//!
+//! Repeatable edits are dispatched through [`App::run_edit`], so the original
+//! keystroke (or command, or prompt) and the repeat take the same path and
+//! the edit is recorded as it runs. They are: `:complete`, `p` (stage
+//! cycle), adding tags (`+`, `:tag`) and `move <ID>` from the rename prompt.
+//! Other edits call [`App::note_edit`] instead, which records them by name so
+//! a following `.` reports that it won't repeat them rather than replaying
+//! something older.
+
+use crate::db::format_process_stage;
+use crate::fields::{ProcessStage, Status};
+use crate::store::LeafId;
+use crate::tui::enums::EditAction;
+
+use super::App;
+
+impl App {
+    /// Apply `action` to `target` and remember it for `.`.
+    pub(super) fn run_edit(&mut self, target: LeafId, action: EditAction) {
+        match &action {
+            EditAction::Complete => self.complete_ticket(target),
+            EditAction::CycleStage => self.cycle_stage(target),
+            EditAction::AddTags(tags) => self.edit_tags(target, tags.clone(), true),
+            EditAction::MoveUnder(parent) => self.move_ticket(target, *parent),
+            EditAction::Other(_) => {}
+        }
+        self.last_edit = Some(action);
+    }
+
+    /// Record a non-repeatable edit so `.` doesn't replay an older one.
+    pub(super) fn note_edit(&mut self, name: &'static str) {
+        self.last_edit = Some(EditAction::Other(name));
+    }
+
+    /// Replay the last edit on the highlighted ticket.
+    pub(super) fn repeat_last_edit(&mut self) {
+        let Some(target) = self.selected_task_id() else {
+            self.set_status_message("No ticket selected".to_string());
+            return;
+        };
+        match self.last_edit.clone() {
+            None => self.set_status_message("Nothing to repeat yet".to_string()),
+            Some(EditAction::Other(name)) => self.set_status_message(format!(
+                "`.` does not repeat {name}; it repeats :complete, p, tag adds and moves"
+            )),
+            Some(action) => self.run_edit(target, action),
+        }
+    }
+
+    /// Mark one ticket Done, honouring the open-children guard and the
+    /// status flow.
+    fn complete_ticket(&mut self, target: LeafId) {
+        if self.completion_blocked(&[target]) || self.status_flow_blocked(&[target], Status::Done) {
+            return;
+        }
+        self.apply_to_ids(&[target], "complete", "Completed", |t| {
+            t.status = Status::Done;
+        });
+    }
+
+    /// Advance a ticket's process stage, wrapping from Release to Ideation.
+    fn cycle_stage(&mut self, target: LeafId) {
+        let Some(task) = self.db.get_mut(target) else {
+            return;
+        };
+        // Cycle through process stages: Ideation -> Design -> Prototyping -> Ready to Implement -> Implementation -> Testing -> Refinement -> Release -> Ideation
+        let new_stage = match task.process_stage {
+            Some(ProcessStage::Ideation) => ProcessStage::Design,
+            Some(ProcessStage::Design) => ProcessStage::Prototyping,
+            Some(ProcessStage::Prototyping) => ProcessStage::ReadyToImplement,
+            Some(ProcessStage::ReadyToImplement) => ProcessStage::Implementation,
+            Some(ProcessStage::Implementation) => ProcessStage::Testing,
+            Some(ProcessStage::Testing) => ProcessStage::Refinement,
+            Some(ProcessStage::Refinement) => ProcessStage::Release,
+            Some(ProcessStage::Release) => ProcessStage::Ideation,
+            None => ProcessStage::Ideation, // Start with Ideation if no stage set
+        };
+        task.process_stage = Some(new_stage);
+        if let Err(e) = self.save_db() {
+            self.set_status_message(format!("Error saving: {}", e));
+        } else {
+            self.set_status_message(format!(
+                "Process stage updated to {}",
+                format_process_stage(Some(new_stage))
+            ));
+        }
+    }
+}
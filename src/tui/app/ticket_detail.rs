@@ -1,7 +1,14 @@
 //! Mode 1 ticket-detail screen. Drilling into a list row pushes
 //! `AppState::TaskDetail`; this module handles the keys that work there
-//! (Esc/q back, e edit, d delete confirm, p / c parent / first child) and
-//! renders the metadata block including hierarchy navigation hints.
+//! (Esc/q back, e edit, d delete confirm, p / c parent / first child, and
+//! `+` / `-` to add / remove tags via a prompt, b the first unfinished
+//! dependency; all but Esc remappable through the keymap) and renders the
+//! metadata block including hierarchy navigation hints and the ticket's
+//! dependencies with their status. Children are listed in a numbered sub-list that
+//! Up/Down scroll; `1`-`9` jump to the child on that row. The task's
+//! artifacts are listed too: `a` selects one and `o` opens it. `n` opens the
+//! add form for a child of the ticket, and saving or leaving it comes back
+//! here.
 
 use std::io;
 
@@ -16,14 +23,24 @@ use ratatui::{
 };
 
 use crate::db::{
-    build_children_map, format_due_relative, format_kind, format_priority, format_process_stage,
-    format_status, format_urgency, project_label,
+    build_children_map, child_kind_for, format_due_detail, format_kind, format_priority,
+    format_stage_progress, format_status, format_urgency, project_label, resolve_artifact_path,
 };
-use crate::tui::enums::{AppState, InputMode};
+use crate::fields::Status;
+use crate::store::LeafId;
+use crate::task::Task;
+use crate::tui::enums::{
+    AppState, HierarchyLevel, InputMode, NavigationContext, Overlay, PromptState, PromptType,
+};
+use crate::tui::keymap::{Action, KeyContext};
 use crate::tui::task_form::TaskForm;
+use crate::tui::utils::{id_label, open_with_default_app};
 
 use super::App;
 
+/// Rows in the detail view's children sub-list, one per digit key.
+const CHILD_ROWS: usize = 9;
+
 impl App {
     /// Handle keyboard input when viewing task details.
     ///
@@ -33,11 +50,44 @@ impl App {
         key: KeyCode,
         _modifiers: KeyModifiers,
     ) -> io::Result<bool> {
-        match key {
-            KeyCode::Esc | KeyCode::Char('q') => {
+        let action = match key {
+            KeyCode::Up => {
+                self.detail_child_scroll = self.detail_child_scroll.saturating_sub(1);
+                return Ok(false);
+            }
+            KeyCode::Down => {
+                let count = self.detail_children().len();
+                if self.detail_child_scroll + CHILD_ROWS < count {
+                    self.detail_child_scroll += 1;
+                }
+                return Ok(false);
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                let children = self.detail_children();
+                let scroll = self
+                    .detail_child_scroll
+                    .min(children.len().saturating_sub(CHILD_ROWS));
+                match children.get(scroll + c as usize - '1' as usize) {
+                    Some(&child) => {
+                        self.show_in_detail(child);
+                        self.set_status_message(format!("Navigated to child task #{}", child));
+                    }
+                    None => self.set_status_message(format!("No child on row {c}")),
+                }
+                return Ok(false);
+            }
+            KeyCode::Esc => Action::DetailBack,
+            KeyCode::Char(c) => match self.keymap.action(KeyContext::Detail, c) {
+                Some(action) => action,
+                None => return Ok(false),
+            },
+            _ => return Ok(false),
+        };
+        match action {
+            Action::DetailBack => {
                 self.state = AppState::TaskList;
             }
-            KeyCode::Char('e') => {
+            Action::DetailEdit => {
                 if let Some(task_id) = self.selected_task {
                     if let Some(task) = self.db.get(task_id) {
                         self.task_form = TaskForm::from_task_with_pm_dir(task, &self.pm_dir);
@@ -47,18 +97,38 @@ impl App {
                     }
                 }
             }
-            KeyCode::Char('d') => {
+            Action::DetailNewChild => {
+                let Some(task) = self.get_selected_task() else {
+                    return Ok(false);
+                };
+                if child_kind_for(task.kind).is_none() {
+                    self.set_status_message(format!(
+                        "A {} cannot have children",
+                        format_kind(task.kind)
+                    ));
+                    return Ok(false);
+                }
+                // The form picks the kind below the parent's level, as
+                // `n` in a list filtered to this ticket's children would.
+                let context = NavigationContext::new_filtered(
+                    HierarchyLevel::of_kind(task.kind),
+                    task.id,
+                    task.title.clone(),
+                );
+                self.child_form_parent = Some(task.id);
+                self.open_add_form(&context);
+            }
+            Action::DetailDelete => {
                 if let Some(task_id) = self.selected_task {
                     self.confirm_action = Some(format!("Delete task #{}", task_id));
                     self.push_state(AppState::Confirm, None);
                 }
             }
-            KeyCode::Char('p') => {
-                // Go to parent
+            Action::DetailParent => {
                 if let Some(task_id) = self.selected_task {
                     if let Some(task) = self.db.get(task_id) {
                         if let Some(parent_id) = task.parent {
-                            self.selected_task = Some(parent_id);
+                            self.show_in_detail(parent_id);
                             self.set_status_message(format!(
                                 "Navigated to parent task #{}",
                                 parent_id
@@ -69,23 +139,46 @@ impl App {
                     }
                 }
             }
-            KeyCode::Char('c') => {
-                // Go to first child
+            Action::DetailChild => {
+                if let Some(&first_child) = self.detail_children().first() {
+                    self.show_in_detail(first_child);
+                    self.set_status_message(format!("Navigated to child task #{}", first_child));
+                } else {
+                    self.set_status_message("No child tasks".to_string());
+                }
+            }
+            Action::DetailBlocker => {
+                let blocker = self
+                    .get_selected_task()
+                    .and_then(|t| self.db.open_blockers(t).first().map(|b| b.id));
+                match blocker {
+                    Some(blocker) => {
+                        self.show_in_detail(blocker);
+                        self.set_status_message(format!("Navigated to blocker #{}", blocker));
+                    }
+                    None => self.set_status_message("Nothing blocks this task".to_string()),
+                }
+            }
+            Action::DetailNextArtifact => {
+                let count = self.get_selected_task().map_or(0, |t| t.artifacts.len());
+                if count == 0 {
+                    self.set_status_message("No artifacts".to_string());
+                } else {
+                    self.detail_artifact = (self.detail_artifact + 1) % count;
+                }
+            }
+            Action::DetailOpenArtifact => self.open_detail_artifact(),
+            Action::DetailAddTags | Action::DetailRemoveTags => {
                 if let Some(task_id) = self.selected_task {
-                    let child_map = build_children_map(&self.db.tasks);
-                    if let Some(children) = child_map.get(&task_id) {
-                        if let Some(&first_child) = children.first() {
-                            self.selected_task = Some(first_child);
-                            self.set_status_message(format!(
-                                "Navigated to child task #{}",
-                                first_child
-                            ));
-                        } else {
-                            self.set_status_message("No child tasks".to_string());
-                        }
+                    let prompt_type = if action == Action::DetailAddTags {
+                        PromptType::AddTags(task_id)
                     } else {
-                        self.set_status_message("No child tasks".to_string());
-                    }
+                        PromptType::RemoveTags(task_id)
+                    };
+                    self.overlay = Overlay::Prompt(PromptState {
+                        prompt_type,
+                        buffer: String::new(),
+                    });
                 }
             }
             _ => {}
@@ -93,180 +186,317 @@ impl App {
         Ok(false)
     }
 
+    /// Show `id` in the detail view, with the children list scrolled to
+    /// the top and the first artifact selected.
+    pub(super) fn show_in_detail(&mut self, id: LeafId) {
+        self.selected_task = Some(id);
+        self.detail_child_scroll = 0;
+        self.detail_artifact = 0;
+    }
+
+    /// Open the selected artifact with the OS default application.
+    fn open_detail_artifact(&mut self) {
+        let Some((id, artifact)) = self.get_selected_task().and_then(|t| {
+            let artifact = t.artifacts.get(self.detail_artifact)?;
+            Some((t.id, artifact.clone()))
+        }) else {
+            self.set_status_message("No artifacts".to_string());
+            return;
+        };
+        let dir = self.detail_artifacts_dir(id);
+        let path = resolve_artifact_path(&dir, &artifact);
+        if !path.exists() {
+            self.set_status_message(format!("Artifact missing: {}", path.display()));
+            return;
+        }
+        match open_with_default_app(&path) {
+            Ok(()) => self.set_status_message(format!("Opened {}", path.display())),
+            Err(e) => self.set_status_message(format!("Could not open {}: {e}", path.display())),
+        }
+    }
+
+    /// The `artifacts/` directory of ticket `id`.
+    fn detail_artifacts_dir(&self, id: LeafId) -> std::path::PathBuf {
+        self.db
+            .artifacts_dir(&self.pm_dir, id)
+            .unwrap_or_else(|| self.pm_dir.join("artifacts"))
+    }
+
+    /// Children of the ticket in the detail view, in sibling order.
+    fn detail_children(&self) -> Vec<LeafId> {
+        self.selected_task
+            .and_then(|id| build_children_map(&self.db.tasks).remove(&id))
+            .unwrap_or_default()
+    }
+
     /// Render the detailed view of a single task.
     pub(super) fn render_task_detail(&mut self, f: &mut Frame, area: Rect) {
         if let Some(task) = self.get_selected_task() {
-            let today = Local::now().date_naive();
+            let text = self.detail_lines(task, area.width);
+            let paragraph = Paragraph::new(text)
+                .block(Block::default().borders(Borders::ALL).title(
+                    "Task Details - [e]dit, [d]elete, [p]arent, [c]hild, [b]locker, [+/-] tag, [Esc] back",
+                ))
+                .wrap(Wrap { trim: true });
 
-            // Get parent and children info for navigation
-            let parent_name = task
-                .parent
-                .and_then(|pid| self.db.get(pid).map(|p| format!("#{} - {}", p.id, p.title)));
+            f.render_widget(paragraph, area);
+        }
+    }
 
-            let child_map = build_children_map(&self.db.tasks);
-            let children_names: Vec<String> = child_map
-                .get(&task.id)
-                .map(|children| {
-                    children
-                        .iter()
-                        .filter_map(|&cid| self.db.get(cid))
-                        .map(|c| format!("#{} - {}", c.id, c.title))
-                        .collect()
-                })
-                .unwrap_or_default();
+    /// The detail view's lines for `task`, laid out for a pane `width`
+    /// columns wide. The form preview passes a ticket built from the
+    /// unsaved form values.
+    pub(super) fn detail_lines(&self, task: &Task, width: u16) -> Vec<Line<'static>> {
+        let today = Local::now().date_naive();
 
-            let mut text = vec![
-                Line::from(vec![
-                    Span::styled("ID: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(task.id.to_string()),
-                ]),
-                Line::from(vec![
-                    Span::styled("Title: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(&task.title),
-                ]),
-            ];
+        // Get parent and children info for navigation
+        // Full ancestor chain rather than the bare parent id; the
+        // width leaves room for the label and the navigation hint.
+        let crumb_width = (width as usize).saturating_sub(40).max(20);
+        let parent_name = task
+            .parent
+            .filter(|pid| self.db.get(*pid).is_some())
+            .map(|_| self.db.breadcrumb_under(task.id, task.parent, crumb_width));
 
-            if let Some(summary) = &task.summary {
-                text.push(Line::from(vec![
-                    Span::styled("Summary: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(summary),
-                ]));
-            }
+        let children_names: Vec<String> = build_children_map(&self.db.tasks)
+            .get(&task.id)
+            .map(|children| {
+                children
+                    .iter()
+                    .filter_map(|&cid| self.db.get(cid))
+                    .map(|c| format!("#{} - {}", id_label(c.id, self.short_ids), c.title))
+                    .collect()
+            })
+            .unwrap_or_default();
+        // Children may have gone since the list was last scrolled.
+        let scroll = self
+            .detail_child_scroll
+            .min(children_names.len().saturating_sub(CHILD_ROWS));
 
-            text.extend(vec![
-                Line::from(vec![
-                    Span::styled("Kind: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(format_kind(task.kind)),
-                ]),
-                Line::from(vec![
-                    Span::styled("Status: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(format_status(task.status)),
-                ]),
-                Line::from(vec![
-                    Span::styled("Priority: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(format_priority(task.priority_level)),
-                ]),
-                Line::from(vec![
-                    Span::styled("Urgency: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(format_urgency(task.urgency)),
-                ]),
-                Line::from(vec![
-                    Span::styled(
-                        "Process Stage: ",
-                        Style::default().add_modifier(Modifier::BOLD),
-                    ),
-                    Span::raw(format_process_stage(task.process_stage)),
-                ]),
-                Line::from(vec![
-                    Span::styled("Project: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(project_label(&self.db, task)),
-                ]),
-                Line::from(vec![
-                    Span::styled("Due: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw(match task.due {
-                        Some(d) => format!("{} ({})", d, format_due_relative(Some(d), today)),
-                        None => "-".to_string(),
-                    }),
-                ]),
-            ]);
+        let mut text = vec![
+            Line::from(vec![
+                Span::styled("ID: ", Style::default().add_modifier(Modifier::BOLD)),
+                // The canonical id stays visible so it can be copied
+                // into scripts and commit messages.
+                Span::raw(if self.short_ids {
+                    format!("{} ({})", task.id.short(), task.id)
+                } else {
+                    task.id.to_string()
+                }),
+            ]),
+            Line::from(vec![
+                Span::styled("Title: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(task.title.clone()),
+            ]),
+        ];
 
-            // Parent navigation
-            if let Some(ref parent_name) = parent_name {
-                text.push(Line::from(vec![
-                    Span::styled("Parent: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::styled(parent_name, Style::default().fg(Color::Blue)),
-                    Span::raw(" (Press 'p' to go to parent)"),
-                ]));
+        if let Some(summary) = &task.summary {
+            text.push(Line::from(vec![
+                Span::styled("Summary: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(summary.clone()),
+            ]));
+        }
+
+        text.extend(vec![
+            Line::from(vec![
+                Span::styled("Kind: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format_kind(task.kind)),
+            ]),
+            Line::from(vec![
+                Span::styled("Status: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format_status(task.status)),
+            ]),
+            Line::from(vec![
+                Span::styled("Priority: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format_priority(task.priority_level)),
+            ]),
+            Line::from(vec![
+                Span::styled("Urgency: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format_urgency(task.urgency)),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    "Process Stage: ",
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format_stage_progress(task.process_stage)),
+            ]),
+            Line::from(vec![
+                Span::styled("Project: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(project_label(&self.db, task)),
+            ]),
+            Line::from(vec![
+                Span::styled("Due: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format_due_detail(task.due, task.remind_days_before, today)),
+            ]),
+        ]);
+
+        // Parent navigation
+        if let Some(parent_name) = parent_name {
+            text.push(Line::from(vec![
+                Span::styled("Parent: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(parent_name, Style::default().fg(Color::Blue)),
+                Span::raw(" (Press 'p' to go to parent)"),
+            ]));
+        } else {
+            text.push(Line::from(vec![
+                Span::styled("Parent: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw("-"),
+            ]));
+        }
+
+        // Children navigation
+        if !children_names.is_empty() {
+            let shown = &children_names[scroll..(scroll + CHILD_ROWS).min(children_names.len())];
+            let hint = if children_names.len() > CHILD_ROWS {
+                format!(
+                    "{}-{} of {} (1-9 to open, Up/Down to scroll)",
+                    scroll + 1,
+                    scroll + shown.len(),
+                    children_names.len()
+                )
             } else {
+                "(1-9 to open)".to_string()
+            };
+            text.push(Line::from(vec![
+                Span::styled("Children: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(hint),
+            ]));
+            for (row, child_name) in shown.iter().enumerate() {
                 text.push(Line::from(vec![
-                    Span::styled("Parent: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw("-"),
+                    Span::styled(
+                        format!("  {} ", row + 1),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::styled(child_name.clone(), Style::default().fg(Color::Blue)),
                 ]));
             }
+        } else {
+            text.push(Line::from(vec![
+                Span::styled("Children: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw("-"),
+            ]));
+        }
 
-            // Children navigation
-            if !children_names.is_empty() {
-                text.push(Line::from(vec![
-                    Span::styled("Children: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw("(Press 'c' to cycle through children)"),
-                ]));
-                for child_name in children_names.iter().take(3) {
-                    // Show first 3
-                    text.push(Line::from(vec![
-                        Span::raw("  "),
-                        Span::styled(child_name, Style::default().fg(Color::Blue)),
-                    ]));
-                }
-                if children_names.len() > 3 {
-                    text.push(Line::from(vec![Span::raw(format!(
-                        "  ... and {} more",
-                        children_names.len() - 3
-                    ))]));
-                }
+        // Dependencies, each with its status; unfinished ones stand out
+        // and `b` opens the first of them.
+        let deps: Vec<&Task> = task
+            .deps
+            .iter()
+            .filter_map(|&dep| self.db.get(dep))
+            .collect();
+        if !deps.is_empty() {
+            let open = deps.iter().filter(|d| d.status != Status::Done).count();
+            let hint = if open == 0 {
+                "(all done)".to_string()
             } else {
+                let key = self
+                    .keymap
+                    .key(Action::DetailBlocker)
+                    .map_or_else(|| "unbound".to_string(), String::from);
+                format!("{open} not done ({key} to open the first)")
+            };
+            text.push(Line::from(vec![
+                Span::styled(
+                    "Blocked by: ",
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(hint),
+            ]));
+            for dep in deps {
+                let color = if dep.status == Status::Done {
+                    Color::DarkGray
+                } else {
+                    Color::LightRed
+                };
                 text.push(Line::from(vec![
-                    Span::styled("Children: ", Style::default().add_modifier(Modifier::BOLD)),
-                    Span::raw("-"),
+                    Span::styled(
+                        format!("  {:<12}", format_status(dep.status)),
+                        Style::default().fg(color),
+                    ),
+                    Span::styled(
+                        format!("#{} - {}", id_label(dep.id, self.short_ids), dep.title),
+                        Style::default().fg(Color::Blue),
+                    ),
                 ]));
             }
+        }
 
-            text.extend(vec![Line::from(vec![
-                Span::styled("Tags: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(if task.tags.is_empty() {
-                    "-".to_string()
-                } else {
-                    task.tags.join(", ")
-                }),
-            ])]);
-
-            // Links section
-            if task.issue_link.is_some() || task.pr_link.is_some() {
-                text.push(Line::from(""));
-                text.push(Line::from(vec![Span::styled(
-                    "Links:",
-                    Style::default().add_modifier(Modifier::BOLD),
-                )]));
-
-                if let Some(issue_link) = &task.issue_link {
-                    text.push(Line::from(vec![
-                        Span::raw("Issue: "),
-                        Span::styled(issue_link, Style::default().fg(Color::Blue)),
-                    ]));
-                }
+        text.extend(vec![Line::from(vec![
+            Span::styled("Tags: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(if task.tags.is_empty() {
+                "-".to_string()
+            } else {
+                task.tags.join(", ")
+            }),
+        ])]);
 
-                if let Some(pr_link) = &task.pr_link {
-                    text.push(Line::from(vec![
-                        Span::raw("PR: "),
-                        Span::styled(pr_link, Style::default().fg(Color::Blue)),
-                    ]));
+        if !task.artifacts.is_empty() {
+            text.push(Line::from(vec![
+                Span::styled("Artifacts: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw("(a to select, o to open)"),
+            ]));
+            let selected = self.detail_artifact.min(task.artifacts.len() - 1);
+            let dir = self.detail_artifacts_dir(task.id);
+            for (i, artifact) in task.artifacts.iter().enumerate() {
+                let marker = if i == selected { "> " } else { "  " };
+                let mut line = vec![
+                    Span::raw(marker),
+                    Span::styled(artifact.clone(), Style::default().fg(Color::Blue)),
+                ];
+                if !resolve_artifact_path(&dir, artifact).exists() {
+                    line.push(Span::styled(" (missing)", Style::default().fg(Color::Red)));
                 }
+                text.push(Line::from(line));
             }
+        }
 
+        // Links section
+        if task.issue_link.is_some() || task.pr_link.is_some() {
             text.push(Line::from(""));
             text.push(Line::from(vec![Span::styled(
-                "Description:",
+                "Links:",
                 Style::default().add_modifier(Modifier::BOLD),
             )]));
-            text.push(Line::from(task.description.as_deref().unwrap_or("-")));
 
-            if let Some(user_story) = &task.user_story {
-                text.push(Line::from(""));
-                text.push(Line::from(vec![Span::styled(
-                    "User Story:",
-                    Style::default().add_modifier(Modifier::BOLD),
-                )]));
-                text.push(Line::from(user_story.as_str()));
+            if let Some(issue_link) = &task.issue_link {
+                text.push(Line::from(vec![
+                    Span::raw("Issue: "),
+                    Span::styled(issue_link.clone(), Style::default().fg(Color::Blue)),
+                ]));
             }
 
-            let paragraph = Paragraph::new(text)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title("Task Details - [e]dit, [d]elete, [p]arent, [c]hild, [Esc] back"),
-                )
-                .wrap(Wrap { trim: true });
+            if let Some(pr_link) = &task.pr_link {
+                text.push(Line::from(vec![
+                    Span::raw("PR: "),
+                    Span::styled(pr_link.clone(), Style::default().fg(Color::Blue)),
+                ]));
+            }
+        }
 
-            f.render_widget(paragraph, area);
+        text.push(Line::from(""));
+        text.push(Line::from(vec![Span::styled(
+            "Description:",
+            Style::default().add_modifier(Modifier::BOLD),
+        )]));
+        // One line per source line, so lists and paragraphs in the text
+        // keep their shape.
+        match &task.description {
+            Some(description) => {
+                text.extend(description.lines().map(|line| Line::from(line.to_string())))
+            }
+            None => text.push(Line::from("-")),
+        }
+
+        if let Some(user_story) = &task.user_story {
+            text.push(Line::from(""));
+            text.push(Line::from(vec![Span::styled(
+                "User Story:",
+                Style::default().add_modifier(Modifier::BOLD),
+            )]));
+            text.extend(user_story.lines().map(|line| Line::from(line.to_string())));
         }
+
+        text
     }
 }
@@ -2,6 +2,8 @@
 
 use ratatui::style::Color;
 
+use crate::db::{Ageing, DueState};
+
 // These support branded views of the UI
 // reflecting the current item hierarchy
 
@@ -15,3 +17,51 @@ pub const GOLD: Color = Color::Rgb(255, 215, 0);
 pub const DARK_RED: Color = Color::Rgb(114, 0, 0);
 /// Used for Milestones
 pub const DARK_PURPLE: Color = Color::Rgb(86, 60, 92);
+
+/// Due-column colour: gold inside the reminder window, orange on the day,
+/// red once overdue.
+pub fn due_state_color(state: DueState) -> Color {
+    match state {
+        DueState::Reminder => GOLD,
+        DueState::Due => Color::Rgb(255, 140, 0),
+        DueState::Overdue => Color::LightRed,
+    }
+}
+
+/// Workflow card background by [`Ageing`]: the usual grey while fresh, then
+/// amber, then red. Dark enough to keep the white card text readable.
+pub fn ageing_color(ageing: Ageing) -> Color {
+    match ageing {
+        Ageing::Fresh => Color::DarkGray,
+        Ageing::Stale => Color::Rgb(140, 90, 0),
+        Ageing::Neglected => Color::Rgb(130, 30, 30),
+    }
+}
+
+/// Chip backgrounds for tags. Light enough for black chip text, and clear
+/// of the reds the TUI uses for blocked and overdue work.
+pub const TAG_PALETTE: [Color; 8] = [
+    Color::LightBlue,
+    Color::LightGreen,
+    Color::LightCyan,
+    Color::LightMagenta,
+    Color::LightYellow,
+    Color::Rgb(255, 170, 90),
+    Color::Rgb(255, 160, 210),
+    Color::Rgb(150, 210, 190),
+];
+
+/// A tag's chip colour. The palette index is an FNV-1a hash of the name,
+/// so a tag keeps its colour across sessions and machines.
+pub fn tag_color(tag: &str) -> Color {
+    let hash = tag.bytes().fold(0x811c_9dc5_u32, |h, b| {
+        (h ^ b as u32).wrapping_mul(0x0100_0193)
+    });
+    TAG_PALETTE[hash as usize % TAG_PALETTE.len()]
+}
+
+/// False when the `NO_COLOR` environment variable is set to anything, in
+/// which case tags stay plain `[a,b]` text.
+pub fn colors_enabled() -> bool {
+    std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+}
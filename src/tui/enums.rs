@@ -1,5 +1,6 @@
 //! Enumerations for TUI state management.
 
+use crate::fields::Kind;
 use crate::store::{LeafId, MemoryRef};
 
 /// Top-level TUI mode. Mode 1 (Tickets) hosts the existing per-screen
@@ -61,6 +62,25 @@ pub enum AppState {
 pub enum InputMode {
     None,
     Text,
+    /// Vim-style `:` command line in the task list.
+    Command,
+}
+
+/// A ticket edit as `.` sees it. Repeatable edits carry what is needed to
+/// replay them on another ticket; anything else is recorded by name as
+/// [`EditAction::Other`] so `.` can explain why it does nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditAction {
+    /// Mark the ticket Done (`:complete`).
+    Complete,
+    /// Advance the process stage one step (`p`).
+    CycleStage,
+    /// Add these tags (`+` in the detail view, `:tag`).
+    AddTags(Vec<String>),
+    /// Reparent under this ticket (`move <ID>` in the rename prompt).
+    MoveUnder(LeafId),
+    /// An edit `.` does not repeat, named for the status message.
+    Other(&'static str),
 }
 
 /// What an active single-line input prompt is collecting.
@@ -70,6 +90,10 @@ pub enum PromptType {
     /// A new title for the ticket, or a `move <ADDRESS>` instruction to
     /// reparent it.
     RenameTicket(LeafId),
+    /// Tags to add to the ticket (comma or space separated).
+    AddTags(LeafId),
+    /// Tags to remove from the ticket.
+    RemoveTags(LeafId),
 }
 
 /// An active single-line input prompt overlaid on the current mode.
@@ -156,6 +180,20 @@ pub enum HierarchyLevel {
     Milestone,
 }
 
+impl HierarchyLevel {
+    /// The level that lists tickets of `kind`.
+    pub fn of_kind(kind: Kind) -> Self {
+        match kind {
+            Kind::Project => HierarchyLevel::Project,
+            Kind::Product => HierarchyLevel::Product,
+            Kind::Epic => HierarchyLevel::Epic,
+            Kind::Task => HierarchyLevel::Task,
+            Kind::Subtask => HierarchyLevel::Subtask,
+            Kind::Milestone => HierarchyLevel::Milestone,
+        }
+    }
+}
+
 /// Context for hierarchical navigation in the TUI.
 #[derive(Clone, PartialEq, Debug)]
 pub struct NavigationContext {
@@ -217,3 +255,12 @@ impl NavigationContext {
         }
     }
 }
+
+/// Where the user was in the hierarchy when switching between the task list
+/// and the workflow board, so the other view can open at the same level.
+/// The stack travels too, so backing out still works after the switch.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ViewHandoff {
+    pub context: NavigationContext,
+    pub stack: Vec<NavigationContext>,
+}
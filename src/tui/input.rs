@@ -1,11 +1,72 @@
 //! Input field handling for the terminal user interface.
 
+use crate::db::parse_due_input;
+use crate::store::IdInput;
+
+/// What an [`InputField`] is expected to hold. Forms draw an invalid field
+/// with a red border and refuse the final submit. Keystrokes are only
+/// filtered where a character can never be part of the value (see
+/// [`FieldKind::admits_char`]). An empty field is always valid since every
+/// typed field is optional.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FieldKind {
+    #[default]
+    FreeText,
+    /// An `http://` or `https://` link.
+    Url,
+    /// A ticket id or address (`TSK7`, `PRJ1-EPC3-TSK7`).
+    TicketId,
+    /// Anything `parse_due_input` accepts (`2025-03-01`, `today`, `in 3d`).
+    Date,
+}
+
+impl FieldKind {
+    /// True when `value` is acceptable for this kind.
+    pub fn accepts(self, value: &str) -> bool {
+        let value = value.trim();
+        if value.is_empty() {
+            return true;
+        }
+        match self {
+            FieldKind::FreeText => true,
+            FieldKind::Url => value
+                .strip_prefix("https://")
+                .or_else(|| value.strip_prefix("http://"))
+                .is_some_and(|rest| !rest.is_empty() && !rest.contains(char::is_whitespace)),
+            FieldKind::TicketId => value.parse::<IdInput>().is_ok(),
+            FieldKind::Date => parse_due_input(value).is_some(),
+        }
+    }
+
+    /// Whether `c` can appear in a value of this kind. [`InputField::handle_char`]
+    /// drops anything else, so a ticket-id field ignores spaces and
+    /// punctuation instead of failing on save. Letters still type because
+    /// ids mix them with digits.
+    pub fn admits_char(self, c: char) -> bool {
+        match self {
+            FieldKind::TicketId => c.is_ascii_alphanumeric() || c == '-',
+            FieldKind::FreeText | FieldKind::Url | FieldKind::Date => true,
+        }
+    }
+
+    /// Short description of the expected format, for titles and errors.
+    pub fn hint(self) -> &'static str {
+        match self {
+            FieldKind::FreeText => "any text",
+            FieldKind::Url => "an http(s):// URL",
+            FieldKind::TicketId => "a ticket id like TSK7",
+            FieldKind::Date => "YYYY-MM-DD, today, tomorrow or in Nd",
+        }
+    }
+}
+
 /// A text input field with cursor position and active state management.
 #[derive(Clone)]
 pub struct InputField {
     pub value: String,
     pub cursor: usize,
     pub active: bool,
+    pub kind: FieldKind,
 }
 
 impl InputField {
@@ -15,6 +76,7 @@ impl InputField {
             value: String::new(),
             cursor: 0,
             active: false,
+            kind: FieldKind::FreeText,
         }
     }
 
@@ -24,11 +86,27 @@ impl InputField {
             value: value.to_string(),
             cursor: value.len(),
             active: false,
+            kind: FieldKind::FreeText,
         }
     }
 
-    /// Insert a character at the current cursor position.
+    /// Set the expected content kind used for validation hints.
+    pub fn with_kind(mut self, kind: FieldKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// True when the current value matches the field's [`FieldKind`].
+    pub fn is_valid(&self) -> bool {
+        self.kind.accepts(&self.value)
+    }
+
+    /// Insert a character at the current cursor position, unless the
+    /// field's [`FieldKind`] never admits it.
     pub fn handle_char(&mut self, c: char) {
+        if !self.kind.admits_char(c) {
+            return;
+        }
         self.value.insert(self.cursor, c);
         self.cursor += 1;
     }
@@ -0,0 +1,173 @@
+//! The keybinding reference shared by the help overlay and `pm help-dump`.
+//!
+//! Each screen is a [`KeySection`] of [`Binding`]s. Remappable commands are
+//! listed by [`Action`], so their key and description come from the keymap;
+//! only the fixed keys (arrows, Enter, Esc, Ctrl chords) are spelled out
+//! here. Keep the rows in step with the screens' `match` arms.
+
+use crate::tui::keymap::{Action, Keymap};
+
+/// One row of the reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Binding {
+    /// A key the keymap does not manage, with what it does.
+    Fixed(&'static str, &'static str),
+    /// A remappable command; shown with whatever key it is bound to.
+    Action(Action),
+    /// A continuation line under the row above, such as `:` commands.
+    Note(&'static str),
+}
+
+impl Binding {
+    /// The key column, as bound in `keymap`. An action without a key shows
+    /// `unbound`.
+    pub fn key_label(self, keymap: &Keymap) -> String {
+        match self {
+            Binding::Fixed(key, _) => key.to_string(),
+            Binding::Action(action) => keymap
+                .key(action)
+                .map_or_else(|| "unbound".to_string(), String::from),
+            Binding::Note(_) => String::new(),
+        }
+    }
+
+    /// The description column.
+    pub fn description(self) -> &'static str {
+        match self {
+            Binding::Fixed(_, text) | Binding::Note(text) => text,
+            Binding::Action(action) => action.description(),
+        }
+    }
+}
+
+/// The bindings of one screen.
+#[derive(Debug, Clone, Copy)]
+pub struct KeySection {
+    pub title: &'static str,
+    pub bindings: &'static [Binding],
+}
+
+pub const TICKET_LIST: KeySection = KeySection {
+    title: "Ticket list",
+    bindings: &[
+        Binding::Fixed("<- ->", "Traverse hierarchy levels"),
+        Binding::Fixed("S-<- S->", "Traverse hierarchy levels, unfiltered"),
+        Binding::Fixed("A-<-", "Go back to the previous position"),
+        Binding::Fixed("^ v", "Move within the list"),
+        Binding::Fixed("Enter Space", "Open the selected ticket"),
+        Binding::Fixed("Esc", "Clear the filter, or quit"),
+        Binding::Action(Action::Workflow),
+        Binding::Action(Action::NewChild),
+        Binding::Action(Action::Form),
+        Binding::Action(Action::Edit),
+        Binding::Action(Action::Artifact),
+        Binding::Action(Action::Checkout),
+        Binding::Action(Action::Checkin),
+        Binding::Action(Action::Complete),
+        Binding::Action(Action::Memories),
+        Binding::Action(Action::Delete),
+        Binding::Action(Action::CycleStatus),
+        Binding::Action(Action::CycleStage),
+        Binding::Action(Action::Repeat),
+        Binding::Action(Action::Pin),
+        Binding::Action(Action::DueLater),
+        Binding::Action(Action::DueEarlier),
+        Binding::Action(Action::DueLaterWeek),
+        Binding::Action(Action::DueEarlierWeek),
+        Binding::Action(Action::ToggleCompleted),
+        Binding::Action(Action::CollapseDone),
+        Binding::Action(Action::Refresh),
+        Binding::Action(Action::Filter),
+        Binding::Action(Action::Sort),
+        Binding::Action(Action::Help),
+        Binding::Action(Action::Command),
+        Binding::Note(":complete :delete :status S :kind K :tag T :untag T"),
+        Binding::Note(":sort due|priority|id|created|updated|off"),
+        Binding::Note(":filter overdue|today|this-week|upcoming|none|off"),
+    ],
+};
+
+pub const TICKET_DETAIL: KeySection = KeySection {
+    title: "Ticket detail",
+    bindings: &[
+        Binding::Fixed("Esc", "Back to the list"),
+        Binding::Fixed("1-9", "Open a listed child"),
+        Binding::Fixed("^ v", "Scroll the children list"),
+        Binding::Action(Action::DetailBack),
+        Binding::Action(Action::DetailEdit),
+        Binding::Action(Action::DetailDelete),
+        Binding::Action(Action::DetailParent),
+        Binding::Action(Action::DetailChild),
+        Binding::Action(Action::DetailBlocker),
+        Binding::Action(Action::DetailAddTags),
+        Binding::Action(Action::DetailRemoveTags),
+        Binding::Action(Action::DetailNextArtifact),
+        Binding::Action(Action::DetailOpenArtifact),
+        Binding::Action(Action::DetailNewChild),
+    ],
+};
+
+pub const TASK_FORM: KeySection = KeySection {
+    title: "Task form",
+    bindings: &[
+        Binding::Fixed("Tab", "Next field; in Tags, accept the first suggestion"),
+        Binding::Fixed("S-Tab ^", "Previous field"),
+        Binding::Fixed("v", "Next field"),
+        Binding::Fixed("<- ->", "Move the cursor, or change a selector"),
+        Binding::Fixed(
+            "Enter",
+            "Save; on User Story or Requirements, open the editor",
+        ),
+        Binding::Fixed("^T", "Cycle saved templates (add form only)"),
+        Binding::Fixed("^P", "Show / hide the preview pane"),
+        Binding::Fixed("^R", "Retry a save that failed to reach disk"),
+        Binding::Fixed("^W", "In the editor, soft-wrap long lines / scroll sideways"),
+        Binding::Fixed("^L", "In the editor, show / hide line numbers"),
+        Binding::Fixed("Esc", "Cancel and return to the list"),
+    ],
+};
+
+pub const WORKFLOW_BOARD: KeySection = KeySection {
+    title: "Workflow board",
+    bindings: &[
+        Binding::Fixed("<- ->", "Move between columns, then hierarchy levels"),
+        Binding::Fixed("^ v", "Move within a column; scroll the detail popup"),
+        Binding::Fixed("^<- ^->", "Move the card to the previous / next column"),
+        Binding::Fixed("S-<- S->", "Show filtered / unfiltered cards"),
+        Binding::Fixed("A-<- A->", "Switch hierarchy level"),
+        Binding::Fixed("Enter", "Toggle the card detail popup"),
+        Binding::Fixed("d / u", "Drill down into / up out of the selected card"),
+        Binding::Fixed("e", "Edit the selected card"),
+        Binding::Fixed(
+            "c",
+            "Complete / reopen the selected card, popup open or not",
+        ),
+        Binding::Fixed("t", "Toggle show/hide completed"),
+        Binding::Fixed("S", "Cycle card order: id, priority, due"),
+        Binding::Fixed("I", "Cycle the iteration filter"),
+        Binding::Fixed("/", "Filter by title / tags / project"),
+        Binding::Fixed("l", "Back to the task list"),
+        Binding::Fixed("h", "Show the key summary"),
+        Binding::Fixed("Esc ^Q ^C", "Exit the board"),
+    ],
+};
+
+pub const GLOBAL: KeySection = KeySection {
+    title: "Everywhere",
+    bindings: &[
+        Binding::Fixed("Tab / S-Tab", "Cycle modes"),
+        Binding::Fixed("1 / 2 / 3", "Jump to a mode"),
+        Binding::Fixed("F1", "Toggle this help"),
+        Binding::Fixed("?", "Toggle the key-hint footer"),
+        Binding::Fixed("^Q ^C", "Quit"),
+    ],
+};
+
+/// Every section, in the order `pm help-dump` prints them.
+pub const SECTIONS: [KeySection; 5] = [
+    TICKET_LIST,
+    TICKET_DETAIL,
+    TASK_FORM,
+    WORKFLOW_BOARD,
+    GLOBAL,
+];
@@ -0,0 +1,352 @@
+//! Remappable single-key commands for the ticket list and detail view.
+//!
+//! Every letter command in those two screens is an [`Action`] looked up
+//! through a [`Keymap`] instead of a literal `match`. The defaults are the
+//! historical bindings; the `keys` table in `.pm/config.json` moves actions
+//! to other keys or binds the ones that have no default:
+//!
+//! ```json
+//! { "keys": { "complete": "x", "checkout": "C", "detail.parent": "u" } }
+//! ```
+//!
+//! Navigation (arrows, Enter, Space, Esc, Tab), the digits (mode keys
+//! `1`-`3`, and `1`-`9` for the detail view's children), `?` and the Ctrl
+//! chords are fixed. A table naming an unknown action, using
+//! anything other than a single character, taking a fixed key or leaving
+//! two actions on one key is rejected as a whole and the defaults apply.
+
+use std::collections::{BTreeMap, HashMap};
+
+/// Which screen an [`Action`] belongs to. Keys only have to be unique
+/// within a context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KeyContext {
+    TaskList,
+    Detail,
+}
+
+/// A command reachable by a single key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    // Ticket list.
+    Workflow,
+    NewChild,
+    Form,
+    Edit,
+    Artifact,
+    Checkin,
+    Memories,
+    Delete,
+    CycleStatus,
+    Checkout,
+    CycleStage,
+    Repeat,
+    Pin,
+    ToggleCompleted,
+    CollapseDone,
+    Sort,
+    Command,
+    Filter,
+    Help,
+    Refresh,
+    Complete,
+    DueLater,
+    DueEarlier,
+    DueLaterWeek,
+    DueEarlierWeek,
+    // Detail view.
+    DetailBack,
+    DetailEdit,
+    DetailDelete,
+    DetailParent,
+    DetailChild,
+    DetailBlocker,
+    DetailAddTags,
+    DetailRemoveTags,
+    DetailNextArtifact,
+    DetailOpenArtifact,
+    DetailNewChild,
+}
+
+impl Action {
+    /// Every action, ticket-list actions first.
+    pub const ALL: [Action; 36] = [
+        Action::Workflow,
+        Action::NewChild,
+        Action::Form,
+        Action::Edit,
+        Action::Artifact,
+        Action::Checkin,
+        Action::Memories,
+        Action::Delete,
+        Action::CycleStatus,
+        Action::Checkout,
+        Action::CycleStage,
+        Action::Repeat,
+        Action::Pin,
+        Action::ToggleCompleted,
+        Action::CollapseDone,
+        Action::Sort,
+        Action::Command,
+        Action::Filter,
+        Action::Help,
+        Action::Refresh,
+        Action::Complete,
+        Action::DueLater,
+        Action::DueEarlier,
+        Action::DueLaterWeek,
+        Action::DueEarlierWeek,
+        Action::DetailBack,
+        Action::DetailEdit,
+        Action::DetailDelete,
+        Action::DetailParent,
+        Action::DetailChild,
+        Action::DetailBlocker,
+        Action::DetailAddTags,
+        Action::DetailRemoveTags,
+        Action::DetailNextArtifact,
+        Action::DetailOpenArtifact,
+        Action::DetailNewChild,
+    ];
+
+    /// Name used as the key in the config `keys` table.
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Workflow => "workflow",
+            Action::NewChild => "new",
+            Action::Form => "form",
+            Action::Edit => "edit",
+            Action::Artifact => "artifact",
+            Action::Checkin => "checkin",
+            Action::Memories => "memories",
+            Action::Delete => "delete",
+            Action::CycleStatus => "status",
+            Action::Checkout => "checkout",
+            Action::CycleStage => "stage",
+            Action::Repeat => "repeat",
+            Action::Pin => "pin",
+            Action::ToggleCompleted => "toggle_completed",
+            Action::CollapseDone => "collapse_done",
+            Action::Sort => "sort",
+            Action::Command => "command",
+            Action::Filter => "filter",
+            Action::Help => "help",
+            Action::Refresh => "refresh",
+            Action::Complete => "complete",
+            Action::DueLater => "due_later",
+            Action::DueEarlier => "due_earlier",
+            Action::DueLaterWeek => "due_later_week",
+            Action::DueEarlierWeek => "due_earlier_week",
+            Action::DetailBack => "detail.back",
+            Action::DetailEdit => "detail.edit",
+            Action::DetailDelete => "detail.delete",
+            Action::DetailParent => "detail.parent",
+            Action::DetailChild => "detail.child",
+            Action::DetailBlocker => "detail.blocker",
+            Action::DetailAddTags => "detail.add_tags",
+            Action::DetailRemoveTags => "detail.remove_tags",
+            Action::DetailNextArtifact => "detail.next_artifact",
+            Action::DetailOpenArtifact => "detail.open_artifact",
+            Action::DetailNewChild => "detail.new_child",
+        }
+    }
+
+    /// Look an action up by its config name.
+    pub fn from_name(name: &str) -> Option<Action> {
+        Action::ALL.into_iter().find(|a| a.name() == name)
+    }
+
+    /// The screen the action is bound in.
+    pub fn context(self) -> KeyContext {
+        match self {
+            Action::DetailBack
+            | Action::DetailEdit
+            | Action::DetailDelete
+            | Action::DetailParent
+            | Action::DetailChild
+            | Action::DetailBlocker
+            | Action::DetailAddTags
+            | Action::DetailRemoveTags
+            | Action::DetailNextArtifact
+            | Action::DetailOpenArtifact
+            | Action::DetailNewChild => KeyContext::Detail,
+            _ => KeyContext::TaskList,
+        }
+    }
+
+    /// The built-in key, or `None` for actions only reachable once bound.
+    pub fn default_key(self) -> Option<char> {
+        Some(match self {
+            Action::Workflow => 'w',
+            Action::NewChild => 'n',
+            Action::Form => 'f',
+            Action::Edit => 'e',
+            Action::Artifact => 'a',
+            Action::Checkin => 'i',
+            Action::Memories => 'm',
+            Action::Delete => 'd',
+            Action::CycleStatus => 's',
+            Action::Checkout => 'c',
+            Action::CycleStage => 'p',
+            Action::Repeat => '.',
+            Action::Pin => '*',
+            Action::ToggleCompleted => 't',
+            Action::CollapseDone => 'T',
+            Action::Sort => 'o',
+            Action::Command => ':',
+            Action::Filter => '/',
+            Action::Help => 'h',
+            Action::Refresh => 'r',
+            Action::Complete => return None,
+            Action::DueLater => '+',
+            Action::DueEarlier => '-',
+            Action::DueLaterWeek => '>',
+            Action::DueEarlierWeek => '<',
+            Action::DetailBack => 'q',
+            Action::DetailEdit => 'e',
+            Action::DetailDelete => 'd',
+            Action::DetailParent => 'p',
+            Action::DetailChild => 'c',
+            Action::DetailBlocker => 'b',
+            Action::DetailAddTags => '+',
+            Action::DetailRemoveTags => '-',
+            Action::DetailNextArtifact => 'a',
+            Action::DetailOpenArtifact => 'o',
+            Action::DetailNewChild => 'n',
+        })
+    }
+
+    /// One-line description shown in the help overlay and `pm help-dump`.
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::Workflow => "Switch to the workflow board (l there comes back)",
+            Action::NewChild => "Add a child ticket",
+            Action::Form => "Open the quick-entry form on the selected ticket",
+            Action::Edit => "Open the ticket's CLAUDE.md in $EDITOR",
+            Action::Artifact => "Add an artifact",
+            Action::Checkin => "Checkin the selected ticket",
+            Action::Memories => "Toggle the memory side-panel",
+            Action::Delete => "Delete the selected ticket",
+            Action::CycleStatus => "Cycle status",
+            Action::Checkout => "Checkout the selected ticket",
+            Action::CycleStage => "Cycle process stage",
+            Action::Repeat => "Repeat the last :complete, p, tag add or move",
+            Action::Pin => "Pin / unpin the selected ticket (pinned list first)",
+            Action::ToggleCompleted => "Toggle show/hide completed",
+            Action::CollapseDone => "Collapse completed subtasks (Done sorts last)",
+            Action::Sort => "Cycle sort: due, priority, id, created, updated",
+            Action::Command => "Command line (Tab completes; % = all visible)",
+            Action::Filter => "Filter by title / tags / project",
+            Action::Help => "Open this help",
+            Action::Refresh => "Reload tickets from disk and say what changed",
+            Action::Complete => "Complete the selected ticket",
+            Action::DueLater => "Due date a day later",
+            Action::DueEarlier => "Due date a day earlier",
+            Action::DueLaterWeek => "Due date a week later",
+            Action::DueEarlierWeek => "Due date a week earlier",
+            Action::DetailBack => "Back to the list",
+            Action::DetailEdit => "Edit the ticket in the form",
+            Action::DetailDelete => "Delete the ticket",
+            Action::DetailParent => "Open the parent ticket",
+            Action::DetailChild => "Open the first child",
+            Action::DetailBlocker => "Open the first dependency not yet Done",
+            Action::DetailAddTags => "Add tags",
+            Action::DetailRemoveTags => "Remove tags",
+            Action::DetailNextArtifact => "Select the next artifact",
+            Action::DetailOpenArtifact => "Open the selected artifact",
+            Action::DetailNewChild => "Add a child ticket under this one",
+        }
+    }
+}
+
+/// Keys handled before the keymap is consulted, so binding them would
+/// never fire.
+const RESERVED: [char; 11] = [' ', '?', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+/// Resolved key-to-action bindings for both contexts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: HashMap<(KeyContext, char), Action>,
+    keys: HashMap<Action, char>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Keymap::build(|action| action.default_key())
+    }
+}
+
+impl Keymap {
+    fn build(key_for: impl Fn(Action) -> Option<char>) -> Self {
+        let mut bindings = HashMap::new();
+        let mut keys = HashMap::new();
+        for action in Action::ALL {
+            if let Some(key) = key_for(action) {
+                bindings.insert((action.context(), key), action);
+                keys.insert(action, key);
+            }
+        }
+        Keymap { bindings, keys }
+    }
+
+    /// Apply the config `keys` table on top of the defaults. Any problem
+    /// with the table is returned instead, one message per entry, and the
+    /// caller falls back to [`Keymap::default`].
+    pub fn from_config(overrides: &BTreeMap<String, String>) -> Result<Self, Vec<String>> {
+        let mut problems = Vec::new();
+        let mut chosen: HashMap<Action, char> = HashMap::new();
+        for (name, key) in overrides {
+            let Some(action) = Action::from_name(name) else {
+                problems.push(format!("unknown action '{name}'"));
+                continue;
+            };
+            let mut chars = key.chars();
+            let (Some(c), None) = (chars.next(), chars.next()) else {
+                problems.push(format!("'{name}' must be a single character, got '{key}'"));
+                continue;
+            };
+            if RESERVED.contains(&c) {
+                problems.push(format!("'{name}' cannot use the fixed key '{c}'"));
+                continue;
+            }
+            chosen.insert(action, c);
+        }
+
+        let keymap = Keymap::build(|action| {
+            chosen
+                .get(&action)
+                .copied()
+                .or_else(|| action.default_key())
+        });
+
+        let mut claimed: BTreeMap<(KeyContext, char), Vec<&'static str>> = BTreeMap::new();
+        for (&action, &key) in &keymap.keys {
+            claimed
+                .entry((action.context(), key))
+                .or_default()
+                .push(action.name());
+        }
+        for ((_, key), mut names) in claimed {
+            if names.len() > 1 {
+                names.sort_unstable();
+                problems.push(format!("'{key}' is bound to {}", names.join(", ")));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(keymap)
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// The action bound to `key` in `context`, if any.
+    pub fn action(&self, context: KeyContext, key: char) -> Option<Action> {
+        self.bindings.get(&(context, key)).copied()
+    }
+
+    /// The key currently bound to `action`, if any.
+    pub fn key(&self, action: Action) -> Option<char> {
+        self.keys.get(&action).copied()
+    }
+}
@@ -1,10 +1,13 @@
 //! Retro-style main menu for project selection and management.
 //!
 //! This module provides a terminal-based menu system for selecting projects,
-//! creating new projects, and viewing application information.
+//! creating new projects, reading the built-in guide, and viewing application
+//! information.
 
+use std::collections::HashMap;
 use std::io;
-use std::time::Duration;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
 
 use crossterm::event::{self, Event, KeyCode};
 use ratatui::{
@@ -16,7 +19,12 @@ use ratatui::{
     Frame, Terminal,
 };
 
-use crate::project::{create_project, discover_projects, get_legacy_project, Project};
+use crate::output::progress_line;
+use crate::project::{
+    create_project, discover_projects, get_legacy_project, last_opened, record_project_opened,
+    sort_projects, Project, ProjectSummary,
+};
+use crate::store::{Config, MenuSort};
 use crate::tui::utils::centered_rect;
 
 /// Main menu application state.
@@ -32,7 +40,29 @@ pub struct MenuApp {
     should_exit: bool,
     selected_project: Option<Project>,
     project_to_delete: Option<Project>,
-    open_workflow: bool, // Flag to indicate workflow should be opened
+    open_workflow: bool,    // Flag to indicate workflow should be opened
+    project_filter: String, // Type-to-filter buffer for the project lists
+    compact: bool,          // Hide the header banner (`ui.compact` in config)
+    summaries: HashMap<PathBuf, CachedSummary>, // Per-project counts, filled lazily
+    spinner_tick: usize,    // Animates the placeholder while loading
+    sort: MenuSort,         // Project list order (`ui.menu_sort`), cycled with Tab
+    help_scroll: usize,     // First guide line shown on the Help screen
+    help_query: String,     // Type-to-search buffer for the Help screen
+    help_page: usize,       // Guide lines that fit on screen, for PageUp/PageDown
+}
+
+/// A project's summary together with the file time it was computed from, so
+/// it is only recomputed after the project changes.
+struct CachedSummary {
+    modified: Option<SystemTime>,
+    summary: ProjectSummary,
+}
+
+/// Frames for the placeholder shown while a summary is still loading.
+const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+
+fn modified_time(path: &std::path::Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
 }
 
 #[derive(Debug, Clone)]
@@ -43,6 +73,7 @@ enum MenuState {
     NewProject,
     DeleteProjectList,
     DeleteConfirmation,
+    Help,
     About,
 }
 
@@ -55,17 +86,27 @@ enum InputMode {
 impl MenuApp {
     /// Create a new menu application.
     pub fn new(pm_dir: std::path::PathBuf) -> io::Result<Self> {
-        let projects = discover_projects(&pm_dir).unwrap_or_else(|_| Vec::new());
+        let config = Config::load(&pm_dir);
+        let mut projects = discover_projects(&pm_dir).unwrap_or_else(|_| Vec::new());
+        sort_projects(&mut projects, config.ui.menu_sort, &last_opened(&pm_dir));
 
         let menu_items = vec![
             "Open Project".to_string(),
             "New Project".to_string(),
             "Delete Project".to_string(),
             "Workflow Manager".to_string(),
+            "Help".to_string(),
             "About".to_string(),
             "Exit".to_string(),
         ];
 
+        // Point owners of a legacy workspace at the one-step upgrade.
+        let status_message = if get_legacy_project(&pm_dir).is_some_and(|p| p.file_path.is_dir()) {
+            "Legacy tasks.json found: `pm migrate-legacy` turns it into a named project".to_string()
+        } else {
+            String::new()
+        };
+
         let mut app = MenuApp {
             pm_dir,
             state: MenuState::MainMenu,
@@ -74,11 +115,19 @@ impl MenuApp {
             menu_items,
             input_mode: InputMode::None,
             input_buffer: String::new(),
-            status_message: String::new(),
+            status_message,
             should_exit: false,
             selected_project: None,
             project_to_delete: None,
             open_workflow: false,
+            project_filter: String::new(),
+            compact: config.ui.compact,
+            summaries: HashMap::new(),
+            spinner_tick: 0,
+            sort: config.ui.menu_sort,
+            help_scroll: 0,
+            help_query: String::new(),
+            help_page: 1,
         };
 
         app.list_state.select(Some(0));
@@ -90,6 +139,7 @@ impl MenuApp {
         self.refresh_projects();
         if !self.projects.is_empty() {
             self.state = MenuState::ProjectActionMenu;
+            self.project_filter.clear();
             self.list_state.select(Some(0));
         }
     }
@@ -104,9 +154,201 @@ impl MenuApp {
         self.should_exit
     }
 
-    /// Refresh the projects list.
+    /// Refresh the projects list, dropping cached summaries for projects that
+    /// were removed or have changed on disk since they were counted.
     fn refresh_projects(&mut self) {
         self.projects = discover_projects(&self.pm_dir).unwrap_or_else(|_| Vec::new());
+        sort_projects(&mut self.projects, self.sort, &last_opened(&self.pm_dir));
+        let projects = &self.projects;
+        self.summaries.retain(|path, cached| {
+            projects.iter().any(|p| &p.file_path == path) && cached.modified == modified_time(path)
+        });
+    }
+
+    /// Whether a project list (and so the summaries) is on screen.
+    fn showing_project_list(&self) -> bool {
+        matches!(
+            self.state,
+            MenuState::ProjectList | MenuState::ProjectActionMenu | MenuState::DeleteProjectList
+        )
+    }
+
+    /// Compute the summary of one project that doesn't have one yet. Called
+    /// once per frame so a large collection fills in without blocking input.
+    fn load_next_summary(&mut self) {
+        if !self.showing_project_list() {
+            return;
+        }
+        let Some(project) = self
+            .projects
+            .iter()
+            .find(|p| !self.summaries.contains_key(&p.file_path))
+        else {
+            return;
+        };
+        let cached = CachedSummary {
+            modified: modified_time(&project.file_path),
+            summary: project.summary(),
+        };
+        self.summaries.insert(project.file_path.clone(), cached);
+    }
+
+    /// Indices into `projects` whose display name matches the current filter.
+    fn filtered_project_indices(&self) -> Vec<usize> {
+        self.projects
+            .iter()
+            .enumerate()
+            .filter(|(_, project)| fuzzy_match(&self.project_filter, &project.display_name))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// The project under the cursor, resolved through the active filter.
+    fn selected_filtered_project(&self) -> Option<&Project> {
+        let selected = self.list_state.selected()?;
+        let index = *self.filtered_project_indices().get(selected)?;
+        self.projects.get(index)
+    }
+
+    /// Keep the list selection inside the filtered range.
+    fn clamp_project_selection(&mut self) {
+        let len = self.filtered_project_indices().len();
+        if len == 0 {
+            self.list_state.select(None);
+        } else {
+            let selected = self.list_state.selected().unwrap_or(0).min(len - 1);
+            self.list_state.select(Some(selected));
+        }
+    }
+
+    /// Shared key handling for the project lists: arrow navigation over the
+    /// filtered view plus type-to-filter. Returns true if the key was consumed.
+    fn handle_project_filter_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Up => {
+                if let Some(selected) = self.list_state.selected() {
+                    if selected > 0 {
+                        self.list_state.select(Some(selected - 1));
+                    }
+                }
+            }
+            KeyCode::Down => {
+                let len = self.filtered_project_indices().len();
+                if let Some(selected) = self.list_state.selected() {
+                    if selected + 1 < len {
+                        self.list_state.select(Some(selected + 1));
+                    }
+                }
+            }
+            KeyCode::Backspace => {
+                self.project_filter.pop();
+                self.list_state.select(Some(0));
+                self.clamp_project_selection();
+            }
+            KeyCode::Char(c) => {
+                self.project_filter.push(c);
+                self.list_state.select(Some(0));
+                self.clamp_project_selection();
+            }
+            KeyCode::Tab => self.cycle_sort(),
+            KeyCode::Esc if !self.project_filter.is_empty() => {
+                // First Esc clears the filter; the next one leaves the list.
+                self.project_filter.clear();
+                self.list_state.select(Some(0));
+                self.clamp_project_selection();
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Switch the project lists to the next order, keep the project under
+    /// the cursor selected, and remember the order in `config.json`.
+    fn cycle_sort(&mut self) {
+        let selected = self
+            .selected_filtered_project()
+            .map(|p| p.file_path.clone());
+        self.sort = self.sort.next();
+        sort_projects(&mut self.projects, self.sort, &last_opened(&self.pm_dir));
+        let position = selected.and_then(|path| {
+            self.filtered_project_indices()
+                .iter()
+                .position(|&i| self.projects[i].file_path == path)
+        });
+        self.list_state.select(Some(position.unwrap_or(0)));
+        self.clamp_project_selection();
+        self.status_message = match Config::save_menu_sort(&self.pm_dir, self.sort) {
+            Ok(()) => format!("Sorted by {}", self.sort.label()),
+            Err(e) => format!("Sorted by {} (not saved: {e})", self.sort.label()),
+        };
+    }
+
+    /// Open `project`, noting the time for the "last opened" order.
+    fn choose_project(&mut self, project: Project) {
+        // Best effort: the menu is about to close, and a failed write only
+        // leaves the project where it was in that order.
+        record_project_opened(&self.pm_dir, &project).ok();
+        self.selected_project = Some(project);
+        self.should_exit = true;
+    }
+
+    /// Title for a project list block, including the filter when one is set.
+    fn project_list_title(&self, base: &str) -> String {
+        let sort = self.sort.label();
+        if self.project_filter.is_empty() {
+            format!("{} (type to filter, Tab: sort by {})", base, sort)
+        } else {
+            let shown = self.filtered_project_indices().len();
+            format!(
+                "{} [filter: {}] ({}/{}, sort: {})",
+                base,
+                self.project_filter,
+                shown,
+                self.projects.len(),
+                sort
+            )
+        }
+    }
+
+    /// Build the list rows for the filtered projects: a status icon, the name,
+    /// and the task counts once they have been loaded.
+    fn project_list_items(&self) -> Vec<ListItem<'static>> {
+        let indices = self.filtered_project_indices();
+        let names: Vec<String> = indices
+            .iter()
+            .map(|&i| {
+                let project = &self.projects[i];
+                if project.name == "default" {
+                    format!("{} (legacy tasks.json)", project.display_name)
+                } else {
+                    project.display_name.clone()
+                }
+            })
+            .collect();
+        let width = names.iter().map(|n| n.chars().count()).max().unwrap_or(0);
+        indices
+            .into_iter()
+            .zip(names)
+            .map(|(i, name)| {
+                let project = &self.projects[i];
+                let name = format!("{name:<width$}");
+                let line = match self.summaries.get(&project.file_path) {
+                    Some(cached) => summary_line(name, cached.summary),
+                    None => {
+                        let frame = SPINNER[self.spinner_tick % SPINNER.len()];
+                        Line::from(vec![
+                            Span::styled(
+                                format!("  {frame} "),
+                                Style::default().fg(Color::DarkGray),
+                            ),
+                            Span::raw(name),
+                            Span::styled("   loading…", Style::default().fg(Color::DarkGray)),
+                        ])
+                    }
+                };
+                ListItem::new(line)
+            })
+            .collect()
     }
 
     /// Handle keyboard input based on current state.
@@ -124,6 +366,7 @@ impl MenuApp {
                     MenuState::DeleteConfirmation => {
                         self.handle_delete_confirmation_input(key.code)
                     }
+                    MenuState::Help => self.handle_help_input(key.code),
                     MenuState::About => self.handle_about_input(key.code),
                 }
             }
@@ -166,6 +409,7 @@ impl MenuApp {
                                     "No projects found. Create a new project first.".to_string();
                             } else {
                                 self.state = MenuState::ProjectList;
+                                self.project_filter.clear();
                                 self.list_state.select(Some(0));
                             }
                         }
@@ -189,6 +433,7 @@ impl MenuApp {
                                 self.status_message = "No projects found to delete.".to_string();
                             } else {
                                 self.state = MenuState::DeleteProjectList;
+                                self.project_filter.clear();
                                 self.list_state.select(Some(0));
                             }
                         }
@@ -207,14 +452,21 @@ impl MenuApp {
                                     "No projects found. Create a new project first.".to_string();
                             } else {
                                 self.state = MenuState::ProjectActionMenu;
+                                self.project_filter.clear();
                                 self.list_state.select(Some(0));
                             }
                         }
                         4 => {
+                            // Help
+                            self.state = MenuState::Help;
+                            self.help_query.clear();
+                            self.help_scroll = 0;
+                        }
+                        5 => {
                             // About
                             self.state = MenuState::About;
                         }
-                        5 => {
+                        6 => {
                             // Exit
                             self.should_exit = true;
                         }
@@ -231,28 +483,14 @@ impl MenuApp {
 
     /// Handle input for the project action menu (workflow selection).
     fn handle_project_action_menu_input(&mut self, key: KeyCode) {
+        if self.handle_project_filter_key(key) {
+            return;
+        }
         match key {
-            KeyCode::Up => {
-                if let Some(selected) = self.list_state.selected() {
-                    if selected > 0 {
-                        self.list_state.select(Some(selected - 1));
-                    }
-                }
-            }
-            KeyCode::Down => {
-                if let Some(selected) = self.list_state.selected() {
-                    if selected < self.projects.len() - 1 {
-                        self.list_state.select(Some(selected + 1));
-                    }
-                }
-            }
             KeyCode::Enter => {
-                if let Some(selected) = self.list_state.selected() {
-                    if let Some(project) = self.projects.get(selected) {
-                        self.selected_project = Some(project.clone());
-                        self.open_workflow = true;
-                        self.should_exit = true;
-                    }
+                if let Some(project) = self.selected_filtered_project().cloned() {
+                    self.open_workflow = true;
+                    self.choose_project(project);
                 }
             }
             KeyCode::Esc => {
@@ -265,27 +503,13 @@ impl MenuApp {
 
     /// Handle input for the project list state.
     fn handle_project_list_input(&mut self, key: KeyCode) {
+        if self.handle_project_filter_key(key) {
+            return;
+        }
         match key {
-            KeyCode::Up => {
-                if let Some(selected) = self.list_state.selected() {
-                    if selected > 0 {
-                        self.list_state.select(Some(selected - 1));
-                    }
-                }
-            }
-            KeyCode::Down => {
-                if let Some(selected) = self.list_state.selected() {
-                    if selected < self.projects.len() - 1 {
-                        self.list_state.select(Some(selected + 1));
-                    }
-                }
-            }
             KeyCode::Enter => {
-                if let Some(selected) = self.list_state.selected() {
-                    if let Some(project) = self.projects.get(selected) {
-                        self.selected_project = Some(project.clone());
-                        self.should_exit = true;
-                    }
+                if let Some(project) = self.selected_filtered_project().cloned() {
+                    self.choose_project(project);
                 }
             }
             KeyCode::Esc => {
@@ -330,27 +554,14 @@ impl MenuApp {
 
     /// Handle input for the delete project selection state.
     fn handle_delete_project_list_input(&mut self, key: KeyCode) {
+        if self.handle_project_filter_key(key) {
+            return;
+        }
         match key {
-            KeyCode::Up => {
-                if let Some(selected) = self.list_state.selected() {
-                    if selected > 0 {
-                        self.list_state.select(Some(selected - 1));
-                    }
-                }
-            }
-            KeyCode::Down => {
-                if let Some(selected) = self.list_state.selected() {
-                    if selected < self.projects.len() - 1 {
-                        self.list_state.select(Some(selected + 1));
-                    }
-                }
-            }
             KeyCode::Enter => {
-                if let Some(selected) = self.list_state.selected() {
-                    if let Some(project) = self.projects.get(selected) {
-                        self.project_to_delete = Some(project.clone());
-                        self.state = MenuState::DeleteConfirmation;
-                    }
+                if let Some(project) = self.selected_filtered_project().cloned() {
+                    self.project_to_delete = Some(project);
+                    self.state = MenuState::DeleteConfirmation;
                 }
             }
             KeyCode::Esc => {
@@ -389,6 +600,44 @@ impl MenuApp {
         }
     }
 
+    /// Handle input for the help screen: arrows and PageUp/PageDown scroll
+    /// the guide, typing searches it, and Esc clears the search before it
+    /// leaves.
+    fn handle_help_input(&mut self, key: KeyCode) {
+        let last = help_lines(&self.help_query)
+            .len()
+            .saturating_sub(self.help_page);
+        match key {
+            KeyCode::Up => self.help_scroll = self.help_scroll.saturating_sub(1),
+            KeyCode::Down => self.help_scroll = (self.help_scroll + 1).min(last),
+            KeyCode::PageUp => {
+                self.help_scroll = self.help_scroll.saturating_sub(self.help_page);
+            }
+            KeyCode::PageDown => {
+                self.help_scroll = (self.help_scroll + self.help_page).min(last);
+            }
+            KeyCode::Home => self.help_scroll = 0,
+            KeyCode::End => self.help_scroll = last,
+            KeyCode::Backspace => {
+                self.help_query.pop();
+                self.help_scroll = 0;
+            }
+            KeyCode::Char(c) => {
+                self.help_query.push(c);
+                self.help_scroll = 0;
+            }
+            KeyCode::Esc if !self.help_query.is_empty() => {
+                self.help_query.clear();
+                self.help_scroll = 0;
+            }
+            KeyCode::Esc => {
+                self.state = MenuState::MainMenu;
+                self.list_state.select(Some(0));
+            }
+            _ => {}
+        }
+    }
+
     /// Handle input for the about screen.
     fn handle_about_input(&mut self, key: KeyCode) {
         match key {
@@ -414,6 +663,7 @@ impl MenuApp {
             MenuState::NewProject => self.render_new_project(f, chunks[0]),
             MenuState::DeleteProjectList => self.render_delete_project_list(f, chunks[0]),
             MenuState::DeleteConfirmation => self.render_delete_confirmation(f, chunks[0]),
+            MenuState::Help => self.render_help(f, chunks[0]),
             MenuState::About => self.render_about(f, chunks[0]),
         }
 
@@ -422,11 +672,12 @@ impl MenuApp {
 
     /// Render the main menu with project management options.
     fn render_main_menu(&mut self, f: &mut Frame, area: Rect) {
+        let header_height = if self.compact { 0 } else { 3 };
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // Standard header
-                Constraint::Min(0),    // Menu items
+                Constraint::Length(header_height), // Standard header
+                Constraint::Min(0),                // Menu items
             ])
             .split(area);
 
@@ -441,7 +692,9 @@ impl MenuApp {
             .alignment(Alignment::Center)
             .style(Style::default().fg(Color::White));
 
-        f.render_widget(header, chunks[0]);
+        if !self.compact {
+            f.render_widget(header, chunks[0]);
+        }
 
         // Menu items
         let menu_items: Vec<ListItem> = self
@@ -464,25 +717,11 @@ impl MenuApp {
 
     /// Render the project selection list.
     fn render_project_list(&mut self, f: &mut Frame, area: Rect) {
-        let project_items: Vec<ListItem> = self
-            .projects
-            .iter()
-            .map(|project| {
-                let line = if project.name == "default" {
-                    Line::from(format!("  {} (legacy tasks.json)", project.display_name))
-                } else {
-                    Line::from(format!("  {}", project.display_name))
-                };
-                ListItem::new(line)
-            })
-            .collect();
+        let project_items = self.project_list_items();
+        let title = self.project_list_title("Select Project");
 
         let projects_list = List::new(project_items)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title("Select Project"),
-            )
+            .block(Block::default().borders(Borders::ALL).title(title))
             .highlight_style(Style::default().bg(Color::Gray).fg(Color::Black))
             .highlight_symbol("► ");
 
@@ -524,6 +763,53 @@ impl MenuApp {
         ));
     }
 
+    /// Render the guide, or the lines matching the search, from the scroll
+    /// offset down. Section headings are bold.
+    fn render_help(&mut self, f: &mut Frame, area: Rect) {
+        let lines = help_lines(&self.help_query);
+        self.help_page = usize::from(area.height.saturating_sub(2)).max(1);
+        self.help_scroll = self
+            .help_scroll
+            .min(lines.len().saturating_sub(self.help_page));
+
+        let title = if self.help_query.is_empty() {
+            "Help (type to search)".to_string()
+        } else {
+            format!(
+                "Help [search: {}] ({} matching lines)",
+                self.help_query,
+                lines.len()
+            )
+        };
+        let text: Vec<Line> = if lines.is_empty() {
+            vec![Line::from(Span::styled(
+                "  Nothing in the guide matches.",
+                Style::default().fg(Color::DarkGray),
+            ))]
+        } else {
+            lines
+                .iter()
+                .skip(self.help_scroll)
+                .take(self.help_page)
+                .map(|&line| {
+                    if line.starts_with(' ') || line.is_empty() {
+                        Line::from(line)
+                    } else {
+                        Line::from(Span::styled(
+                            line,
+                            Style::default()
+                                .fg(Color::Cyan)
+                                .add_modifier(Modifier::BOLD),
+                        ))
+                    }
+                })
+                .collect()
+        };
+
+        let help = Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(title));
+        f.render_widget(help, area);
+    }
+
     /// Render the about screen with application information.
     fn render_about(&mut self, f: &mut Frame, area: Rect) {
         let about_text = vec![
@@ -556,25 +842,11 @@ impl MenuApp {
 
     /// Render the project selection list for deletion.
     fn render_delete_project_list(&mut self, f: &mut Frame, area: Rect) {
-        let project_items: Vec<ListItem> = self
-            .projects
-            .iter()
-            .map(|project| {
-                let line = if project.name == "default" {
-                    Line::from(format!("  {} (legacy tasks.json)", project.display_name))
-                } else {
-                    Line::from(format!("  {}", project.display_name))
-                };
-                ListItem::new(line)
-            })
-            .collect();
+        let project_items = self.project_list_items();
+        let title = self.project_list_title("Select Project to Delete");
 
         let projects_list = List::new(project_items)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title("Select Project to Delete"),
-            )
+            .block(Block::default().borders(Borders::ALL).title(title))
             .highlight_style(Style::default().bg(Color::Red).fg(Color::White))
             .highlight_symbol("► ");
 
@@ -631,16 +903,29 @@ impl MenuApp {
 
     /// Render the status bar with context-appropriate help text.
     fn render_status_bar(&mut self, f: &mut Frame, area: Rect) {
+        let loaded = self
+            .projects
+            .iter()
+            .filter(|p| self.summaries.contains_key(&p.file_path))
+            .count();
         let status_text = if !self.status_message.is_empty() {
             self.status_message.clone()
+        } else if self.showing_project_list() && loaded < self.projects.len() {
+            // Summaries fill in one per frame; say how far along they are.
+            format!(
+                "{} {}",
+                SPINNER[self.spinner_tick % SPINNER.len()],
+                progress_line(loaded + 1, self.projects.len())
+            )
         } else {
             match self.state {
                 MenuState::MainMenu => "Use ↑↓ to navigate, Enter to select, q/Esc to quit".to_string(),
-                MenuState::ProjectList => "Use ↑↓ to navigate, Enter to select, Esc to go back".to_string(),
-                MenuState::ProjectActionMenu => "Select a project for Workflow - Use ↑↓ to navigate, Enter to select, Esc to go back".to_string(),
+                MenuState::ProjectList => "Type to filter, ↑↓ to navigate, Tab to change sort, Enter to select, Esc to clear/go back".to_string(),
+                MenuState::ProjectActionMenu => "Select a project for Workflow - Type to filter, ↑↓ to navigate, Tab to change sort, Enter to select, Esc to clear/go back".to_string(),
                 MenuState::NewProject => "Type project name, Enter to create, Esc to cancel".to_string(),
-                MenuState::DeleteProjectList => "Use ↑↓ to navigate, Enter to select, Esc to go back".to_string(),
+                MenuState::DeleteProjectList => "Type to filter, ↑↓ to navigate, Tab to change sort, Enter to select, Esc to clear/go back".to_string(),
                 MenuState::DeleteConfirmation => "Press Y to confirm, N or Esc to cancel".to_string(),
+                MenuState::Help => "Type to search, ↑↓ PgUp PgDn Home End to scroll, Esc to clear/go back".to_string(),
                 MenuState::About => "Press any key to return".to_string(),
             }
         };
@@ -658,6 +943,8 @@ impl MenuApp {
             terminal.draw(|f| self.render(f))?;
 
             self.handle_input()?;
+            self.load_next_summary();
+            self.spinner_tick = self.spinner_tick.wrapping_add(1);
 
             if self.should_exit {
                 break;
@@ -678,3 +965,100 @@ impl MenuApp {
         self.should_exit = false;
     }
 }
+
+/// A loaded project row: the icon and colour say whether anything is overdue
+/// (red `!`), still open (yellow `●`), all closed (green `✓`) or empty.
+fn summary_line(name: String, summary: ProjectSummary) -> Line<'static> {
+    let (icon, color) = if summary.overdue > 0 {
+        ('!', Color::Red)
+    } else if summary.open > 0 {
+        ('●', Color::Yellow)
+    } else if summary.total > 0 {
+        ('✓', Color::Green)
+    } else {
+        ('○', Color::DarkGray)
+    };
+    let mut spans = vec![
+        Span::styled(
+            format!("  {icon} "),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(name),
+        Span::styled(
+            format!("   {} tasks · {} open", summary.total, summary.open),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+    if summary.overdue > 0 {
+        spans.push(Span::styled(
+            format!(" · {} overdue", summary.overdue),
+            Style::default().fg(Color::Red),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// The guide on the menu's Help screen. Lines that don't start with a space
+/// are section headings.
+const GUIDE: &[&str] = &[
+    "Getting around this menu",
+    "  ↑/↓ move, Enter picks, Esc goes back a screen, q or Esc on the main menu quits.",
+    "  In the project lists, type to filter by name and press Tab to change the order",
+    "  (name, last modified, last opened). Each row shows the open and overdue counts.",
+    "  Open Project starts the ticket browser; Workflow Manager opens the board.",
+    "",
+    "Projects",
+    "  A project is one task file under .pm/, such as .pm/web_tasks.json.",
+    "  New Project creates one; Delete Project removes its file after you confirm.",
+    "  CLI: pm --project web list runs any command against one project.",
+    "",
+    "The hierarchy",
+    "  Project > Product > Epic > Task > Subtask, plus Milestones across them.",
+    "  Ids carry the kind: PRJ1, PRD2, EPC3, TSK4, SBT5, MLS6. Ids are never reused.",
+    "  CLI: pm add --kind epic \"Checkout\" --parent PRD2",
+    "       pm add \"Card form\" --parent EPC3",
+    "       pm list --descendants-of EPC3",
+    "",
+    "The ticket browser",
+    "  Enter opens a ticket's detail view; n adds a child, e edits, s cycles the",
+    "  status, d deletes, / filters, o changes the sort, h lists every key.",
+    "  In the detail view, p goes to the parent and n adds a child under it.",
+    "  CLI: pm ui opens it directly; pm view TSK4 prints a ticket.",
+    "",
+    "The workflow board",
+    "  Tickets as cards in process-stage columns. ←/→ pick a column, ↑/↓ a card,",
+    "  Ctrl+←/→ moves the card to the next stage. Enter shows its details.",
+    "  c completes a card, d drills into its children and u comes back up,",
+    "  P moves the card and its subtree to another project, h shows the keys.",
+    "  CLI: pm wf opens it directly.",
+    "",
+    "Everyday commands",
+    "  pm list                      open tasks, soonest due first",
+    "  pm update TSK4 --status in-progress",
+    "  pm complete TSK4             mark done and report the parent's progress",
+    "  pm list --due overdue        what has slipped",
+    "  pm --help, pm <command> --help   every command and flag",
+];
+
+/// The [`GUIDE`] lines containing `query`, ignoring case; all of them for
+/// an empty query.
+pub fn help_lines(query: &str) -> Vec<&'static str> {
+    let query = query.to_lowercase();
+    GUIDE
+        .iter()
+        .copied()
+        .filter(|line| query.is_empty() || line.to_lowercase().contains(&query))
+        .collect()
+}
+
+/// Case-insensitive fuzzy match: every character of `needle` must appear in
+/// `haystack` in order, though not necessarily contiguously. An empty needle
+/// matches everything.
+fn fuzzy_match(needle: &str, haystack: &str) -> bool {
+    let haystack = haystack.to_lowercase();
+    let mut chars = haystack.chars();
+    needle
+        .to_lowercase()
+        .chars()
+        .all(|n| chars.by_ref().any(|h| h == n))
+}
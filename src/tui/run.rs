@@ -9,12 +9,16 @@ use crossterm::{
 };
 use ratatui::{prelude::CrosstermBackend, Terminal};
 
-use crate::store::LeafId;
-use crate::tui::app::App;
+use crate::store::{Config, LeafId};
+use crate::tui::app::{App, AppExit};
+use crate::tui::enums::ViewHandoff;
 use crate::views::events_view::{ActivityAction, ActivityView};
 
-/// Initialise and run the terminal user interface.
-pub fn run_tui(db_path: &Path) -> io::Result<()> {
+/// Initialise and run the terminal user interface. `compact` hides the
+/// header banner; `ui.compact` in the workspace config has the same effect.
+/// `start` opens the list at a position handed over by the workflow board.
+/// Returns the exit action requested by the user.
+pub fn run_tui(db_path: &Path, compact: bool, start: Option<ViewHandoff>) -> io::Result<AppExit> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
@@ -22,7 +26,12 @@ pub fn run_tui(db_path: &Path) -> io::Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = App::new(db_path)?;
+    app.set_compact(compact || Config::load(db_path).ui.compact);
+    if let Some(handoff) = start {
+        app.set_navigation(handoff);
+    }
     let result = app.run(&mut terminal);
+    let exit_action = app.get_exit_action();
 
     disable_raw_mode()?;
     execute!(
@@ -32,7 +41,8 @@ pub fn run_tui(db_path: &Path) -> io::Result<()> {
     )?;
     terminal.show_cursor()?;
 
-    result
+    result?;
+    Ok(exit_action)
 }
 
 /// Drive the full-screen activity view standalone, the way `pm tv` does. The
@@ -93,7 +103,7 @@ fn drive_activity_view<B: ratatui::backend::Backend>(
 }
 
 /// Run the TUI with a specific task pre-selected for editing.
-pub fn run_tui_with_edit(db_path: &Path, task_id: LeafId) -> io::Result<()> {
+pub fn run_tui_with_edit(db_path: &Path, task_id: LeafId, compact: bool) -> io::Result<AppExit> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
@@ -101,8 +111,10 @@ pub fn run_tui_with_edit(db_path: &Path, task_id: LeafId) -> io::Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = App::new(db_path)?;
+    app.set_compact(compact || Config::load(db_path).ui.compact);
     app.open_task_for_edit(task_id);
     let result = app.run(&mut terminal);
+    let exit_action = app.get_exit_action();
 
     disable_raw_mode()?;
     execute!(
@@ -112,5 +124,6 @@ pub fn run_tui_with_edit(db_path: &Path, task_id: LeafId) -> io::Result<()> {
     )?;
     terminal.show_cursor()?;
 
-    result
+    result?;
+    Ok(exit_action)
 }
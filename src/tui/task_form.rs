@@ -5,14 +5,17 @@
 //! and form state management.
 
 use crate::{
+    db::{complete_tag, parse_due_input, split_and_normalise_tags, validate_hierarchy},
     fields::{Kind, Priority, ProcessStage, Status, Urgency},
     project::{discover_projects, get_legacy_project},
-    task::Task,
+    store::ProjectDefaults,
+    task::{Task, TaskTemplate},
     tui::{
         enums::{HierarchyLevel, NavigationContext},
-        input::InputField,
+        input::{FieldKind, InputField},
     },
 };
+use std::collections::BTreeSet;
 use std::path::Path;
 
 /// Global order constants for task editing view fields.
@@ -60,6 +63,8 @@ pub struct TaskForm {
     pub available_projects: Vec<String>,
     pub user_story: InputField,
     pub requirements: InputField,
+    /// Index into the workspace templates last applied with Ctrl+T, if any.
+    pub template_index: Option<usize>,
 }
 
 impl TaskForm {
@@ -76,10 +81,10 @@ impl TaskForm {
             summary: InputField::new(),
             description: InputField::new(),
             tags: InputField::new(),
-            due: InputField::new(),
-            parent: InputField::new(),
-            issue_link: InputField::new(),
-            pr_link: InputField::new(),
+            due: InputField::new().with_kind(FieldKind::Date),
+            parent: InputField::new().with_kind(FieldKind::TicketId),
+            issue_link: InputField::new().with_kind(FieldKind::Url),
+            pr_link: InputField::new().with_kind(FieldKind::Url),
             user_story: InputField::new(),
             requirements: InputField::new(),
             artifacts: InputField::new(),
@@ -98,7 +103,13 @@ impl TaskForm {
                 Kind::Subtask,
                 Kind::Milestone,
             ],
-            statuses: vec![Status::Open, Status::InProgress, Status::Done],
+            statuses: vec![
+                Status::Open,
+                Status::InProgress,
+                Status::Blocked,
+                Status::Done,
+                Status::Cancelled,
+            ],
             priorities: vec![
                 None,
                 Some(Priority::MustHave),
@@ -124,6 +135,7 @@ impl TaskForm {
                 Some(ProcessStage::Release),
             ],
             available_projects,
+            template_index: None,
         }
     }
 
@@ -162,7 +174,8 @@ impl TaskForm {
 
         // Set parent ID if we're in a filtered view
         if let Some(parent_id) = context.parent_id {
-            form.parent = InputField::with_value(&parent_id.to_string());
+            form.parent =
+                InputField::with_value(&parent_id.to_string()).with_kind(FieldKind::TicketId);
         }
 
         // Set the appropriate child kind based on the current navigation level
@@ -185,6 +198,41 @@ impl TaskForm {
         form
     }
 
+    /// Fill in a project's configured defaults on a fresh add form. The kind
+    /// is only taken when it may sit under `parent_kind`; tags only when the
+    /// field is still empty. A template applied afterwards overrides these.
+    pub fn apply_project_defaults(
+        &mut self,
+        defaults: &ProjectDefaults,
+        parent_kind: Option<Kind>,
+    ) {
+        if let Some(kind) = defaults
+            .kind
+            .filter(|&k| parent_kind.is_none_or(|p| validate_hierarchy(p, k)))
+        {
+            if let Some(i) = self.kinds.iter().position(|&k| k == kind) {
+                self.kind = i;
+            }
+        }
+        if let Some(i) = self
+            .priorities
+            .iter()
+            .position(|&p| p.is_some() && p == defaults.priority)
+        {
+            self.priority_level = i;
+        }
+        if let Some(i) = self
+            .urgencies
+            .iter()
+            .position(|&u| u.is_some() && u == defaults.urgency)
+        {
+            self.urgency = i;
+        }
+        if self.tags.value.trim().is_empty() && !defaults.tags.is_empty() {
+            self.tags = InputField::with_value(&defaults.tags.join(", "));
+        }
+    }
+
     /// Create a task form populated from an existing task.
     pub fn from_task(task: &Task) -> Self {
         Self::from_task_with_pm_dir(task, &Path::new(".pm"))
@@ -201,11 +249,15 @@ impl TaskForm {
         // stays at its default; callers that want to scope creation to a
         // specific project drive that through navigation context.
         form.tags = InputField::with_value(&task.tags.join(","));
-        form.due = InputField::with_value(&task.due.map(|d| d.to_string()).unwrap_or_default());
+        form.due = InputField::with_value(&task.due.map(|d| d.to_string()).unwrap_or_default())
+            .with_kind(FieldKind::Date);
         form.parent =
-            InputField::with_value(&task.parent.map(|p| p.to_string()).unwrap_or_default());
-        form.issue_link = InputField::with_value(&task.issue_link.clone().unwrap_or_default());
-        form.pr_link = InputField::with_value(&task.pr_link.clone().unwrap_or_default());
+            InputField::with_value(&task.parent.map(|p| p.to_string()).unwrap_or_default())
+                .with_kind(FieldKind::TicketId);
+        form.issue_link = InputField::with_value(&task.issue_link.clone().unwrap_or_default())
+            .with_kind(FieldKind::Url);
+        form.pr_link = InputField::with_value(&task.pr_link.clone().unwrap_or_default())
+            .with_kind(FieldKind::Url);
         form.user_story = InputField::with_value(&task.user_story.clone().unwrap_or_default());
         form.requirements = InputField::with_value(&task.requirements.clone().unwrap_or_default());
         form.artifacts = InputField::with_value(&task.artifacts.join(","));
@@ -233,6 +285,48 @@ impl TaskForm {
         form
     }
 
+    /// Apply a saved template's defaults to the form. Text seeds replace the
+    /// current field contents only when the template provides them, so the
+    /// user story and requirements checklist land in the fullscreen editors.
+    pub fn apply_template(&mut self, template: &TaskTemplate) {
+        if let Some(desc) = &template.description_template {
+            self.description = InputField::with_value(desc);
+        }
+        if let Some(story) = &template.user_story_template {
+            self.user_story = InputField::with_value(story);
+        }
+        if let Some(reqs) = &template.requirements_template {
+            self.requirements = InputField::with_value(reqs);
+        }
+        if !template.tags.is_empty() {
+            self.tags = InputField::with_value(&template.tags.join(","));
+        }
+        if let Some(i) = self.kinds.iter().position(|&k| k == template.kind) {
+            self.kind = i;
+        }
+        if let Some(i) = self.statuses.iter().position(|&s| s == template.status) {
+            self.status = i;
+        }
+        if let Some(i) = self
+            .priorities
+            .iter()
+            .position(|&p| p == template.priority_level)
+        {
+            self.priority_level = i;
+        }
+        if let Some(i) = self.urgencies.iter().position(|&u| u == template.urgency) {
+            self.urgency = i;
+        }
+        if let Some(i) = self
+            .process_stages
+            .iter()
+            .position(|&s| s == template.process_stage)
+        {
+            self.process_stage = i;
+        }
+        self.update_active_field();
+    }
+
     /// Get mutable references to all input fields in visual order.
     pub fn fields_mut(&mut self) -> Vec<&mut InputField> {
         // Order matches the visual layout: left column first, then right column
@@ -320,6 +414,32 @@ impl TaskForm {
         }
     }
 
+    /// Known tags that complete the one being typed at the end of the tags
+    /// field, leaving out tags already entered before it. Empty unless the
+    /// tags field is active with the cursor at the end.
+    pub fn tag_suggestions<'a>(&self, known: &'a BTreeSet<String>) -> Vec<&'a str> {
+        if self.current_field != TAGS_GLOBAL_ORDER || self.tags.cursor != self.tags.value.len() {
+            return Vec::new();
+        }
+        let (entered, token) = match self.tags.value.rsplit_once(',') {
+            Some((head, token)) => (split_and_normalise_tags(&[head.to_string()]), token),
+            None => (Vec::new(), self.tags.value.as_str()),
+        };
+        complete_tag(known, token)
+            .into_iter()
+            .filter(|t| !entered.iter().any(|e| e == t))
+            .collect()
+    }
+
+    /// Replace the tag being typed at the end of the tags field with `tag`.
+    pub fn accept_tag(&mut self, tag: &str) {
+        self.tags.value = match self.tags.value.rsplit_once(',') {
+            Some((head, _)) => format!("{}, {tag}", head.trim_end()),
+            None => tag.to_string(),
+        };
+        self.tags.cursor = self.tags.value.len();
+    }
+
     /// Handle backspace input for the currently active field.
     pub fn handle_backspace(&mut self) {
         match self.current_field {
@@ -497,4 +617,49 @@ impl TaskForm {
             None
         }
     }
+
+    /// Describe the first typed field whose value does not match its kind,
+    /// e.g. `Issue Link: expected an http(s):// URL`.
+    pub fn first_invalid_field(&self) -> Option<String> {
+        [
+            ("Due", &self.due),
+            ("Parent ID", &self.parent),
+            ("Issue Link", &self.issue_link),
+            ("PR Link", &self.pr_link),
+        ]
+        .into_iter()
+        .find(|(_, field)| !field.is_valid())
+        .map(|(label, field)| format!("{label}: expected {}", field.kind.hint()))
+    }
+
+    /// Copy the form's values onto `task`: text fields trimmed, empty ones
+    /// cleared, tags normalised and the due date parsed. The parent is left
+    /// alone, since checking it needs the database.
+    pub fn apply_to(&self, task: &mut Task) {
+        let optional = |field: &InputField| {
+            let value = field.value.trim();
+            (!value.is_empty()).then(|| value.to_string())
+        };
+        task.title = self.title.value.trim().to_string();
+        task.summary = optional(&self.summary);
+        task.description = optional(&self.description);
+        task.user_story = optional(&self.user_story);
+        task.requirements = optional(&self.requirements);
+        task.tags = split_and_normalise_tags(std::slice::from_ref(&self.tags.value));
+        task.due = optional(&self.due).and_then(|due| parse_due_input(&due));
+        task.kind = self.kinds[self.kind];
+        task.status = self.statuses[self.status];
+        task.priority_level = self.priorities[self.priority_level];
+        task.urgency = self.urgencies[self.urgency];
+        task.process_stage = self.process_stages[self.process_stage];
+        task.issue_link = optional(&self.issue_link);
+        task.pr_link = optional(&self.pr_link);
+        task.artifacts = self
+            .artifacts
+            .value
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+    }
 }
@@ -1,6 +1,15 @@
 //! Utility functions for the terminal user interface.
 
+use std::io;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::Span;
+
+use crate::store::LeafId;
+use crate::tui::colors::tag_color;
 
 /// Create a centered rectangle within the given area.
 ///
@@ -24,3 +33,284 @@ pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         ])
         .split(popup_layout[1])[1]
 }
+
+/// Byte ranges of every case-insensitive occurrence of `needle` in
+/// `haystack`, left to right and non-overlapping. Ranges always fall on
+/// char boundaries of `haystack`, so they can slice it directly.
+pub fn match_ranges(haystack: &str, needle: &str) -> Vec<(usize, usize)> {
+    let needle: Vec<char> = needle.chars().flat_map(char::to_lowercase).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let chars: Vec<(usize, char)> = haystack.char_indices().collect();
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        // Walk forward from `i`, consuming lowered haystack chars against
+        // the lowered needle until it is exhausted or diverges.
+        let mut want = needle.iter();
+        let mut j = i;
+        let matched = loop {
+            let Some(&(_, c)) = chars.get(j) else {
+                break false;
+            };
+            if !c.to_lowercase().all(|lc| want.next() == Some(&lc)) {
+                break false;
+            }
+            j += 1;
+            if want.len() == 0 {
+                break true;
+            }
+        };
+        if matched {
+            let end = chars.get(j).map_or(haystack.len(), |&(b, _)| b);
+            ranges.push((chars[i].0, end));
+            i = j;
+        } else {
+            i += 1;
+        }
+    }
+    ranges
+}
+
+/// The text that follows a title for its tags, with the byte range and
+/// colour of each chip in it. With `chips` each tag is a padded ` tag `
+/// cell to be painted [`tag_color`]; without, the tags are plain `[a,b]`
+/// and no ranges come back.
+pub fn tag_suffix(tags: &[String], chips: bool) -> (String, Vec<(usize, usize, Color)>) {
+    if tags.is_empty() {
+        return (String::new(), Vec::new());
+    }
+    if !chips {
+        return (format!(" [{}]", tags.join(",")), Vec::new());
+    }
+    let mut text = String::new();
+    let mut ranges = Vec::new();
+    for tag in tags {
+        text.push(' ');
+        let start = text.len();
+        text.push_str(&format!(" {tag} "));
+        ranges.push((start, text.len(), tag_color(tag)));
+    }
+    (text, ranges)
+}
+
+/// Split `text` into spans styled by `layers`, byte ranges each patched
+/// over the ones before it. Ranges must fall on char boundaries and are
+/// clamped to the text, so layers worked out before truncating still fit.
+pub fn layered_spans(text: &str, layers: &[(usize, usize, Style)]) -> Vec<Span<'static>> {
+    let mut cuts: Vec<usize> = vec![0, text.len()];
+    for &(start, end, _) in layers {
+        cuts.push(start.min(text.len()));
+        cuts.push(end.min(text.len()));
+    }
+    cuts.sort_unstable();
+    cuts.dedup();
+    cuts.windows(2)
+        .map(|w| {
+            let style = layers
+                .iter()
+                .filter(|&&(start, end, _)| start <= w[0] && w[1] <= end)
+                .fold(Style::default(), |acc, &(_, _, s)| acc.patch(s));
+            Span::styled(text[w[0]..w[1]].to_string(), style)
+        })
+        .collect()
+}
+
+/// How the TUI shows an id: the short `T-a3` form when `ui.short_ids` is
+/// set, otherwise the canonical `TSK370`.
+pub fn id_label(id: LeafId, short: bool) -> String {
+    if short {
+        id.short()
+    } else {
+        id.to_string()
+    }
+}
+
+/// The row one step down (`down`) or up from `current` in a list of `len`
+/// rows. At either end the cursor stays put, or with `wrap`
+/// (`ui.wrap_navigation`) comes round to the other end. An empty list
+/// gives 0.
+pub fn step_index(current: usize, len: usize, down: bool, wrap: bool) -> usize {
+    let last = len.saturating_sub(1);
+    match (down, wrap) {
+        (true, _) if current < last => current + 1,
+        (true, true) => 0,
+        (false, _) if current > 0 => current - 1,
+        (false, true) => last,
+        _ => current.min(last),
+    }
+}
+
+/// Word-wrap `text` into at most `max_lines` lines of `width` characters.
+/// Words longer than a line are broken. When text is left over, the last
+/// line ends in `…` so the cut is visible.
+pub fn wrap_with_ellipsis(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+    if width == 0 || max_lines == 0 {
+        return Vec::new();
+    }
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut words = text.split_whitespace().peekable();
+    let mut truncated = false;
+    'outer: while let Some(word) = words.next() {
+        let mut word: Vec<char> = word.chars().collect();
+        loop {
+            let used = current.chars().count();
+            let gap = usize::from(used > 0);
+            if used + gap + word.len() <= width {
+                if gap == 1 {
+                    current.push(' ');
+                }
+                current.extend(word.iter());
+                break;
+            }
+            if used == 0 {
+                // A single word wider than the line: hard-break it.
+                current.extend(word.drain(..width));
+            }
+            lines.push(std::mem::take(&mut current));
+            if lines.len() == max_lines {
+                truncated = !word.is_empty() || words.peek().is_some();
+                break 'outer;
+            }
+            if word.is_empty() {
+                break;
+            }
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    if truncated {
+        if let Some(last) = lines.last_mut() {
+            let mut chars: Vec<char> = last.chars().collect();
+            chars.truncate(width.saturating_sub(1));
+            while chars.last() == Some(&' ') {
+                chars.pop();
+            }
+            chars.push('…');
+            *last = chars.into_iter().collect();
+        }
+    }
+    lines
+}
+
+/// One screen row of the fullscreen editor: bytes `start..end` of logical
+/// line `line`, counting lines from 0 and splitting on `\n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EditorRow {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Lay `text` out as fullscreen editor rows. With `wrap`, a line longer
+/// than `width` characters continues on the next row, broken after the
+/// last space that fits, or mid-word when one word fills the row; a space
+/// right at the break stays at the end of the row it follows. The text
+/// itself is left alone. Without `wrap` each line is one row.
+pub fn editor_rows(text: &str, width: usize, wrap: bool) -> Vec<EditorRow> {
+    let mut rows = Vec::new();
+    for (line, content) in text.split('\n').enumerate() {
+        let mut start = 0;
+        loop {
+            let rest = &content[start..];
+            let cut = match rest.char_indices().nth(width) {
+                Some((cut, _)) if wrap && width > 0 => cut,
+                _ => {
+                    rows.push(EditorRow {
+                        line,
+                        start,
+                        end: content.len(),
+                    });
+                    break;
+                }
+            };
+            let brk = if rest[cut..].starts_with(' ') {
+                cut + 1
+            } else {
+                match rest[..cut].rfind(' ') {
+                    Some(space) if space > 0 => space + 1,
+                    _ => cut,
+                }
+            };
+            rows.push(EditorRow {
+                line,
+                start,
+                end: start + brk,
+            });
+            start += brk;
+        }
+    }
+    rows
+}
+
+/// Where the editor cursor at byte `x` of line `line` is drawn: the index
+/// into `rows` and the column in characters from that row's start. A
+/// cursor on a row break belongs to the later row.
+pub fn editor_cursor(rows: &[EditorRow], text: &str, line: usize, x: usize) -> (usize, usize) {
+    let Some(row) = rows
+        .iter()
+        .rposition(|r| r.line == line && r.start <= x)
+        .or_else(|| rows.len().checked_sub(1))
+    else {
+        return (0, 0);
+    };
+    let content = text.split('\n').nth(rows[row].line).unwrap_or("");
+    let mut end = x.min(content.len()).max(rows[row].start);
+    if rows[row].line != line {
+        end = content.len();
+    }
+    while !content.is_char_boundary(end) {
+        end -= 1;
+    }
+    (row, content[rows[row].start..end].chars().count())
+}
+
+/// The cursor (line, byte) for column `col` of editor row `row`: the
+/// inverse of [`editor_cursor`]. Columns past the row's text land on its
+/// last position, which for a wrapped row is before the break so the
+/// cursor stays on that row.
+pub fn editor_offset(rows: &[EditorRow], text: &str, row: usize, col: usize) -> (usize, usize) {
+    let Some(r) = rows.get(row) else {
+        return (0, 0);
+    };
+    let content = text.split('\n').nth(r.line).unwrap_or("");
+    let continued = rows.get(row + 1).is_some_and(|next| next.line == r.line);
+    let segment = &content[r.start..r.end];
+    let last = if continued {
+        segment.char_indices().last().map_or(0, |(i, _)| i)
+    } else {
+        segment.len()
+    };
+    let x = segment
+        .char_indices()
+        .nth(col)
+        .map_or(segment.len(), |(i, _)| i)
+        .min(last);
+    (r.line, r.start + x)
+}
+
+/// Open `target` (a file path or URL) with the OS default application:
+/// `open` on macOS, `start` on Windows, `xdg-open` elsewhere. The viewer is
+/// started in the background with its output discarded, so the TUI keeps
+/// the terminal.
+pub fn open_with_default_app(target: &Path) -> io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    command
+        .arg(target)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(drop)
+}
@@ -2,9 +2,13 @@
 //!
 //! This module implements a Kanban-style board view where tasks are organized
 //! into columns by process stage, allowing for visual task management and
-//! rapid status updates through drag-and-drop style interactions.
+//! rapid status updates through drag-and-drop style interactions. A project
+//! may group several stages into one column (`stage_columns` in
+//! `config.json`, or `pm wf --stage-columns`).
 
+use std::collections::BTreeSet;
 use std::io;
+use std::ops::Bound;
 use std::path::Path;
 use std::time::Duration;
 
@@ -18,15 +22,24 @@ use ratatui::{
     Frame, Terminal,
 };
 
-use crate::store::LeafId;
+use crate::project::{
+    discover_projects, get_legacy_project, move_to_project, workspace_dir, Project,
+};
+use crate::store::{AgeingConfig, Config, LeafId};
 use crate::task::Task;
 use crate::{
-    db::{format_status, project_label, Database},
-    tui::enums::{HierarchyLevel, NavigationContext},
+    db::{
+        advance_stage, ageing, board_columns, child_kind_for, completion_blocker,
+        default_board_columns, format_completion_blocker, format_sort_key, format_status,
+        project_ancestor, project_label, sort_tasks, status_flow_error, truncate, BoardColumn,
+        Database,
+    },
+    tui::enums::{HierarchyLevel, NavigationContext, ViewHandoff},
+    tui::utils::{centered_rect, id_label, step_index, tag_suffix, wrap_with_ellipsis},
 };
 use crate::{
     fields::*,
-    tui::colors::{DARK_GREEN, DARK_PURPLE, DARK_RED, GOLD},
+    tui::colors::{ageing_color, colors_enabled, DARK_GREEN, DARK_PURPLE, DARK_RED, GOLD},
 };
 
 /// Return value for workflow app to indicate what should happen next
@@ -34,56 +47,125 @@ use crate::{
 pub enum WorkflowExit {
     Quit,
     EditTask(LeafId),
+    ListView(ViewHandoff),
 }
 
 /// Main workflow application state
 pub struct WorkflowApp {
     db: Database,
+    // `db` as last loaded or saved; see `save_db`.
+    loaded: Database,
     db_path: std::path::PathBuf,
     navigation_context: NavigationContext,
     navigation_stack: Vec<NavigationContext>, // For drill-down/up navigation
-    selected_column: usize,                   // Current process stage column (0-8)
+    selected_column: usize,                   // Current board column
     selected_card: usize,                     // Selected card within the column
-    column_scroll_offsets: [usize; 9],        // Scroll offset for each column
+    column_scroll_offsets: Vec<usize>,        // Scroll offset for each column
     status_message: String,
     show_task_detail: bool,       // Whether to show task detail popup
     show_completed: bool,         // Whether to show completed tasks
     edit_task_id: Option<LeafId>, // Task ID to edit when exiting
+    open_list: bool,              // Return to the task list when exiting
+    short_ids: bool,              // Show ids as `T-a3` (`ui.short_ids`)
+    wrap_navigation: bool,        // Up/Down wrap round a column (`ui.wrap_navigation`)
+    ageing: AgeingConfig,         // Card background thresholds (`ui.ageing`)
     filter_active: bool,          // Whether filter mode is active
     filter_text: String,          // Current filter text
-
-    // Organised tasks by process stage. 9 columns: None, Ideation, Design,
-    // Prototyping, Ready to Implement, Implementation, Testing, Refinement,
-    // Release.
-    columns: [Vec<LeafId>; 9],
+    card_sort: SortKey,           // Ordering of cards within each column
+    iteration: Option<String>,    // Only show cards planned into this iteration
+    compact: bool,                // Hide the header banner
+    detail_scroll: u16,           // First visible row of the detail popup
+    detail_max_scroll: u16,       // Overflow rows measured at the last render
+    stage_columns: Option<Vec<BoardColumn>>, // `--stage-columns`, over any config
+    focus: Option<LeafId>,        // `pm focus` root the board is limited to
+    move_targets: Vec<Project>,   // Projects the `P` picker offers
+    move_picker: Option<usize>,   // Highlighted project while the picker is open
+    move_pending: Option<usize>,  // Picked project awaiting y/n
+
+    // The board's columns and the stages each shows: by default None,
+    // Ideation, Design, Prototyping, Ready to Implement, Implementation,
+    // Testing, Refinement, Release; see `resolve_board`.
+    board: Vec<BoardColumn>,
+    // Organised tasks, one list per board column.
+    columns: Vec<Vec<LeafId>>,
 }
 
 impl WorkflowApp {
     /// Create a new WorkflowApp instance
     pub fn new(db_path: &Path) -> io::Result<Self> {
-        let db = Database::load(db_path);
+        let db = Database::load_guarded(db_path);
+        let config = Config::load(db_path);
+        let ui = config.ui;
+        // Under `pm focus`, open on the focus root's children.
+        let focus = config.focus.filter(|&root| db.get(root).is_some());
+        let navigation_context = match focus.and_then(|root| db.get(root)) {
+            Some(root) => match child_kind_for(root.kind) {
+                Some(kind) => NavigationContext::new_filtered(
+                    HierarchyLevel::of_kind(kind),
+                    root.id,
+                    root.title.clone(),
+                ),
+                None => NavigationContext::new_all_level(HierarchyLevel::of_kind(root.kind)),
+            },
+            None => NavigationContext::new_all_level(HierarchyLevel::Product), // Default to products view
+        };
 
         let mut app = WorkflowApp {
+            loaded: db.clone(),
             db,
             db_path: db_path.to_path_buf(),
-            navigation_context: NavigationContext::new_all_level(HierarchyLevel::Product), // Default to products view
+            navigation_context,
             navigation_stack: Vec::new(),
             selected_column: 0,
             selected_card: 0,
-            column_scroll_offsets: [0; 9],
+            column_scroll_offsets: Vec::new(),
             status_message: String::new(),
             show_task_detail: false,
             show_completed: false, // Hide completed tasks by default
             edit_task_id: None,
+            open_list: false,
+            short_ids: ui.short_ids,
+            wrap_navigation: ui.wrap_navigation,
+            ageing: ui.ageing,
             filter_active: false,
             filter_text: String::new(),
-            columns: Default::default(),
+            card_sort: SortKey::Id,
+            iteration: None,
+            compact: false,
+            detail_scroll: 0,
+            detail_max_scroll: 0,
+            stage_columns: None,
+            focus,
+            move_targets: Vec::new(),
+            move_picker: None,
+            move_pending: None,
+            board: default_board_columns(),
+            columns: Vec::new(),
         };
 
         app.update_columns();
         Ok(app)
     }
 
+    /// Open at the hierarchy position handed over by the task list.
+    pub fn set_navigation(&mut self, handoff: ViewHandoff) {
+        self.navigation_context = handoff.context;
+        self.navigation_stack = handoff.stack;
+        self.update_columns();
+    }
+
+    /// Show these columns whichever project is on the board
+    /// (`pm wf --stage-columns`).
+    pub fn set_stage_columns(&mut self, columns: Vec<BoardColumn>) {
+        self.stage_columns = Some(columns);
+        self.update_columns();
+    }
+
+    /// Hide or show the header banner above the board.
+    pub fn set_compact(&mut self, compact: bool) {
+        self.compact = compact;
+    }
+
     /// Get the theme color for the current hierarchy level
     fn get_hierarchy_color(&self) -> Color {
         match self.navigation_context.level {
@@ -107,21 +189,63 @@ impl WorkflowApp {
         }
     }
 
+    /// The columns to show: `--stage-columns` when given, else the
+    /// `stage_columns` configured for the project being viewed, else one
+    /// column per stage. Invalid configured columns fall back to the
+    /// default with a status message.
+    fn resolve_board(&mut self) -> Vec<BoardColumn> {
+        if let Some(columns) = &self.stage_columns {
+            return columns.clone();
+        }
+        let project = self
+            .navigation_context
+            .parent_id
+            .and_then(|id| self.db.get(id))
+            .and_then(|parent| match parent.kind {
+                Kind::Project => Some(parent),
+                _ => project_ancestor(&self.db, parent),
+            });
+        let Some(project) = project else {
+            return default_board_columns();
+        };
+        let config = Config::load(&self.db_path);
+        let id = project.id.to_string();
+        let Some(spec) = config.project_stage_columns(&id, &project.title) else {
+            return default_board_columns();
+        };
+        match board_columns(spec) {
+            Ok(columns) => columns,
+            Err(e) => {
+                self.set_status_message(format!("Ignoring stage_columns for {id}: {e}"));
+                default_board_columns()
+            }
+        }
+    }
+
     /// Update the task columns based on current context and filters
     fn update_columns(&mut self) {
-        // Clear all columns and reset scroll offsets
-        for (i, column) in self.columns.iter_mut().enumerate() {
-            column.clear();
-            self.column_scroll_offsets[i] = 0;
-        }
+        // Rebuild the board and reset scroll offsets
+        self.board = self.resolve_board();
+        self.columns = vec![Vec::new(); self.board.len()];
+        self.column_scroll_offsets = vec![0; self.board.len()];
 
         let hierarchy_level = self.navigation_context.level;
         let parent_filter = self.navigation_context.parent_id;
 
+        let mut buckets: Vec<Vec<&Task>> = vec![Vec::new(); self.board.len()];
+        let in_focus = self
+            .focus
+            .filter(|&root| self.db.get(root).is_some())
+            .map(|root| self.db.subtree_ids(root));
+
         // Filter tasks based on context
         for task in &self.db.tasks {
+            if in_focus.as_ref().is_some_and(|ids| !ids.contains(&task.id)) {
+                continue;
+            }
+
             // Filter out completed tasks unless show_completed is true
-            if task.status == Status::Done && !self.show_completed {
+            if task.status.is_closed() && !self.show_completed {
                 continue;
             }
 
@@ -146,6 +270,10 @@ impl WorkflowApp {
                 }
             }
 
+            if self.iteration.is_some() && task.iteration != self.iteration {
+                continue;
+            }
+
             // Apply text filter if active. The project field is gone; we
             // derive the project label from the parent chain instead.
             if !self.filter_text.is_empty() {
@@ -165,19 +293,18 @@ impl WorkflowApp {
             }
 
             // Organize into columns by process stage
-            let column_index = match task.process_stage {
-                None => 0,
-                Some(ProcessStage::Ideation) => 1,
-                Some(ProcessStage::Design) => 2,
-                Some(ProcessStage::Prototyping) => 3,
-                Some(ProcessStage::ReadyToImplement) => 4,
-                Some(ProcessStage::Implementation) => 5,
-                Some(ProcessStage::Testing) => 6,
-                Some(ProcessStage::Refinement) => 7,
-                Some(ProcessStage::Release) => 8, // Release is now its own column
-            };
+            if let Some(column_index) = self
+                .board
+                .iter()
+                .position(|c| c.stages.contains(&task.process_stage))
+            {
+                buckets[column_index].push(task);
+            }
+        }
 
-            self.columns[column_index].push(task.id);
+        for (column, mut bucket) in self.columns.iter_mut().zip(buckets) {
+            sort_tasks(&mut bucket, self.card_sort);
+            column.extend(bucket.iter().map(|t| t.id));
         }
 
         // Ensure selected card is valid
@@ -207,7 +334,10 @@ impl WorkflowApp {
         // The actual calculation happens in render_column where we know the available height
     }
 
-    /// Toggle completion status of the selected task
+    /// Toggle completion status of the selected task. Works with the detail
+    /// popup open: the popup stays on the card, wherever the new status and
+    /// stage put it, and closes only if hiding completed takes the card off
+    /// the board.
     fn toggle_task_completion(&mut self) {
         if self.columns[self.selected_column].is_empty() {
             return;
@@ -215,22 +345,50 @@ impl WorkflowApp {
 
         let task_id = self.columns[self.selected_column][self.selected_card];
 
-        if let Some(task) = self.db.get_mut(task_id) {
-            // Toggle between Done and Open (or InProgress if it was InProgress)
-            let new_status = if task.status == Status::Done {
-                // Uncomplete: restore to Open or InProgress based on process stage
-                if task.process_stage == Some(ProcessStage::Implementation)
-                    || task.process_stage == Some(ProcessStage::Testing)
-                {
-                    Status::InProgress
-                } else {
-                    Status::Open
-                }
+        let closing = self.db.get(task_id).is_some_and(|t| !t.status.is_closed());
+        if closing
+            && Config::load(&self.db_path)
+                .complete
+                .block_parent_completion_with_open_children
+        {
+            if let Some((id, open)) = completion_blocker(&self.db, &[task_id]) {
+                self.set_status_message(format!(
+                    "{}; complete them first",
+                    format_completion_blocker(id, &open)
+                ));
+                return;
+            }
+        }
+
+        let Some(task) = self.db.get(task_id) else {
+            return;
+        };
+        // Toggle between Done and Open (or InProgress if it was InProgress)
+        let new_status = if task.status.is_closed() {
+            // Uncomplete: restore to Open or InProgress based on process stage
+            if task.process_stage == Some(ProcessStage::Implementation)
+                || task.process_stage == Some(ProcessStage::Testing)
+            {
+                Status::InProgress
             } else {
-                Status::Done
-            };
+                Status::Open
+            }
+        } else {
+            Status::Done
+        };
+        let workflow = Config::load(&self.db_path).workflow;
+        if workflow.enforce_status_flow {
+            if let Some(e) = status_flow_error(task_id, task.status, new_status) {
+                self.set_status_message(e);
+                return;
+            }
+        }
 
+        if let Some(task) = self.db.get_mut(task_id) {
+            let from = task.status;
             task.status = new_status;
+            advance_stage(task, from, &workflow);
+            task.updated_at_utc = chrono::Utc::now().timestamp();
 
             if let Err(e) = self.save_db() {
                 self.set_status_message(format!("Error saving: {}", e));
@@ -238,22 +396,47 @@ impl WorkflowApp {
                 let status_text = match new_status {
                     Status::Done => "Task marked as completed",
                     Status::InProgress => "Task marked as in progress",
-                    Status::Open => "Task marked as open",
+                    // The toggle never produces Blocked or Cancelled.
+                    _ => "Task marked as open",
                 };
                 self.set_status_message(status_text.to_string());
+                self.follow_card(task_id);
+            }
+        }
+    }
 
-                // If we just completed a task and we're hiding completed, it will disappear
-                if new_status == Status::Done && !self.show_completed {
-                    self.update_columns();
-                }
+    /// Re-select `task_id` after `update_columns`, since a status change can
+    /// advance its stage into another column. If hiding completed took it
+    /// off the board, the cursor keeps the neighbouring card and the detail
+    /// popup closes rather than switch to a different ticket.
+    fn follow_card(&mut self, task_id: LeafId) {
+        let found = self.columns.iter().enumerate().find_map(|(column, ids)| {
+            ids.iter()
+                .position(|&id| id == task_id)
+                .map(|card| (column, card))
+        });
+        match found {
+            Some((column, card)) => {
+                self.selected_column = column;
+                self.selected_card = card;
+                self.update_scroll_for_selection();
             }
+            None if self.show_task_detail => {
+                self.show_task_detail = false;
+                self.detail_scroll = 0;
+                self.status_message
+                    .push_str(" and hidden (t shows completed)");
+            }
+            None => {}
         }
     }
 
-    /// Save the database to disk and refresh columns
+    /// Save the database to disk and refresh columns. Edits made since the
+    /// last load are replayed onto anything the CLI saved in the meantime.
     fn save_db(&mut self) -> io::Result<()> {
-        self.db.save(&self.db_path)?;
-        self.db = Database::load(&self.db_path); // Reload to ensure consistency
+        self.db.save_since(&self.db_path, &self.loaded)?;
+        self.db = Database::load_guarded(&self.db_path); // Reload to ensure consistency
+        self.loaded = self.db.clone();
         self.update_columns();
         Ok(())
     }
@@ -268,21 +451,6 @@ impl WorkflowApp {
         self.status_message.clear();
     }
 
-    /// Get column titles
-    fn get_column_titles() -> [&'static str; 9] {
-        [
-            "Unassigned",
-            "Ideation",
-            "Design",
-            "Prototyping",
-            "Ready to Implement",
-            "Implementation",
-            "Testing",
-            "Refinement",
-            "Release",
-        ]
-    }
-
     /// Handle keyboard input
     fn handle_input(&mut self) -> io::Result<bool> {
         if event::poll(Duration::from_millis(50))? {
@@ -324,6 +492,34 @@ impl WorkflowApp {
                     return Ok(false);
                 }
 
+                // The `P` project picker, then its confirmation
+                if let Some(selected) = self.move_picker {
+                    match key.code {
+                        KeyCode::Up => self.move_picker = Some(selected.saturating_sub(1)),
+                        KeyCode::Down => {
+                            self.move_picker = Some((selected + 1).min(self.move_targets.len() - 1))
+                        }
+                        KeyCode::Enter => {
+                            self.move_picker = None;
+                            self.confirm_project_move(selected);
+                        }
+                        KeyCode::Esc => {
+                            self.move_picker = None;
+                            self.set_status_message("Move cancelled".to_string());
+                        }
+                        _ => {}
+                    }
+                    return Ok(false);
+                }
+                if let Some(target) = self.move_pending.take() {
+                    if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                        self.move_card_to_project(target);
+                    } else {
+                        self.set_status_message("Move cancelled".to_string());
+                    }
+                    return Ok(false);
+                }
+
                 self.clear_status_message();
 
                 match key.code {
@@ -345,6 +541,7 @@ impl WorkflowApp {
                     // Task detail popup
                     KeyCode::Enter => {
                         self.show_task_detail = !self.show_task_detail;
+                        self.detail_scroll = 0;
                         if !self.show_task_detail {
                             self.clear_status_message();
                         }
@@ -394,19 +591,24 @@ impl WorkflowApp {
                         }
                     }
 
-                    // Card navigation within column with scrolling
-                    KeyCode::Up => {
-                        if self.selected_card > 0 {
-                            self.selected_card -= 1;
-                            self.update_scroll_for_selection();
-                        }
+                    // While the detail popup is open, Up/Down scroll its content
+                    KeyCode::Up if self.show_task_detail => {
+                        self.detail_scroll = self.detail_scroll.saturating_sub(1);
                     }
-                    KeyCode::Down => {
+                    KeyCode::Down if self.show_task_detail => {
+                        self.detail_scroll = (self.detail_scroll + 1).min(self.detail_max_scroll);
+                    }
+
+                    // Card navigation within column with scrolling
+                    KeyCode::Up | KeyCode::Down => {
                         let column_len = self.columns[self.selected_column].len();
-                        if column_len > 0 && self.selected_card < column_len - 1 {
-                            self.selected_card += 1;
-                            self.update_scroll_for_selection();
-                        }
+                        self.selected_card = step_index(
+                            self.selected_card,
+                            column_len,
+                            key.code == KeyCode::Down,
+                            self.wrap_navigation,
+                        );
+                        self.update_scroll_for_selection();
                     }
 
                     // Edit task
@@ -418,6 +620,12 @@ impl WorkflowApp {
                         }
                     }
 
+                    // Back to the task list at the same hierarchy position
+                    KeyCode::Char('l') => {
+                        self.open_list = true;
+                        return Ok(true);
+                    }
+
                     // Complete/uncomplete task
                     KeyCode::Char('c') => {
                         self.toggle_task_completion();
@@ -435,6 +643,21 @@ impl WorkflowApp {
                         self.set_status_message(status.to_string());
                     }
 
+                    // Cycle card ordering within columns
+                    KeyCode::Char('S') => {
+                        self.cycle_card_sort();
+                    }
+
+                    // Move the card to another project
+                    KeyCode::Char('P') => {
+                        self.open_project_picker();
+                    }
+
+                    // Cycle the iteration filter
+                    KeyCode::Char('I') => {
+                        self.cycle_iteration();
+                    }
+
                     // Filter mode
                     KeyCode::Char('/') => {
                         self.filter_active = true;
@@ -443,7 +666,7 @@ impl WorkflowApp {
 
                     // Help
                     KeyCode::Char('h') => {
-                        self.set_status_message("Help: Enter: Details | e: Edit | c: Complete | t: Toggle done | /: Filter | d: Drill | u: Up | m: Menu | Esc: Exit".to_string());
+                        self.set_status_message("Help: Enter: Details | e: Edit | c: Complete | t: Toggle done | S: Sort | I: Iteration | P: Project | /: Filter | d: Drill | u: Up | l: List | m: Menu | Esc: Exit".to_string());
                     }
 
                     _ => {}
@@ -453,35 +676,150 @@ impl WorkflowApp {
         Ok(false)
     }
 
-    /// Move the selected card to the left column (previous process stage)
+    /// Cycle the within-column card ordering (Id -> Priority -> Due) and keep
+    /// the cursor on the same card.
+    /// Step the iteration filter through every iteration on the board's
+    /// tickets, in name order, then back to showing all cards.
+    fn cycle_iteration(&mut self) {
+        let names: BTreeSet<String> = self
+            .db
+            .tasks
+            .iter()
+            .filter_map(|t| t.iteration.clone())
+            .collect();
+        self.iteration = match self.iteration.take() {
+            None => names.first().cloned(),
+            Some(current) => names
+                .range::<String, _>((Bound::Excluded(current), Bound::Unbounded))
+                .next()
+                .cloned(),
+        };
+        self.selected_card = 0;
+        self.update_columns();
+        let status = match &self.iteration {
+            Some(name) => format!("Iteration: {name}"),
+            None if names.is_empty() => "No tasks are planned into an iteration".to_string(),
+            None => "Showing all iterations".to_string(),
+        };
+        self.set_status_message(status);
+    }
+
+    fn cycle_card_sort(&mut self) {
+        let selected = self.columns[self.selected_column]
+            .get(self.selected_card)
+            .copied();
+        self.card_sort = match self.card_sort {
+            SortKey::Id => SortKey::Priority,
+            SortKey::Priority => SortKey::Due,
+            SortKey::Due => SortKey::Created,
+            SortKey::Created => SortKey::Updated,
+            SortKey::Updated => SortKey::Id,
+        };
+        self.update_columns();
+        if let Some(id) = selected {
+            if let Some(pos) = self.columns[self.selected_column]
+                .iter()
+                .position(|&t| t == id)
+            {
+                self.selected_card = pos;
+            }
+        }
+        self.set_status_message(format!(
+            "Sorting cards by {}",
+            format_sort_key(self.card_sort)
+        ));
+    }
+
+    /// Open the picker of projects to move the selected card to (`P`):
+    /// every other project in the workspace, as the menu lists them.
+    fn open_project_picker(&mut self) {
+        if self.columns[self.selected_column].is_empty() {
+            self.set_status_message("No card selected to move".to_string());
+            return;
+        }
+        let workspace = workspace_dir(&self.db_path);
+        let mut projects = discover_projects(&workspace).unwrap_or_default();
+        projects.extend(get_legacy_project(&workspace));
+        projects.retain(|p| p.file_path != self.db_path);
+        if projects.is_empty() {
+            self.set_status_message("No other project to move the card to".to_string());
+            return;
+        }
+        self.move_targets = projects;
+        self.move_picker = Some(0);
+    }
+
+    /// The selected card's id and how many descendants go with it, as the
+    /// move prompt and result name them.
+    fn moving_label(&self, task_id: LeafId) -> String {
+        match self.db.subtree_ids(task_id).len() - 1 {
+            0 => task_id.to_string(),
+            1 => format!("{task_id} and 1 descendant"),
+            n => format!("{task_id} and {n} descendants"),
+        }
+    }
+
+    /// Ask before moving: the move rewrites two projects.
+    fn confirm_project_move(&mut self, target: usize) {
+        let Some(&task_id) = self.columns[self.selected_column].get(self.selected_card) else {
+            return;
+        };
+        self.set_status_message(format!(
+            "Move {} to {}? It gets a new id there. y to confirm, any other key cancels",
+            self.moving_label(task_id),
+            self.move_targets[target].display_name
+        ));
+        self.move_pending = Some(target);
+    }
+
+    /// Move the selected card and its subtree into the picked project.
+    fn move_card_to_project(&mut self, target: usize) {
+        let Some(&task_id) = self.columns[self.selected_column].get(self.selected_card) else {
+            return;
+        };
+        let project = self.move_targets[target].clone();
+        let label = self.moving_label(task_id);
+        let result = move_to_project(&mut self.db, &self.db_path, task_id, &project);
+        self.db = Database::load_guarded(&self.db_path);
+        self.loaded = self.db.clone();
+        self.update_columns();
+        match result {
+            Ok(report) => {
+                let new_id = report
+                    .remapped
+                    .first()
+                    .map(|&(_, new)| new)
+                    .unwrap_or(task_id);
+                self.set_status_message(format!(
+                    "Moved {label} to {} as {new_id}",
+                    project.display_name
+                ));
+            }
+            Err(e) => self.set_status_message(format!("Error moving {task_id}: {e}")),
+        }
+    }
+
+    /// Move the selected card to the left column, taking its last stage
+    /// (the one nearest the column it left)
     fn move_card_left(&mut self) {
         if self.selected_column == 0 || self.columns[self.selected_column].is_empty() {
             return;
         }
 
         let task_id = self.columns[self.selected_column][self.selected_card];
+        let target_column = self.selected_column - 1;
+        let Some(&new_stage) = self.board[target_column].stages.last() else {
+            return;
+        };
 
         if let Some(task) = self.db.get_mut(task_id) {
-            let new_stage = match self.selected_column {
-                1 => None,                                 // Ideation -> Unassigned
-                2 => Some(ProcessStage::Ideation),         // Design -> Ideation
-                3 => Some(ProcessStage::Design),           // Prototyping -> Design
-                4 => Some(ProcessStage::Prototyping),      // Ready to Implement -> Prototyping
-                5 => Some(ProcessStage::ReadyToImplement), // Implementation -> Ready to Implement
-                6 => Some(ProcessStage::Implementation),   // Testing -> Implementation
-                7 => Some(ProcessStage::Testing),          // Refinement -> Testing
-                8 => Some(ProcessStage::Refinement),       // Release -> Refinement
-                _ => return,
-            };
-
             task.process_stage = new_stage;
             if let Err(e) = self.save_db() {
                 self.set_status_message(format!("Error saving: {}", e));
             } else {
-                let target_column = self.selected_column - 1;
                 self.set_status_message(format!(
                     "Moved task to {}",
-                    Self::get_column_titles()[target_column]
+                    self.board[target_column].title
                 ));
                 self.selected_column = target_column;
 
@@ -498,7 +836,7 @@ impl WorkflowApp {
         }
     }
 
-    /// Move the selected card to the right column (next process stage)
+    /// Move the selected card to the right column, taking its first stage
     fn move_card_right(&mut self) {
         if self.selected_column >= self.columns.len() - 1
             || self.columns[self.selected_column].is_empty()
@@ -507,28 +845,19 @@ impl WorkflowApp {
         }
 
         let task_id = self.columns[self.selected_column][self.selected_card];
+        let target_column = self.selected_column + 1;
+        let Some(&new_stage) = self.board[target_column].stages.first() else {
+            return;
+        };
 
         if let Some(task) = self.db.get_mut(task_id) {
-            let new_stage = match self.selected_column {
-                0 => Some(ProcessStage::Ideation),    // Unassigned -> Ideation
-                1 => Some(ProcessStage::Design),      // Ideation -> Design
-                2 => Some(ProcessStage::Prototyping), // Design -> Prototyping
-                3 => Some(ProcessStage::ReadyToImplement), // Prototyping -> Ready to Implement
-                4 => Some(ProcessStage::Implementation), // Ready to Implement -> Implementation
-                5 => Some(ProcessStage::Testing),     // Implementation -> Testing
-                6 => Some(ProcessStage::Refinement),  // Testing -> Refinement
-                7 => Some(ProcessStage::Release),     // Refinement -> Release
-                _ => return,
-            };
-
             task.process_stage = new_stage;
             if let Err(e) = self.save_db() {
                 self.set_status_message(format!("Error saving: {}", e));
             } else {
-                let target_column = self.selected_column + 1;
                 self.set_status_message(format!(
                     "Moved task to {}",
-                    Self::get_column_titles()[target_column]
+                    self.board[target_column].title
                 ));
                 self.selected_column = target_column;
 
@@ -673,16 +1002,19 @@ impl WorkflowApp {
 
     /// Render the workflow kanban board
     fn render(&mut self, f: &mut Frame) {
+        let header_height = if self.compact { 0 } else { 3 };
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // Header
-                Constraint::Min(0),    // Board
-                Constraint::Length(1), // Status bar
+                Constraint::Length(header_height), // Header
+                Constraint::Min(0),                // Board
+                Constraint::Length(1),             // Status bar
             ])
             .split(f.area());
 
-        self.render_header(f, chunks[0]);
+        if !self.compact {
+            self.render_header(f, chunks[0]);
+        }
         self.render_board(f, chunks[1]);
         self.render_status_bar(f, chunks[2]);
 
@@ -690,16 +1022,68 @@ impl WorkflowApp {
         if self.show_task_detail {
             self.render_task_detail_popup(f);
         }
+        if let Some(selected) = self.move_picker {
+            self.render_project_picker(f, selected);
+        }
+    }
+
+    /// Render the `P` project picker over the board
+    fn render_project_picker(&self, f: &mut Frame, selected: usize) {
+        let area = centered_rect(40, 50, f.area());
+        f.render_widget(Clear, area);
+        let hierarchy_color = self.get_hierarchy_color();
+        let lines: Vec<Line> = self
+            .move_targets
+            .iter()
+            .enumerate()
+            .map(|(i, project)| {
+                let style = if i == selected {
+                    Style::default()
+                        .bg(hierarchy_color)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Line::from(Span::styled(project.display_name.clone(), style))
+            })
+            .collect();
+        // Keep the highlighted project in view on long lists.
+        let rows = area.height.saturating_sub(2) as usize;
+        let scroll = selected.saturating_sub(rows.saturating_sub(1)) as u16;
+        let picker = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Move to project (Enter picks, Esc cancels)")
+                    .title_alignment(Alignment::Center)
+                    .border_style(
+                        Style::default()
+                            .fg(hierarchy_color)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+            )
+            .scroll((scroll, 0))
+            .style(Style::default().bg(Color::Black));
+        f.render_widget(picker, area);
     }
 
     /// Render the header
     fn render_header(&self, f: &mut Frame, area: Rect) {
         let project_name = self.get_current_project_name();
-        let context_display = format!(
-            "Current Project: {}  Current View: {}",
+        let mut context_display = format!(
+            "Current Project: {}  Current View: {}  Sort: {}",
             project_name,
-            self.navigation_context.get_display_name()
+            self.navigation_context.get_display_name(),
+            format_sort_key(self.card_sort)
         );
+        if let Some(root) = self.focus {
+            context_display.push_str(&format!("  Focus: {root}"));
+        }
+        // Borders plus the "WORKFLOW MANAGEMENT  " label; long project or
+        // view titles end in an ellipsis instead of running off the line.
+        let room = (area.width as usize).saturating_sub(2 + 21);
+        let context_display = truncate(&context_display, room);
 
         let header_text = vec![Line::from(vec![
             Span::styled(
@@ -733,10 +1117,10 @@ impl WorkflowApp {
             .constraints(constraints)
             .split(area);
 
-        let column_titles = Self::get_column_titles();
+        let column_titles: Vec<String> = self.board.iter().map(|c| c.title.clone()).collect();
 
         for (i, &column_area) in columns_layout.iter().enumerate() {
-            self.render_column(f, column_area, i, column_titles[i]);
+            self.render_column(f, column_area, i, &column_titles[i]);
         }
     }
 
@@ -863,42 +1247,51 @@ impl WorkflowApp {
                 .bg(hierarchy_color)
                 .fg(Color::Black)
                 .add_modifier(Modifier::BOLD)
-        } else {
+        } else if task.status.is_closed() {
             Style::default().bg(Color::DarkGray)
+        } else {
+            // Open work shades from grey to amber to red as it goes
+            // without an update; the selection highlight above wins.
+            let age = ageing(
+                task.updated_at_utc,
+                chrono::Utc::now().timestamp(),
+                &self.ageing,
+            );
+            Style::default().bg(ageing_color(age))
         };
 
         // All cards now show full title wrapped across multiple lines
         let mut card_text = vec![];
 
-        // Show ID on first line
-        card_text.push(Line::from(format!("#{}", task.id)));
-
-        // Manually wrap the title to fit in the available width (accounting for borders)
+        // Show ID on first line, followed by as many tag chips as fit.
         let available_width = area.width.saturating_sub(2) as usize;
-
-        // Simple word wrapping
-        let mut current_line = String::new();
-        let mut lines = Vec::new();
-
-        for word in task.title.split_whitespace() {
-            if current_line.is_empty() {
-                current_line = word.to_string();
-            } else if current_line.len() + 1 + word.len() <= available_width {
-                current_line.push(' ');
-                current_line.push_str(word);
-            } else {
-                lines.push(current_line.clone());
-                current_line = word.to_string();
-                if lines.len() >= 2 {
-                    break; // Maximum 2 lines of title
+        let id_text = format!("#{}", id_label(task.id, self.short_ids));
+        let mut used = id_text.chars().count();
+        let mut id_line = vec![Span::raw(id_text)];
+        let chip_colors = colors_enabled();
+        let (tags_text, chips) = tag_suffix(&task.tags, chip_colors);
+        if chip_colors {
+            for (start, end, color) in chips {
+                let chip = &tags_text[start..end];
+                let width = 1 + chip.chars().count();
+                if used + width > available_width {
+                    break;
                 }
+                id_line.push(Span::raw(" "));
+                id_line.push(Span::styled(
+                    chip.to_string(),
+                    Style::default().fg(Color::Black).bg(color),
+                ));
+                used += width;
             }
+        } else if !tags_text.is_empty() && used + 1 < available_width {
+            id_line.push(Span::raw(truncate(&tags_text, available_width - used)));
         }
-        if !current_line.is_empty() && lines.len() < 2 {
-            lines.push(current_line);
-        }
+        card_text.push(Line::from(id_line));
 
-        for line in lines {
+        // Wrap the title to the card's inner width, at most two lines; a
+        // longer title ends in an ellipsis.
+        for line in wrap_with_ellipsis(&task.title, available_width, 2) {
             card_text.push(Line::from(line));
         }
 
@@ -930,12 +1323,15 @@ impl WorkflowApp {
         } else {
             let total_tasks: usize = self.columns.iter().map(|col| col.len()).sum();
             let completed_indicator = if self.show_completed { " [+Done]" } else { "" };
-            let filter_indicator = if !self.filter_text.is_empty() {
+            let mut filter_indicator = if !self.filter_text.is_empty() {
                 format!(" [Filter: {}]", self.filter_text)
             } else {
                 String::new()
             };
-            format!("Tasks: {}{}{} | /: Filter | c: Complete | t: Toggle done | d/u: Drill | m: Menu | h: Help", 
+            if let Some(name) = &self.iteration {
+                filter_indicator.push_str(&format!(" [Iteration: {name}]"));
+            }
+            format!("Tasks: {}{}{} | /: Filter | c: Complete | t: Toggle done | d/u: Drill | l: List | m: Menu | h: Help", 
                 total_tasks, completed_indicator, filter_indicator)
         };
 
@@ -953,7 +1349,7 @@ impl WorkflowApp {
     }
 
     /// Render the task detail popup
-    fn render_task_detail_popup(&self, f: &mut Frame) {
+    fn render_task_detail_popup(&mut self, f: &mut Frame) {
         if self.columns[self.selected_column].is_empty() {
             return;
         }
@@ -975,18 +1371,20 @@ impl WorkflowApp {
 
             // Create task detail content
             use crate::db::{
-                format_due_relative, format_kind, format_priority, format_process_stage,
-                format_urgency,
+                format_due_detail, format_kind, format_priority, format_stage_progress,
+                format_timestamp_with_age, format_urgency,
             };
-            use chrono::Local;
+            use chrono::{Local, Utc};
 
             let today = Local::now().date_naive();
-            let due_str = format_due_relative(task.due, today);
+            let now = Utc::now().timestamp();
+            let due_str = format_due_detail(task.due, task.remind_days_before, today);
             let parent_str = if let Some(parent_id) = task.parent {
+                let label = id_label(parent_id, self.short_ids);
                 if let Some(parent_task) = self.db.get(parent_id) {
-                    format!("{} ({})", parent_id, parent_task.title)
+                    format!("{} ({})", label, parent_task.title)
                 } else {
-                    parent_id.to_string()
+                    label
                 }
             } else {
                 "-".to_string()
@@ -994,7 +1392,7 @@ impl WorkflowApp {
 
             let mut detail_lines = vec![
                 Line::from(vec![Span::styled(
-                    format!("Task {}: {}", task.id, task.title),
+                    format!("Task {}: {}", id_label(task.id, self.short_ids), task.title),
                     Style::default().add_modifier(Modifier::BOLD),
                 )]),
                 Line::from(""),
@@ -1007,7 +1405,7 @@ impl WorkflowApp {
                 Line::from(format!("Urgency:      {}", format_urgency(task.urgency))),
                 Line::from(format!(
                     "Process Stage: {}",
-                    format_process_stage(task.process_stage)
+                    format_stage_progress(task.process_stage)
                 )),
                 Line::from(format!("Due:          {}", due_str)),
                 Line::from(format!("Parent:       {}", parent_str)),
@@ -1020,6 +1418,14 @@ impl WorkflowApp {
                         task.tags.join(", ")
                     }
                 )),
+                Line::from(format!(
+                    "Created:      {}",
+                    format_timestamp_with_age(task.created_at_utc, now)
+                )),
+                Line::from(format!(
+                    "Updated:      {}",
+                    format_timestamp_with_age(task.updated_at_utc, now)
+                )),
                 Line::from(""),
                 Line::from("Description:"),
                 Line::from(task.description.as_deref().unwrap_or("-")),
@@ -1035,10 +1441,26 @@ impl WorkflowApp {
                 }
             }
 
+            // Measure wrapped height so long descriptions can scroll instead
+            // of spilling past the popup's 80% bounds.
+            let inner_width = popup_area.width.saturating_sub(2).max(1) as usize;
+            let inner_height = popup_area.height.saturating_sub(2) as usize;
+            let content_rows: usize = detail_lines
+                .iter()
+                .map(|l| l.width().div_ceil(inner_width).max(1))
+                .sum();
+            let max_scroll = content_rows.saturating_sub(inner_height) as u16;
+            let scroll = self.detail_scroll.min(max_scroll);
+            let title = if max_scroll > 0 {
+                "Task Details (Up/Down scroll, c complete/reopen, Enter to close)"
+            } else {
+                "Task Details (c complete/reopen, Enter to close)"
+            };
+
             let hierarchy_color = self.get_hierarchy_color();
             let popup_block = Block::default()
                 .borders(Borders::ALL)
-                .title("Task Details (Press Enter to close)")
+                .title(title)
                 .title_alignment(Alignment::Center)
                 .border_style(
                     Style::default()
@@ -1049,9 +1471,12 @@ impl WorkflowApp {
             let popup_paragraph = Paragraph::new(detail_lines)
                 .block(popup_block)
                 .wrap(Wrap { trim: true })
+                .scroll((scroll, 0))
                 .style(Style::default().bg(Color::Black));
 
             f.render_widget(popup_paragraph, popup_area);
+            self.detail_max_scroll = max_scroll;
+            self.detail_scroll = scroll;
         }
     }
 
@@ -1059,6 +1484,11 @@ impl WorkflowApp {
     pub fn get_exit_action(&self) -> WorkflowExit {
         if let Some(task_id) = self.edit_task_id {
             WorkflowExit::EditTask(task_id)
+        } else if self.open_list {
+            WorkflowExit::ListView(ViewHandoff {
+                context: self.navigation_context.clone(),
+                stack: self.navigation_stack.clone(),
+            })
         } else {
             WorkflowExit::Quit
         }
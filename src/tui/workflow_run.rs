@@ -9,11 +9,22 @@ use crossterm::{
 };
 use ratatui::{prelude::CrosstermBackend, Terminal};
 
+use crate::db::BoardColumn;
+use crate::store::Config;
+use crate::tui::enums::ViewHandoff;
 use crate::tui::workflow::{WorkflowApp, WorkflowExit};
 
 /// Initialise and run the workflow terminal user interface.
-/// Returns the exit action requested by the user.
-pub fn run_workflow_tui(db_path: &Path) -> io::Result<WorkflowExit> {
+/// Returns the exit action requested by the user. `compact` hides the header
+/// banner, as does `ui.compact` in the workspace config. `board` replaces
+/// the configured columns (`pm wf --stage-columns`). `start` opens the
+/// board at a position handed over by the task list.
+pub fn run_workflow_tui(
+    db_path: &Path,
+    compact: bool,
+    board: Option<&[BoardColumn]>,
+    start: Option<ViewHandoff>,
+) -> io::Result<WorkflowExit> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
@@ -21,6 +32,13 @@ pub fn run_workflow_tui(db_path: &Path) -> io::Result<WorkflowExit> {
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = WorkflowApp::new(db_path)?;
+    app.set_compact(compact || Config::load(db_path).ui.compact);
+    if let Some(board) = board {
+        app.set_stage_columns(board.to_vec());
+    }
+    if let Some(handoff) = start {
+        app.set_navigation(handoff);
+    }
     let result = app.run(&mut terminal);
     let exit_action = app.get_exit_action();
 
@@ -0,0 +1,40 @@
+//! Inline markers accepted by `pm add --stdin`: `#tag` words become tags,
+//! a `!priority` word sets the priority, and everything else is the title.
+
+use project_management::db::{parse_add_line, AddLine};
+use project_management::fields::Priority;
+
+#[test]
+fn plain_lines_become_titles() {
+    assert_eq!(
+        parse_add_line("  Write the release notes  "),
+        Some(AddLine {
+            title: "Write the release notes".to_string(),
+            tags: Vec::new(),
+            priority: None,
+        })
+    );
+}
+
+#[test]
+fn markers_are_stripped_from_the_title() {
+    let line = parse_add_line("Fix login #Auth #bug !must-have timeout").unwrap();
+    assert_eq!(line.title, "Fix login timeout");
+    assert_eq!(line.tags, vec!["auth".to_string(), "bug".to_string()]);
+    assert_eq!(line.priority, Some(Priority::MustHave));
+}
+
+#[test]
+fn lookalike_markers_stay_in_the_title() {
+    let line = parse_add_line("Ship it ! # now !urgent").unwrap();
+    assert_eq!(line.title, "Ship it ! # now !urgent");
+    assert!(line.tags.is_empty());
+    assert_eq!(line.priority, None);
+}
+
+#[test]
+fn blank_or_marker_only_lines_are_skipped() {
+    assert_eq!(parse_add_line(""), None);
+    assert_eq!(parse_add_line("   \t"), None);
+    assert_eq!(parse_add_line("#tag !cut-first"), None);
+}
@@ -0,0 +1,120 @@
+//! `pm add --print-id`: stdout carries only the new ids, one per line, so a
+//! script can capture them; confirmations go to stderr, or nowhere with
+//! `--quiet`.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-add-print-id-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm_with_stdin(pm_dir: &Path, args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("invoke pm binary");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let out = child.wait_with_output().unwrap();
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    out
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> Output {
+    pm_with_stdin(pm_dir, args, "")
+}
+
+fn stdout(out: &Output) -> String {
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn stderr(out: &Output) -> String {
+    String::from_utf8_lossy(&out.stderr).into_owned()
+}
+
+#[test]
+fn print_id_prints_the_bare_id_and_confirms_on_stderr() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "First"]);
+
+    let out = pm(&pm_dir, &["add", "Second", "--print-id"]);
+    assert_eq!(stdout(&out), "TSK2\n");
+    assert!(stderr(&out).contains("Added task TSK2"), "{}", stderr(&out));
+
+    let quiet = pm(&pm_dir, &["add", "Third", "--print-id", "--quiet"]);
+    assert_eq!(stdout(&quiet), "TSK3\n");
+    assert_eq!(stderr(&quiet), "");
+
+    // The printed id feeds straight into the next command.
+    let id = stdout(&quiet).trim().to_string();
+    pm(&pm_dir, &["update", &id, "--status", "in-progress"]);
+    let view = stdout(&pm(&pm_dir, &["view", &id]));
+    assert!(view.contains("Third"), "{view}");
+}
+
+#[test]
+fn print_id_with_stdin_prints_one_id_per_line() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+
+    let out = pm_with_stdin(
+        &pm_dir,
+        &["add", "--stdin", "--print-id"],
+        "Alpha\n\nBravo #ops\nCharlie\n",
+    );
+    assert_eq!(stdout(&out), "TSK1\nTSK2\nTSK3\n");
+    assert!(stderr(&out).contains("Added 3 tasks"), "{}", stderr(&out));
+}
+
+#[test]
+fn print_id_composes_with_template() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(
+        &pm_dir,
+        &[
+            "template",
+            "create",
+            "bug",
+            "--kind",
+            "task",
+            "--title-template",
+            "[BUG] {title}",
+        ],
+    );
+
+    let out = pm(
+        &pm_dir,
+        &["add", "crash", "--template", "bug", "--print-id", "-q"],
+    );
+    assert_eq!(stdout(&out), "TSK1\n");
+    let view = stdout(&pm(&pm_dir, &["view", "TSK1"]));
+    assert!(view.contains("[BUG] crash"), "{view}");
+}
@@ -0,0 +1,129 @@
+//! Parent-chain walks: `Database::depth_of` / `ancestor_ids` report a real
+//! depth for a well-formed hierarchy and a `CycleDetected` error for a parent
+//! loop (as a hand-edited CLAUDE.md can introduce), instead of spinning or
+//! silently truncating.
+
+use project_management::db::{collect_ancestors, Database};
+use project_management::fields::Kind;
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+use project_management::task::Task;
+
+mod common;
+
+fn task(id: LeafId, parent: Option<LeafId>, kind: Kind) -> Task {
+    Task {
+        parent,
+        kind,
+        ..common::task(id, &id.to_string())
+    }
+}
+
+#[test]
+fn depth_counts_hops_to_the_root() {
+    let epc = LeafId::new(TypePrefix::Epic, 1);
+    let tsk = LeafId::new(TypePrefix::Task, 1);
+    let sbt = LeafId::new(TypePrefix::Subtask, 1);
+    let db = Database {
+        tasks: vec![
+            task(epc, None, Kind::Epic),
+            task(tsk, Some(epc), Kind::Task),
+            task(sbt, Some(tsk), Kind::Subtask),
+        ],
+        state: Default::default(),
+    };
+    assert_eq!(db.depth_of(epc), Ok(0));
+    assert_eq!(db.depth_of(sbt), Ok(2));
+    assert_eq!(db.ancestor_ids(sbt), Ok(vec![tsk, epc]));
+}
+
+#[test]
+fn parent_loop_is_reported_as_a_cycle() {
+    let a = LeafId::new(TypePrefix::Subtask, 1);
+    let b = LeafId::new(TypePrefix::Subtask, 2);
+    let c = LeafId::new(TypePrefix::Subtask, 3);
+    let db = Database {
+        tasks: vec![
+            task(a, Some(b), Kind::Subtask),
+            task(b, Some(a), Kind::Subtask),
+            task(c, Some(a), Kind::Subtask),
+        ],
+        state: Default::default(),
+    };
+    let err = db.depth_of(c).unwrap_err();
+    assert!(err.id == a || err.id == b);
+    assert!(err.to_string().contains("pm doctor"));
+    assert!(db.depth_of(a).is_err());
+    // The lenient walker stops rather than looping forever.
+    assert_eq!(collect_ancestors(c, &db), vec![a, b]);
+}
+
+#[test]
+fn breadcrumb_lists_ancestor_titles_root_first() {
+    let epc = LeafId::new(TypePrefix::Epic, 1);
+    let tsk = LeafId::new(TypePrefix::Task, 1);
+    let sbt = LeafId::new(TypePrefix::Subtask, 1);
+    let mut e = task(epc, None, Kind::Epic);
+    e.title = "E-commerce".into();
+    let mut t = task(tsk, Some(epc), Kind::Task);
+    t.title = "User Mgmt".into();
+    let db = Database {
+        tasks: vec![e, t, task(sbt, Some(tsk), Kind::Subtask)],
+        state: Default::default(),
+    };
+    assert_eq!(db.breadcrumb(sbt, 80), "E-commerce > User Mgmt");
+    assert_eq!(db.breadcrumb(epc, 80), "-");
+}
+
+#[test]
+fn breadcrumb_under_uses_the_given_parent() {
+    let epc = LeafId::new(TypePrefix::Epic, 1);
+    let tsk = LeafId::new(TypePrefix::Task, 1);
+    let mut e = task(epc, None, Kind::Epic);
+    e.title = "E-commerce".into();
+    let db = Database {
+        tasks: vec![e, task(tsk, None, Kind::Task)],
+        state: Default::default(),
+    };
+    // A saved ticket being moved, and one that is not saved at all.
+    assert_eq!(db.breadcrumb_under(tsk, Some(epc), 80), "E-commerce");
+    let unsaved = LeafId::new(TypePrefix::Task, 2);
+    assert_eq!(db.breadcrumb_under(unsaved, Some(epc), 80), "E-commerce");
+    assert_eq!(db.breadcrumb_under(tsk, None, 80), "-");
+}
+
+#[test]
+fn long_breadcrumbs_collapse_the_middle_first() {
+    let ids: Vec<LeafId> = (1..=5)
+        .map(|n| LeafId::new(TypePrefix::Subtask, n))
+        .collect();
+    let tasks = ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let mut t = task(*id, i.checked_sub(1).map(|p| ids[p]), Kind::Subtask);
+            t.title = format!("level-{i}");
+            t
+        })
+        .collect();
+    let db = Database {
+        tasks,
+        state: Default::default(),
+    };
+    assert_eq!(db.breadcrumb(ids[4], 30), "level-0 > … > level-3");
+    assert!(db.breadcrumb(ids[4], 10).chars().count() <= 10);
+}
+
+#[test]
+fn breadcrumb_marks_cycles() {
+    let a = LeafId::new(TypePrefix::Subtask, 1);
+    let b = LeafId::new(TypePrefix::Subtask, 2);
+    let db = Database {
+        tasks: vec![
+            task(a, Some(b), Kind::Subtask),
+            task(b, Some(a), Kind::Subtask),
+        ],
+        state: Default::default(),
+    };
+    assert!(db.breadcrumb(a, 80).starts_with("(cycle) > "));
+}
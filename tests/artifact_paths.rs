@@ -0,0 +1,80 @@
+//! `pm view --artifacts`: artifact paths resolved against the ticket's
+//! `artifacts/` directory, with missing files flagged.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use project_management::db::resolve_artifact_path;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-artifact-paths-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn file_names_resolve_from_the_artifacts_dir() {
+    let dir = Path::new("/work/repo/.pm/tasks/TSK1/artifacts");
+    assert_eq!(
+        resolve_artifact_path(dir, "/tmp/spec.pdf"),
+        Path::new("/tmp/spec.pdf")
+    );
+    assert_eq!(resolve_artifact_path(dir, "spec.md"), dir.join("spec.md"));
+}
+
+#[test]
+fn view_lists_absolute_paths_and_flags_missing_files() {
+    let pm_dir = tmp_pm_dir();
+    let root = pm_dir.parent().unwrap().to_path_buf();
+    fs::write(root.join("spec.md"), "spec").unwrap();
+    fs::write(root.join("gone.png"), "png").unwrap();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Write spec"]);
+    pm(&pm_dir, &["add", "Bare"]);
+    pm(&pm_dir, &["artifact", "add", "TSK1", "spec.md"]);
+    pm(&pm_dir, &["artifact", "add", "TSK1", "gone.png"]);
+
+    let out = pm(&pm_dir, &["view", "TSK1", "--artifacts"]);
+    let spec = out
+        .lines()
+        .find(|l| l.ends_with("spec.md"))
+        .unwrap_or_else(|| panic!("{out}"))
+        .trim();
+    assert!(Path::new(spec).is_absolute(), "{out}");
+    fs::remove_file(Path::new(spec).with_file_name("gone.png")).unwrap();
+
+    let out = pm(&pm_dir, &["view", "TSK1", "--artifacts"]);
+    assert!(out.contains(&format!("  {spec}\n")), "{out}");
+    assert!(out.contains("gone.png (missing)\n"), "{out}");
+    assert!(pm(&pm_dir, &["view", "TSK2", "--artifacts"]).ends_with("Artifacts:\n  -\n"));
+    assert!(!pm(&pm_dir, &["view", "TSK1"]).contains("Artifacts:"));
+    fs::remove_dir_all(root).ok();
+}
@@ -0,0 +1,84 @@
+//! Automatic snapshots before destructive bulk commands, and the global
+//! `--no-backup` opt-out.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-backups-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn snapshots(pm_dir: &Path, prefix: &str) -> usize {
+    fs::read_dir(pm_dir.join("snapshots"))
+        .map(|dir| {
+            dir.flatten()
+                .filter(|e| e.file_name().to_string_lossy().starts_with(prefix))
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+#[test]
+fn bulk_deletes_back_up_and_single_deletes_do_not() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Keep"]);
+    pm(&pm_dir, &["add", "Stale one", "--tag", "stale"]);
+    pm(&pm_dir, &["add", "Stale two", "--tag", "stale"]);
+
+    pm(&pm_dir, &["delete", "TSK1"]);
+    assert_eq!(snapshots(&pm_dir, "pre-delete-"), 0);
+
+    let out = pm(&pm_dir, &["delete", "--tag", "stale"]);
+    assert!(out.contains("Created backup:"), "{out}");
+    assert_eq!(snapshots(&pm_dir, "pre-delete-"), 1);
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn no_backup_is_accepted_anywhere_on_the_command_line() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Stale one", "--tag", "stale"]);
+    pm(&pm_dir, &["add", "Stale two", "--tag", "stale"]);
+    pm(&pm_dir, &["snapshot", "before"]);
+
+    pm(&pm_dir, &["--no-backup", "delete", "--tag", "stale"]);
+    pm(&pm_dir, &["restore", "before", "--no-backup"]);
+    assert_eq!(snapshots(&pm_dir, "pre-"), 0);
+    assert!(pm(&pm_dir, &["view", "TSK2"]).contains("Stale two"));
+
+    pm(&pm_dir, &["restore", "before"]);
+    assert_eq!(snapshots(&pm_dir, "pre-restore-"), 1);
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,53 @@
+//! `Database::open_blockers`: the dependencies that keep a task blocked in
+//! the TUI list and detail view are the ones not Done yet.
+
+use project_management::db::Database;
+use project_management::fields::Status;
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+use project_management::task::Task;
+
+mod common;
+
+fn task(n: u64, status: Status, deps: &[u64]) -> Task {
+    Task {
+        deps: deps
+            .iter()
+            .map(|&d| LeafId::new(TypePrefix::Task, d))
+            .collect(),
+        status,
+        ..common::task(LeafId::new(TypePrefix::Task, n), &format!("t{n}"))
+    }
+}
+
+fn blocker_ids(db: &Database, n: u64) -> Vec<String> {
+    let t = db.get(LeafId::new(TypePrefix::Task, n)).unwrap();
+    db.open_blockers(t)
+        .iter()
+        .map(|b| b.id.to_string())
+        .collect()
+}
+
+#[test]
+fn unfinished_dependencies_block_in_deps_order() {
+    let db = Database {
+        tasks: vec![
+            task(1, Status::Done, &[]),
+            task(2, Status::InProgress, &[]),
+            task(3, Status::Cancelled, &[]),
+            task(4, Status::Open, &[3, 1, 2]),
+        ],
+        ..Default::default()
+    };
+    assert_eq!(blocker_ids(&db, 4), ["TSK3", "TSK2"]);
+}
+
+#[test]
+fn done_or_missing_dependencies_block_nothing() {
+    let db = Database {
+        tasks: vec![task(1, Status::Done, &[]), task(2, Status::Open, &[1, 99])],
+        ..Default::default()
+    };
+    assert!(blocker_ids(&db, 2).is_empty());
+    assert!(blocker_ids(&db, 1).is_empty());
+}
@@ -0,0 +1,86 @@
+//! `pm delete --tag/--project/--status`: each matching task goes with its
+//! subtree, the preview counts every task removed, and sweeps of more than
+//! ten tasks need `--yes` when there is no terminal to confirm on.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-bulk-delete-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .stdin(Stdio::null())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn status_sweep_takes_descendants_of_matched_tasks() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "--kind", "epic", "Old epic"]);
+    pm(&pm_dir, &["add", "Done child", "--parent", "EPC1"]);
+    pm(&pm_dir, &["add", "Unrelated"]);
+    pm(&pm_dir, &["complete", "TSK1"]);
+    pm(&pm_dir, &["complete", "TSK2"]);
+
+    // Only the epic is open, but its done child must not be orphaned.
+    let out = pm(&pm_dir, &["delete", "--status", "open"]);
+    assert!(
+        out.contains("Will delete 2 task(s): 1 matched and 1 descendant(s) of them:"),
+        "{out}"
+    );
+    assert!(out.contains("TSK1 - Done child (descendant)"), "{out}");
+
+    let left = pm(&pm_dir, &["list", "--all"]);
+    assert!(!left.contains("Old epic"), "{left}");
+    assert!(!left.contains("Done child"), "{left}");
+    assert!(left.contains("Unrelated"), "{left}");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn large_sweeps_need_yes_without_a_terminal() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    for n in 1..=11 {
+        pm(&pm_dir, &["add", &format!("Stale {n}"), "--tag", "stale"]);
+    }
+
+    let refused = run(&pm_dir, &["delete", "--tag", "stale"]);
+    assert!(!refused.status.success());
+    let err = String::from_utf8_lossy(&refused.stderr);
+    assert!(err.contains("pass --yes"), "{err}");
+    assert!(pm(&pm_dir, &["list"]).contains("Stale 11"));
+
+    let out = pm(&pm_dir, &["delete", "--tag", "stale", "--yes"]);
+    assert!(out.contains("Will delete 11 task(s):"), "{out}");
+    assert!(!pm(&pm_dir, &["list"]).contains("Stale"));
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,143 @@
+//! Effort estimates: parsing, subtree rollup, and the end-of-day burndown
+//! series behind `pm burndown`.
+
+use std::process::Command;
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use project_management::db::{
+    burndown, estimate_rollup, format_estimate, parse_estimate, Database,
+};
+use project_management::fields::Status;
+use project_management::store::id::TypePrefix;
+use project_management::store::{LeafId, State};
+use project_management::task::Task;
+
+mod common;
+
+fn at(day: u32, hour: u32) -> i64 {
+    Utc.with_ymd_and_hms(2024, 5, day, hour, 0, 0)
+        .unwrap()
+        .timestamp()
+}
+
+fn task(n: u64, parent: Option<u64>, estimate: Option<u32>, status: Status) -> Task {
+    Task {
+        parent: parent.map(|p| LeafId::new(TypePrefix::Task, p)),
+        estimate_minutes: estimate,
+        status,
+        created_at_utc: at(1, 9),
+        updated_at_utc: at(1, 9),
+        ..common::task(LeafId::new(TypePrefix::Task, n), &format!("task {n}"))
+    }
+}
+
+fn day(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 5, d).unwrap()
+}
+
+#[test]
+fn estimates_parse_as_minutes_or_hours_and_minutes() {
+    assert_eq!(parse_estimate("90"), Some(90));
+    assert_eq!(parse_estimate("2h"), Some(120));
+    assert_eq!(parse_estimate("1h30m"), Some(90));
+    assert_eq!(parse_estimate("1h 30m"), Some(90));
+    assert_eq!(parse_estimate("45M"), Some(45));
+    assert_eq!(parse_estimate("0"), None);
+    assert_eq!(parse_estimate("soon"), None);
+    assert_eq!(parse_estimate("1d"), None);
+    assert_eq!(format_estimate(45), "45m");
+    assert_eq!(format_estimate(120), "2h");
+    assert_eq!(format_estimate(90), "1h 30m");
+}
+
+#[test]
+fn rollup_totals_the_subtree_and_what_is_still_open() {
+    let db = Database {
+        tasks: vec![
+            task(1, None, Some(60), Status::Open),
+            task(2, Some(1), Some(120), Status::Done),
+            task(3, Some(1), None, Status::Open),
+            task(4, Some(3), Some(30), Status::InProgress),
+            task(5, None, Some(500), Status::Open),
+        ],
+        state: State::fresh(),
+    };
+    assert_eq!(
+        estimate_rollup(&db, LeafId::new(TypePrefix::Task, 1)),
+        (210, 90)
+    );
+    assert_eq!(
+        estimate_rollup(&db, LeafId::new(TypePrefix::Task, 3)),
+        (30, 30)
+    );
+}
+
+#[test]
+fn burndown_snapshots_remaining_work_at_end_of_day() {
+    let mut done_day_two = task(1, None, Some(60), Status::Done);
+    done_day_two.completed_at_utc = Some(at(2, 15));
+    let open = task(2, None, Some(30), Status::Open);
+    let mut added_day_three = task(3, None, Some(45), Status::Open);
+    added_day_three.created_at_utc = at(3, 10);
+    // Added and finished on the same day: never part of a snapshot.
+    let mut same_day = task(4, None, Some(15), Status::Done);
+    same_day.created_at_utc = at(2, 9);
+    same_day.completed_at_utc = Some(at(2, 17));
+    let unestimated = task(5, None, None, Status::Open);
+    let cancelled = task(6, None, Some(240), Status::Cancelled);
+    let tasks = [
+        done_day_two,
+        open,
+        added_day_three,
+        same_day,
+        unestimated,
+        cancelled,
+    ];
+    let refs: Vec<&Task> = tasks.iter().collect();
+
+    let remaining: Vec<(NaiveDate, u32)> = burndown(&refs, day(1), day(4), &Utc)
+        .into_iter()
+        .map(|p| (p.date, p.remaining_minutes))
+        .collect();
+    assert_eq!(
+        remaining,
+        vec![(day(1), 90), (day(2), 30), (day(3), 75), (day(4), 75)]
+    );
+}
+
+#[test]
+fn done_tasks_without_a_completion_time_use_their_last_update() {
+    let mut legacy = task(1, None, Some(60), Status::Done);
+    legacy.updated_at_utc = at(3, 12);
+    let refs = [&legacy];
+    let remaining: Vec<u32> = burndown(&refs, day(2), day(3), &Utc)
+        .into_iter()
+        .map(|p| p.remaining_minutes)
+        .collect();
+    assert_eq!(remaining, vec![60, 0]);
+}
+
+#[test]
+fn burndown_offers_only_its_own_formats() {
+    let run = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_pm"))
+            .args(args)
+            .output()
+            .expect("invoke pm binary")
+    };
+    let help = run(&["burndown", "--help"]);
+    let help = String::from_utf8_lossy(&help.stdout);
+    assert!(
+        help.contains("- csv:") && help.contains("- json:"),
+        "{help}"
+    );
+    assert!(!help.contains("taskwarrior"), "{help}");
+
+    let out = run(&["burndown", "--format", "taskwarrior"]);
+    assert_eq!(out.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("invalid value 'taskwarrior'"), "{stderr}");
+
+    let export = run(&["export", "--help"]);
+    assert!(String::from_utf8_lossy(&export.stdout).contains("taskwarrior"));
+}
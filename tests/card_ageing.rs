@@ -0,0 +1,30 @@
+//! Workflow card ageing buckets behind `ui.ageing`.
+
+use project_management::db::{ageing, Ageing};
+use project_management::store::AgeingConfig;
+
+const DAY: i64 = 86_400;
+
+#[test]
+fn default_thresholds_are_a_week_and_a_month() {
+    let cfg = AgeingConfig::default();
+    let now = 100 * DAY;
+    assert_eq!(ageing(now, now, &cfg), Ageing::Fresh);
+    assert_eq!(ageing(now - 6 * DAY, now, &cfg), Ageing::Fresh);
+    assert_eq!(ageing(now - 7 * DAY, now, &cfg), Ageing::Stale);
+    assert_eq!(ageing(now - 29 * DAY, now, &cfg), Ageing::Stale);
+    assert_eq!(ageing(now - 30 * DAY, now, &cfg), Ageing::Neglected);
+}
+
+#[test]
+fn custom_thresholds_and_future_timestamps() {
+    let cfg = AgeingConfig {
+        amber_days: 1,
+        red_days: 3,
+    };
+    let now = 100 * DAY;
+    assert_eq!(ageing(now - DAY, now, &cfg), Ageing::Stale);
+    assert_eq!(ageing(now - 3 * DAY, now, &cfg), Ageing::Neglected);
+    // A clock skewed into the future reads as fresh, not negative age.
+    assert_eq!(ageing(now + DAY, now, &cfg), Ageing::Fresh);
+}
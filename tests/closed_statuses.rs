@@ -0,0 +1,88 @@
+//! `Blocked` and `Cancelled`: parsing, persistence, and `pm list` treating
+//! Cancelled like Done.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use project_management::db::{format_status, parse_status};
+use project_management::fields::Status;
+
+#[test]
+fn closed_statuses_are_done_and_cancelled() {
+    assert!(Status::Done.is_closed());
+    assert!(Status::Cancelled.is_closed());
+    for open in [Status::Open, Status::InProgress, Status::Blocked] {
+        assert!(!open.is_closed(), "{open:?}");
+    }
+}
+
+#[test]
+fn new_statuses_parse_and_format() {
+    assert_eq!(parse_status("blocked"), Status::Blocked);
+    assert_eq!(parse_status("Cancelled"), Status::Cancelled);
+    assert_eq!(parse_status("canceled"), Status::Cancelled);
+    assert_eq!(format_status(Status::Blocked), "Blocked");
+    assert_eq!(format_status(Status::Cancelled), "Cancelled");
+}
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-closed-statuses-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn cancelled_tasks_are_hidden_and_blocked_tasks_listed() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "abandoned idea"]);
+    pm(
+        &pm_dir,
+        &["add", "waiting on vendor", "--status", "blocked"],
+    );
+    pm(&pm_dir, &["set-status", "TSK1", "cancelled"]);
+
+    let default = pm(&pm_dir, &["list"]);
+    assert!(!default.contains("abandoned idea"), "{default}");
+    assert!(default.contains("waiting on vendor") && default.contains("Blocked"));
+
+    let all = pm(&pm_dir, &["list", "--all"]);
+    assert!(
+        all.contains("abandoned idea") && all.contains("Cancelled"),
+        "{all}"
+    );
+    let only = pm(&pm_dir, &["list", "--status", "cancelled"]);
+    assert!(
+        only.contains("abandoned idea") && !only.contains("waiting"),
+        "{only}"
+    );
+
+    // The status survives a reload from CLAUDE.md front-matter.
+    assert!(pm(&pm_dir, &["view", "TSK1"]).contains("Cancelled"));
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,42 @@
+//! Fixtures shared by the integration tests.
+
+use project_management::db::prefix_to_kind;
+use project_management::fields::Status;
+use project_management::store::LeafId;
+use project_management::task::Task;
+
+/// An open task with only an id and a title, its kind taken from the id's
+/// prefix and every other field empty. Override fields with struct update
+/// syntax: `Task { parent, ..task(id, "Title") }`.
+pub fn task(id: LeafId, title: &str) -> Task {
+    Task {
+        id,
+        title: title.to_string(),
+        summary: None,
+        description: None,
+        user_story: None,
+        requirements: None,
+        tags: Vec::new(),
+        deps: Vec::new(),
+        milestone: None,
+        memories: Vec::new(),
+        due: None,
+        remind_days_before: None,
+        parent: None,
+        order: None,
+        iteration: None,
+        estimate_minutes: None,
+        completed_at_utc: None,
+        pinned: false,
+        kind: prefix_to_kind(id.prefix()),
+        status: Status::Open,
+        priority_level: None,
+        urgency: None,
+        process_stage: None,
+        issue_link: None,
+        pr_link: None,
+        artifacts: Vec::new(),
+        created_at_utc: 0,
+        updated_at_utc: 0,
+    }
+}
@@ -0,0 +1,78 @@
+//! `pm complete` reports the progress of the parents above what it closed.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-complete-progress-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn completion_reports_parent_progress() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "User Registration", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "Billing", "--kind", "epic"]);
+    for title in ["Form", "Emails", "Captcha"] {
+        pm(&pm_dir, &["add", title, "--parent", "EPC1"]);
+    }
+    pm(&pm_dir, &["add", "Invoices", "--parent", "EPC2", "--tag", "q3"]);
+    pm(&pm_dir, &["add", "Receipts", "--parent", "EPC2"]);
+    pm(&pm_dir, &["set-status", "TSK3", "cancelled"]);
+
+    // Cancelled siblings drop out of the count.
+    let out = pm(&pm_dir, &["complete", "TSK1"]);
+    assert!(
+        out.contains("Parent 'User Registration' now 1/2 done"),
+        "{out}"
+    );
+    let out = pm(&pm_dir, &["complete", "TSK2"]);
+    assert!(
+        out.contains("'User Registration' now 2/2 done - all children done"),
+        "{out}"
+    );
+
+    // A bulk completion lists each parent it touched.
+    pm(&pm_dir, &["tag", "TSK5", "+q3"]);
+    pm(&pm_dir, &["reopen", "TSK2"]);
+    pm(&pm_dir, &["tag", "TSK2", "+q3"]);
+    let out = pm(&pm_dir, &["complete", "--tag", "q3"]);
+    assert!(out.contains("Progress on 2 parents:"), "{out}");
+    assert!(out.contains("  EPC1 'User Registration' now 2/2 done"), "{out}");
+    assert!(out.contains("  EPC2 'Billing' now 2/2 done"), "{out}");
+
+    // Completing the parent itself reports nothing further up.
+    let out = pm(&pm_dir, &["complete", "EPC2"]);
+    assert!(!out.contains("Parent"), "{out}");
+
+    // Quiet drops the report with the confirmation.
+    pm(&pm_dir, &["reopen", "TSK1"]);
+    let out = pm(&pm_dir, &["-q", "complete", "TSK1"]);
+    assert!(out.is_empty(), "{out}");
+}
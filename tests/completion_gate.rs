@@ -0,0 +1,104 @@
+//! Definition-of-done gate: `complete.block_parent_completion_with_open_children`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use project_management::db::{completion_blocker, format_completion_blocker, Database};
+use project_management::fields::{Kind, Status};
+use project_management::store::id::TypePrefix;
+use project_management::store::{LeafId, State};
+use project_management::task::Task;
+
+mod common;
+
+fn task(id: LeafId, parent: Option<LeafId>, status: Status) -> Task {
+    Task {
+        parent,
+        kind: Kind::Task,
+        status,
+        ..common::task(id, &id.to_string())
+    }
+}
+
+#[test]
+fn blocker_ignores_done_descendants_and_those_completed_together() {
+    let epc = LeafId::new(TypePrefix::Epic, 1);
+    let open = LeafId::new(TypePrefix::Task, 1);
+    let done = LeafId::new(TypePrefix::Task, 2);
+    let grandchild = LeafId::new(TypePrefix::Subtask, 1);
+    let db = Database {
+        tasks: vec![
+            task(epc, None, Status::Open),
+            task(open, Some(epc), Status::InProgress),
+            task(done, Some(epc), Status::Done),
+            task(grandchild, Some(done), Status::Open),
+        ],
+        state: State::fresh(),
+    };
+
+    assert_eq!(
+        completion_blocker(&db, &[epc]),
+        Some((epc, vec![open, grandchild]))
+    );
+    assert_eq!(completion_blocker(&db, &[epc, open, grandchild]), None);
+    assert_eq!(completion_blocker(&db, &[grandchild]), None);
+    assert_eq!(
+        format_completion_blocker(epc, &[open]),
+        "EPC1 has 1 open descendant: TSK1"
+    );
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-completion-gate-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+#[test]
+fn pm_complete_refuses_open_children_only_when_configured() {
+    let pm_dir = tmp_pm_dir();
+    assert!(pm(&pm_dir, &["init"]).status.success());
+    assert!(pm(&pm_dir, &["add", "parent"]).status.success());
+    assert!(pm(
+        &pm_dir,
+        &["add", "--kind", "subtask", "child", "--parent", "TSK1"]
+    )
+    .status
+    .success());
+
+    fs::write(
+        pm_dir.join("config.json"),
+        r#"{ "complete": { "block_parent_completion_with_open_children": true } }"#,
+    )
+    .unwrap();
+    let refused = pm(&pm_dir, &["complete", "TSK1"]);
+    assert!(!refused.status.success());
+    let stderr = String::from_utf8_lossy(&refused.stderr);
+    assert!(
+        stderr.contains("TSK1 has 1 open descendant: SBT1") && stderr.contains("--recurse"),
+        "{stderr}"
+    );
+    assert!(pm(&pm_dir, &["complete", "TSK1", "--recurse"])
+        .status
+        .success());
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
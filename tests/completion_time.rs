@@ -0,0 +1,97 @@
+//! `pm complete --at`: parsing backdated completion times and refusing
+//! future ones unless `--force` is given.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use chrono::{Local, TimeZone};
+use project_management::db::parse_completion_time;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-completion-time-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn parses_dates_local_times_and_rfc3339() {
+    let midnight = Local
+        .with_ymd_and_hms(2025, 3, 1, 0, 0, 0)
+        .unwrap()
+        .timestamp();
+    assert_eq!(parse_completion_time("2025-03-01"), Some(midnight));
+    assert_eq!(
+        parse_completion_time("2025-03-01 14:30"),
+        Some(midnight + 14 * 3600 + 30 * 60)
+    );
+    assert_eq!(
+        parse_completion_time("2025-03-01T14:30:15"),
+        Some(midnight + 14 * 3600 + 30 * 60 + 15)
+    );
+    assert_eq!(
+        parse_completion_time("2025-03-01T12:00:00Z"),
+        Some(1_740_830_400)
+    );
+    assert!(parse_completion_time("yesterday").is_some());
+    assert_eq!(parse_completion_time("last tuesday"), None);
+}
+
+#[test]
+fn backdates_and_guards_future_completions() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "old work"]);
+    pm(&pm_dir, &["add", "future work"]);
+
+    pm(&pm_dir, &["complete", "TSK1", "--at", "2025-03-01 09:00"]);
+    let out = pm(
+        &pm_dir,
+        &["list", "--done-only", "--fields", "id,updated,title"],
+    );
+    assert!(out.contains("2025-03-01"), "{out}");
+
+    let refused = run(&pm_dir, &["complete", "TSK2", "--at", "2999-01-01"]);
+    assert!(!refused.status.success());
+    assert!(String::from_utf8_lossy(&refused.stderr).contains("--force"));
+    assert!(!pm(&pm_dir, &["list", "--done-only"]).contains("TSK2"));
+
+    let forced = run(
+        &pm_dir,
+        &["complete", "TSK2", "--at", "2999-01-01", "--force"],
+    );
+    assert!(forced.status.success());
+    assert!(String::from_utf8_lossy(&forced.stderr).contains("warning"));
+
+    let bad = run(&pm_dir, &["complete", "TSK1", "--at", "someday"]);
+    assert!(!bad.status.success());
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
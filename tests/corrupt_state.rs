@@ -0,0 +1,163 @@
+//! A corrupt `state.json` is recovered from the newest backup, never
+//! silently replaced by an empty index.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use project_management::db::Database;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-corrupt-state-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn list_ids(pm_dir: &Path) -> String {
+    pm(
+        pm_dir,
+        &[
+            "list",
+            "--format",
+            "plain",
+            "--no-header",
+            "--fields",
+            "id",
+            "--sort",
+            "id",
+        ],
+    )
+}
+
+/// Cut `state.json` in half, as an interrupted write would.
+fn truncate_state(pm_dir: &Path) {
+    let path = pm_dir.join("state.json");
+    let raw = fs::read_to_string(&path).unwrap();
+    fs::write(&path, &raw[..raw.len() / 2]).unwrap();
+}
+
+#[test]
+fn truncated_state_is_restored_from_the_latest_backup() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "First"]);
+    pm(&pm_dir, &["add", "Second"]);
+    pm(&pm_dir, &["snapshot", "tmp"]);
+    let backup = pm_dir.parent().unwrap().join("backup");
+    fs::create_dir_all(&backup).unwrap();
+    fs::rename(
+        pm_dir.join("snapshots").join("tmp.json"),
+        backup.join("2025-01-01_09-00-00_.pm"),
+    )
+    .unwrap();
+    pm(&pm_dir, &["add", "After the backup"]);
+    truncate_state(&pm_dir);
+
+    let out = run(
+        &pm_dir,
+        &[
+            "list",
+            "--format",
+            "plain",
+            "--no-header",
+            "--fields",
+            "id",
+            "--sort",
+            "id",
+        ],
+    );
+    assert!(out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("restoring the index from backup 2025-01-01_09-00-00_.pm"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("1 ticket(s) on disk are newer"), "{stderr}");
+    assert_eq!(String::from_utf8_lossy(&out.stdout), "TSK1\nTSK2\n");
+
+    // The bad file is kept, and the next load is clean.
+    let kept = fs::read_dir(&pm_dir).unwrap().flatten().any(|e| {
+        e.file_name()
+            .to_string_lossy()
+            .starts_with("state.json.corrupt-")
+    });
+    assert!(kept);
+    let out = run(&pm_dir, &["list"]);
+    assert!(String::from_utf8_lossy(&out.stderr).is_empty());
+
+    // TSK3 exists on disk but not in the restored index; its id is not reused.
+    let out = pm(&pm_dir, &["add", "Fresh"]);
+    assert!(out.contains("Added task TSK4"), "{out}");
+    pm(&pm_dir, &["doctor"]);
+    assert_eq!(list_ids(&pm_dir), "TSK1\nTSK2\nTSK3\nTSK4\n");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn without_a_backup_it_refuses_rather_than_starting_empty() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Only"]);
+    truncate_state(&pm_dir);
+    let damaged = fs::read_to_string(pm_dir.join("state.json")).unwrap();
+
+    let out = run(&pm_dir, &["list"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("pm doctor"));
+    assert_eq!(
+        fs::read_to_string(pm_dir.join("state.json")).unwrap(),
+        damaged
+    );
+
+    // Doctor still runs and rebuilds the index from the ticket files.
+    pm(&pm_dir, &["doctor"]);
+    assert_eq!(list_ids(&pm_dir), "TSK1\n");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn try_load_reports_an_unreadable_state_without_touching_it() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "First"]);
+    pm(&pm_dir, &["snapshot", "tmp"]);
+    truncate_state(&pm_dir);
+    let before = fs::read(pm_dir.join("state.json")).unwrap();
+
+    // The library never asks on stdin; the caller decides what to do.
+    let bad = Database::try_load(&pm_dir).unwrap_err();
+    assert_eq!(bad.path, pm_dir.join("state.json"));
+    assert!(bad.to_string().contains("is unreadable"), "{bad}");
+    assert_eq!(fs::read(pm_dir.join("state.json")).unwrap(), before);
+
+    let db = Database::recover(&pm_dir, &bad, |_| true);
+    assert_eq!(db.tasks.len(), 1);
+    assert!(Database::try_load(&pm_dir).is_ok());
+}
@@ -0,0 +1,83 @@
+//! `pm count --by`: grouped task counts, filters and JSON output.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-count-by-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn counts_group_filter_and_serialise() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Login", "--tag", "auth,ui"]);
+    pm(&pm_dir, &["add", "Logout", "--tag", "auth"]);
+    pm(&pm_dir, &["add", "Docs"]);
+    pm(&pm_dir, &["add", "Ship", "--kind", "milestone"]);
+    pm(&pm_dir, &["complete", "TSK3"]);
+
+    // Closed tasks are left out by default.
+    let out = pm(&pm_dir, &["count", "--by", "status"]);
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(
+        lines[0].split_whitespace().collect::<Vec<_>>(),
+        ["Status", "Count"]
+    );
+    assert_eq!(
+        lines[1].split_whitespace().collect::<Vec<_>>(),
+        ["Open", "3"]
+    );
+    assert_eq!(lines.len(), 2);
+
+    let out = pm(&pm_dir, &["count", "--by", "status", "--all"]);
+    assert!(out.lines().any(|l| l.split_whitespace().eq(["Done", "1"])));
+
+    // A task counts once per tag; untagged tasks count under `-`.
+    let out = pm(
+        &pm_dir,
+        &["count", "--by", "tag", "--all", "--kind", "task"],
+    );
+    let rows: Vec<Vec<&str>> = out
+        .lines()
+        .skip(1)
+        .map(|l| l.split_whitespace().collect())
+        .collect();
+    assert_eq!(rows, [vec!["auth", "2"], vec!["-", "1"], vec!["ui", "1"]]);
+
+    let out = pm(&pm_dir, &["count", "--by", "kind", "--all", "--json"]);
+    let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([
+            { "value": "Task", "count": 3 },
+            { "value": "Milestone", "count": 1 },
+        ])
+    );
+}
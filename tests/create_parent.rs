@@ -0,0 +1,128 @@
+//! `pm add --parent <name> --create-parent`: a missing parent is created one
+//! level above the new item.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-create-parent-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn missing_parent_is_created_and_then_reused() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    let out = pm(
+        &pm_dir,
+        &["add", "First", "--parent", "New Epic", "--create-parent"],
+    );
+    assert!(out.contains("Created parent EPC1: New Epic"), "{out}");
+    assert!(out.contains("Added task TSK1"), "{out}");
+
+    // The second add finds the epic by name instead of creating another.
+    let out = pm(
+        &pm_dir,
+        &["add", "Second", "--parent", "New Epic", "--create-parent"],
+    );
+    assert!(!out.contains("Created parent"), "{out}");
+    for id in ["TSK1", "TSK2"] {
+        let view = pm(&pm_dir, &["view", id]);
+        assert!(view.contains("Parent:       EPC1"), "{view}");
+    }
+    assert!(pm_dir.join("epics/EPC1/tasks/TSK2/CLAUDE.md").exists());
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn parent_kind_follows_the_child_kind() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    let out = pm(
+        &pm_dir,
+        &[
+            "add",
+            "Step",
+            "--kind",
+            "subtask",
+            "--parent",
+            "Umbrella",
+            "--create-parent",
+        ],
+    );
+    assert!(out.contains("Created parent TSK1: Umbrella"), "{out}");
+    assert!(out.contains("Added task SBT1"), "{out}");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn without_the_flag_a_missing_parent_is_an_error() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    let out = run(&pm_dir, &["add", "Orphan", "--parent", "Nowhere"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("No task found"));
+    let list = pm(&pm_dir, &["list", "--format", "plain", "--no-header"]);
+    assert!(list.is_empty(), "{list}");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn ambiguous_names_and_missing_ids_are_not_created() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "--kind", "epic", "Launch"]);
+    pm(&pm_dir, &["add", "--kind", "epic", "Launch"]);
+
+    let out = run(
+        &pm_dir,
+        &["add", "Task", "--parent", "Launch", "--create-parent"],
+    );
+    assert!(!out.status.success());
+    let err = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        err.contains("Multiple tasks found with name 'Launch'"),
+        "{err}"
+    );
+
+    let out = run(
+        &pm_dir,
+        &["add", "Task", "--parent", "EPC99", "--create-parent"],
+    );
+    assert!(!out.status.success());
+    let err = String::from_utf8_lossy(&out.stderr);
+    assert!(err.contains("Task with id EPC99 not found"), "{err}");
+
+    let list = pm(&pm_dir, &["list", "--all"]);
+    assert!(!list.contains("EPC3") && !list.contains("EPC99"), "{list}");
+    assert!(!list.contains("TSK1"), "{list}");
+}
@@ -0,0 +1,181 @@
+//! `pm import --update`: CSV rows whose ID matches an existing task update it
+//! in place; the rest are created.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-csv-update-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+const HEADER: &str = "ID,Title,Kind,Status,Priority,Urgency,ProcessStage,Project,Tags,Due,Parent,CreatedUTC,UpdatedUTC,Description";
+
+fn write_csv(pm_dir: &Path, rows: &[&str]) -> String {
+    let path = pm_dir.parent().unwrap().join("edit.csv");
+    let mut body = format!("{HEADER}\n");
+    for row in rows {
+        body.push_str(row);
+        body.push('\n');
+    }
+    fs::write(&path, body).unwrap();
+    path.display().to_string()
+}
+
+#[test]
+fn updates_matching_rows_and_creates_the_rest() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Write spec"]);
+    pm(&pm_dir, &["add", "Review spec"]);
+
+    let csv = write_csv(
+        &pm_dir,
+        &[
+            "TSK1,Write the spec,Task,Done,Must Have,-,Testing,-,docs;q3,2030-01-15,-,-,-,-",
+            "TSK2,-,Task,-,-,-,-,-,-,-,-,-,-,-",
+            "-,Brand new,Task,Open,-,-,-,-,-,-,-,-,-,-",
+        ],
+    );
+    let out = pm(&pm_dir, &["import", &csv, "--update"]);
+    assert!(
+        out.contains("Updated TSK1: title, status, priority, stage, tags, due"),
+        "{out}"
+    );
+    assert!(!out.contains("Updated TSK2"), "{out}");
+    assert!(
+        out.contains("1 tasks updated, 1 imported, 0 skipped"),
+        "{out}"
+    );
+
+    let view = pm(&pm_dir, &["view", "TSK1"]);
+    assert!(view.contains("Title:        Write the spec"), "{view}");
+    assert!(view.contains("Status:       Done"), "{view}");
+    assert!(view.contains("Priority:     Must Have"), "{view}");
+    assert!(view.contains("2030-01-15"), "{view}");
+    // `-` left TSK2 alone; the id-less row became TSK3.
+    assert!(pm(&pm_dir, &["view", "TSK2"]).contains("Title:        Review spec"));
+    assert!(pm(&pm_dir, &["view", "TSK3"]).contains("Title:        Brand new"));
+
+    // The workspace was snapshotted before any change.
+    let snapshots: Vec<_> = fs::read_dir(pm_dir.join("snapshots"))
+        .unwrap()
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    assert!(
+        snapshots.iter().any(|n| n.starts_with("pre-import-")),
+        "{snapshots:?}"
+    );
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn exported_csv_round_trips_through_update() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Launch", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "Ship it", "--parent", "EPC1"]);
+    pm(&pm_dir, &["set-status", "TSK1", "in-progress"]);
+    let export = pm_dir.parent().unwrap().join("out.csv");
+    pm(&pm_dir, &["export", "--output", export.to_str().unwrap()]);
+
+    // Re-importing the untouched export changes nothing.
+    let out = pm(
+        &pm_dir,
+        &[
+            "import",
+            export.to_str().unwrap(),
+            "--update",
+            "--no-backup",
+        ],
+    );
+    assert!(
+        out.contains("0 tasks updated, 0 imported, 0 skipped"),
+        "{out}"
+    );
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn bad_cells_and_kind_changes_are_reported_not_applied() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Keep"]);
+    let csv = write_csv(
+        &pm_dir,
+        &["TSK1,-,Epic,Finished,-,-,-,-,-,not-a-date,TSK9,-,-,-"],
+    );
+    let out = run(&pm_dir, &["import", &csv, "--update", "--no-backup"]);
+    assert!(out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("unrecognised Status 'Finished'"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("unrecognised Due 'not-a-date'"), "{stderr}");
+    assert!(stderr.contains("Kind of TSK1 is fixed"), "{stderr}");
+    assert!(stderr.contains("TSK9 does not exist"), "{stderr}");
+    let view = pm(&pm_dir, &["view", "TSK1"]);
+    assert!(view.contains("Status:       Open"), "{view}");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn without_update_matching_rows_are_still_appended() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Original"]);
+    let csv = write_csv(&pm_dir, &["TSK1,Different,Task,Done,-,-,-,-,-,-,-,-,-,-"]);
+    let out = pm(&pm_dir, &["import", &csv, "--no-backup"]);
+    assert!(out.contains("1 tasks imported"), "{out}");
+    assert!(pm(&pm_dir, &["view", "TSK1"]).contains("Status:       Open"));
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn update_with_json_input_fails_before_any_backup() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Original"]);
+    let bundle = pm_dir.parent().unwrap().join("bundle.json");
+    fs::write(&bundle, "{}").unwrap();
+    let out = run(&pm_dir, &["import", bundle.to_str().unwrap(), "--update"]);
+    assert!(!out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("--update applies to CSV files only"),
+        "{stderr}"
+    );
+    assert!(!pm_dir.join("snapshots").exists(), "backup taken anyway");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,86 @@
+//! `--iso-dates` / `--relative-dates` and `output.dates` in `config.json`:
+//! how `pm list` tables and `pm view` show due dates. Plain output is ISO
+//! whatever the setting.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::{Duration, Local};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-date-style-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+/// A workspace with TSK1 due in three days, and that date.
+fn workspace() -> (PathBuf, String) {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    let due = (Local::now().date_naive() + Duration::days(3))
+        .format("%Y-%m-%d")
+        .to_string();
+    pm(&pm_dir, &["add", "Ship it", "--due", &due]);
+    (pm_dir, due)
+}
+
+#[test]
+fn iso_dates_flag_prints_the_date_in_tables_and_view() {
+    let (pm_dir, due) = workspace();
+
+    let relative = pm(&pm_dir, &["list"]);
+    assert!(relative.contains("in 3d"), "{relative}");
+    let view = pm(&pm_dir, &["view", "TSK1"]);
+    assert!(
+        view.contains(&format!("Due:          {due} (in 3d)")),
+        "{view}"
+    );
+
+    let iso = pm(&pm_dir, &["list", "--iso-dates"]);
+    assert!(iso.contains(&due), "{iso}");
+    assert!(!iso.contains("in 3d"), "{iso}");
+    let view = pm(&pm_dir, &["--iso-dates", "view", "TSK1"]);
+    assert!(view.contains(&format!("Due:          {due}\n")), "{view}");
+}
+
+#[test]
+fn config_sets_the_default_and_the_flag_overrides_it() {
+    let (pm_dir, due) = workspace();
+    fs::write(
+        pm_dir.join("config.json"),
+        r#"{ "output": { "dates": "iso" } }"#,
+    )
+    .unwrap();
+
+    let iso = pm(&pm_dir, &["list"]);
+    assert!(iso.contains(&due), "{iso}");
+    let relative = pm(&pm_dir, &["list", "--relative-dates"]);
+    assert!(relative.contains("in 3d"), "{relative}");
+    // Plain output stays ISO either way.
+    let plain = pm(&pm_dir, &["list", "--relative-dates", "--format", "plain"]);
+    assert!(plain.contains(&due), "{plain}");
+}
@@ -0,0 +1,155 @@
+//! `pm dedupe`: grouping same-titled siblings and merging a group into the
+//! task the user keeps.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use project_management::db::{duplicate_groups, Database};
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+use project_management::task::Task;
+
+mod common;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-dedupe-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm_with_stdin(pm_dir: &Path, args: &[&str], stdin: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("invoke pm binary");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let out = child.wait_with_output().unwrap();
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    pm_with_stdin(pm_dir, args, "")
+}
+
+fn task(n: u64, title: &str, parent: Option<LeafId>) -> Task {
+    Task {
+        parent,
+        ..common::task(LeafId::new(TypePrefix::Task, n), title)
+    }
+}
+
+#[test]
+fn groups_need_the_same_parent_and_a_matching_title() {
+    let epic = Some(LeafId::new(TypePrefix::Epic, 1));
+    let tasks = vec![
+        task(1, "Fix login bug", None),
+        task(2, "  fix LOGIN bug ", None),
+        task(3, "Fix login bug", epic),
+        task(4, "Fix login bugs!", None),
+        task(5, "Fix", None),
+        task(6, "Mix", None),
+    ];
+    let ids = |ns: &[u64]| -> Vec<LeafId> {
+        ns.iter()
+            .map(|&n| LeafId::new(TypePrefix::Task, n))
+            .collect()
+    };
+    assert_eq!(duplicate_groups(&tasks, false), vec![ids(&[1, 2])]);
+    assert_eq!(duplicate_groups(&tasks, true), vec![ids(&[1, 2, 4])]);
+}
+
+#[test]
+fn merge_moves_children_and_links_onto_the_kept_task() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Launch", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "Write docs", "--parent", "EPC1"]);
+    pm(&pm_dir, &["add", "write docs", "--parent", "EPC1"]);
+    pm(
+        &pm_dir,
+        &["add", "Outline", "--kind", "subtask", "--parent", "TSK2"],
+    );
+    pm(&pm_dir, &["add", "Publish", "--parent", "EPC1"]);
+    pm(&pm_dir, &["dep", "TSK3", "needs", "TSK2"]);
+
+    let report = pm(&pm_dir, &["dedupe"]);
+    assert!(report.contains("Group 1 (under EPC1):"), "{report}");
+    assert!(
+        report.contains("  TSK2     Open         write docs\n"),
+        "{report}"
+    );
+
+    let out = pm_with_stdin(&pm_dir, &["dedupe", "--no-backup"], "TSK3\nTSK1\n");
+    assert!(
+        out.contains("Merged TSK2 into TSK1 (1 child(ren) moved)."),
+        "{out}"
+    );
+    let tsk = |n| LeafId::new(TypePrefix::Task, n);
+    let db = Database::load(&pm_dir);
+    assert!(db.get(tsk(2)).is_none());
+    let outline = db.get(LeafId::new(TypePrefix::Subtask, 1)).unwrap();
+    assert_eq!(outline.parent, Some(tsk(1)));
+    assert_eq!(db.get(tsk(3)).unwrap().deps, vec![tsk(1)]);
+    assert!(pm(&pm_dir, &["dedupe"]).contains("No duplicates found."));
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn merge_leaves_no_directories_behind_and_aliases_moved_children() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Launch", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "Write docs", "--parent", "EPC1"]);
+    pm(&pm_dir, &["add", "write docs", "--parent", "EPC1"]);
+    pm(
+        &pm_dir,
+        &["add", "Outline", "--kind", "subtask", "--parent", "TSK2"],
+    );
+    let tasks = pm_dir.join("epics/EPC1/tasks");
+    assert!(tasks.join("TSK2/subtasks/SBT1/CLAUDE.md").is_file());
+
+    pm_with_stdin(&pm_dir, &["dedupe", "--no-backup"], "TSK1\n");
+    assert!(
+        !tasks.join("TSK2").exists(),
+        "the duplicate's directory is left"
+    );
+    assert!(tasks.join("TSK1/subtasks/SBT1/CLAUDE.md").is_file());
+    let mut left: Vec<String> = fs::read_dir(&tasks)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    left.sort();
+    assert_eq!(left, ["TSK1"]);
+
+    let aliases = fs::read_to_string(pm_dir.join("aliases.json")).unwrap();
+    assert!(
+        aliases.contains(r#""EPC1-TSK2-SBT1": "EPC1-TSK1-SBT1""#),
+        "{aliases}"
+    );
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
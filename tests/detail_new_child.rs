@@ -0,0 +1,43 @@
+//! `n` in the TUI detail view: the add form opens with the ticket as parent
+//! and the kind one level below it.
+
+use project_management::fields::Kind;
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+use project_management::tui::enums::{HierarchyLevel, NavigationContext};
+use project_management::tui::keymap::{Action, KeyContext, Keymap};
+use project_management::tui::task_form::TaskForm;
+
+#[test]
+fn n_is_new_child_in_the_detail_view() {
+    let keymap = Keymap::default();
+    assert_eq!(
+        keymap.action(KeyContext::Detail, 'n'),
+        Some(Action::DetailNewChild)
+    );
+    assert_eq!(
+        Action::from_name("detail.new_child"),
+        Some(Action::DetailNewChild)
+    );
+}
+
+#[test]
+fn form_for_a_child_takes_the_parent_and_the_kind_below_it() {
+    let cases = [
+        (Kind::Project, TypePrefix::Project, Kind::Product),
+        (Kind::Epic, TypePrefix::Epic, Kind::Task),
+        (Kind::Task, TypePrefix::Task, Kind::Subtask),
+        (Kind::Subtask, TypePrefix::Subtask, Kind::Subtask),
+    ];
+    for (kind, prefix, child) in cases {
+        let parent = LeafId::new(prefix, 4);
+        let context = NavigationContext::new_filtered(
+            HierarchyLevel::of_kind(kind),
+            parent,
+            "Parent".to_string(),
+        );
+        let form = TaskForm::new_with_context_and_pm_dir(&context, std::path::Path::new(".pm"));
+        assert_eq!(form.parent.value, parent.to_string());
+        assert_eq!(form.kinds[form.kind], child, "child of a {kind:?}");
+    }
+}
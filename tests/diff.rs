@@ -0,0 +1,92 @@
+//! `pm diff`: a backup or snapshot against the current workspace.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-diff-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn reports_added_removed_and_changed_fields() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Keep"]);
+    pm(&pm_dir, &["add", "Drop"]);
+    pm(&pm_dir, &["snapshot", "sprint-start"]);
+    pm(&pm_dir, &["complete", "TSK1"]);
+    pm(&pm_dir, &["delete", "TSK2"]);
+    pm(&pm_dir, &["add", "Fresh"]);
+
+    let out = pm(&pm_dir, &["diff", "sprint-start"]);
+    let body: Vec<&str> = out.lines().skip(1).collect();
+    assert_eq!(
+        body,
+        vec![
+            "~ TSK1 modified: Keep",
+            "~ TSK1 status: Open -> Done",
+            "- TSK2 removed: Drop [Open]",
+            "+ TSK3 added: Fresh [Open]",
+            "1 added, 1 removed, 1 modified.",
+        ]
+    );
+
+    // A backup is found by the date at the start of its file name.
+    let backup = pm_dir.parent().unwrap().join("backup");
+    fs::create_dir_all(&backup).unwrap();
+    fs::copy(
+        pm_dir.join("snapshots").join("sprint-start.json"),
+        backup.join("2025-01-02_09-00-00_.pm"),
+    )
+    .unwrap();
+    let out = pm(&pm_dir, &["diff", "2025-01-02"]);
+    assert!(
+        out.starts_with("Comparing backup 2025-01-02_09-00-00_.pm (2025-01-02 09:00)"),
+        "{out}"
+    );
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn unchanged_and_unknown_references() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Only"]);
+    pm(&pm_dir, &["snapshot", "now"]);
+    assert!(pm(&pm_dir, &["diff", "now"]).ends_with("No differences.\n"));
+
+    let out = run(&pm_dir, &["diff", "later"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("no backup or snapshot matches"));
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,110 @@
+//! Reminder lead times: the reminder / due / overdue states behind
+//! `pm list --due upcoming` and the TUI due colours.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::NaiveDate;
+use project_management::db::{due_filter_matches, due_state, DueState};
+use project_management::fields::DueFilter;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, 12).unwrap()
+}
+
+fn days(offset: i64) -> Option<NaiveDate> {
+    Some(today() + chrono::Duration::days(offset))
+}
+
+#[test]
+fn reminder_window_opens_remind_days_before_due() {
+    assert_eq!(
+        due_state(days(3), Some(3), today()),
+        Some(DueState::Reminder)
+    );
+    assert_eq!(
+        due_state(days(1), Some(3), today()),
+        Some(DueState::Reminder)
+    );
+    assert_eq!(due_state(days(4), Some(3), today()), None);
+    assert_eq!(due_state(days(0), Some(3), today()), Some(DueState::Due));
+    assert_eq!(
+        due_state(days(-1), Some(3), today()),
+        Some(DueState::Overdue)
+    );
+}
+
+#[test]
+fn tasks_without_a_lead_time_only_become_due() {
+    assert_eq!(due_state(days(1), None, today()), None);
+    assert_eq!(due_state(days(0), None, today()), Some(DueState::Due));
+    assert_eq!(due_state(days(-2), None, today()), Some(DueState::Overdue));
+    assert_eq!(due_state(None, Some(7), today()), None);
+}
+
+#[test]
+fn upcoming_filter_excludes_due_and_overdue_tasks() {
+    let upcoming = |due, remind| due_filter_matches(due, remind, DueFilter::Upcoming, today());
+    assert!(upcoming(days(2), Some(5)));
+    assert!(!upcoming(days(6), Some(5)));
+    assert!(!upcoming(days(0), Some(5)));
+    assert!(!upcoming(days(-1), Some(5)));
+    assert!(!upcoming(days(2), None));
+}
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-due-reminders-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn remind_is_stored_and_drives_list_due_upcoming() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(
+        &pm_dir,
+        &["add", "renew cert", "--due", "in 5d", "--remind", "7"],
+    );
+    pm(&pm_dir, &["add", "file taxes", "--due", "in 5d"]);
+
+    let out = pm(&pm_dir, &["list", "--due", "upcoming"]);
+    assert!(
+        out.contains("renew cert") && !out.contains("file taxes"),
+        "{out}"
+    );
+    assert!(pm(&pm_dir, &["view", "TSK1"]).contains("remind 7d before"));
+
+    pm(&pm_dir, &["update", "TSK2", "--remind", "5"]);
+    assert!(pm(&pm_dir, &["list", "--due", "upcoming"]).contains("file taxes"));
+
+    pm(&pm_dir, &["update", "TSK1", "--clear-remind"]);
+    let out = pm(&pm_dir, &["list", "--due", "upcoming"]);
+    assert!(!out.contains("renew cert"), "{out}");
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,40 @@
+//! Numeric due-date thresholds behind `pm list --overdue-by` and `--due-in`,
+//! checked against a fixed "today" at each boundary.
+
+use chrono::NaiveDate;
+use project_management::db::{due_within, overdue_by_at_least};
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, 12).unwrap()
+}
+
+fn days(offset: i64) -> Option<NaiveDate> {
+    Some(today() + chrono::Duration::days(offset))
+}
+
+#[test]
+fn overdue_by_counts_whole_days_past_due() {
+    assert!(overdue_by_at_least(days(-7), today(), 7));
+    assert!(overdue_by_at_least(days(-8), today(), 7));
+    assert!(!overdue_by_at_least(days(-6), today(), 7));
+}
+
+#[test]
+fn overdue_by_zero_still_requires_a_past_date() {
+    assert!(overdue_by_at_least(days(-1), today(), 0));
+    assert!(!overdue_by_at_least(days(0), today(), 0));
+    assert!(!overdue_by_at_least(None, today(), 0));
+}
+
+#[test]
+fn due_in_includes_today_and_the_last_day() {
+    assert!(due_within(days(0), today(), 0));
+    assert!(due_within(days(3), today(), 3));
+    assert!(!due_within(days(4), today(), 3));
+}
+
+#[test]
+fn due_in_excludes_overdue_and_undated_tasks() {
+    assert!(!due_within(days(-1), today(), 30));
+    assert!(!due_within(None, today(), 30));
+}
@@ -0,0 +1,62 @@
+//! Row layout behind the TUI fullscreen editor: long lines soft-wrap on
+//! screen without touching the text, and the cursor maps between logical
+//! (line, byte) positions and on-screen rows.
+
+use project_management::tui::utils::{editor_cursor, editor_offset, editor_rows, EditorRow};
+
+fn row_texts<'a>(text: &'a str, rows: &[EditorRow]) -> Vec<&'a str> {
+    let lines: Vec<&str> = text.split('\n').collect();
+    rows.iter()
+        .map(|r| &lines[r.line][r.start..r.end])
+        .collect()
+}
+
+#[test]
+fn long_lines_wrap_after_the_last_space_that_fits() {
+    let text = "the quick brown fox\nshort";
+    let rows = editor_rows(text, 10, true);
+    assert_eq!(row_texts(text, &rows), ["the quick ", "brown fox", "short"]);
+    assert_eq!(rows.iter().map(|r| r.line).collect::<Vec<_>>(), [0, 0, 1]);
+}
+
+#[test]
+fn words_wider_than_a_row_break_mid_word() {
+    let text = "abcdefghij kl";
+    let rows = editor_rows(text, 4, true);
+    assert_eq!(row_texts(text, &rows), ["abcd", "efgh", "ij ", "kl"]);
+}
+
+#[test]
+fn without_wrap_each_line_is_one_row_including_empty_ones() {
+    let text = "a very long line indeed\n\nend\n";
+    let rows = editor_rows(text, 5, false);
+    assert_eq!(
+        row_texts(text, &rows),
+        ["a very long line indeed", "", "end", ""]
+    );
+}
+
+#[test]
+fn cursor_maps_to_wrapped_rows_and_back() {
+    let text = "the quick brown fox";
+    let rows = editor_rows(text, 10, true);
+
+    assert_eq!(editor_cursor(&rows, text, 0, 3), (0, 3));
+    // A cursor on the break is drawn at the start of the next row.
+    assert_eq!(editor_cursor(&rows, text, 0, 10), (1, 0));
+    assert_eq!(editor_cursor(&rows, text, 0, 19), (1, 9));
+
+    assert_eq!(editor_offset(&rows, text, 1, 2), (0, 12));
+    // Past the end of a wrapped row stays on that row.
+    assert_eq!(editor_offset(&rows, text, 0, 40), (0, 9));
+    assert_eq!(editor_offset(&rows, text, 1, 40), (0, 19));
+}
+
+#[test]
+fn columns_count_characters_not_bytes() {
+    let text = "café crème";
+    let rows = editor_rows(text, 40, true);
+    let after_e_acute = "café".len();
+    assert_eq!(editor_cursor(&rows, text, 0, after_e_acute), (0, 4));
+    assert_eq!(editor_offset(&rows, text, 0, 4), (0, after_e_acute));
+}
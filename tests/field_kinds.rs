@@ -0,0 +1,64 @@
+//! Validation hints for typed TUI form fields.
+
+use project_management::tui::input::{FieldKind, InputField};
+use project_management::tui::task_form::TaskForm;
+
+#[test]
+fn empty_values_are_always_valid() {
+    for kind in [
+        FieldKind::FreeText,
+        FieldKind::Url,
+        FieldKind::TicketId,
+        FieldKind::Date,
+    ] {
+        assert!(kind.accepts(""));
+        assert!(kind.accepts("   "));
+    }
+}
+
+#[test]
+fn url_fields_need_an_http_scheme_and_host() {
+    assert!(FieldKind::Url.accepts("https://github.com/o/r/issues/4"));
+    assert!(FieldKind::Url.accepts("http://localhost:8080"));
+    assert!(!FieldKind::Url.accepts("github.com/o/r"));
+    assert!(!FieldKind::Url.accepts("https://"));
+    assert!(!FieldKind::Url.accepts("https://a b"));
+}
+
+#[test]
+fn ticket_and_date_fields_reuse_the_cli_parsers() {
+    assert!(FieldKind::TicketId.accepts("TSK7"));
+    assert!(FieldKind::TicketId.accepts("PRJ1-EPC3-TSK7"));
+    assert!(!FieldKind::TicketId.accepts("7x"));
+    assert!(FieldKind::Date.accepts("2025-03-01"));
+    assert!(FieldKind::Date.accepts("in 3d"));
+    assert!(!FieldKind::Date.accepts("someday"));
+}
+
+#[test]
+fn ticket_id_fields_drop_characters_no_id_has() {
+    let mut parent = InputField::new().with_kind(FieldKind::TicketId);
+    for c in "PRJ1 -EPC3/TSK7!".chars() {
+        parent.handle_char(c);
+    }
+    assert_eq!(parent.value, "PRJ1-EPC3TSK7");
+    assert_eq!(parent.cursor, parent.value.len());
+
+    let mut title = InputField::new();
+    for c in "a b!".chars() {
+        title.handle_char(c);
+    }
+    assert_eq!(title.value, "a b!");
+}
+
+#[test]
+fn form_reports_the_first_invalid_field() {
+    let mut form = TaskForm::new();
+    assert_eq!(form.first_invalid_field(), None);
+    form.pr_link = InputField::with_value("nope").with_kind(FieldKind::Url);
+    form.parent.value = "??".to_string();
+    assert_eq!(
+        form.first_invalid_field().as_deref(),
+        Some("Parent ID: expected a ticket id like TSK7")
+    );
+}
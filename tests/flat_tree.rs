@@ -0,0 +1,102 @@
+//! `pm list --flat-tree`: rows in hierarchy pre-order, printed unindented.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-flat-tree-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn ids(out: &str) -> Vec<&str> {
+    out.lines().collect()
+}
+
+#[test]
+fn parents_are_followed_by_their_subtrees() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "First epic", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "Second epic", "--kind", "epic"]);
+    pm(
+        &pm_dir,
+        &["add", "Late", "--parent", "EPC2", "--due", "2020-01-01"],
+    );
+    pm(&pm_dir, &["add", "Early", "--parent", "EPC1"]);
+    pm(
+        &pm_dir,
+        &["add", "Step", "--kind", "subtask", "--parent", "TSK2"],
+    );
+
+    let args = ["list", "--format", "plain", "--no-header", "--fields", "id"];
+    let flat = pm(&pm_dir, &[&args[..], &["--flat-tree"]].concat());
+    assert_eq!(ids(&flat), ["EPC1", "TSK2", "SBT1", "EPC2", "TSK1"]);
+
+    // `--sort` is ignored in tree order; without `--flat-tree` it applies.
+    let sorted = pm(
+        &pm_dir,
+        &[&args[..], &["--flat-tree", "--sort", "id"]].concat(),
+    );
+    assert_eq!(sorted, flat);
+    let by_due = pm(&pm_dir, &args);
+    assert_eq!(ids(&by_due)[0], "TSK1");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn filtered_rows_keep_their_tree_position() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Epic", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "Loose"]);
+    pm(&pm_dir, &["add", "Filed", "--parent", "EPC1"]);
+    let out = pm(
+        &pm_dir,
+        &[
+            "list",
+            "--kind",
+            "task",
+            "--flat-tree",
+            "--format",
+            "plain",
+            "--no-header",
+            "--fields",
+            "id,title",
+        ],
+    );
+    // TSK2 sits under EPC1, which comes before the root TSK1; no indent.
+    assert_eq!(out, "TSK2\tFiled\nTSK1\tLoose\n");
+    assert!(!run(&pm_dir, &["list", "--tree", "--flat-tree"])
+        .status
+        .success());
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,133 @@
+//! `pm focus <id>`: `pm list` shows only the focus root's subtree under a
+//! `Focus:` header, `pm add` files new tickets under the root, and
+//! `pm focus --clear` shows the whole workspace again.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-focus-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm_raw(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = pm_raw(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn listed_ids(pm_dir: &Path) -> Vec<String> {
+    pm(
+        pm_dir,
+        &[
+            "list",
+            "--all",
+            "--sort",
+            "id",
+            "--format",
+            "plain",
+            "--no-header",
+        ],
+    )
+    .lines()
+    .filter_map(|line| line.split('\t').next())
+    .map(str::to_string)
+    .collect()
+}
+
+/// Two epics under one product, with a task and subtask under the first.
+fn workspace() -> PathBuf {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "--kind", "product", "Core"]);
+    pm(
+        &pm_dir,
+        &["add", "--kind", "epic", "Editor", "--parent", "PRD1"],
+    );
+    pm(
+        &pm_dir,
+        &["add", "--kind", "epic", "Sync", "--parent", "PRD1"],
+    );
+    pm(&pm_dir, &["add", "Undo", "--parent", "EPC1"]);
+    pm(
+        &pm_dir,
+        &["add", "--kind", "subtask", "Redo", "--parent", "TSK1"],
+    );
+    pm(&pm_dir, &["add", "Conflicts", "--parent", "EPC2"]);
+    pm_dir
+}
+
+#[test]
+fn focus_limits_list_to_the_subtree() {
+    let pm_dir = workspace();
+    pm(&pm_dir, &["focus", "EPC1"]);
+
+    assert_eq!(listed_ids(&pm_dir), ["EPC1", "TSK1", "SBT1"]);
+    let table = pm(&pm_dir, &["list", "--all"]);
+    assert!(
+        table.starts_with("Focus: EPC1 Editor (3 tickets)\n"),
+        "{table}"
+    );
+    assert_eq!(pm(&pm_dir, &["focus"]), "EPC1 Editor (3 tickets)\n");
+    let plain = pm_raw(&pm_dir, &["list", "--all", "--format", "plain"]);
+    let stdout = String::from_utf8_lossy(&plain.stdout);
+    assert!(!stdout.contains("Focus:"), "{stdout}");
+    assert!(String::from_utf8_lossy(&plain.stderr).starts_with("Focus: EPC1 Editor"));
+
+    pm(&pm_dir, &["focus", "--clear"]);
+    assert_eq!(
+        listed_ids(&pm_dir),
+        ["PRD1", "EPC1", "EPC2", "TSK1", "TSK2", "SBT1"]
+    );
+    assert!(!pm(&pm_dir, &["list"]).contains("Focus:"));
+    assert!(pm(&pm_dir, &["focus"]).starts_with("No focus"));
+}
+
+#[test]
+fn add_defaults_the_parent_to_the_focus_root() {
+    let pm_dir = workspace();
+    pm(&pm_dir, &["focus", "EPC2"]);
+
+    pm(&pm_dir, &["add", "Offline queue"]);
+    let view = pm(&pm_dir, &["view", "TSK3"]);
+    assert!(view.contains("EPC2"), "{view}");
+    assert_eq!(listed_ids(&pm_dir), ["EPC2", "TSK2", "TSK3"]);
+
+    // An explicit --parent still wins, and a task root takes subtasks.
+    pm(&pm_dir, &["add", "Elsewhere", "--parent", "EPC1"]);
+    assert!(pm(&pm_dir, &["view", "TSK4"]).contains("EPC1"));
+    pm(&pm_dir, &["focus", "TSK3"]);
+    pm(&pm_dir, &["add", "Retry with backoff"]);
+    assert_eq!(listed_ids(&pm_dir), ["TSK3", "SBT2"]);
+}
+
+#[test]
+fn focus_on_an_unknown_ticket_is_an_error() {
+    let pm_dir = workspace();
+    let out = pm_raw(&pm_dir, &["focus", "EPC9"]);
+    assert!(!out.status.success());
+    assert!(listed_ids(&pm_dir).contains(&"EPC2".to_string()));
+}
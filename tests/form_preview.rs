@@ -0,0 +1,55 @@
+//! The TUI form's values applied to a ticket, as saved and as shown in the
+//! form's preview pane.
+
+use project_management::fields::{Kind, Status};
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+use project_management::task::Task;
+use project_management::tui::task_form::TaskForm;
+
+mod common;
+
+#[test]
+fn apply_to_copies_and_normalises_the_form() {
+    let mut form = TaskForm::new();
+    form.title.value = "  Login page ".to_string();
+    form.summary.value = "   ".to_string();
+    form.description.value = "Steps:\n- open\n- sign in\n".to_string();
+    form.tags.value = "UI, auth,".to_string();
+    form.due.value = "2030-01-31".to_string();
+    form.artifacts.value = "a.png, ,b.md".to_string();
+    form.status = form
+        .statuses
+        .iter()
+        .position(|s| *s == Status::InProgress)
+        .unwrap();
+    form.kind = form.kinds.iter().position(|k| *k == Kind::Epic).unwrap();
+
+    let id = LeafId::new(TypePrefix::Task, 3);
+    let mut task = Task {
+        summary: Some("old summary".to_string()),
+        tags: vec!["stale".to_string()],
+        remind_days_before: Some(2),
+        parent: Some(LeafId::new(TypePrefix::Epic, 1)),
+        iteration: Some("2024-S3".to_string()),
+        ..common::task(id, "old")
+    };
+    form.apply_to(&mut task);
+
+    assert_eq!(task.title, "Login page");
+    assert_eq!(task.summary, None);
+    assert_eq!(
+        task.description.as_deref(),
+        Some("Steps:\n- open\n- sign in")
+    );
+    assert_eq!(task.tags, ["auth", "ui"]);
+    assert_eq!(task.due.unwrap().to_string(), "2030-01-31");
+    assert_eq!(task.artifacts, ["a.png", "b.md"]);
+    assert_eq!(task.status, Status::InProgress);
+    assert_eq!(task.kind, Kind::Epic);
+    // Fields the form does not carry are kept, the parent included.
+    assert_eq!(task.id, id);
+    assert_eq!(task.parent, Some(LeafId::new(TypePrefix::Epic, 1)));
+    assert_eq!(task.iteration.as_deref(), Some("2024-S3"));
+    assert_eq!(task.remind_days_before, Some(2));
+}
@@ -0,0 +1,60 @@
+//! `pm help-dump`: keybindings and subcommands as markdown.
+
+use std::process::Command;
+
+use project_management::tui::keyhelp::{Binding, SECTIONS};
+use project_management::tui::keymap::Action;
+
+fn help_dump() -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("help-dump")
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm help-dump failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn dump_lists_keys_and_commands() {
+    let out = help_dump();
+    for section in [
+        "## Ticket list",
+        "## Ticket detail",
+        "## Task form",
+        "## Workflow board",
+        "## Global options",
+        "## pm add",
+        "## pm memory write",
+    ] {
+        assert!(out.contains(section), "missing {section}:\n{out}");
+    }
+    assert!(out.contains("| `s` | Cycle status | `status` |"));
+    assert!(out.contains("| unbound | Complete the selected ticket | `complete` |"));
+    assert!(out.contains("- `--tag <TAGS>`: Comma-separated tags"));
+    assert!(out.contains("- `<TITLE>`: Short title for the task"));
+    // Hidden commands stay out of the docs, this one included.
+    assert!(!out.contains("## pm help-dump"));
+
+    let listed = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--help")
+        .output()
+        .expect("invoke pm binary");
+    assert!(!String::from_utf8_lossy(&listed.stdout).contains("help-dump"));
+}
+
+#[test]
+fn every_action_has_a_help_row() {
+    for action in Action::ALL {
+        assert!(
+            SECTIONS
+                .iter()
+                .any(|s| s.bindings.contains(&Binding::Action(action))),
+            "{} is missing from the key reference",
+            action.name()
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! `pm list --ancestors-of / --children-of / --descendants-of`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-hierarchy-queries-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+/// Ids of a `--fields id --format plain --no-header` listing, in order.
+fn ids(pm_dir: &Path, query: &[&str]) -> Vec<String> {
+    let mut args = vec![
+        "list",
+        "--all",
+        "--fields",
+        "id",
+        "--format",
+        "plain",
+        "--no-header",
+    ];
+    args.extend_from_slice(query);
+    pm(pm_dir, &args).lines().map(str::to_string).collect()
+}
+
+#[test]
+fn hierarchy_queries_walk_up_and_down_from_an_anchor() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Platform", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "Login", "--parent", "EPC1"]);
+    pm(&pm_dir, &["add", "Signup", "--parent", "EPC1"]);
+    pm(
+        &pm_dir,
+        &[
+            "add",
+            "Hash passwords",
+            "--kind",
+            "subtask",
+            "--parent",
+            "TSK1",
+        ],
+    );
+    pm(&pm_dir, &["add", "Unrelated"]);
+
+    // Root first, whatever --sort says.
+    assert_eq!(
+        ids(&pm_dir, &["--ancestors-of", "SBT1", "--sort", "id"]),
+        ["EPC1", "TSK1"]
+    );
+    assert_eq!(ids(&pm_dir, &["--children-of", "Platform"]).len(), 2);
+    let mut below = ids(&pm_dir, &["--descendants-of", "EPC1"]);
+    below.sort();
+    assert_eq!(below, ["SBT1", "TSK1", "TSK2"]);
+    // Other filters still narrow the relatives.
+    assert_eq!(
+        ids(&pm_dir, &["--descendants-of", "EPC1", "--kind", "subtask"]),
+        ["SBT1"]
+    );
+    assert!(ids(&pm_dir, &["--ancestors-of", "EPC1"]).is_empty());
+
+    let out = run(&pm_dir, &["list", "--ancestors-of", "Nope"]);
+    assert!(!out.status.success());
+    let out = run(
+        &pm_dir,
+        &["list", "--ancestors-of", "SBT1", "--children-of", "EPC1"],
+    );
+    assert!(!out.status.success());
+}
+
+#[test]
+fn closed_ancestors_are_listed_without_all() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Platform", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "Login", "--parent", "EPC1"]);
+    pm(&pm_dir, &["add", "Reset", "--parent", "EPC1"]);
+    pm(
+        &pm_dir,
+        &["add", "Hash", "--kind", "subtask", "--parent", "TSK1"],
+    );
+    pm(&pm_dir, &["update", "EPC1", "--status", "done"]);
+    pm(&pm_dir, &["update", "TSK2", "--status", "done"]);
+
+    let open_only = |query: &[&str]| {
+        let mut args = vec!["list", "--fields", "id", "--format", "plain", "--no-header"];
+        args.extend_from_slice(query);
+        pm(&pm_dir, &args)
+            .lines()
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(open_only(&["--ancestors-of", "SBT1"]), ["EPC1", "TSK1"]);
+    // An explicit status still narrows the chain.
+    assert_eq!(
+        open_only(&["--ancestors-of", "SBT1", "--status", "open"]),
+        ["TSK1"]
+    );
+    // The other relations keep hiding closed tasks by default.
+    assert_eq!(open_only(&["--children-of", "EPC1"]), ["TSK1"]);
+}
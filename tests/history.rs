@@ -0,0 +1,103 @@
+//! `pm history`: a task's timeline rebuilt from backups and snapshots.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-history-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+/// Take a snapshot and move it into `backup/` under a fixed timestamp, so
+/// the ordering doesn't depend on file mtimes.
+fn backup_as(pm_dir: &Path, stamp: &str) {
+    pm(pm_dir, &["snapshot", "tmp", "--force"]);
+    let backup = pm_dir.parent().unwrap().join("backup");
+    fs::create_dir_all(&backup).unwrap();
+    fs::rename(
+        pm_dir.join("snapshots").join("tmp.json"),
+        backup.join(format!("{stamp}_.pm")),
+    )
+    .unwrap();
+}
+
+#[test]
+fn timeline_shows_creation_changes_and_deletion() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    backup_as(&pm_dir, "2025-01-01_09-00-00");
+    pm(&pm_dir, &["add", "Write docs"]);
+    backup_as(&pm_dir, "2025-01-02_09-00-00");
+    pm(&pm_dir, &["update", "TSK1", "--title", "Write the docs"]);
+    pm(&pm_dir, &["set-status", "TSK1", "done"]);
+    backup_as(&pm_dir, "2025-01-03_09-00-00");
+    pm(&pm_dir, &["delete", "TSK1"]);
+
+    let out = pm(&pm_dir, &["history", "TSK1"]);
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines[0], "History of TSK1: Write the docs");
+    assert!(lines[1].starts_with("2025-01-01 09:00  backup"));
+    assert_eq!(lines[2], "    not present yet");
+    assert_eq!(lines[4], "    present: Write docs [Open]");
+    assert!(out.contains("    title: Write docs -> Write the docs\n"));
+    assert!(out.contains("    status: Open -> Done\n"));
+    assert!(out.ends_with("now               current\n    deleted\n"));
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn unreadable_backups_are_skipped_with_a_warning() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Only"]);
+    let backup = pm_dir.parent().unwrap().join("backup");
+    fs::create_dir_all(&backup).unwrap();
+    fs::write(backup.join("2024-05-01_00-00-00_tasks.json"), "[1, 2]").unwrap();
+
+    let out = run(&pm_dir, &["history", "TSK1"]);
+    assert!(out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("skipped"), "{stderr}");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("No backups or snapshots found"));
+    assert!(stdout.contains("present: Only [Open]"));
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn unknown_ids_fail() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    let out = run(&pm_dir, &["history", "TSK9"]);
+    assert!(!out.status.success());
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,104 @@
+//! `pm inbox`: listing parentless tasks and filing them with `--triage`.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-inbox-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm_with_stdin(pm_dir: &Path, args: &[&str], stdin: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("invoke pm binary");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let out = child.wait_with_output().unwrap();
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    pm_with_stdin(pm_dir, args, "")
+}
+
+#[test]
+fn lists_only_open_parentless_tasks() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Launch", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "filed", "--parent", "EPC1"]);
+    pm(&pm_dir, &["add", "loose"]);
+    pm(&pm_dir, &["add", "finished"]);
+    pm(&pm_dir, &["complete", "TSK3"]);
+
+    let out = pm(&pm_dir, &["inbox"]);
+    assert!(out.contains("loose"), "{out}");
+    for absent in ["Launch", "filed", "finished"] {
+        assert!(!out.contains(absent), "{absent}:\n{out}");
+    }
+
+    pm(&pm_dir, &["complete", "TSK2"]);
+    assert!(pm(&pm_dir, &["inbox"]).contains("Inbox is empty."));
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn triage_files_tasks_under_a_parent_and_skips_on_enter() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Launch", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "first"]);
+    pm(&pm_dir, &["add", "second"]);
+    pm(&pm_dir, &["add", "third"]);
+
+    // TSK1: an unknown parent is re-asked. TSK2: a task cannot sit under
+    // another task, so EPC1 is given second. TSK3: Enter leaves it alone.
+    let script = "nowhere\nEPC1\nTSK1\nEPC1\n\n";
+    let out = pm_with_stdin(&pm_dir, &["inbox", "--triage", "--sort", "id"], script);
+    assert!(out.contains("Filed TSK1 under EPC1"), "{out}");
+    assert!(out.contains("Filed TSK2 under EPC1"), "{out}");
+    assert!(out.contains("Filed 2, left 1 in the inbox."), "{out}");
+
+    let inbox = pm(&pm_dir, &["inbox"]);
+    assert!(
+        inbox.contains("third") && !inbox.contains("first"),
+        "{inbox}"
+    );
+    // Filing moves the ticket directory rather than copying it.
+    assert!(!pm_dir.join("tasks/TSK1").exists());
+    assert!(pm_dir.join("epics/EPC1/tasks/TSK1/CLAUDE.md").exists());
+
+    // Quitting (or running out of input) keeps what was filed so far.
+    let out = pm_with_stdin(&pm_dir, &["inbox", "--triage"], "q\n");
+    assert!(out.contains("Filed 0, left 1 in the inbox."), "{out}");
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
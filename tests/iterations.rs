@@ -0,0 +1,101 @@
+//! Iteration planning: `--iteration` on add/update/list and the
+//! `pm iteration` report.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-iterations-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn sprint(pm_dir: &Path) {
+    pm(pm_dir, &["init"]);
+    pm(pm_dir, &["add", "Login form", "--iteration", "2024-S3"]);
+    pm(pm_dir, &["add", "Session expiry", "--iteration", "2024-S3"]);
+    pm(pm_dir, &["add", "Audit log", "--iteration", "2024-S3"]);
+    pm(pm_dir, &["add", "Password reset"]);
+    pm(pm_dir, &["update", "TSK4", "--iteration", "2024-S4"]);
+    pm(pm_dir, &["complete", "TSK1"]);
+    pm(pm_dir, &["set-status", "TSK3", "cancelled"]);
+}
+
+#[test]
+fn report_counts_committed_completed_and_remaining() {
+    let pm_dir = tmp_pm_dir();
+    sprint(&pm_dir);
+    let out = pm(&pm_dir, &["iteration", "2024-S3"]);
+    assert!(out.contains("Committed:  3\n"), "{out}");
+    assert!(out.contains("Completed:  1 (33%)\n"), "{out}");
+    assert!(out.contains("Cancelled:  1\n"), "{out}");
+    assert!(out.contains("Remaining:  1\n"), "{out}");
+    assert!(out.contains("TSK2     Open"), "{out}");
+    assert!(!out.contains("TSK4"), "{out}");
+
+    let out = pm(&pm_dir, &["iteration"]);
+    assert!(out.contains("2024-S3              1      3"), "{out}");
+    assert!(out.contains("2024-S4              0      1"), "{out}");
+
+    assert!(!run(&pm_dir, &["iteration", "2024-S9"]).status.success());
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn list_filters_and_update_clears() {
+    let pm_dir = tmp_pm_dir();
+    sprint(&pm_dir);
+    let ids = |pm_dir: &Path| {
+        pm(
+            pm_dir,
+            &[
+                "list",
+                "--all",
+                "--iteration",
+                "2024-S3",
+                "--format",
+                "plain",
+                "--no-header",
+                "--fields",
+                "id",
+                "--sort",
+                "id",
+            ],
+        )
+    };
+    assert_eq!(ids(&pm_dir), "TSK1\nTSK2\nTSK3\n");
+    assert!(pm(&pm_dir, &["view", "TSK2"]).contains("Iteration:    2024-S3\n"));
+
+    pm(&pm_dir, &["update", "TSK2", "--clear-iteration"]);
+    assert_eq!(ids(&pm_dir), "TSK1\nTSK3\n");
+    assert!(pm(&pm_dir, &["view", "TSK2"]).contains("Iteration:    -\n"));
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
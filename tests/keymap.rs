@@ -0,0 +1,127 @@
+//! The TUI keymap built from the config `keys` table.
+
+use std::collections::BTreeMap;
+
+use project_management::tui::keymap::{Action, KeyContext, Keymap};
+
+fn table(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+    entries
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn defaults_match_the_historical_bindings() {
+    let keymap = Keymap::default();
+    assert_eq!(
+        keymap.action(KeyContext::TaskList, 's'),
+        Some(Action::CycleStatus)
+    );
+    assert_eq!(
+        keymap.action(KeyContext::TaskList, 'c'),
+        Some(Action::Checkout)
+    );
+    assert_eq!(
+        keymap.action(KeyContext::Detail, 'c'),
+        Some(Action::DetailChild)
+    );
+    assert_eq!(keymap.key(Action::Complete), None);
+    assert_eq!(Keymap::from_config(&BTreeMap::new()), Ok(keymap));
+}
+
+#[test]
+fn overrides_move_and_bind_actions() {
+    let keymap = Keymap::from_config(&table(&[("complete", "x"), ("checkout", "C")])).unwrap();
+    assert_eq!(
+        keymap.action(KeyContext::TaskList, 'x'),
+        Some(Action::Complete)
+    );
+    assert_eq!(
+        keymap.action(KeyContext::TaskList, 'C'),
+        Some(Action::Checkout)
+    );
+    // The old key is freed rather than left as a second binding.
+    assert_eq!(keymap.action(KeyContext::TaskList, 'c'), None);
+    // Detail-view bindings are independent of the list.
+    assert_eq!(
+        keymap.action(KeyContext::Detail, 'c'),
+        Some(Action::DetailChild)
+    );
+}
+
+#[test]
+fn conflicting_bindings_are_rejected() {
+    let problems = Keymap::from_config(&table(&[("complete", "s")])).unwrap_err();
+    assert_eq!(
+        problems,
+        vec!["'s' is bound to complete, status".to_string()]
+    );
+
+    // Swapping two keys is fine.
+    let keymap = Keymap::from_config(&table(&[("status", "d"), ("delete", "s")])).unwrap();
+    assert_eq!(
+        keymap.action(KeyContext::TaskList, 'd'),
+        Some(Action::CycleStatus)
+    );
+}
+
+#[test]
+fn invalid_entries_are_reported() {
+    let problems = Keymap::from_config(&table(&[
+        ("frobnicate", "z"),
+        ("complete", "xy"),
+        ("refresh", "1"),
+    ]))
+    .unwrap_err();
+    assert_eq!(problems.len(), 3);
+    assert!(problems
+        .iter()
+        .any(|p| p.contains("unknown action 'frobnicate'")));
+    assert!(problems.iter().any(|p| p.contains("single character")));
+    assert!(problems.iter().any(|p| p.contains("fixed key '1'")));
+}
+
+#[test]
+fn digits_stay_free_for_jumping_to_children() {
+    let problems = Keymap::from_config(&table(&[("detail.edit", "7")])).unwrap_err();
+    assert_eq!(problems, vec!["'detail.edit' cannot use the fixed key '7'"]);
+}
+
+#[test]
+fn due_nudges_date_undated_tickets_only_forward() {
+    use chrono::NaiveDate;
+    use project_management::db::nudge_due;
+
+    let today = NaiveDate::from_ymd_opt(2030, 1, 31).unwrap();
+    let due = NaiveDate::from_ymd_opt(2030, 2, 27);
+    assert_eq!(
+        nudge_due(due, 1, today),
+        NaiveDate::from_ymd_opt(2030, 2, 28)
+    );
+    assert_eq!(
+        nudge_due(due, 7, today),
+        NaiveDate::from_ymd_opt(2030, 3, 6)
+    );
+    assert_eq!(
+        nudge_due(due, -7, today),
+        NaiveDate::from_ymd_opt(2030, 2, 20)
+    );
+    assert_eq!(nudge_due(None, 1, today), Some(today));
+    assert_eq!(nudge_due(None, 7, today), Some(today));
+    assert_eq!(nudge_due(None, -1, today), None);
+
+    let keymap = Keymap::default();
+    assert_eq!(
+        keymap.action(KeyContext::TaskList, '+'),
+        Some(Action::DueLater)
+    );
+    assert_eq!(
+        keymap.action(KeyContext::TaskList, '<'),
+        Some(Action::DueEarlierWeek)
+    );
+    assert_eq!(
+        keymap.action(KeyContext::Detail, '+'),
+        Some(Action::DetailAddTags)
+    );
+}
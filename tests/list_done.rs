@@ -0,0 +1,72 @@
+//! `pm list` visibility of completed tasks: `--done-only` / `--completed`
+//! and the implicit `--all` behind `--status done`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-list-done-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn done_tasks_are_listed_when_asked_for() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "still open"]);
+    pm(&pm_dir, &["add", "finished"]);
+    pm(&pm_dir, &["complete", "TSK2"]);
+
+    let default = pm(&pm_dir, &["list"]);
+    assert!(default.contains("still open") && !default.contains("finished"));
+
+    for args in [
+        &["list", "--status", "done"][..],
+        &["list", "--done-only"],
+        &["list", "--completed", "--all"],
+    ] {
+        let out = pm(&pm_dir, args);
+        assert!(out.contains("finished"), "{args:?}:\n{out}");
+        assert!(!out.contains("still open"), "{args:?}:\n{out}");
+    }
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn status_done_without_all_returns_done_tasks() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "shipped"]);
+    pm(&pm_dir, &["complete", "TSK1"]);
+
+    let out = pm(&pm_dir, &["list", "--status", "done"]);
+    assert!(out.contains("TSK1") && out.contains("shipped"), "{out}");
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
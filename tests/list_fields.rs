@@ -0,0 +1,118 @@
+//! `pm list --fields` and `--format`: column selection, ordering and the
+//! tab-separated plain output.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use project_management::db::{list_columns, DEFAULT_LIST_COLUMNS};
+use project_management::fields::ListColumn;
+
+#[test]
+fn default_columns_apply_without_fields() {
+    assert_eq!(list_columns(&[], false), DEFAULT_LIST_COLUMNS);
+}
+
+#[test]
+fn breadcrumb_adds_parents_before_the_title_once() {
+    let cols = list_columns(&[ListColumn::Id, ListColumn::Title], true);
+    assert_eq!(
+        cols,
+        vec![ListColumn::Id, ListColumn::Parents, ListColumn::Title]
+    );
+    let explicit = list_columns(&[ListColumn::Parents, ListColumn::Id], true);
+    assert_eq!(explicit, vec![ListColumn::Parents, ListColumn::Id]);
+}
+
+#[test]
+fn repeated_fields_keep_the_first_position() {
+    let cols = list_columns(
+        &[ListColumn::Title, ListColumn::Id, ListColumn::Title],
+        false,
+    );
+    assert_eq!(cols, vec![ListColumn::Title, ListColumn::Id]);
+}
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-list-fields-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+#[test]
+fn fields_select_and_order_printed_columns() {
+    let pm_dir = tmp_pm_dir();
+    assert!(pm(&pm_dir, &["init"]).status.success());
+    assert!(pm(&pm_dir, &["add", "Write docs", "--tag", "docs"])
+        .status
+        .success());
+
+    let out = pm(&pm_dir, &["list", "--fields", "title,id"]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert!(lines[0].starts_with("Title [tags]") && lines[0].ends_with("ID"));
+    assert!(lines[1].starts_with("Write docs [docs]") && lines[1].ends_with("TSK1"));
+
+    let out = pm(&pm_dir, &["list", "--fields", "id,tags,title"]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.lines().nth(1).unwrap().ends_with(" Write docs"));
+
+    let bad = pm(&pm_dir, &["list", "--fields", "id,owner"]);
+    assert!(!bad.status.success());
+    assert!(String::from_utf8_lossy(&bad.stderr).contains("possible values"));
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn plain_format_prints_untruncated_tab_separated_fields() {
+    let pm_dir = tmp_pm_dir();
+    let title = "A title long enough to be cut off in the forty column table";
+    assert!(pm(&pm_dir, &["init"]).status.success());
+    assert!(pm(
+        &pm_dir,
+        &["add", title, "--due", "2030-01-02", "--tag", "x"]
+    )
+    .status
+    .success());
+
+    let args = ["list", "--fields", "id,due,title,id", "--format", "plain"];
+    let out = pm(&pm_dir, &args);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines,
+        vec!["ID\tDue\tTitle", &format!("TSK1\t2030-01-02\t{title}")]
+    );
+
+    let mut no_header = args.to_vec();
+    no_header.push("--no-header");
+    let out = pm(&pm_dir, &no_header);
+    assert_eq!(
+        String::from_utf8_lossy(&out.stdout),
+        format!("TSK1\t2030-01-02\t{title}\n")
+    );
+
+    // The table keeps its header unless asked otherwise.
+    let out = pm(&pm_dir, &["list", "--no-header"]);
+    assert!(!String::from_utf8_lossy(&out.stdout).contains("Title"));
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,144 @@
+//! `pm list --missing FIELD` / `--has FIELD`: backlog-hygiene filters. Blank
+//! text counts as missing, the same as no value at all.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use project_management::db::field_present;
+use project_management::fields::PresenceField;
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+use project_management::task::Task;
+
+mod common;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-list-hygiene-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn listed_ids(pm_dir: &Path, filter: &[&str]) -> Vec<String> {
+    let mut args = vec!["list", "--sort", "id", "--format", "plain", "--no-header"];
+    args.extend_from_slice(filter);
+    pm(pm_dir, &args)
+        .lines()
+        .filter_map(|line| line.split('\t').next())
+        .map(str::to_string)
+        .collect()
+}
+
+fn task() -> Task {
+    Task {
+        ..common::task(LeafId::new(TypePrefix::Task, 1), "t")
+    }
+}
+
+#[test]
+fn missing_due_lists_only_undated_tasks() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Dated", "--due", "in 3d"]);
+    pm(&pm_dir, &["add", "Undated"]);
+
+    assert_eq!(listed_ids(&pm_dir, &["--missing", "due"]), ["TSK2"]);
+    assert_eq!(listed_ids(&pm_dir, &["--has", "due"]), ["TSK1"]);
+}
+
+#[test]
+fn missing_description_treats_blank_text_as_missing() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Described", "--desc", "why it matters"]);
+    pm(&pm_dir, &["add", "Blank", "--desc", "   "]);
+    pm(&pm_dir, &["add", "Bare"]);
+
+    assert_eq!(
+        listed_ids(&pm_dir, &["--missing", "description"]),
+        ["TSK2", "TSK3"]
+    );
+    assert_eq!(listed_ids(&pm_dir, &["--has", "description"]), ["TSK1"]);
+}
+
+#[test]
+fn has_issue_link_and_repeated_missing_combine_as_and() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(
+        &pm_dir,
+        &["add", "Linked", "--issue-link", "https://example.com/1"],
+    );
+    pm(&pm_dir, &["add", "Unlinked", "--desc", "has words"]);
+    pm(&pm_dir, &["add", "Empty"]);
+
+    assert_eq!(listed_ids(&pm_dir, &["--has", "issue-link"]), ["TSK1"]);
+    assert_eq!(
+        listed_ids(
+            &pm_dir,
+            &["--missing", "issue-link", "--missing", "description"]
+        ),
+        ["TSK3"]
+    );
+    assert_eq!(
+        listed_ids(&pm_dir, &["--missing", "issue-link,description"]),
+        ["TSK3"]
+    );
+    assert!(listed_ids(&pm_dir, &["--has", "issue-link", "--has", "description"]).is_empty());
+}
+
+#[test]
+fn text_fields_count_empty_and_whitespace_as_missing() {
+    let no_locks = HashMap::new();
+    let mut t = task();
+    assert!(!field_present(&t, PresenceField::Description, &no_locks));
+    t.description = Some(String::new());
+    assert!(!field_present(&t, PresenceField::Description, &no_locks));
+    t.description = Some(" \n".to_string());
+    assert!(!field_present(&t, PresenceField::Description, &no_locks));
+    t.description = Some("x".to_string());
+    assert!(field_present(&t, PresenceField::Description, &no_locks));
+
+    t.issue_link = Some(String::new());
+    assert!(!field_present(&t, PresenceField::IssueLink, &no_locks));
+    t.issue_link = Some("https://example.com/1".to_string());
+    assert!(field_present(&t, PresenceField::IssueLink, &no_locks));
+
+    t.tags = vec![String::new()];
+    assert!(!field_present(&t, PresenceField::Tags, &no_locks));
+    t.tags.push("infra".to_string());
+    assert!(field_present(&t, PresenceField::Tags, &no_locks));
+}
+
+#[test]
+fn assignee_comes_from_the_live_checkout_map() {
+    let t = task();
+    assert!(!field_present(&t, PresenceField::Assignee, &HashMap::new()));
+    let held = HashMap::from([(t.id, "claude-a".to_string())]);
+    assert!(field_present(&t, PresenceField::Assignee, &held));
+    assert!(!field_present(&t, PresenceField::Due, &held));
+}
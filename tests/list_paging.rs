@@ -0,0 +1,143 @@
+//! `pm list --offset M --limit N`: pages are cut after filtering and
+//! sorting, and a footer gives the rows shown out of the total: under the
+//! table, or on stderr for `--format plain`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-list-paging-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+/// stdout and stderr of a successful run.
+fn run(pm_dir: &Path, args: &[&str]) -> (String, String) {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    (
+        String::from_utf8_lossy(&out.stdout).into_owned(),
+        String::from_utf8_lossy(&out.stderr).into_owned(),
+    )
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    run(pm_dir, args).0
+}
+
+/// Seven tasks, TSK1..TSK7, with `later` tagged on the odd ones.
+fn workspace() -> PathBuf {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    for n in 1..=7 {
+        let title = format!("Task {n}");
+        let mut args = vec!["add", title.as_str()];
+        if n % 2 == 1 {
+            args.extend(["--tag", "later"]);
+        }
+        pm(&pm_dir, &args);
+    }
+    pm_dir
+}
+
+fn ids(out: &str) -> Vec<&str> {
+    out.lines()
+        .filter(|line| line.starts_with("TSK"))
+        .filter_map(|line| line.split('\t').next())
+        .collect()
+}
+
+#[test]
+fn offset_skips_sorted_rows_before_the_limit() {
+    let pm_dir = workspace();
+    let page = |offset: &str| {
+        run(
+            &pm_dir,
+            &[
+                "list", "--sort", "id", "--format", "plain", "--offset", offset, "--limit", "3",
+            ],
+        )
+    };
+
+    let (first, footer) = page("0");
+    assert_eq!(ids(&first), ["TSK1", "TSK2", "TSK3"]);
+    assert_eq!(footer, "Rows 1-3 of 7\n");
+    let (second, footer) = page("3");
+    assert_eq!(ids(&second), ["TSK4", "TSK5", "TSK6"]);
+    assert_eq!(footer, "Rows 4-6 of 7\n");
+    let (last, footer) = page("6");
+    assert_eq!(ids(&last), ["TSK7"]);
+    assert_eq!(footer, "Rows 7-7 of 7\n");
+    let (past, footer) = page("9");
+    assert!(ids(&past).is_empty(), "{past}");
+    assert_eq!(footer, "No rows past 9 of 7\n");
+}
+
+#[test]
+fn offset_counts_filtered_rows_only() {
+    let pm_dir = workspace();
+    let (out, footer) = run(
+        &pm_dir,
+        &[
+            "list", "--tag", "later", "--sort", "id", "--format", "plain", "--offset", "1",
+        ],
+    );
+    assert_eq!(ids(&out), ["TSK3", "TSK5", "TSK7"]);
+    assert_eq!(footer, "Rows 2-4 of 4\n");
+}
+
+#[test]
+fn footer_only_when_paging_and_not_with_no_header() {
+    let pm_dir = workspace();
+    let plain = pm(&pm_dir, &["list", "--sort", "id"]);
+    assert!(!plain.contains("Rows "), "{plain}");
+
+    let bare = pm(
+        &pm_dir,
+        &[
+            "list",
+            "--sort",
+            "id",
+            "--format",
+            "plain",
+            "--no-header",
+            "--limit",
+            "2",
+        ],
+    );
+    assert_eq!(bare.lines().count(), 2, "{bare}");
+    assert!(!bare.contains("Rows "), "{bare}");
+}
+
+#[test]
+fn plain_stdout_has_no_footer_and_the_table_keeps_it() {
+    let pm_dir = workspace();
+    let (plain, _) = run(
+        &pm_dir,
+        &["list", "--sort", "id", "--format", "plain", "--limit", "2"],
+    );
+    assert_eq!(plain.lines().count(), 3, "{plain}");
+    assert!(!plain.contains("Rows "), "{plain}");
+
+    let (table, stderr) = run(&pm_dir, &["list", "--sort", "id", "--limit", "2"]);
+    assert!(table.ends_with("Rows 1-2 of 7\n"), "{table}");
+    assert!(!stderr.contains("Rows "), "{stderr}");
+}
@@ -0,0 +1,33 @@
+//! Case-insensitive match ranges used to highlight `/` filter hits in the
+//! TUI ticket list.
+
+use project_management::tui::utils::match_ranges;
+
+#[test]
+fn finds_every_occurrence_ignoring_case() {
+    let title = "Fix login; LOGIN page logs";
+    let hits: Vec<&str> = match_ranges(title, "login")
+        .into_iter()
+        .map(|(s, e)| &title[s..e])
+        .collect();
+    assert_eq!(hits, vec!["login", "LOGIN"]);
+}
+
+#[test]
+fn matches_do_not_overlap_and_empty_needles_match_nothing() {
+    assert_eq!(match_ranges("aaaa", "aa"), vec![(0, 2), (2, 4)]);
+    assert!(match_ranges("anything", "").is_empty());
+    assert!(match_ranges("short", "longer needle").is_empty());
+}
+
+#[test]
+fn ranges_fall_on_char_boundaries() {
+    let title = "Über café CAFÉ";
+    let ranges = match_ranges(title, "café");
+    assert_eq!(ranges.len(), 2);
+    for (s, e) in ranges {
+        assert!(title.is_char_boundary(s) && title.is_char_boundary(e));
+        assert_eq!(title[s..e].to_lowercase(), "café");
+    }
+    assert_eq!(match_ranges(title, "über"), vec![(0, "Über".len())]);
+}
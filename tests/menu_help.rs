@@ -0,0 +1,29 @@
+//! The guide on `pm menu`'s Help screen and its type-to-search.
+
+use project_management::tui::menu::help_lines;
+
+#[test]
+fn empty_search_shows_the_whole_guide() {
+    let all = help_lines("");
+    for heading in [
+        "Getting around this menu",
+        "Projects",
+        "The hierarchy",
+        "The workflow board",
+    ] {
+        assert!(all.contains(&heading), "missing {heading}");
+    }
+    assert!(all.iter().any(|line| line.contains("pm add")));
+}
+
+#[test]
+fn search_keeps_matching_lines_ignoring_case() {
+    let board = help_lines("WORKFLOW");
+    assert!(!board.is_empty());
+    assert!(board
+        .iter()
+        .all(|line| line.to_lowercase().contains("workflow")));
+    assert!(board.len() < help_lines("").len());
+
+    assert!(help_lines("no such words here").is_empty());
+}
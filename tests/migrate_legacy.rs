@@ -0,0 +1,141 @@
+//! `pm migrate-legacy`: the legacy `tasks.json` workspace becomes a named
+//! project with its tasks and templates, the original moves under
+//! `.legacy-backup/`, running it again changes nothing, and a run that
+//! stopped part-way is finished.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_repo() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-migrate-legacy-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(dir.join(".pm")).unwrap();
+    dir
+}
+
+fn run(db: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(db)
+        .args(args)
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(db: &Path, args: &[&str]) -> String {
+    let out = run(db, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+/// A workspace whose only project is a legacy `tasks.json` with two tasks
+/// and a template.
+fn legacy_workspace() -> PathBuf {
+    let repo = tmp_repo();
+    let legacy = repo.join(".pm/tasks.json");
+    pm(&legacy, &["init"]);
+    pm(&legacy, &["add", "--kind", "epic", "Old epic"]);
+    pm(&legacy, &["add", "Old task", "--parent", "EPC1"]);
+    pm(&legacy, &["template", "create", "bug", "--tags", "bug"]);
+    repo
+}
+
+#[test]
+fn migrates_into_a_named_project_and_backs_up_the_original() {
+    let repo = legacy_workspace();
+    let pm_dir = repo.join(".pm");
+
+    let out = pm(&pm_dir, &["migrate-legacy", "--name", "Old Work"]);
+    assert!(
+        out.contains("Migrated 2 tasks and 1 templates into project 'old_work'"),
+        "{out}"
+    );
+    assert!(!pm_dir.join("tasks.json").exists());
+    let backups: Vec<_> = fs::read_dir(pm_dir.join(".legacy-backup"))
+        .unwrap()
+        .flatten()
+        .collect();
+    assert_eq!(backups.len(), 1);
+
+    let project = pm_dir.join("old_work_tasks.json");
+    let view = pm(&project, &["view", "TSK1"]);
+    assert!(view.contains("Old task"), "{view}");
+    assert!(pm(&project, &["template", "list"]).contains("bug"));
+
+    let again = pm(&pm_dir, &["migrate-legacy", "--name", "Old Work"]);
+    assert!(again.contains("nothing to migrate"), "{again}");
+    assert!(pm(&project, &["list"]).contains("Old epic"));
+    fs::remove_dir_all(&repo).ok();
+}
+
+#[test]
+fn never_overwrites_an_existing_project() {
+    let repo = legacy_workspace();
+    let pm_dir = repo.join(".pm");
+    let taken = pm_dir.join("default_tasks.json");
+    pm(&taken, &["init"]);
+    pm(&taken, &["add", "Already here"]);
+
+    let out = run(&pm_dir, &["migrate-legacy"]);
+    assert!(!out.status.success());
+    let err = String::from_utf8_lossy(&out.stderr);
+    assert!(err.contains("already exists"), "{err}");
+    assert!(pm_dir.join("tasks.json").is_dir());
+    let list = pm(&taken, &["list"]);
+    assert!(
+        list.contains("Already here") && !list.contains("Old task"),
+        "{list}"
+    );
+    fs::remove_dir_all(&repo).ok();
+}
+
+#[test]
+fn a_run_that_stopped_before_the_backup_is_finished() {
+    let repo = legacy_workspace();
+    let pm_dir = repo.join(".pm");
+    // Where an interrupted run stops: the copy has the project's name, the
+    // original is still in place.
+    let project = pm_dir.join("old_work_tasks.json");
+    let copy = Command::new("cp")
+        .arg("-r")
+        .arg(pm_dir.join("tasks.json"))
+        .arg(&project)
+        .status()
+        .unwrap();
+    assert!(copy.success());
+
+    let out = pm(&pm_dir, &["migrate-legacy", "--name", "Old Work"]);
+    assert!(out.contains("Migrated 2 tasks"), "{out}");
+    assert!(!pm_dir.join("tasks.json").exists());
+    assert_eq!(
+        fs::read_dir(pm_dir.join(".legacy-backup")).unwrap().count(),
+        1
+    );
+    assert!(pm(&project, &["list"]).contains("Old epic"));
+    fs::remove_dir_all(&repo).ok();
+}
+
+#[test]
+fn a_v09_task_file_is_left_to_doctor() {
+    let repo = tmp_repo();
+    let pm_dir = repo.join(".pm");
+    fs::write(pm_dir.join("tasks.json"), "[]").unwrap();
+
+    let out = run(&pm_dir, &["migrate-legacy"]);
+    assert!(!out.status.success());
+    let err = String::from_utf8_lossy(&out.stderr);
+    assert!(err.contains("pm doctor --migrate"), "{err}");
+    assert!(pm_dir.join("tasks.json").is_file());
+    fs::remove_dir_all(&repo).ok();
+}
@@ -0,0 +1,73 @@
+//! `pm milestones`: timeline order and readiness counts.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-milestones-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn timeline_counts_work_due_before_each_milestone() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    assert!(pm(&pm_dir, &["milestones"]).starts_with("No milestones yet."));
+
+    let add = |title: &str, extra: &[&str]| {
+        let mut args = vec!["add", title];
+        args.extend_from_slice(extra);
+        pm(&pm_dir, &args);
+    };
+    add("GA", &["--kind", "milestone", "--due", "2030-06-01"]);
+    add(
+        "Beta Launch",
+        &["--kind", "milestone", "--due", "2030-03-01"],
+    );
+    add("Someday", &["--kind", "milestone"]);
+    add("Spec", &["--due", "2030-01-10"]);
+    add("Build", &["--due", "2030-02-20"]);
+    add("Docs", &["--due", "2030-03-01"]);
+    add("Polish", &["--due", "2030-05-01"]);
+    add("Dropped", &["--due", "2030-01-05"]);
+    pm(&pm_dir, &["complete", "TSK1"]);
+    pm(&pm_dir, &["set-status", "TSK5", "cancelled"]);
+
+    let out = pm(&pm_dir, &["milestones"]);
+    let rows: Vec<&str> = out.lines().skip(1).collect();
+    assert_eq!(
+        rows,
+        [
+            "2030-03-01 MLS2     Open         1/2 done before 'Beta Launch'",
+            "2030-06-01 MLS1     Open         1/4 done before 'GA'",
+            "-          MLS3     Open         no date set for 'Someday'",
+        ],
+        "{out}"
+    );
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,128 @@
+//! `pm add --after`/`--before`: manual sibling order via fractional positions.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use project_management::db::positions_beside;
+use project_management::fields::Kind;
+use project_management::store::LeafId;
+use project_management::task::Task;
+
+mod common;
+
+fn task(id: &str, parent: Option<&str>, order: Option<f64>) -> Task {
+    Task {
+        parent: parent.map(|p| p.parse::<LeafId>().unwrap()),
+        order,
+        kind: Kind::Task,
+        ..common::task(id.parse().unwrap(), id)
+    }
+}
+
+#[test]
+fn positions_split_the_gap_to_the_neighbour() {
+    let tasks = vec![
+        task("TSK1", Some("EPC1"), None),
+        task("TSK2", Some("EPC1"), None),
+        task("TSK3", Some("EPC1"), Some(1.5)),
+        // Not a sibling: a different parent.
+        task("TSK4", None, Some(1.2)),
+    ];
+    assert_eq!(positions_beside(&tasks, &tasks[0], false, 1), vec![1.25]);
+    assert_eq!(positions_beside(&tasks, &tasks[1], true, 1), vec![1.75]);
+    assert_eq!(
+        positions_beside(&tasks, &tasks[1], false, 2),
+        vec![3.0, 4.0]
+    );
+    assert_eq!(positions_beside(&tasks, &tasks[0], true, 1), vec![0.0]);
+}
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-ordered-insert-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn inserted_tasks_land_beside_the_reference() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Checklist", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "first", "--parent", "EPC1"]);
+    pm(&pm_dir, &["add", "third", "--parent", "EPC1"]);
+    pm(&pm_dir, &["add", "second", "--after", "TSK1"]);
+    pm(&pm_dir, &["add", "zeroth", "--before", "first"]);
+    pm(&pm_dir, &["add", "between", "--before", "TSK2"]);
+
+    let out = pm(
+        &pm_dir,
+        &[
+            "list",
+            "--flat-tree",
+            "--format",
+            "plain",
+            "--no-header",
+            "--fields",
+            "id,title",
+        ],
+    );
+    assert_eq!(
+        out,
+        "EPC1\tChecklist\nTSK4\tzeroth\nTSK1\tfirst\nTSK3\tsecond\nTSK5\tbetween\nTSK2\tthird\n"
+    );
+    // The inserted task took the reference's parent.
+    assert!(pm(&pm_dir, &["view", "TSK3"]).contains("Parent:       EPC1"));
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn both_anchors_or_a_missing_reference_are_errors() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "one"]);
+    assert!(!run(
+        &pm_dir,
+        &["add", "x", "--after", "TSK1", "--before", "TSK1"]
+    )
+    .status
+    .success());
+    let out = run(&pm_dir, &["add", "x", "--after", "TSK9"]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("TSK9 not found"));
+    assert_eq!(
+        pm(
+            &pm_dir,
+            &["list", "--format", "plain", "--no-header", "--fields", "id"]
+        ),
+        "TSK1\n"
+    );
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,68 @@
+//! `pm list --parent-status`: keep tasks by their parent's status, with
+//! parentless tasks left out unless `--include-rootless` is given.
+
+use project_management::db::{parent_status_matches, Database};
+use project_management::fields::{Kind, Status};
+use project_management::store::id::TypePrefix;
+use project_management::store::{LeafId, State};
+use project_management::task::Task;
+
+mod common;
+
+fn task(id: LeafId, parent: Option<LeafId>, status: Status) -> Task {
+    Task {
+        parent,
+        kind: Kind::Task,
+        status,
+        ..common::task(id, &id.to_string())
+    }
+}
+
+fn matching(db: &Database, status: Status, include_rootless: bool) -> Vec<LeafId> {
+    db.tasks
+        .iter()
+        .filter(|t| parent_status_matches(db, t, status, include_rootless))
+        .map(|t| t.id)
+        .collect()
+}
+
+#[test]
+fn filters_on_the_parent_status() {
+    let done_epic = LeafId::new(TypePrefix::Epic, 1);
+    let active_epic = LeafId::new(TypePrefix::Epic, 2);
+    let leftover = LeafId::new(TypePrefix::Task, 1);
+    let active = LeafId::new(TypePrefix::Task, 2);
+    let db = Database {
+        tasks: vec![
+            task(done_epic, None, Status::Done),
+            task(active_epic, None, Status::InProgress),
+            task(leftover, Some(done_epic), Status::Open),
+            task(active, Some(active_epic), Status::Open),
+        ],
+        state: State::fresh(),
+    };
+
+    assert_eq!(matching(&db, Status::Done, false), vec![leftover]);
+    assert_eq!(matching(&db, Status::InProgress, false), vec![active]);
+    assert!(matching(&db, Status::Blocked, false).is_empty());
+}
+
+#[test]
+fn rootless_tasks_need_the_opt_in() {
+    let root = LeafId::new(TypePrefix::Task, 1);
+    let dangling = LeafId::new(TypePrefix::Task, 2);
+    let db = Database {
+        tasks: vec![
+            task(root, None, Status::Open),
+            task(
+                dangling,
+                Some(LeafId::new(TypePrefix::Epic, 9)),
+                Status::Open,
+            ),
+        ],
+        state: State::fresh(),
+    };
+
+    assert!(matching(&db, Status::Done, false).is_empty());
+    assert_eq!(matching(&db, Status::Done, true), vec![root, dangling]);
+}
@@ -13,13 +13,15 @@ use std::fs;
 use std::path::PathBuf;
 
 use project_management::db::{kind_to_prefix, Database};
-use project_management::fields::{Kind, Status};
+use project_management::fields::Kind;
 use project_management::store::claude_md::CLAUDE_MD;
 use project_management::store::layout::Layout;
 use project_management::store::state::State;
 use project_management::store::LeafId;
 use project_management::task::Task;
 
+mod common;
+
 fn tmp_pm_dir() -> PathBuf {
     let dir = std::env::temp_dir().join(format!(
         "pm-phase4-{}-{}",
@@ -35,28 +37,9 @@ fn tmp_pm_dir() -> PathBuf {
 
 fn fresh_task(id: LeafId, title: &str, parent: Option<LeafId>, kind: Kind) -> Task {
     Task {
-        id,
-        title: title.to_string(),
-        summary: None,
-        description: None,
-        user_story: None,
-        requirements: None,
-        tags: Vec::new(),
-        deps: Vec::new(),
-        milestone: None,
-        memories: Vec::new(),
-        due: None,
         parent,
         kind,
-        status: Status::Open,
-        priority_level: None,
-        urgency: None,
-        process_stage: None,
-        issue_link: None,
-        pr_link: None,
-        artifacts: Vec::new(),
-        created_at_utc: 0,
-        updated_at_utc: 0,
+        ..common::task(id, title)
     }
 }
 
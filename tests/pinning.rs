@@ -0,0 +1,92 @@
+//! `pm pin` / `pm unpin`: pinned tasks list first, starred, and survive edits.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-pinning-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn listed_ids(out: &str) -> Vec<String> {
+    out.lines()
+        .filter_map(|line| line.split('\t').next())
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn pinned_tasks_list_first_under_any_sort() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    for title in ["Alpha", "Bravo", "Charlie"] {
+        pm(&pm_dir, &["add", title]);
+    }
+    pm(&pm_dir, &["pin", "TSK3"]);
+
+    for sort in ["id", "created", "priority"] {
+        let out = pm(
+            &pm_dir,
+            &["list", "--sort", sort, "--format", "plain", "--no-header"],
+        );
+        assert_eq!(listed_ids(&out)[0], "TSK3", "sort {sort}: {out}");
+    }
+
+    let table = pm(&pm_dir, &["list", "--sort", "id"]);
+    assert!(table.contains("★ Charlie"), "{table}");
+    assert!(!table.contains("★ Alpha"), "{table}");
+
+    let pinned = pm(
+        &pm_dir,
+        &["list", "--pinned", "--format", "plain", "--no-header"],
+    );
+    assert_eq!(listed_ids(&pinned), ["TSK3"]);
+
+    pm(&pm_dir, &["unpin", "TSK3"]);
+    let out = pm(
+        &pm_dir,
+        &["list", "--sort", "id", "--format", "plain", "--no-header"],
+    );
+    assert_eq!(listed_ids(&out), ["TSK1", "TSK2", "TSK3"]);
+}
+
+#[test]
+fn pins_survive_edits() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Alpha"]);
+    pm(&pm_dir, &["pin", "TSK1"]);
+    pm(&pm_dir, &["update", "TSK1", "--title", "Alpha prime"]);
+    pm(&pm_dir, &["set-status", "TSK1", "in-progress"]);
+
+    let pinned = pm(
+        &pm_dir,
+        &["list", "--pinned", "--format", "plain", "--no-header"],
+    );
+    assert_eq!(listed_ids(&pinned), ["TSK1"]);
+}
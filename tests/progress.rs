@@ -0,0 +1,57 @@
+//! The "Processing project 3/12…" counter shown while a command walks every
+//! project. It only draws on a terminal, so piped runs stay clean.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use project_management::output::progress_line;
+
+fn tmp_repo() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-progress-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn pm(repo: &Path, args: &[&str]) -> Output {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .args(args)
+        .current_dir(repo)
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    out
+}
+
+#[test]
+fn progress_text_counts_projects() {
+    assert_eq!(progress_line(3, 12), "Processing project 3/12…");
+}
+
+#[test]
+fn export_all_projects_draws_nothing_when_piped() {
+    let repo = tmp_repo();
+    pm(&repo, &["init"]);
+    pm(&repo, &["--db", ".pm/web_tasks.json", "add", "Web thing"]);
+    pm(&repo, &["--db", ".pm/app_tasks.json", "add", "App thing"]);
+
+    let out = pm(&repo, &["export", "--all-projects", "-o", "all.csv"]);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(!stderr.contains("Processing"), "{stderr}");
+    let csv = fs::read_to_string(repo.join("all.csv")).unwrap();
+    assert!(
+        csv.contains("Web thing") && csv.contains("App thing"),
+        "{csv}"
+    );
+}
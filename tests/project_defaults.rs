@@ -0,0 +1,99 @@
+//! Per-project add defaults from `.pm/config.json` and their precedence
+//! against explicit flags.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-project-defaults-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn row<'a>(listing: &'a str, id: &str) -> &'a str {
+    listing
+        .lines()
+        .find(|l| l.starts_with(id))
+        .unwrap_or_else(|| panic!("no row for {id} in:\n{listing}"))
+}
+
+#[test]
+fn defaults_fill_unset_fields_and_flags_win() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "--kind", "project", "Website"]);
+    pm(
+        &pm_dir,
+        &["add", "--kind", "product", "Site", "--parent", "PRJ1"],
+    );
+    pm(
+        &pm_dir,
+        &["add", "--kind", "epic", "Launch", "--parent", "PRD1"],
+    );
+    fs::write(
+        pm_dir.join("config.json"),
+        r#"{ "projects": { "website": { "defaults": {
+            "kind": "subtask", "priority": "must-have", "tags": ["sprint-3"]
+        } } } }"#,
+    )
+    .unwrap();
+
+    // Subtask cannot sit under an epic, so the default kind is skipped.
+    pm(&pm_dir, &["add", "Defaults", "--parent", "EPC1"]);
+    pm(
+        &pm_dir,
+        &[
+            "add",
+            "Flags",
+            "--parent",
+            "EPC1",
+            "--tag",
+            "bug",
+            "--priority-level",
+            "cut-first",
+        ],
+    );
+    pm(&pm_dir, &["add", "Nested", "--parent", "TSK1"]);
+    pm(&pm_dir, &["add", "Loose"]);
+
+    let listing = pm(&pm_dir, &["list", "--fields", "id,kind,priority,tags"]);
+    assert!(row(&listing, "TSK1").contains("Must Have"));
+    assert!(row(&listing, "TSK1").contains("sprint-3"));
+    let flagged = row(&listing, "TSK2");
+    assert!(flagged.contains("Cut First") && flagged.contains("bug"));
+    assert!(!flagged.contains("sprint-3"));
+    assert!(row(&listing, "SBT1").contains("Subtask"));
+    assert!(row(&listing, "TSK3").ends_with('-'));
+
+    let projects = pm(&pm_dir, &["projects", "-v"]);
+    assert!(
+        projects.contains("kind Subtask, priority Must Have, tags sprint-3"),
+        "{projects}"
+    );
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,83 @@
+//! `pm projects -v`: after the Project tickets of the current database
+//! comes the list of project files in the workspace, with task counts,
+//! last change and path, each section labelled with what it counts.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-project-files-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(db: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(db)
+        .args(args)
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn row<'a>(out: &'a str, name: &str) -> &'a str {
+    out.lines()
+        .find(|line| line.starts_with(name))
+        .unwrap_or_else(|| panic!("no row for {name} in {out}"))
+}
+
+#[test]
+fn verbose_lists_project_files_with_counts_and_paths() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    let web = pm_dir.join("web_tasks.json");
+    pm(&web, &["init"]);
+    pm(&web, &["add", "Open one"]);
+    pm(&web, &["add", "Done one"]);
+    pm(&web, &["complete", "TSK2"]);
+    pm(&pm_dir.join("mobile_app_tasks.json"), &["init"]);
+
+    let out = pm(&pm_dir, &["projects", "-v"]);
+    assert!(
+        out.contains("Tasks in this database by Project ticket:"),
+        "{out}"
+    );
+    assert!(out.contains("Project files in "), "{out}");
+    let web_row = row(&out, "web ");
+    let cells: Vec<&str> = web_row.split_whitespace().collect();
+    assert_eq!(cells[..3], ["web", "2", "1"], "{web_row}");
+    assert!(web_row.ends_with(&web.display().to_string()), "{web_row}");
+    let mobile: Vec<&str> = row(&out, "mobile app").split_whitespace().collect();
+    assert_eq!(mobile[2..4], ["0", "0"], "{out}");
+
+    // Run against one project file, the listing is of its siblings.
+    let from_web = pm(&web, &["projects", "-v"]);
+    assert!(from_web.contains("mobile app"), "{from_web}");
+
+    let plain = pm(&pm_dir, &["projects"]);
+    assert!(!plain.contains("Project files"), "{plain}");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn verbose_says_when_there_are_no_project_files() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    let out = pm(&pm_dir, &["projects", "-v"]);
+    assert!(out.contains("No project files in "), "{out}");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,108 @@
+//! The global `pm --project NAME` flag: commands run against the named
+//! project's database inside the workspace, found by name or unique prefix,
+//! and an explicit `--db` wins over it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_repo() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-project-flag-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Run `pm` in `repo` without `--db`, so the workspace is discovered.
+fn pm_raw(repo: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .args(args)
+        .current_dir(repo)
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(repo: &Path, args: &[&str]) -> String {
+    let out = pm_raw(repo, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn pm_err(repo: &Path, args: &[&str]) -> String {
+    let out = pm_raw(repo, args);
+    assert!(!out.status.success(), "pm {args:?} unexpectedly succeeded");
+    String::from_utf8_lossy(&out.stderr).into_owned()
+}
+
+/// A workspace holding a `web_app` and a `mobile` project, one task each.
+fn workspace_with_projects() -> PathBuf {
+    let repo = tmp_repo();
+    pm(&repo, &["init"]);
+    pm(&repo, &["add", "Workspace chore"]);
+    pm(
+        &repo,
+        &["--db", ".pm/web_app_tasks.json", "add", "Ship the web app"],
+    );
+    pm(
+        &repo,
+        &[
+            "--db",
+            ".pm/mobile_tasks.json",
+            "add",
+            "Ship the mobile app",
+        ],
+    );
+    repo
+}
+
+#[test]
+fn project_flag_targets_the_named_project() {
+    let repo = workspace_with_projects();
+
+    let web = pm(&repo, &["--project", "web app", "list"]);
+    assert!(web.contains("Ship the web app"), "{web}");
+    assert!(!web.contains("mobile"), "{web}");
+    assert!(!web.contains("Workspace chore"), "{web}");
+
+    pm(&repo, &["--project", "mob", "add", "Push notifications"]);
+    let mobile = pm(&repo, &["--db", ".pm/mobile_tasks.json", "list"]);
+    assert!(mobile.contains("Push notifications"), "{mobile}");
+    let main = pm(&repo, &["list"]);
+    assert!(!main.contains("Push notifications"), "{main}");
+}
+
+#[test]
+fn unknown_and_ambiguous_names_are_errors() {
+    let repo = workspace_with_projects();
+    pm(
+        &repo,
+        &["--db", ".pm/web_api_tasks.json", "add", "Rate limits"],
+    );
+
+    let missing = pm_err(&repo, &["--project", "desktop", "list"]);
+    assert!(missing.contains("No project named 'desktop'"), "{missing}");
+    assert!(missing.contains("mobile, web_api, web_app"), "{missing}");
+
+    let ambiguous = pm_err(&repo, &["--project", "web", "list"]);
+    assert!(ambiguous.contains("ambiguous"), "{ambiguous}");
+    assert!(ambiguous.contains("web_api, web_app"), "{ambiguous}");
+}
+
+#[test]
+fn db_wins_over_project() {
+    let repo = workspace_with_projects();
+
+    let out = pm(&repo, &["--db", ".pm", "--project", "web_app", "list"]);
+    assert!(out.contains("Workspace chore"), "{out}");
+    assert!(!out.contains("Ship the web app"), "{out}");
+}
@@ -0,0 +1,100 @@
+//! Moving a ticket to another project, as the workflow board's `P` does:
+//! the subtree is renumbered into the target project, its artifacts go with
+//! it, and links from tickets left behind are dropped, as are the moved
+//! tickets' links to milestones that stay behind.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use project_management::db::Database;
+use project_management::project::{move_to_project, workspace_dir, Project};
+use project_management::store::LeafId;
+
+fn tmp_repo() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-project-move-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn pm(db: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(db)
+        .args(args)
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn id(raw: &str) -> LeafId {
+    raw.parse().unwrap()
+}
+
+#[test]
+fn workspace_dir_is_where_the_projects_live() {
+    let pm_dir = Path::new("repo/.pm");
+    assert_eq!(workspace_dir(pm_dir), pm_dir);
+    assert_eq!(workspace_dir(&pm_dir.join("mobile_tasks.json")), pm_dir);
+    assert_eq!(workspace_dir(&pm_dir.join("tasks.json")), pm_dir);
+}
+
+#[test]
+fn move_renumbers_the_subtree_into_the_target_project() {
+    let repo = tmp_repo();
+    let web = repo.join(".pm/web_tasks.json");
+    let mobile = repo.join(".pm/mobile_tasks.json");
+    pm(&web, &["init"]);
+    pm(&mobile, &["init"]);
+    pm(&mobile, &["add", "Existing mobile task"]);
+    pm(&mobile, &["add", "--kind", "milestone", "Mobile beta"]);
+    pm(&web, &["add", "--kind", "milestone", "Web launch"]);
+    pm(&web, &["add", "--kind", "epic", "Offline mode"]);
+    pm(&web, &["add", "Cache reads", "--parent", "EPC1"]);
+    pm(&web, &["add", "Stays behind"]);
+    pm(&web, &["dep", "TSK2", "needs", "TSK1"]);
+    pm(&web, &["milestone", "TSK1", "MLS1"]);
+    let notes = repo.join("notes.txt");
+    fs::write(&notes, "sync design").unwrap();
+    pm(&web, &["artifact", "add", "TSK1", notes.to_str().unwrap()]);
+
+    let mut db = Database::load(&web);
+    let target = Project::from_file(mobile.clone()).unwrap();
+    let report = move_to_project(&mut db, &web, id("EPC1"), &target).unwrap();
+    assert_eq!(
+        report.remapped,
+        vec![(id("EPC1"), id("EPC1")), (id("TSK1"), id("TSK2"))]
+    );
+    assert_eq!(report.dropped_milestones, 1);
+
+    let left = pm(&web, &["list"]);
+    assert!(left.contains("Stays behind"), "{left}");
+    assert!(!left.contains("Cache reads"), "{left}");
+    assert!(Database::load(&web)
+        .get(id("TSK2"))
+        .unwrap()
+        .deps
+        .is_empty());
+
+    let moved = Database::load(&mobile);
+    assert_eq!(moved.get(id("EPC1")).unwrap().title, "Offline mode");
+    let task = moved.get(id("TSK2")).unwrap();
+    assert_eq!(task.title, "Cache reads");
+    assert_eq!(task.parent, Some(id("EPC1")));
+    assert_eq!(task.milestone, None, "kept a link to the web milestone");
+    let artifacts = pm(&mobile, &["artifact", "list", "TSK2"]);
+    assert!(artifacts.contains("notes.txt"), "{artifacts}");
+    fs::remove_dir_all(&repo).ok();
+}
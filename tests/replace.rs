@@ -0,0 +1,167 @@
+//! `pm replace`: literal and regex substitution across task fields.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use project_management::db::replace_in_task;
+use project_management::fields::ReplaceField;
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+use project_management::task::Task;
+use regex::Regex;
+
+mod common;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-replace-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn task(title: &str, description: Option<&str>, tags: &[&str]) -> Task {
+    Task {
+        description: description.map(str::to_string),
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        ..common::task(LeafId::new(TypePrefix::Task, 1), title)
+    }
+}
+
+const ALL: [ReplaceField; 3] = [
+    ReplaceField::Title,
+    ReplaceField::Description,
+    ReplaceField::Tags,
+];
+
+#[test]
+fn literal_replacement_touches_only_the_chosen_fields() {
+    let pattern = Regex::new(&regex::escape("login")).unwrap();
+    let mut t = task("login form", Some("The login page"), &["login", "ui"]);
+    let (edits, n) = replace_in_task(&mut t, &pattern, "auth", true, &[ReplaceField::Title]);
+    assert_eq!((edits.len(), n), (1, 1));
+    assert_eq!(t.title, "auth form");
+    assert_eq!(t.description.as_deref(), Some("The login page"));
+
+    let (edits, n) = replace_in_task(&mut t, &pattern, "auth", true, &ALL);
+    assert_eq!((edits.len(), n), (2, 2));
+    assert_eq!(t.tags, vec!["auth", "ui"]);
+    assert_eq!(edits[1].before, "login,ui");
+}
+
+#[test]
+fn regex_replacement_expands_groups_and_merges_tags() {
+    let pattern = Regex::new(r"v(\d+)").unwrap();
+    let mut t = task("ship v2", None, &["v2", "release-v2"]);
+    let (_, n) = replace_in_task(&mut t, &pattern, "version-$1", false, &ALL);
+    assert_eq!(n, 3);
+    assert_eq!(t.title, "ship version-2");
+    assert_eq!(t.tags, vec!["release-version-2", "version-2"]);
+
+    // Without --regex, `$1` is inserted literally.
+    let pattern = Regex::new(&regex::escape("ship")).unwrap();
+    replace_in_task(&mut t, &pattern, "$1", true, &ALL);
+    assert_eq!(t.title, "$1 version-2");
+
+    let pattern = Regex::new("^ui$").unwrap();
+    let mut t = task("x", None, &["ui", "web"]);
+    replace_in_task(&mut t, &pattern, "web", false, &ALL);
+    assert_eq!(t.tags, vec!["web"]);
+}
+
+#[test]
+fn dry_run_saves_nothing_and_a_real_run_backs_up() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Login form", "--tag", "login"]);
+    pm(&pm_dir, &["add", "Logout"]);
+
+    let out = pm(&pm_dir, &["replace", "Login", "Auth", "--dry-run"]);
+    assert!(
+        out.contains(r#"TSK1 title: "Login form" -> "Auth form""#),
+        "{out}"
+    );
+    assert!(out.contains("1 replacement(s) in 1 task(s)"), "{out}");
+    assert!(pm(&pm_dir, &["view", "TSK1"]).contains("Title:        Login form"));
+
+    let out = pm(&pm_dir, &["replace", "(?i)log(in)?", "auth", "--regex"]);
+    assert!(out.contains("Made 3 replacement(s) in 2 task(s)"), "{out}");
+    assert!(out.contains("Created backup:"), "{out}");
+    let view = pm(&pm_dir, &["view", "TSK1"]);
+    assert!(view.contains("Title:        auth form"), "{view}");
+    assert!(view.contains("Tags:         auth"), "{view}");
+    assert!(pm(&pm_dir, &["view", "TSK2"]).contains("Title:        authout"));
+
+    assert!(!run(&pm_dir, &["replace", "(", "x", "--regex"])
+        .status
+        .success());
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn a_title_is_never_blanked() {
+    let pattern = Regex::new(&regex::escape("Fix login")).unwrap();
+    let mut t = task("Fix login", Some("Fix login flow"), &[]);
+    let (edits, n) = replace_in_task(&mut t, &pattern, "", true, &ALL);
+    assert_eq!(t.title, "Fix login");
+    assert_eq!(t.description.as_deref(), Some(" flow"));
+    assert_eq!(n, 1);
+    assert_eq!(edits.len(), 1);
+
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Fix login"]);
+    fs::write(
+        pm_dir.join("config.json"),
+        r#"{ "titles": { "max_length": 20 } }"#,
+    )
+    .unwrap();
+    let out = run(&pm_dir, &["replace", "Fix login", ""]);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("TSK1: the replacement would leave an empty title"),
+        "{stderr}"
+    );
+    assert!(String::from_utf8_lossy(&out.stdout).contains("No matches."));
+    assert!(pm(&pm_dir, &["view", "TSK1"]).contains("Title:        Fix login"));
+
+    let out = run(
+        &pm_dir,
+        &[
+            "replace",
+            "login",
+            "the login page for the web app",
+            "--no-backup",
+        ],
+    );
+    assert!(out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("TSK1: warning: title is"), "{stderr}");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,110 @@
+//! Without `--db`, pm uses the nearest `.pm/` at or above the current
+//! directory, then `~/.pm/`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn tmp_root() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-repo-discovery-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(dir.join("home")).unwrap();
+    dir
+}
+
+/// Run pm from `cwd` with `root/home` as `$HOME`.
+fn pm_in(root: &Path, cwd: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .args(args)
+        .current_dir(cwd)
+        .env("HOME", root.join("home"))
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn titles(root: &Path, cwd: &Path) -> String {
+    pm_in(
+        root,
+        cwd,
+        &[
+            "list",
+            "--format",
+            "plain",
+            "--no-header",
+            "--fields",
+            "title",
+        ],
+    )
+}
+
+#[test]
+fn nested_directories_find_the_repo_workspace() {
+    let root = tmp_root();
+    let repo = root.join("repo");
+    let deep = repo.join("src").join("bin");
+    fs::create_dir_all(&deep).unwrap();
+
+    let out = pm_in(&root, &repo, &["init"]);
+    assert!(
+        out.contains(&repo.join(".pm").display().to_string()),
+        "{out}"
+    );
+    pm_in(&root, &deep, &["add", "From deep inside"]);
+    assert_eq!(titles(&root, &repo), "From deep inside\n");
+
+    // Nothing leaked into the global workspace.
+    let global = root.join("home").join(".pm");
+    assert!(!global.join("state.json").exists());
+
+    // --db still wins over the discovered workspace.
+    let other = root.join("other").join(".pm");
+    let db = other.to_str().unwrap();
+    pm_in(&root, &deep, &["--db", db, "init"]);
+    pm_in(&root, &deep, &["--db", db, "add", "Elsewhere"]);
+    assert_eq!(
+        pm_in(
+            &root,
+            &deep,
+            &[
+                "--db",
+                db,
+                "list",
+                "--format",
+                "plain",
+                "--no-header",
+                "--fields",
+                "title"
+            ],
+        ),
+        "Elsewhere\n"
+    );
+    assert_eq!(titles(&root, &deep), "From deep inside\n");
+    fs::remove_dir_all(&root).ok();
+}
+
+#[test]
+fn outside_any_repo_the_global_workspace_is_used() {
+    let root = tmp_root();
+    let loose = root.join("scratch");
+    fs::create_dir_all(&loose).unwrap();
+    let home_pm = root.join("home").join(".pm");
+    let db = home_pm.to_str().unwrap();
+    pm_in(&root, &loose, &["--db", db, "init"]);
+
+    pm_in(&root, &loose, &["add", "Global task"]);
+    assert_eq!(titles(&root, &root.join("home")), "Global task\n");
+    assert!(!loose.join(".pm").exists());
+    fs::remove_dir_all(&root).ok();
+}
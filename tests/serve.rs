@@ -0,0 +1,287 @@
+//! `pm serve`: the HTTP/JSON routes, and the message a build without the
+//! `server` feature gives. Run with `cargo test --features server` to cover
+//! the routes.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-serve-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+#[cfg(not(feature = "server"))]
+#[test]
+fn serve_explains_the_missing_feature() {
+    let pm_dir = tmp_pm_dir();
+    assert!(pm(&pm_dir, &["init"]).status.success());
+    let out = pm(&pm_dir, &["serve", "--port", "0"]);
+    assert!(!out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("--features server"), "{stderr}");
+}
+
+#[cfg(feature = "server")]
+mod routes {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpStream;
+    use std::process::{Child, Stdio};
+
+    use project_management::mcp::handlers::Context;
+    use project_management::serve::{route, screen, Headers};
+
+    use super::*;
+
+    fn workspace() -> PathBuf {
+        let pm_dir = tmp_pm_dir();
+        for args in [
+            &["init"][..],
+            &["add", "Website", "--kind", "project"],
+            &["add", "Site", "--kind", "product", "--parent", "PRJ1"],
+            &["add", "Launch", "--kind", "epic", "--parent", "PRD1"],
+            &["add", "Landing page", "--parent", "EPC1"],
+        ] {
+            let out = pm(&pm_dir, args);
+            assert!(out.status.success(), "pm {args:?}: {out:?}");
+        }
+        pm_dir
+    }
+
+    #[test]
+    fn reads_list_and_fetch_tasks() {
+        let pm_dir = workspace();
+        let mut ctx = Context::for_pm_dir(pm_dir);
+
+        let all = route(&mut ctx, "GET", "/tasks", "");
+        assert_eq!(all.status, 200);
+        assert_eq!(all.body.as_array().unwrap().len(), 4);
+
+        let projects = route(&mut ctx, "GET", "/projects?verbose=1", "");
+        let projects = projects.body.as_array().unwrap().clone();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0]["title"], "Website");
+
+        let one = route(&mut ctx, "GET", "/tasks/TSK1", "");
+        assert_eq!(one.status, 200);
+        assert_eq!(one.body["title"], "Landing page");
+        assert_eq!(one.body["parent"], "EPC1");
+
+        assert_eq!(route(&mut ctx, "GET", "/tasks/TSK9", "").status, 404);
+        assert_eq!(route(&mut ctx, "GET", "/nope", "").status, 404);
+        assert_eq!(route(&mut ctx, "DELETE", "/tasks/TSK1", "").status, 405);
+    }
+
+    #[test]
+    fn posts_create_and_complete_tasks() {
+        let pm_dir = workspace();
+        let mut ctx = Context::for_pm_dir(pm_dir.clone());
+
+        let created = route(
+            &mut ctx,
+            "POST",
+            "/tasks",
+            r#"{"title": "Signup form", "parent": "EPC1"}"#,
+        );
+        assert_eq!(created.status, 201, "{:?}", created.body);
+        assert_eq!(created.body["id"], "TSK2");
+        assert_eq!(created.body["kind"], "task");
+
+        let bad = route(&mut ctx, "POST", "/tasks", "{not json");
+        assert_eq!(bad.status, 400);
+        let wrong_parent = route(
+            &mut ctx,
+            "POST",
+            "/tasks",
+            r#"{"title": "Orphan", "kind": "epic", "parent": "TSK1"}"#,
+        );
+        assert_eq!(wrong_parent.status, 400, "{:?}", wrong_parent.body);
+
+        let done = route(&mut ctx, "POST", "/tasks/TSK2/complete", "");
+        assert_eq!(done.status, 200, "{:?}", done.body);
+        assert_eq!(done.body["status"], "done");
+
+        // The changes are on disk for the CLI to see.
+        let listed = pm(&pm_dir, &["list", "--all", "--format", "plain"]);
+        let listed = String::from_utf8_lossy(&listed.stdout);
+        assert!(listed.contains("Signup form"), "{listed}");
+    }
+
+    #[test]
+    fn screen_turns_away_foreign_hosts_origins_and_form_posts() {
+        let loopback = "127.0.0.1".parse().unwrap();
+        let local = |host| Headers {
+            host: Some(host),
+            ..Headers::default()
+        };
+        for host in [
+            "localhost:7878",
+            "127.0.0.1:7878",
+            "[::1]:7878",
+            "LOCALHOST",
+        ] {
+            assert_eq!(screen("GET", &local(host), loopback), Ok(()), "{host}");
+        }
+        for host in [
+            "evil.example:7878",
+            "127.0.0.1.evil.example",
+            "10.0.0.5:7878",
+        ] {
+            assert_eq!(
+                screen("GET", &local(host), loopback).unwrap_err().status,
+                403,
+                "{host}"
+            );
+        }
+        assert_eq!(
+            screen("GET", &Headers::default(), loopback)
+                .unwrap_err()
+                .status,
+            403
+        );
+        // A non-loopback bind also answers to its own address.
+        let lan = "10.0.0.5".parse().unwrap();
+        assert_eq!(screen("GET", &local("10.0.0.5:7878"), lan), Ok(()));
+
+        let from = |origin| Headers {
+            host: Some("localhost:7878"),
+            origin: Some(origin),
+            ..Headers::default()
+        };
+        assert_eq!(
+            screen("GET", &from("http://localhost:7878"), loopback),
+            Ok(())
+        );
+        for origin in ["http://evil.example", "null", "http://localhost:9999"] {
+            assert_eq!(
+                screen("GET", &from(origin), loopback).unwrap_err().status,
+                403,
+                "{origin}"
+            );
+        }
+
+        let posted = |content_type| Headers {
+            host: Some("localhost:7878"),
+            content_type,
+            ..Headers::default()
+        };
+        assert_eq!(
+            screen("POST", &posted(Some("application/json")), loopback),
+            Ok(())
+        );
+        assert_eq!(
+            screen(
+                "POST",
+                &posted(Some("application/json; charset=utf-8")),
+                loopback
+            ),
+            Ok(())
+        );
+        for content_type in [
+            None,
+            Some("text/plain"),
+            Some("application/x-www-form-urlencoded"),
+        ] {
+            let refused = screen("POST", &posted(content_type), loopback).unwrap_err();
+            assert_eq!(refused.status, 415, "{content_type:?}");
+        }
+        assert_eq!(screen("GET", &posted(None), loopback), Ok(()));
+    }
+
+    struct Serving(Child);
+
+    impl Drop for Serving {
+        fn drop(&mut self) {
+            let _ = self.0.kill();
+            let _ = self.0.wait();
+        }
+    }
+
+    #[test]
+    fn serves_json_over_localhost() {
+        let pm_dir = workspace();
+        let mut child = Command::new(env!("CARGO_BIN_EXE_pm"))
+            .arg("--db")
+            .arg(&pm_dir)
+            .args(["serve", "--port", "0"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("spawn pm serve");
+        let stdout = child.stdout.take().unwrap();
+        let _serving = Serving(child);
+
+        let mut banner = String::new();
+        BufReader::new(stdout).read_line(&mut banner).unwrap();
+        let addr = banner
+            .split("http://")
+            .nth(1)
+            .and_then(|rest| rest.split_whitespace().next())
+            .unwrap_or_else(|| panic!("no address in {banner:?}"))
+            .to_string();
+        assert!(addr.starts_with("127.0.0.1:"), "{addr}");
+
+        let send = |head: &str, body: &str| {
+            let mut stream = TcpStream::connect(&addr).unwrap();
+            write!(
+                stream,
+                "{head}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+
+        let response = send(&format!("GET /tasks/TSK1 HTTP/1.1\r\nHost: {addr}"), "");
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(response.contains("application/json"), "{response}");
+        assert!(response.contains("\"Landing page\""), "{response}");
+
+        let rebound = send("GET /tasks HTTP/1.1\r\nHost: evil.example", "");
+        assert!(rebound.starts_with("HTTP/1.1 403"), "{rebound}");
+        let cross = send(
+            &format!("GET /tasks HTTP/1.1\r\nHost: {addr}\r\nOrigin: http://evil.example"),
+            "",
+        );
+        assert!(cross.starts_with("HTTP/1.1 403"), "{cross}");
+        let form = send(
+            &format!(
+                "POST /tasks HTTP/1.1\r\nHost: {addr}\r\n\
+                 Content-Type: application/x-www-form-urlencoded"
+            ),
+            r#"{"title": "Forged"}"#,
+        );
+        assert!(form.starts_with("HTTP/1.1 415"), "{form}");
+        let json = send(
+            &format!("POST /tasks HTTP/1.1\r\nHost: {addr}\r\nContent-Type: application/json"),
+            r#"{"title": "Posted", "parent": "EPC1"}"#,
+        );
+        assert!(json.starts_with("HTTP/1.1 201"), "{json}");
+
+        let listed = pm(&pm_dir, &["list", "--all", "--format", "plain"]);
+        let listed = String::from_utf8_lossy(&listed.stdout);
+        assert!(
+            listed.contains("Posted") && !listed.contains("Forged"),
+            "{listed}"
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Short `T-a3` ids: accepted wherever the CLI resolves a task, never stored.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-short-ids-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn view_and_complete_accept_the_short_form() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    for n in 1..=11 {
+        pm(&pm_dir, &["add", &format!("task {n}")]);
+    }
+    // TSK11 is `b` in base 36.
+    let out = pm(&pm_dir, &["view", "T-b"]);
+    assert!(out.contains("ID:           TSK11"), "{out}");
+    pm(&pm_dir, &["complete", "t-B"]);
+    let out = pm(&pm_dir, &["view", "TSK11"]);
+    assert!(out.contains("Status:       Done"), "{out}");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn unknown_short_ids_fall_back_to_titles() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "T-shirt"]);
+    let out = pm(&pm_dir, &["view", "T-shirt"]);
+    assert!(out.contains("ID:           TSK1"), "{out}");
+    assert!(!run(&pm_dir, &["view", "T-zz"]).status.success());
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn storage_and_listings_keep_canonical_ids() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    fs::write(pm_dir.join("config.json"), r#"{"ui":{"short_ids":true}}"#).unwrap();
+    pm(&pm_dir, &["add", "Only"]);
+    let out = pm(&pm_dir, &["list", "--format", "plain", "--no-header"]);
+    assert!(out.starts_with("TSK1\t"), "{out}");
+    let state = fs::read_to_string(pm_dir.join("state.json")).unwrap();
+    assert!(state.contains("\"TSK1\""));
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
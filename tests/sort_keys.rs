@@ -0,0 +1,112 @@
+//! Sort keys shared by `pm list --sort` and the TUI `o` cycle, their
+//! tiebreakers, and multi-key `--sort` specs.
+
+use chrono::NaiveDate;
+use project_management::db::{sort_tasks, sort_tasks_by};
+use project_management::fields::{Priority, SortKey, SortSpec};
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+use project_management::task::Task;
+
+mod common;
+
+fn stamped(n: u64, created: i64, updated: i64) -> Task {
+    Task {
+        created_at_utc: created,
+        updated_at_utc: updated,
+        ..common::task(LeafId::new(TypePrefix::Task, n), &format!("task {n}"))
+    }
+}
+
+fn order(tasks: &[Task], key: SortKey) -> Vec<u64> {
+    let mut refs: Vec<&Task> = tasks.iter().collect();
+    sort_tasks(&mut refs, key);
+    refs.iter().map(|t| t.id.number()).collect()
+}
+
+#[test]
+fn created_and_updated_sort_newest_first() {
+    let tasks = vec![
+        stamped(1, 100, 900),
+        stamped(2, 300, 500),
+        stamped(3, 200, 700),
+    ];
+    assert_eq!(order(&tasks, SortKey::Created), vec![2, 3, 1]);
+    assert_eq!(order(&tasks, SortKey::Updated), vec![1, 3, 2]);
+}
+
+#[test]
+fn equal_timestamps_fall_back_to_id() {
+    let tasks = vec![stamped(2, 100, 100), stamped(1, 100, 100)];
+    assert_eq!(order(&tasks, SortKey::Created), vec![1, 2]);
+    assert_eq!(order(&tasks, SortKey::Updated), vec![1, 2]);
+}
+
+#[test]
+fn due_ties_fall_back_to_priority_then_recent_updates() {
+    let mut tasks = vec![
+        stamped(1, 0, 100),
+        stamped(2, 0, 300),
+        stamped(3, 0, 200),
+        stamped(4, 0, 100),
+    ];
+    tasks[0].priority_level = Some(Priority::NiceToHave);
+    tasks[2].priority_level = Some(Priority::MustHave);
+    tasks[3].due = NaiveDate::from_ymd_opt(2030, 1, 1);
+    assert_eq!(order(&tasks, SortKey::Due), vec![4, 3, 1, 2]);
+}
+
+fn order_by(tasks: &[Task], spec: &str) -> Vec<u64> {
+    let spec: SortSpec = spec.parse().unwrap();
+    let mut refs: Vec<&Task> = tasks.iter().collect();
+    sort_tasks_by(&mut refs, &spec.0);
+    refs.iter().map(|t| t.id.number()).collect()
+}
+
+#[test]
+fn sort_spec_parses_keys_directions_and_the_custom_prefix() {
+    let spec: SortSpec = "priority, -due,id".parse().unwrap();
+    assert_eq!(
+        spec.0,
+        vec![
+            (SortKey::Priority, false),
+            (SortKey::Due, true),
+            (SortKey::Id, false)
+        ]
+    );
+    assert_eq!(
+        "custom:-updated".parse::<SortSpec>().unwrap().0,
+        vec![(SortKey::Updated, true)]
+    );
+
+    let err = "priority,owner".parse::<SortSpec>().unwrap_err();
+    assert!(err.contains("unknown sort key 'owner'"), "{err}");
+    assert!(err.contains("due, priority, id, created, updated"), "{err}");
+    assert!("due,,id".parse::<SortSpec>().is_err());
+}
+
+#[test]
+fn multi_key_sort_applies_keys_in_order_with_unset_last() {
+    let mut tasks = vec![
+        stamped(1, 0, 0),
+        stamped(2, 0, 0),
+        stamped(3, 0, 0),
+        stamped(4, 0, 0),
+        stamped(5, 0, 0),
+    ];
+    for n in [0, 1, 3] {
+        tasks[n].priority_level = Some(Priority::MustHave);
+    }
+    tasks[1].due = NaiveDate::from_ymd_opt(2030, 1, 1);
+    tasks[2].due = NaiveDate::from_ymd_opt(2031, 1, 1);
+    tasks[3].due = NaiveDate::from_ymd_opt(2032, 1, 1);
+
+    // Must-haves by latest due first, the undated one last among them.
+    assert_eq!(order_by(&tasks, "priority,-due"), vec![4, 2, 1, 3, 5]);
+    assert_eq!(order_by(&tasks, "-id"), vec![5, 4, 3, 2, 1]);
+    // A lone key keeps its full tiebreaker chain.
+    assert_eq!(order_by(&tasks, "due"), order(&tasks, SortKey::Due));
+
+    tasks[4].pinned = true;
+    assert_eq!(order_by(&tasks, "priority,-due")[0], 5);
+}
@@ -0,0 +1,78 @@
+//! Workflow board columns: `stage_columns` / `pm wf --stage-columns`
+//! validation.
+
+use std::process::Command;
+
+use project_management::db::{
+    board_columns, default_board_columns, parse_stage_columns, BoardColumn,
+};
+use project_management::fields::ProcessStage;
+
+const LIGHT: &str = "Todo=unassigned,ideation,design;\
+    Doing=prototyping,ready-to-implement,implementation,testing;\
+    Done=refinement,release";
+
+#[test]
+fn default_board_has_a_column_per_stage() {
+    let board = default_board_columns();
+    assert_eq!(board.len(), 9);
+    assert_eq!(
+        board[0],
+        BoardColumn {
+            title: "Unassigned".to_string(),
+            stages: vec![None],
+        }
+    );
+    assert_eq!(board[4].title, "Ready to Implement");
+    assert_eq!(board[8].stages, vec![Some(ProcessStage::Release)]);
+}
+
+#[test]
+fn columns_group_stages() {
+    let board = board_columns(&parse_stage_columns(LIGHT).unwrap()).unwrap();
+    let titles: Vec<&str> = board.iter().map(|c| c.title.as_str()).collect();
+    assert_eq!(titles, ["Todo", "Doing", "Done"]);
+    assert_eq!(
+        board[0].stages,
+        vec![
+            None,
+            Some(ProcessStage::Ideation),
+            Some(ProcessStage::Design)
+        ]
+    );
+    assert_eq!(board[1].stages[1], Some(ProcessStage::ReadyToImplement));
+}
+
+#[test]
+fn every_stage_needs_exactly_one_column() {
+    let twice = parse_stage_columns(&format!("{LIGHT};Again=design")).unwrap();
+    assert_eq!(
+        board_columns(&twice).unwrap_err(),
+        "stage 'design' is in both 'Todo' and 'Again'"
+    );
+    let missing = parse_stage_columns("Todo=ideation,design;Done=release").unwrap();
+    assert_eq!(
+        board_columns(&missing).unwrap_err(),
+        "not in any column: unassigned, prototyping, ready-to-implement, \
+         implementation, testing, refinement"
+    );
+    let unknown = parse_stage_columns("Todo=review").unwrap();
+    assert_eq!(
+        board_columns(&unknown).unwrap_err(),
+        "unknown stage 'review' in column 'Todo'"
+    );
+    assert!(parse_stage_columns("Todo").is_err());
+}
+
+#[test]
+fn wf_refuses_an_invalid_layout() {
+    let pm_dir = std::env::temp_dir().join(format!("pm-stage-columns-{}", std::process::id()));
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(&pm_dir)
+        .args(["wf", "--stage-columns", "Todo=ideation"])
+        .output()
+        .expect("invoke pm binary");
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).starts_with("--stage-columns: not in any column"));
+}
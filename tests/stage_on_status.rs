@@ -0,0 +1,156 @@
+//! `workflow.stage_on_status`: a status change moves the process stage.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use project_management::db::advance_stage;
+use project_management::fields::{ProcessStage, Status};
+use project_management::store::config::WorkflowConfig;
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+use project_management::task::Task;
+
+mod common;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-stage-on-status-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+/// The stage column of `id`, as `pm list` prints it.
+fn stage(pm_dir: &Path, id: &str) -> String {
+    let out = pm(
+        pm_dir,
+        &[
+            "list",
+            "--all",
+            "--fields",
+            "id,stage",
+            "--format",
+            "plain",
+            "--no-header",
+        ],
+    );
+    out.lines()
+        .find_map(|line| line.strip_prefix(&format!("{id}\t")))
+        .unwrap_or_else(|| panic!("{id} not listed: {out}"))
+        .to_string()
+}
+
+fn config(pm_dir: &Path) {
+    fs::write(
+        pm_dir.join("config.json"),
+        r#"{ "workflow": {
+            "stage_on_status": { "in-progress": "implementation", "done": "release" },
+            "stage_on_status_by_kind": { "epic": { "in-progress": "design" } }
+        } }"#,
+    )
+    .unwrap();
+}
+
+fn in_progress_task() -> Task {
+    Task {
+        status: Status::InProgress,
+        ..common::task(LeafId::new(TypePrefix::Task, 1), "task 1")
+    }
+}
+
+#[test]
+fn advance_needs_a_status_change_and_a_mapping() {
+    let mut workflow = WorkflowConfig::default();
+    let mut task = in_progress_task();
+    assert_eq!(advance_stage(&mut task, Status::Open, &workflow), None);
+
+    workflow
+        .stage_on_status
+        .insert(Status::InProgress, ProcessStage::Implementation);
+    assert_eq!(
+        advance_stage(&mut task, Status::InProgress, &workflow),
+        None
+    );
+    assert_eq!(
+        advance_stage(&mut task, Status::Open, &workflow),
+        Some(ProcessStage::Implementation)
+    );
+    assert_eq!(task.process_stage, Some(ProcessStage::Implementation));
+}
+
+#[test]
+fn stages_stay_put_until_configured() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Login"]);
+    pm(&pm_dir, &["set-status", "TSK1", "in-progress"]);
+    assert_eq!(stage(&pm_dir, "TSK1"), "-");
+}
+
+#[test]
+fn status_changes_move_the_stage() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    config(&pm_dir);
+    pm(&pm_dir, &["add", "Accounts", "--kind", "epic"]);
+    pm(&pm_dir, &["add", "Login", "--parent", "EPC1"]);
+    pm(&pm_dir, &["add", "Logout", "--parent", "EPC1"]);
+
+    let out = pm(&pm_dir, &["set-status", "TSK1", "in-progress"]);
+    assert!(out.contains("Stage moved to Implementation."), "{out}");
+    assert_eq!(stage(&pm_dir, "TSK1"), "Implementation");
+
+    // The kind entry wins for epics.
+    pm(&pm_dir, &["update", "EPC1", "--status", "in-progress"]);
+    assert_eq!(stage(&pm_dir, "EPC1"), "Design");
+
+    pm(&pm_dir, &["complete", "TSK1"]);
+    assert_eq!(stage(&pm_dir, "TSK1"), "Release");
+}
+
+#[test]
+fn an_explicit_stage_wins() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    config(&pm_dir);
+    pm(&pm_dir, &["add", "Login"]);
+    let out = pm(
+        &pm_dir,
+        &[
+            "update",
+            "TSK1",
+            "--status",
+            "in-progress",
+            "--process-stage",
+            "testing",
+        ],
+    );
+    assert!(!out.contains("Stage moved"), "{out}");
+    assert_eq!(stage(&pm_dir, "TSK1"), "Testing");
+
+    // Re-setting the same status is not a change, so the stage stays.
+    pm(&pm_dir, &["set-status", "TSK1", "in-progress"]);
+    assert_eq!(stage(&pm_dir, "TSK1"), "Testing");
+}
@@ -0,0 +1,92 @@
+//! Process-stage progress bars in `pm view` and the TUI detail panes.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use project_management::db::{format_stage_progress, process_stage_ordinal, PROCESS_STAGE_COUNT};
+use project_management::fields::ProcessStage;
+
+#[test]
+fn bar_has_one_cell_per_stage() {
+    assert_eq!(
+        format_stage_progress(Some(ProcessStage::Implementation)),
+        "[#####---] 5/8 Implementation"
+    );
+    assert_eq!(
+        format_stage_progress(Some(ProcessStage::Release)),
+        "[########] 8/8 Release"
+    );
+    assert_eq!(
+        format_stage_progress(Some(ProcessStage::Ideation)),
+        "[#-------] 1/8 Ideation"
+    );
+}
+
+#[test]
+fn unassigned_stage_is_an_empty_bar() {
+    assert_eq!(format_stage_progress(None), "[--------] 0/8 -");
+}
+
+#[test]
+fn ordinals_run_in_process_order() {
+    let stages = [
+        ProcessStage::Ideation,
+        ProcessStage::Design,
+        ProcessStage::Prototyping,
+        ProcessStage::ReadyToImplement,
+        ProcessStage::Implementation,
+        ProcessStage::Testing,
+        ProcessStage::Refinement,
+        ProcessStage::Release,
+    ];
+    assert_eq!(stages.len(), PROCESS_STAGE_COUNT);
+    for (i, stage) in stages.into_iter().enumerate() {
+        assert_eq!(process_stage_ordinal(Some(stage)), i + 1);
+    }
+}
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-stage-progress-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn view_prints_the_bar() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Staged", "--process-stage", "testing"]);
+    pm(&pm_dir, &["add", "Unstaged"]);
+    let out = pm(&pm_dir, &["view", "TSK1"]);
+    assert!(
+        out.contains("Stage:        [######--] 6/8 Testing\n"),
+        "{out}"
+    );
+    let out = pm(&pm_dir, &["view", "TSK2"]);
+    assert!(out.contains("Stage:        [--------] 0/8 -\n"), "{out}");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
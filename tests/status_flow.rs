@@ -0,0 +1,137 @@
+//! Status flow enforcement: `workflow.enforce_status_flow`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use project_management::db::status_flow_error;
+use project_management::fields::Status;
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+
+#[test]
+fn steps_follow_open_in_progress_done() {
+    assert!(Status::Open.can_step_to(Status::InProgress));
+    assert!(Status::InProgress.can_step_to(Status::Done));
+    assert!(!Status::Open.can_step_to(Status::Done));
+    assert!(!Status::Done.can_step_to(Status::InProgress));
+}
+
+#[test]
+fn resets_pauses_and_cancels_are_allowed() {
+    assert!(Status::Done.can_step_to(Status::Open));
+    assert!(Status::Blocked.can_step_to(Status::Open));
+    assert!(Status::InProgress.can_step_to(Status::Blocked));
+    assert!(Status::Blocked.can_step_to(Status::InProgress));
+    assert!(Status::Open.can_step_to(Status::Cancelled));
+    assert!(!Status::Blocked.can_step_to(Status::Done));
+    assert!(!Status::Open.can_step_to(Status::Blocked));
+    assert!(Status::Done.can_step_to(Status::Done));
+}
+
+#[test]
+fn error_names_the_allowed_moves() {
+    let id = LeafId::new(TypePrefix::Task, 7);
+    assert_eq!(
+        status_flow_error(id, Status::Open, Status::Done).as_deref(),
+        Some("TSK7 cannot go from Open to Done; next: InProgress, Cancelled")
+    );
+    assert_eq!(
+        status_flow_error(id, Status::Open, Status::InProgress),
+        None
+    );
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-status-flow-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+#[test]
+fn cli_refuses_skipped_steps_only_when_configured() {
+    let pm_dir = tmp_pm_dir();
+    assert!(pm(&pm_dir, &["init"]).status.success());
+    assert!(pm(&pm_dir, &["add", "first"]).status.success());
+    assert!(pm(&pm_dir, &["add", "second"]).status.success());
+    assert!(pm(&pm_dir, &["update", "TSK1", "--status", "done"])
+        .status
+        .success());
+
+    fs::write(
+        pm_dir.join("config.json"),
+        r#"{ "workflow": { "enforce_status_flow": true } }"#,
+    )
+    .unwrap();
+    let refused = pm(&pm_dir, &["update", "TSK2", "--status", "done"]);
+    assert!(!refused.status.success());
+    let stderr = String::from_utf8_lossy(&refused.stderr);
+    assert!(
+        stderr.contains("TSK2 cannot go from Open to Done"),
+        "{stderr}"
+    );
+    assert!(!pm(&pm_dir, &["complete", "TSK2"]).status.success());
+
+    assert!(pm(&pm_dir, &["update", "TSK2", "--status", "in-progress"])
+        .status
+        .success());
+    assert!(pm(&pm_dir, &["complete", "TSK2"]).status.success());
+    assert!(pm(&pm_dir, &["set-status", "TSK1", "open"])
+        .status
+        .success());
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn csv_update_keeps_a_refused_status_and_applies_the_rest() {
+    let pm_dir = tmp_pm_dir();
+    assert!(pm(&pm_dir, &["init"]).status.success());
+    assert!(pm(&pm_dir, &["add", "first"]).status.success());
+    fs::write(
+        pm_dir.join("config.json"),
+        r#"{ "workflow": { "enforce_status_flow": true } }"#,
+    )
+    .unwrap();
+    let csv = pm_dir.parent().unwrap().join("edit.csv");
+    fs::write(
+        &csv,
+        "ID,Title,Kind,Status,Priority,Urgency,ProcessStage,Project,Tags,Due,Parent,CreatedUTC,UpdatedUTC,Description\n\
+         TSK1,Renamed,Task,Done,-,-,-,-,-,-,-,-,-,-\n",
+    )
+    .unwrap();
+
+    let out = pm(
+        &pm_dir,
+        &["import", csv.to_str().unwrap(), "--update", "--no-backup"],
+    );
+    assert!(out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("TSK1 cannot go from Open to Done"),
+        "{stderr}"
+    );
+    let view = pm(&pm_dir, &["view", "TSK1"]);
+    let view = String::from_utf8_lossy(&view.stdout);
+    assert!(view.contains("Renamed"), "{view}");
+    assert!(view.contains("Status:       Open"), "{view}");
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
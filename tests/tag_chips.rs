@@ -0,0 +1,60 @@
+//! Tag chips in the TUI list and cards: stable colours, chip ranges and
+//! layered span styling.
+
+use ratatui::style::{Color, Style};
+
+use project_management::tui::colors::{tag_color, TAG_PALETTE};
+use project_management::tui::utils::{layered_spans, tag_suffix};
+
+fn tags(names: &[&str]) -> Vec<String> {
+    names.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn tag_colours_are_stable_and_from_the_palette() {
+    for tag in ["backend", "ui", "area.docs", "über"] {
+        assert_eq!(tag_color(tag), tag_color(tag));
+        assert!(TAG_PALETTE.contains(&tag_color(tag)));
+    }
+    // Pinned so a change to the hash, which would recolour every tag for
+    // every user, shows up here.
+    assert_eq!(tag_color("backend"), TAG_PALETTE[7]);
+    assert_eq!(tag_color("ui"), TAG_PALETTE[3]);
+    let spread: std::collections::HashSet<_> = ["a", "b", "c", "d", "e", "f", "g", "h"]
+        .iter()
+        .map(|t| format!("{:?}", tag_color(t)))
+        .collect();
+    assert!(spread.len() > 1);
+}
+
+#[test]
+fn chips_cover_each_padded_tag() {
+    let (text, chips) = tag_suffix(&tags(&["api", "ui"]), true);
+    assert_eq!(text, "  api   ui ");
+    let cells: Vec<&str> = chips.iter().map(|&(s, e, _)| &text[s..e]).collect();
+    assert_eq!(cells, [" api ", " ui "]);
+    assert_eq!(chips[0].2, tag_color("api"));
+
+    let (plain, none) = tag_suffix(&tags(&["api", "ui"]), false);
+    assert_eq!(plain, " [api,ui]");
+    assert!(none.is_empty());
+    assert_eq!(tag_suffix(&[], true), (String::new(), Vec::new()));
+}
+
+#[test]
+fn later_layers_patch_earlier_ones() {
+    let chip = Style::default().fg(Color::Black).bg(Color::LightBlue);
+    let hit = Style::default().bg(Color::Yellow);
+    // A chip over "api", a match over "pi x", and a range past the end.
+    let spans = layered_spans("api x", &[(0, 3, chip), (1, 5, hit), (4, 99, chip)]);
+    let parts: Vec<(&str, Style)> = spans.iter().map(|s| (&*s.content, s.style)).collect();
+    assert_eq!(
+        parts,
+        [
+            ("a", chip),
+            ("pi", chip.patch(hit)),
+            (" ", hit),
+            ("x", hit.patch(chip)),
+        ]
+    );
+}
@@ -0,0 +1,66 @@
+//! Tag filtering helpers behind `pm list --tag` and `pm tags --tree`.
+//!
+//! Exact membership stays the default; `--expand-tags` widens a filter to
+//! dotted descendants without catching unrelated tags that merely share a
+//! string prefix.
+
+use project_management::db::{has_all_tags, split_and_normalise_tags, tag_matches, tag_tree};
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+use project_management::task::Task;
+
+mod common;
+
+fn tagged(n: u64, tags: &[&str]) -> Task {
+    Task {
+        tags: split_and_normalise_tags(&tags.iter().map(|t| t.to_string()).collect::<Vec<_>>()),
+        ..common::task(LeafId::new(TypePrefix::Task, n), &format!("task {n}"))
+    }
+}
+
+#[test]
+fn exact_matching_ignores_dotted_children() {
+    assert!(tag_matches("area", "area", false));
+    assert!(!tag_matches("area.backend", "area", false));
+}
+
+#[test]
+fn prefix_matching_covers_dotted_descendants_only() {
+    assert!(tag_matches("area", "area", true));
+    assert!(tag_matches("area.backend", "area", true));
+    assert!(tag_matches("area.backend.db", "area.backend", true));
+    assert!(!tag_matches("areas", "area", true));
+    assert!(!tag_matches("area-backend", "area", true));
+}
+
+#[test]
+fn has_all_tags_requires_every_filter() {
+    let tags = split_and_normalise_tags(&["Area.Backend, urgent".to_string()]);
+    let wanted = split_and_normalise_tags(&["area".to_string()]);
+    assert!(!has_all_tags(&tags, &wanted, false));
+    assert!(has_all_tags(&tags, &wanted, true));
+
+    let wanted = split_and_normalise_tags(&["area,ops".to_string()]);
+    assert!(!has_all_tags(&tags, &wanted, true));
+    assert!(has_all_tags(&tags, &[], false));
+}
+
+#[test]
+fn tag_tree_groups_by_prefix_and_counts_tasks_once() {
+    let tasks = vec![
+        tagged(1, &["area.backend", "area.frontend"]),
+        tagged(2, &["area.backend"]),
+        tagged(3, &["areas"]),
+    ];
+    let tree = tag_tree(&tasks);
+    let flat: Vec<(String, usize)> = tree.into_iter().map(|(p, c)| (p.join("."), c)).collect();
+    assert_eq!(
+        flat,
+        vec![
+            ("area".to_string(), 2),
+            ("area.backend".to_string(), 2),
+            ("area.frontend".to_string(), 1),
+            ("areas".to_string(), 1),
+        ]
+    );
+}
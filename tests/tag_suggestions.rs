@@ -0,0 +1,64 @@
+//! Tag vocabulary helpers behind the TUI tags-field suggestions and the
+//! near-miss warning printed when `--tag` or `pm tag` introduces a new tag.
+
+use project_management::db::{
+    complete_tag, similar_tags, split_and_normalise_tags, tag_spelling_warnings,
+};
+use project_management::store::id::TypePrefix;
+use project_management::store::LeafId;
+use project_management::task::Task;
+use std::collections::BTreeSet;
+
+mod common;
+
+fn tagged(n: u64, tags: &[&str]) -> Task {
+    Task {
+        tags: split_and_normalise_tags(&tags.iter().map(|t| t.to_string()).collect::<Vec<_>>()),
+        ..common::task(LeafId::new(TypePrefix::Task, n), &format!("task {n}"))
+    }
+}
+
+fn known(tags: &[&str]) -> BTreeSet<String> {
+    tags.iter().map(|t| t.to_string()).collect()
+}
+
+#[test]
+fn completion_lists_tags_starting_with_the_prefix() {
+    let known = known(&["backend", "backlog", "frontend"]);
+    assert_eq!(complete_tag(&known, "Back"), vec!["backend", "backlog"]);
+    assert_eq!(complete_tag(&known, "front"), vec!["frontend"]);
+}
+
+#[test]
+fn completion_skips_empty_prefixes_and_exact_matches() {
+    let known = known(&["backend"]);
+    assert!(complete_tag(&known, "").is_empty());
+    assert!(complete_tag(&known, "  ").is_empty());
+    assert!(complete_tag(&known, "backend").is_empty());
+}
+
+#[test]
+fn similar_tags_are_one_edit_away() {
+    let known = known(&["backend", "bug", "docs"]);
+    assert_eq!(similar_tags(&known, "back-end"), vec!["backend"]);
+    assert_eq!(similar_tags(&known, "bugs"), vec!["bug"]);
+    assert_eq!(similar_tags(&known, "dogs"), vec!["docs"]);
+    assert!(similar_tags(&known, "frontend").is_empty());
+    assert!(similar_tags(&known, "backend").is_empty());
+}
+
+#[test]
+fn warnings_name_the_existing_tag_for_new_near_misses() {
+    let tasks = vec![tagged(1, &["backend"]), tagged(2, &["docs"])];
+    let warnings = tag_spelling_warnings(
+        &tasks,
+        &[
+            "back-end".to_string(),
+            "docs".to_string(),
+            "infra".to_string(),
+        ],
+    );
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("'back-end'"));
+    assert!(warnings[0].contains("'backend'"));
+}
@@ -0,0 +1,262 @@
+//! `pm export --format taskwarrior` and importing the result: the fields
+//! with a Taskwarrior counterpart survive a round trip, the hierarchy comes
+//! back through the `pm_*` UDAs, and plain `task export` output imports too.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::NaiveDate;
+use project_management::db::Database;
+use project_management::fields::{Kind, Priority, Status};
+use project_management::store::taskwarrior::{from_taskwarrior, to_taskwarrior, TwTask};
+
+fn tmp_pm_dir(tag: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-taskwarrior-{tag}-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+/// Atlas > Core > Editor > {Undo stack (done, dated, tagged), Redo}, with
+/// Redo depending on Undo stack.
+fn workspace() -> PathBuf {
+    let pm_dir = tmp_pm_dir("src");
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "--kind", "project", "Atlas"]);
+    pm(
+        &pm_dir,
+        &["add", "--kind", "product", "Core", "--parent", "PRJ1"],
+    );
+    pm(
+        &pm_dir,
+        &["add", "--kind", "epic", "Editor", "--parent", "PRD1"],
+    );
+    pm(
+        &pm_dir,
+        &[
+            "add",
+            "Undo stack",
+            "--parent",
+            "EPC1",
+            "--tag",
+            "editor,core",
+            "--due",
+            "2026-11-02",
+            "--priority-level",
+            "must-have",
+            "--desc",
+            "Ring buffer of edits",
+        ],
+    );
+    pm(&pm_dir, &["add", "Redo", "--parent", "EPC1"]);
+    pm(&pm_dir, &["dep", "TSK2", "needs", "TSK1"]);
+    pm(&pm_dir, &["complete", "TSK1"]);
+    pm_dir
+}
+
+#[test]
+fn export_writes_taskwarrior_fields() {
+    let pm_dir = workspace();
+    let file = pm_dir.parent().unwrap().join("tw.json");
+    pm(
+        &pm_dir,
+        &[
+            "export",
+            "--format",
+            "taskwarrior",
+            "--all",
+            "-o",
+            file.to_str().unwrap(),
+        ],
+    );
+    let items: Vec<serde_json::Value> =
+        serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+    let undo = items
+        .iter()
+        .find(|t| t["description"] == "Undo stack")
+        .unwrap();
+    assert_eq!(undo["status"], "completed");
+    assert_eq!(undo["project"], "Atlas");
+    assert_eq!(undo["tags"], serde_json::json!(["core", "editor"]));
+    assert_eq!(undo["due"], "20261102T000000Z");
+    assert_eq!(undo["priority"], "H");
+    assert!(undo["entry"].as_str().unwrap().ends_with('Z'));
+    assert!(undo["modified"].is_string());
+    assert_eq!(undo["pm_parent"], "EPC1");
+
+    let redo = items.iter().find(|t| t["description"] == "Redo").unwrap();
+    assert_eq!(redo["status"], "pending");
+    assert_eq!(redo["depends"], serde_json::json!([undo["uuid"]]));
+}
+
+#[test]
+fn round_trip_keeps_the_fields_that_map() {
+    let pm_dir = workspace();
+    let file = pm_dir.parent().unwrap().join("tw.json");
+    pm(
+        &pm_dir,
+        &[
+            "export",
+            "--format",
+            "taskwarrior",
+            "--all",
+            "-o",
+            file.to_str().unwrap(),
+        ],
+    );
+
+    let target = tmp_pm_dir("dst");
+    pm(&target, &["init"]);
+    pm(&target, &["add", "Already here"]);
+    pm(&target, &["import", file.to_str().unwrap(), "--no-backup"]);
+
+    // Fresh ids: the existing TSK1 keeps its number.
+    let undo = pm(&target, &["view", "TSK2"]);
+    assert!(undo.contains("Undo stack"), "{undo}");
+    assert!(undo.contains("Done"), "{undo}");
+    assert!(undo.contains("2026-11-02"), "{undo}");
+    assert!(undo.contains("Tags:         core,editor"), "{undo}");
+    assert!(undo.contains("Ring buffer of edits"), "{undo}");
+    assert!(undo.contains("Parent:       EPC1"), "{undo}");
+    assert!(undo.contains("Project:      Atlas"), "{undo}");
+    let redo = pm(&target, &["view", "TSK3"]);
+    assert!(redo.contains("Redo"), "{redo}");
+    assert!(redo.contains("Open"), "{redo}");
+
+    // Like any other write, the import is committed and in the feed.
+    let log = Command::new("git")
+        .arg("-C")
+        .arg(&target)
+        .args(["log", "-1", "--pretty=%s"])
+        .output()
+        .expect("git log");
+    let subject = String::from_utf8_lossy(&log.stdout);
+    assert!(
+        subject.starts_with("pm: import batch (5 tickets from"),
+        "{subject}"
+    );
+    let events = fs::read_to_string(target.join("events.log")).unwrap();
+    let imports = events
+        .lines()
+        .filter(|l| l.contains(r#""verb":"import""#))
+        .count();
+    assert_eq!(imports, 5, "{events}");
+}
+
+#[test]
+fn library_round_trip_keeps_deps_priority_and_timestamps() {
+    let pm_dir = workspace();
+    let db = Database::load(&pm_dir);
+    let tasks: Vec<_> = db.tasks.iter().collect();
+    let exported = to_taskwarrior(&db, &tasks);
+    let json = serde_json::to_string(&exported).unwrap();
+    let (bundle, report) = from_taskwarrior(serde_json::from_str(&json).unwrap());
+    assert_eq!(report.detached, 0);
+
+    let mut back = Database::default();
+    bundle.import_into(&mut back).unwrap();
+    let undo = back.tasks.iter().find(|t| t.title == "Undo stack").unwrap();
+    let original = db.tasks.iter().find(|t| t.title == "Undo stack").unwrap();
+    assert_eq!(undo.kind, Kind::Task);
+    assert_eq!(undo.priority_level, Some(Priority::MustHave));
+    assert_eq!(undo.due, NaiveDate::from_ymd_opt(2026, 11, 2));
+    assert_eq!(undo.created_at_utc, original.created_at_utc);
+    assert_eq!(undo.updated_at_utc, original.updated_at_utc);
+    assert_eq!(undo.completed_at_utc, original.completed_at_utc);
+    let redo = back.tasks.iter().find(|t| t.title == "Redo").unwrap();
+    assert_eq!(redo.deps, [undo.id]);
+    assert_eq!(redo.parent, undo.parent);
+}
+
+#[test]
+fn plain_taskwarrior_exports_import_with_lossy_defaults() {
+    let items: Vec<TwTask> = serde_json::from_str(
+        r#"[
+            {"id": 1, "uuid": "a", "description": "Buy milk", "status": "pending",
+             "project": "Home", "tags": ["errand"], "priority": "L",
+             "entry": "20250301T090000Z", "urgency": 4.2},
+            {"id": 2, "uuid": "b", "description": "Cook", "status": "waiting",
+             "depends": "a", "due": "20250302T180000Z"},
+            {"uuid": "c", "description": "Old", "status": "deleted"},
+            {"uuid": "d", "description": "Weekly review", "status": "recurring"}
+        ]"#,
+    )
+    .unwrap();
+    let (bundle, report) = from_taskwarrior(items);
+    assert_eq!(report.skipped_recurring, 1);
+    assert_eq!(bundle.tasks.len(), 3);
+
+    let mut db = Database::default();
+    bundle.import_into(&mut db).unwrap();
+    let milk = db.tasks.iter().find(|t| t.title == "Buy milk").unwrap();
+    assert_eq!(milk.kind, Kind::Task);
+    assert_eq!(milk.tags, ["errand", "home"]);
+    assert_eq!(milk.priority_level, Some(Priority::CutFirst));
+    assert_eq!(milk.parent, None);
+    let cook = db.tasks.iter().find(|t| t.title == "Cook").unwrap();
+    assert_eq!(cook.status, Status::Open);
+    assert_eq!(cook.deps, [milk.id]);
+    assert_eq!(cook.due, NaiveDate::from_ymd_opt(2025, 3, 2));
+    let old = db.tasks.iter().find(|t| t.title == "Old").unwrap();
+    assert_eq!(old.status, Status::Cancelled);
+}
+
+#[test]
+fn status_changed_in_taskwarrior_wins_over_pm_status() {
+    let pm_dir = workspace();
+    pm(&pm_dir, &["update", "TSK2", "--status", "in-progress"]);
+    pm(&pm_dir, &["add", "Scrap", "--parent", "EPC1"]);
+    let db = Database::load(&pm_dir);
+    let tasks: Vec<_> = db.tasks.iter().collect();
+    let mut exported = to_taskwarrior(&db, &tasks);
+
+    // In Taskwarrior: reopen Undo stack, complete Redo, delete Scrap.
+    for item in &mut exported {
+        item.status = match item.description.as_str() {
+            "Undo stack" => "pending",
+            "Redo" => "completed",
+            "Scrap" => "deleted",
+            _ => continue,
+        }
+        .to_string();
+    }
+    let json = serde_json::to_string(&exported).unwrap();
+    let (bundle, _) = from_taskwarrior(serde_json::from_str(&json).unwrap());
+    let mut back = Database::default();
+    bundle.import_into(&mut back).unwrap();
+
+    let status = |title: &str| back.tasks.iter().find(|t| t.title == title).unwrap().status;
+    assert_eq!(status("Undo stack"), Status::Open);
+    assert_eq!(status("Redo"), Status::Done);
+    assert_eq!(status("Scrap"), Status::Cancelled);
+
+    // Where the two agree, pm_status keeps the finer pm status.
+    let (bundle, _) = from_taskwarrior(to_taskwarrior(&db, &tasks));
+    let mut same = Database::default();
+    bundle.import_into(&mut same).unwrap();
+    let redo = same.tasks.iter().find(|t| t.title == "Redo").unwrap();
+    assert_eq!(redo.status, Status::InProgress);
+}
@@ -0,0 +1,183 @@
+//! `pm add --template`: `{title}`, `{date}` and `{project}` in a template's
+//! title and description are filled in per task; templates without
+//! placeholders keep the title as given.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::{Local, NaiveDate};
+use project_management::fields::{Kind, Status};
+use project_management::task::{fill_placeholders, TaskTemplate, TemplateValues};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-template-placeholders-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn template(title_template: Option<&str>) -> TaskTemplate {
+    TaskTemplate {
+        name: "bug".to_string(),
+        title_template: title_template.map(str::to_string),
+        description_template: None,
+        user_story_template: None,
+        requirements_template: None,
+        tags: Vec::new(),
+        kind: Kind::Task,
+        priority_level: None,
+        urgency: None,
+        process_stage: None,
+        status: Status::Open,
+    }
+}
+
+fn values(title: &str) -> TemplateValues<'_> {
+    TemplateValues {
+        title,
+        date: NaiveDate::from_ymd_opt(2025, 3, 4).unwrap(),
+        project: "Atlas",
+    }
+}
+
+#[test]
+fn every_placeholder_is_filled() {
+    assert_eq!(
+        fill_placeholders("{project}: {title} ({date})", &values("crash")),
+        "Atlas: crash (2025-03-04)"
+    );
+    assert_eq!(
+        fill_placeholders("{title} / {title}", &values("x")),
+        "x / x"
+    );
+}
+
+#[test]
+fn unknown_braces_and_substituted_text_are_left_alone() {
+    assert_eq!(
+        fill_placeholders("{owner} {title", &values("x")),
+        "{owner} {title"
+    );
+    assert_eq!(
+        fill_placeholders("[BUG] {title}", &values("shows {date} raw")),
+        "[BUG] shows {date} raw"
+    );
+}
+
+#[test]
+fn title_template_without_a_title_placeholder_keeps_the_given_title() {
+    assert_eq!(
+        template(Some("[BUG] {title}")).fill_title(&values("crash on save")),
+        "[BUG] crash on save"
+    );
+    assert_eq!(
+        template(Some("Saved from a task")).fill_title(&values("crash on save")),
+        "crash on save"
+    );
+    assert_eq!(
+        template(None).fill_title(&values("crash on save")),
+        "crash on save"
+    );
+}
+
+#[test]
+fn add_with_template_fills_title_and_description() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "--kind", "project", "Atlas"]);
+    pm(
+        &pm_dir,
+        &["add", "--kind", "product", "Core", "--parent", "PRJ1"],
+    );
+    pm(
+        &pm_dir,
+        &["add", "--kind", "epic", "Editor", "--parent", "PRD1"],
+    );
+    pm(
+        &pm_dir,
+        &[
+            "template",
+            "create",
+            "bug",
+            "--title-template",
+            "[BUG] {title}",
+            "--description",
+            "Reported {date} against {project}.",
+        ],
+    );
+
+    pm(
+        &pm_dir,
+        &[
+            "add",
+            "crash on save",
+            "--template",
+            "bug",
+            "--parent",
+            "EPC1",
+        ],
+    );
+    let view = pm(&pm_dir, &["view", "TSK1"]);
+    let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+    assert!(view.contains("[BUG] crash on save"), "{view}");
+    assert!(
+        view.contains(&format!("Reported {today} against Atlas.")),
+        "{view}"
+    );
+
+    // An explicit --desc is taken as written.
+    pm(
+        &pm_dir,
+        &[
+            "add",
+            "slow start",
+            "--template",
+            "bug",
+            "--desc",
+            "{title} as typed",
+        ],
+    );
+    let view = pm(&pm_dir, &["view", "TSK2"]);
+    assert!(view.contains("[BUG] slow start"), "{view}");
+    assert!(view.contains("{title} as typed"), "{view}");
+}
+
+#[test]
+fn add_with_a_plain_template_keeps_title_and_description() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(
+        &pm_dir,
+        &["add", "Original", "--desc", "Steps to reproduce"],
+    );
+    pm(&pm_dir, &["template", "save", "TSK1", "plain"]);
+
+    pm(&pm_dir, &["add", "Another bug", "--template", "plain"]);
+    let view = pm(&pm_dir, &["view", "TSK2"]);
+    assert!(view.contains("Another bug"), "{view}");
+    assert!(!view.contains("Original"), "{view}");
+    assert!(view.contains("Steps to reproduce"), "{view}");
+}
@@ -0,0 +1,108 @@
+//! `pm add --template X --tag y`: the given tags are added to the
+//! template's, normalised and deduplicated; `--replace-tags` uses them
+//! instead.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-template-tags-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn tags_of(pm_dir: &Path, id: &str) -> String {
+    let view = pm(pm_dir, &["view", id]);
+    view.lines()
+        .find_map(|line| line.strip_prefix("Tags:"))
+        .map(|tags| tags.trim().to_string())
+        .unwrap_or_else(|| panic!("no Tags line in {view}"))
+}
+
+/// A workspace with a `bug` template tagged `bug,triage`.
+fn workspace() -> PathBuf {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(
+        &pm_dir,
+        &["template", "create", "bug", "--tags", "bug,triage"],
+    );
+    pm_dir
+}
+
+#[test]
+fn tags_merge_with_the_template_and_dedupe() {
+    let pm_dir = workspace();
+    pm(
+        &pm_dir,
+        &["add", "crash", "--template", "bug", "--tag", "ui"],
+    );
+    assert_eq!(tags_of(&pm_dir, "TSK1"), "bug,triage,ui");
+
+    pm(
+        &pm_dir,
+        &[
+            "add",
+            "hang",
+            "--template",
+            "bug",
+            "--tag",
+            "Triage,ui",
+            "--tag",
+            "ui",
+        ],
+    );
+    assert_eq!(tags_of(&pm_dir, "TSK2"), "bug,triage,ui");
+
+    pm(&pm_dir, &["add", "leak", "--template", "bug"]);
+    assert_eq!(tags_of(&pm_dir, "TSK3"), "bug,triage");
+}
+
+#[test]
+fn replace_tags_drops_the_template_tags() {
+    let pm_dir = workspace();
+    pm(
+        &pm_dir,
+        &[
+            "add",
+            "crash",
+            "--template",
+            "bug",
+            "--tag",
+            "ui",
+            "--replace-tags",
+        ],
+    );
+    assert_eq!(tags_of(&pm_dir, "TSK1"), "ui");
+
+    // Nothing to replace them with: the template's tags stay.
+    pm(
+        &pm_dir,
+        &["add", "leak", "--template", "bug", "--replace-tags"],
+    );
+    assert_eq!(tags_of(&pm_dir, "TSK2"), "bug,triage");
+}
@@ -0,0 +1,180 @@
+//! Carrying task templates between workspaces: `pm export --templates-only`
+//! and `pm import --templates [--overwrite]`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use project_management::db::{Database, TemplateMerge};
+use project_management::fields::{Kind, Status};
+use project_management::task::TaskTemplate;
+
+fn template(name: &str, title: &str) -> TaskTemplate {
+    TaskTemplate {
+        name: name.to_string(),
+        title_template: Some(title.to_string()),
+        description_template: None,
+        user_story_template: None,
+        requirements_template: None,
+        tags: Vec::new(),
+        kind: Kind::Task,
+        priority_level: None,
+        urgency: None,
+        process_stage: None,
+        status: Status::Open,
+    }
+}
+
+fn titles(db: &Database) -> Vec<(String, Option<String>)> {
+    db.state
+        .templates
+        .iter()
+        .map(|t| (t.name.clone(), t.title_template.clone()))
+        .collect()
+}
+
+#[test]
+fn merge_adds_new_names_and_skips_collisions() {
+    let mut db = Database::default();
+    db.state.templates.push(template("bug", "Bug: {title}"));
+    let merge = db.merge_templates(
+        vec![
+            template("bug", "Defect: {title}"),
+            template("spike", "Spike"),
+        ],
+        false,
+    );
+    assert_eq!(
+        merge,
+        TemplateMerge {
+            added: vec!["spike".to_string()],
+            replaced: Vec::new(),
+            skipped: vec!["bug".to_string()],
+        }
+    );
+    assert_eq!(
+        titles(&db),
+        vec![
+            ("bug".to_string(), Some("Bug: {title}".to_string())),
+            ("spike".to_string(), Some("Spike".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn overwrite_replaces_collisions_in_place() {
+    let mut db = Database::default();
+    db.state.templates.push(template("bug", "Bug: {title}"));
+    db.state.templates.push(template("chore", "Chore"));
+    let merge = db.merge_templates(vec![template("bug", "Defect: {title}")], true);
+    assert_eq!(merge.replaced, vec!["bug".to_string()]);
+    assert_eq!(
+        titles(&db),
+        vec![
+            ("bug".to_string(), Some("Defect: {title}".to_string())),
+            ("chore".to_string(), Some("Chore".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn repeated_names_in_one_file_collide_with_each_other() {
+    let mut db = Database::default();
+    let merge = db.merge_templates(vec![template("bug", "one"), template("bug", "two")], false);
+    assert_eq!(merge.added, vec!["bug".to_string()]);
+    assert_eq!(merge.skipped, vec!["bug".to_string()]);
+    assert_eq!(
+        titles(&db),
+        vec![("bug".to_string(), Some("one".to_string()))]
+    );
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn tmp_pm_dir(label: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-template-transfer-{label}-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+#[test]
+fn templates_round_trip_between_workspaces() {
+    let source = tmp_pm_dir("source");
+    let target = tmp_pm_dir("target");
+    let file = source.parent().unwrap().join("templates.json");
+    let file_arg = file.to_str().unwrap();
+
+    assert!(pm(&source, &["init"]).status.success());
+    for (name, title) in [("bug", "Bug: {title}"), ("spike", "Spike: {title}")] {
+        assert!(pm(
+            &source,
+            &["template", "create", name, "--title-template", title]
+        )
+        .status
+        .success());
+    }
+    assert!(!pm(&source, &["export", "--templates-only"])
+        .status
+        .success());
+    assert!(pm(
+        &source,
+        &[
+            "export",
+            "--templates-only",
+            "--format",
+            "json",
+            "-o",
+            file_arg
+        ]
+    )
+    .status
+    .success());
+
+    assert!(pm(&target, &["init"]).status.success());
+    assert!(pm(
+        &target,
+        &["template", "create", "bug", "--title-template", "Mine"]
+    )
+    .status
+    .success());
+    let merged = pm(&target, &["import", "--templates", "--no-backup", file_arg]);
+    assert!(merged.status.success());
+    let stdout = String::from_utf8_lossy(&merged.stdout);
+    assert!(
+        stdout.contains("1 added, 0 replaced, 1 skipped"),
+        "{stdout}"
+    );
+    let overwritten = pm(
+        &target,
+        &[
+            "import",
+            "--templates",
+            "--overwrite",
+            "--no-backup",
+            file_arg,
+        ],
+    );
+    let stdout = String::from_utf8_lossy(&overwritten.stdout);
+    assert!(
+        stdout.contains("0 added, 2 replaced, 0 skipped"),
+        "{stdout}"
+    );
+
+    fs::remove_dir_all(source.parent().unwrap()).ok();
+    fs::remove_dir_all(target.parent().unwrap()).ok();
+}
@@ -0,0 +1,92 @@
+//! Word, character and line counts for long text fields in `pm view` and
+//! the fullscreen editor.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use project_management::db::format_text_stats;
+
+#[test]
+fn counts_words_chars_and_lines() {
+    assert_eq!(
+        format_text_stats("Ship the importer.\nThen the exporter."),
+        "6 words, 37 chars, 2 lines, ~1 min read"
+    );
+    assert_eq!(
+        format_text_stats("one"),
+        "1 word, 3 chars, 1 line, ~1 min read"
+    );
+    assert_eq!(
+        format_text_stats(""),
+        "0 words, 0 chars, 0 lines, ~0 min read"
+    );
+}
+
+#[test]
+fn reading_time_rounds_up() {
+    let text = vec!["word"; 201].join(" ");
+    assert!(format_text_stats(&text).ends_with("~2 min read"));
+}
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-text-stats-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+#[test]
+fn view_prints_counts_for_description_and_requirements() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(
+        &pm_dir,
+        &[
+            "add",
+            "Specced",
+            "--desc",
+            "Import CSV files",
+            "--requirements",
+            "Must not drop rows",
+        ],
+    );
+    pm(&pm_dir, &["add", "Bare"]);
+    let out = pm(&pm_dir, &["view", "TSK1"]);
+    assert!(
+        out.contains("Description (3 words, 16 chars, 1 line, ~1 min read):\nImport CSV files\n"),
+        "{out}"
+    );
+    assert!(
+        out.contains(
+            "Requirements (4 words, 18 chars, 1 line, ~1 min read):\nMust not drop rows\n"
+        ),
+        "{out}"
+    );
+    let out = pm(&pm_dir, &["view", "TSK2"]);
+    assert!(out.contains("Description:\n-\n"), "{out}");
+    assert!(!out.contains("Requirements"), "{out}");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
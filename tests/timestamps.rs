@@ -0,0 +1,27 @@
+//! Created/updated stamps in the workflow detail popup: an RFC3339 instant
+//! plus a coarse relative age.
+
+use project_management::db::{format_age, format_timestamp_with_age};
+
+const NOW: i64 = 1_741_824_000; // 2025-03-13T00:00:00Z
+
+#[test]
+fn age_uses_the_largest_whole_unit() {
+    assert_eq!(format_age(NOW - 30, NOW), "just now");
+    assert_eq!(format_age(NOW - 5 * 60, NOW), "5m ago");
+    assert_eq!(format_age(NOW - 3 * 3600, NOW), "3h ago");
+    assert_eq!(format_age(NOW - 3 * 86_400 - 10, NOW), "3d ago");
+}
+
+#[test]
+fn future_timestamps_read_as_just_now() {
+    assert_eq!(format_age(NOW + 600, NOW), "just now");
+}
+
+#[test]
+fn timestamp_pairs_absolute_and_relative() {
+    assert_eq!(
+        format_timestamp_with_age(NOW - 86_400, NOW),
+        "2025-03-12T00:00:00+00:00 (1d ago)"
+    );
+}
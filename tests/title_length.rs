@@ -0,0 +1,103 @@
+//! Long titles: the `titles.max_length` warning on add / update and the
+//! ellipsis wrapping used by workflow cards.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use project_management::db::title_length_warning;
+use project_management::tui::utils::wrap_with_ellipsis;
+
+#[test]
+fn wrap_keeps_short_titles_whole() {
+    assert_eq!(wrap_with_ellipsis("Fix login", 20, 2), vec!["Fix login"]);
+    assert_eq!(
+        wrap_with_ellipsis("Fix the login form", 10, 2),
+        vec!["Fix the", "login form"]
+    );
+}
+
+#[test]
+fn wrap_marks_dropped_text_with_an_ellipsis() {
+    let lines = wrap_with_ellipsis("one two three four five six", 9, 2);
+    assert_eq!(lines, vec!["one two", "three…"]);
+    assert!(lines.iter().all(|l| l.chars().count() <= 9));
+}
+
+#[test]
+fn wrap_breaks_words_wider_than_the_card() {
+    assert_eq!(
+        wrap_with_ellipsis("abcdefghijkl", 5, 2),
+        vec!["abcde", "fghi…"]
+    );
+    assert_eq!(
+        wrap_with_ellipsis("abcdefghij", 5, 2),
+        vec!["abcde", "fghij"]
+    );
+    assert!(wrap_with_ellipsis("anything", 0, 2).is_empty());
+}
+
+#[test]
+fn warning_counts_characters_not_bytes() {
+    assert!(title_length_warning("ééééé", 5).is_none());
+    let w = title_length_warning("éééééé", 5).unwrap();
+    assert!(
+        w.contains("6 characters") && w.contains("titles.max_length"),
+        "{w}"
+    );
+}
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-title-length-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> Output {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    out
+}
+
+#[test]
+fn add_and_update_warn_but_keep_long_titles() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    fs::write(
+        pm_dir.join("config.json"),
+        r#"{ "titles": { "max_length": 10 } }"#,
+    )
+    .unwrap();
+
+    let short = pm(&pm_dir, &["add", "short"]);
+    assert!(!String::from_utf8_lossy(&short.stderr).contains("warning"));
+
+    let long = pm(&pm_dir, &["add", "a rather long title"]);
+    assert!(String::from_utf8_lossy(&long.stderr).contains("19 characters"));
+    let updated = pm(&pm_dir, &["update", "TSK1", "--title", "another long one"]);
+    assert!(String::from_utf8_lossy(&updated.stderr).contains("16 characters"));
+
+    // Piped output is never cut.
+    let list = pm(&pm_dir, &["list"]);
+    let stdout = String::from_utf8_lossy(&list.stdout);
+    assert!(stdout.contains("a rather long title") && stdout.contains("another long one"));
+
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
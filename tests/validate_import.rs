@@ -0,0 +1,133 @@
+//! `pm validate-import`: a read-only report of what `pm import` would trip
+//! over.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-validate-import-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = run(pm_dir, args);
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+const HEADER: &str = "ID,Title,Kind,Status,Priority,Urgency,ProcessStage,Project,Tags,Due,Parent,CreatedUTC,UpdatedUTC,Description";
+
+fn write_file(pm_dir: &Path, name: &str, body: &str) -> String {
+    let path = pm_dir.parent().unwrap().join(name);
+    fs::write(&path, body).unwrap();
+    path.display().to_string()
+}
+
+#[test]
+fn reports_each_problem_by_line_and_imports_nothing() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "Existing"]);
+    pm(&pm_dir, &["add", "Launch", "--kind", "epic"]);
+    let csv = write_file(
+        &pm_dir,
+        "in.csv",
+        &[
+            HEADER,
+            "-,Fine,Task,Open,Must Have,-,Testing,-,a;b,2030-01-01,EPC1,-,-,-",
+            "-,Existing,Task,-,-,-,-,-,-,-,-,-,-,-",
+            "-,Odd cells,Task,Finished,-,-,-,-,-,31/12/2030,TSK9,-,-,-",
+            "-,Too deep,Subtask,-,-,-,-,-,-,-,EPC1,-,-,-",
+            "short,row",
+        ]
+        .join("\n"),
+    );
+
+    let out = run(&pm_dir, &["validate-import", &csv]);
+    assert!(!out.status.success());
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(!stdout.contains("Line 2:"), "{stdout}");
+    assert!(
+        stdout.contains("Line 3: error: a task titled 'Existing' already exists"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("Line 4: warning: unrecognised Status 'Finished'"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("Line 4: warning: unrecognised Due '31/12/2030'"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("Line 4: error: parent TSK9 does not exist"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("Line 5: error: Subtask cannot be a child of Epic EPC1"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("Line 6: error: 2 fields, expected 14"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("5 rows, 4 errors, 2 warnings."), "{stdout}");
+
+    let list = pm(&pm_dir, &["list", "--format", "plain", "--no-header"]);
+    assert_eq!(list.lines().count(), 2, "{list}");
+    assert!(!pm_dir.join("snapshots").exists());
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn clean_files_pass() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    let csv = write_file(
+        &pm_dir,
+        "ok.csv",
+        &format!("{HEADER}\n-,One,Task,Open,-,-,-,-,-,-,-,-,-,-\n"),
+    );
+    let out = pm(&pm_dir, &["validate-import", &csv]);
+    assert!(out.contains("1 rows, no problems found."), "{out}");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn bad_headers_and_bundles_are_errors() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    let csv = write_file(&pm_dir, "bad.csv", "Title,Status\nx,Open\n");
+    let out = run(&pm_dir, &["validate-import", &csv]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stdout).contains("Line 1: error: invalid header"));
+
+    let json = write_file(&pm_dir, "bad.json", "{ \"version\": 1 }");
+    let out = run(&pm_dir, &["validate-import", &json]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stdout).contains("error: not a task bundle"));
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,162 @@
+//! `pm stats --velocity`: tasks completed in each ISO week, oldest first,
+//! with empty weeks kept and the average over the window.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::{Duration, Local, NaiveDate, Utc};
+use project_management::db::{velocity, WeekVelocity};
+use project_management::fields::Status;
+use project_management::task::Task;
+
+mod common;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-velocity-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn done_on(n: u64, date: NaiveDate) -> Task {
+    let at = date.and_hms_opt(12, 0, 0).unwrap().and_utc().timestamp();
+    Task {
+        completed_at_utc: Some(at),
+        status: Status::Done,
+        created_at_utc: at,
+        updated_at_utc: at,
+        ..common::task(format!("TSK{n}").parse().unwrap(), &format!("Task {n}"))
+    }
+}
+
+#[test]
+fn weeks_run_monday_to_sunday_and_empty_ones_stay() {
+    // 2026-10-16 is a Friday; its week starts Monday 2026-10-12.
+    let tasks = [
+        done_on(1, day(2026, 10, 12)),
+        done_on(2, day(2026, 10, 11)),
+        done_on(3, day(2026, 9, 28)),
+        done_on(4, day(2026, 10, 4)),
+        done_on(5, day(2026, 1, 1)),
+    ];
+    let mut open = done_on(6, day(2026, 10, 14));
+    open.status = Status::Open;
+    let refs: Vec<&Task> = tasks.iter().chain([&open]).collect();
+
+    let report = velocity(&refs, 3, day(2026, 10, 16), &Utc);
+    assert_eq!(
+        report,
+        vec![
+            WeekVelocity {
+                week_start: day(2026, 9, 28),
+                completed: 2,
+            },
+            WeekVelocity {
+                week_start: day(2026, 10, 5),
+                completed: 1,
+            },
+            WeekVelocity {
+                week_start: day(2026, 10, 12),
+                completed: 1,
+            },
+        ]
+    );
+    assert_eq!(
+        velocity(&[], 2, day(2026, 10, 16), &Utc)
+            .iter()
+            .map(|w| w.completed)
+            .sum::<usize>(),
+        0
+    );
+}
+
+#[test]
+fn stats_velocity_charts_weeks_and_composes_with_project() {
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    pm(&pm_dir, &["add", "--kind", "project", "Atlas"]);
+    pm(
+        &pm_dir,
+        &["add", "--kind", "product", "Core", "--parent", "PRJ1"],
+    );
+    pm(
+        &pm_dir,
+        &["add", "--kind", "epic", "Editor", "--parent", "PRD1"],
+    );
+    pm(&pm_dir, &["add", "Ship it", "--parent", "EPC1"]);
+    pm(&pm_dir, &["add", "Fix it", "--parent", "EPC1"]);
+    pm(&pm_dir, &["add", "Elsewhere"]);
+    let last_week = (Local::now().date_naive() - Duration::weeks(1))
+        .format("%Y-%m-%d")
+        .to_string();
+    pm(&pm_dir, &["complete", "TSK1"]);
+    pm(&pm_dir, &["complete", "TSK2", "--at", &last_week]);
+    pm(&pm_dir, &["complete", "TSK3"]);
+
+    let out = pm(&pm_dir, &["stats", "--velocity", "--weeks", "4"]);
+    let rows: Vec<&str> = out.lines().skip(1).take(4).collect();
+    assert_eq!(rows.len(), 4, "{out}");
+    assert!(rows[0].ends_with(" 0"), "{out}");
+    assert!(rows[2].ends_with(" 1 #"), "{out}");
+    assert!(rows[3].ends_with(" 2 ##"), "{out}");
+    assert!(
+        out.contains("Average: 0.8 per week over 4 weeks (3 completed)"),
+        "{out}"
+    );
+
+    let atlas = pm(
+        &pm_dir,
+        &["stats", "--velocity", "--weeks", "4", "--project", "Atlas"],
+    );
+    assert!(atlas.contains("(2 completed)"), "{atlas}");
+}
+
+#[test]
+fn oversized_windows_are_refused_or_cut_short_instead_of_panicking() {
+    let near_start = NaiveDate::MIN + Duration::weeks(2);
+    let report = velocity(&[], 20_000_000, near_start, &Utc);
+    assert!(!report.is_empty() && report.len() <= 3, "{report:?}");
+    assert!(report.windows(2).all(|w| w[0].week_start < w[1].week_start));
+
+    let pm_dir = tmp_pm_dir();
+    pm(&pm_dir, &["init"]);
+    for weeks in ["20000000", "0"] {
+        let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+            .arg("--db")
+            .arg(&pm_dir)
+            .args(["stats", "--velocity", "--weeks", weeks])
+            .output()
+            .expect("invoke pm binary");
+        assert_eq!(out.status.code(), Some(2), "--weeks {weeks}");
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(stderr.contains("--weeks"), "{stderr}");
+        assert!(!stderr.contains("panicked"), "{stderr}");
+    }
+}
@@ -0,0 +1,93 @@
+//! Global `-q` / `-v`: confirmations dropped, or workspace and task
+//! resolution noted on stderr.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-verbosity-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn run(pm_dir: &Path, args: &[&str]) -> Output {
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap())
+        .output()
+        .expect("invoke pm binary");
+    assert!(
+        out.status.success(),
+        "pm {args:?} failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    out
+}
+
+fn stdout(out: &Output) -> String {
+    String::from_utf8_lossy(&out.stdout).into_owned()
+}
+
+fn stderr(out: &Output) -> String {
+    String::from_utf8_lossy(&out.stderr).into_owned()
+}
+
+#[test]
+fn quiet_drops_confirmations_but_not_output() {
+    let pm_dir = tmp_pm_dir();
+    run(&pm_dir, &["init", "-q"]);
+    assert_eq!(stdout(&run(&pm_dir, &["-q", "add", "Write spec"])), "");
+    assert_eq!(stdout(&run(&pm_dir, &["complete", "TSK1", "--quiet"])), "");
+    assert!(stdout(&run(&pm_dir, &["-q", "view", "TSK1"])).contains("Title:        Write spec"));
+    assert_eq!(
+        stdout(&run(&pm_dir, &["add", "Review"])),
+        "Added task TSK2\n"
+    );
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn verbose_names_the_workspace_and_resolved_task() {
+    let pm_dir = tmp_pm_dir();
+    run(&pm_dir, &["init"]);
+    run(&pm_dir, &["add", "Write spec"]);
+    let out = run(
+        &pm_dir,
+        &["-v", "update", "write SPEC", "--due", "2030-01-01"],
+    );
+    let err = stderr(&out);
+    assert!(
+        err.contains(&format!("Workspace: {} (--db)", pm_dir.display())),
+        "{err}"
+    );
+    assert!(
+        err.contains("Resolved 'write SPEC' to TSK1: Task \"Write spec\" (Open, -, "),
+        "{err}"
+    );
+    assert_eq!(stdout(&out), "Updated task TSK1\n");
+    assert_eq!(stderr(&run(&pm_dir, &["view", "TSK1"])), "");
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
+
+#[test]
+fn quiet_and_verbose_conflict() {
+    let pm_dir = tmp_pm_dir();
+    let out = Command::new(env!("CARGO_BIN_EXE_pm"))
+        .arg("--db")
+        .arg(&pm_dir)
+        .args(["-q", "-v", "list"])
+        .output()
+        .expect("invoke pm binary");
+    assert!(!out.status.success());
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}
@@ -0,0 +1,28 @@
+//! `ui.wrap_navigation`: Up on the first row goes to the last and Down on
+//! the last goes to the first; without it the cursor stops at the ends.
+
+use project_management::tui::utils::step_index;
+
+#[test]
+fn stops_at_the_ends_by_default() {
+    assert_eq!(step_index(0, 3, false, false), 0);
+    assert_eq!(step_index(2, 3, true, false), 2);
+    assert_eq!(step_index(1, 3, true, false), 2);
+    assert_eq!(step_index(1, 3, false, false), 0);
+}
+
+#[test]
+fn wraps_round_when_enabled() {
+    assert_eq!(step_index(0, 3, false, true), 2);
+    assert_eq!(step_index(2, 3, true, true), 0);
+    assert_eq!(step_index(1, 3, true, true), 2);
+    assert_eq!(step_index(0, 1, true, true), 0);
+}
+
+#[test]
+fn empty_and_shrunk_lists_stay_in_range() {
+    assert_eq!(step_index(0, 0, true, true), 0);
+    assert_eq!(step_index(0, 0, false, true), 0);
+    assert_eq!(step_index(0, 0, true, false), 0);
+    assert_eq!(step_index(5, 3, true, false), 2);
+}
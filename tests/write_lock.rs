@@ -0,0 +1,201 @@
+//! The workspace write lock: concurrent writers serialise instead of
+//! overwriting each other, a lock held elsewhere fails cleanly, and readers
+//! and prompts don't hold it. A TUI-style session that saves after a CLI
+//! write keeps that write.
+
+mod common;
+
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+use project_management::db::Database;
+use project_management::store::TypePrefix;
+
+fn tmp_pm_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "pm-write-lock-{}-{}",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos(),
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(".pm")
+}
+
+fn pm_cmd(pm_dir: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_pm"));
+    cmd.arg("--db")
+        .arg(pm_dir)
+        .args(args)
+        .current_dir(pm_dir.parent().unwrap());
+    cmd
+}
+
+fn pm(pm_dir: &Path, args: &[&str]) -> Output {
+    pm_cmd(pm_dir, args).output().expect("invoke pm binary")
+}
+
+#[test]
+fn concurrent_adds_are_all_kept() {
+    let pm_dir = tmp_pm_dir();
+    assert!(pm(&pm_dir, &["init"]).status.success());
+
+    let titles: Vec<String> = (1..=8).map(|n| format!("Writer {n}")).collect();
+    let children: Vec<_> = titles
+        .iter()
+        .map(|t| pm_cmd(&pm_dir, &["add", t]).spawn().expect("spawn pm"))
+        .collect();
+    for child in children {
+        let out = child.wait_with_output().unwrap();
+        assert!(out.status.success());
+    }
+
+    let list = pm(&pm_dir, &["list", "--all", "--format", "plain"]);
+    let list = String::from_utf8_lossy(&list.stdout);
+    for t in &titles {
+        assert!(list.contains(t.as_str()), "lost '{t}':\n{list}");
+    }
+    assert_eq!(list.lines().count(), titles.len() + 1);
+}
+
+#[test]
+fn a_held_lock_is_reported_then_released() {
+    let pm_dir = tmp_pm_dir();
+    assert!(pm(&pm_dir, &["init"]).status.success());
+
+    let lock = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(pm_dir.join("write.lock"))
+        .unwrap();
+    lock.lock().unwrap();
+    let out = pm(&pm_dir, &["add", "Blocked out"]);
+    assert!(!out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("another pm process is writing to this workspace"),
+        "{stderr}"
+    );
+
+    lock.unlock().unwrap();
+    assert!(pm(&pm_dir, &["add", "Let in"]).status.success());
+}
+
+#[test]
+fn readers_do_not_wait_for_a_held_lock() {
+    let pm_dir = tmp_pm_dir();
+    assert!(pm(&pm_dir, &["init"]).status.success());
+    assert!(pm(&pm_dir, &["add", "Readable"]).status.success());
+
+    let lock = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(pm_dir.join("write.lock"))
+        .unwrap();
+    lock.lock().unwrap();
+    let started = std::time::Instant::now();
+    let out = pm(&pm_dir, &["list", "--format", "plain"]);
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(String::from_utf8_lossy(&out.stdout).contains("Readable"));
+    assert!(started.elapsed() < std::time::Duration::from_secs(4));
+    lock.unlock().unwrap();
+}
+
+#[test]
+fn a_prompt_leaves_the_lock_free_and_will_not_overwrite_a_save() {
+    let pm_dir = tmp_pm_dir();
+    assert!(pm(&pm_dir, &["init"]).status.success());
+    assert!(pm(&pm_dir, &["add", "Twin"]).status.success());
+    assert!(pm(&pm_dir, &["add", "Twin"]).status.success());
+
+    let mut dedupe = pm_cmd(&pm_dir, &["dedupe", "--no-backup"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("spawn pm");
+    let mut stdout = dedupe.stdout.take().unwrap();
+    let mut seen = Vec::new();
+    let mut byte = [0u8; 1];
+    while !String::from_utf8_lossy(&seen).contains("keep which?") {
+        assert_eq!(stdout.read(&mut byte).unwrap(), 1, "dedupe never prompted");
+        seen.push(byte[0]);
+    }
+
+    // dedupe is waiting on stdin; another writer gets straight in.
+    let out = pm(&pm_dir, &["add", "Meanwhile"]);
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+
+    dedupe.stdin.take().unwrap().write_all(b"TSK1\n").unwrap();
+    let out = dedupe.wait_with_output().unwrap();
+    assert!(!out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("workspace changed while pm was waiting"),
+        "{stderr}"
+    );
+
+    let list = pm(&pm_dir, &["list", "--all", "--format", "plain"]);
+    let list = String::from_utf8_lossy(&list.stdout);
+    assert!(list.contains("Meanwhile"), "{list}");
+    assert_eq!(list.matches("Twin").count(), 2, "{list}");
+}
+
+#[test]
+fn a_session_save_keeps_a_cli_write_made_since_it_loaded() {
+    let pm_dir = tmp_pm_dir();
+    assert!(pm(&pm_dir, &["init"]).status.success());
+    assert!(pm(&pm_dir, &["add", "Existing"]).status.success());
+
+    // What the TUI does: load, edit in memory, save later.
+    let mut db = Database::load_guarded(&pm_dir);
+    let base = db.clone();
+    let existing = db.tasks[0].id;
+    db.get_mut(existing).unwrap().title = "Edited in the TUI".into();
+    let added = db.allocate_id(TypePrefix::Task);
+    db.tasks.push(common::task(added, "Added in the TUI"));
+
+    let cli = pm(&pm_dir, &["add", "Added by the CLI", "--print-id"]);
+    assert!(cli.status.success());
+    let cli_id = String::from_utf8_lossy(&cli.stdout).trim().to_string();
+    assert_eq!(cli_id, added.to_string(), "both sides minted the same id");
+
+    db.save_since(&pm_dir, &base).unwrap();
+
+    let after = Database::load(&pm_dir);
+    let titles: Vec<(String, &str)> = after
+        .tasks
+        .iter()
+        .map(|t| (t.id.to_string(), t.title.as_str()))
+        .collect();
+    assert_eq!(after.get(existing).unwrap().title, "Edited in the TUI");
+    assert!(titles.contains(&(cli_id, "Added by the CLI")), "{titles:?}");
+    let renumbered = after
+        .tasks
+        .iter()
+        .find(|t| t.title == "Added in the TUI")
+        .expect("TUI task kept");
+    assert_ne!(renumbered.id, added);
+
+    let next = pm(&pm_dir, &["add", "After both", "--print-id"]);
+    let next_id = String::from_utf8_lossy(&next.stdout).trim().to_string();
+    assert!(
+        after.get(next_id.parse().unwrap()).is_none(),
+        "{next_id} handed out again"
+    );
+    fs::remove_dir_all(pm_dir.parent().unwrap()).ok();
+}